@@ -6,6 +6,141 @@ fn main() {
         .force_build(true)
         .unit_test()
         .log_debug()
-        .process_current_dir()
+        .use_cargo_dir_conventions()
+        .process_dir("src")
+        .unwrap();
+
+    // a grammar can also be checked for conflicts without generating
+    // a parser, e.g. to fail a build when an edit introduces one
+    lalrpop::assert_no_conflicts(include_str!("src/expr.lalrpop")).unwrap();
+
+    // The grammars that need options of their own live in `options`
+    // rather than `src`, so that the call above does not generate them
+    // a second time.
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+
+    lalrpop::Configuration::new()
+        .emit_dump_helpers(true)
+        .emit_boxed_errors(true)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/dump.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .emit_extern_crate(false)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/no_extern_crate.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .byte_input(true)
+        .emit_tokenizers(true)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/bytes.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .force_build(true)
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/counted_location.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .emit_item_parsers(true)
+        .force_build(true)
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/items.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .emit_prefix_parsers(true)
+        .force_build(true)
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/prefix.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .user_error_spans(true)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/user_error_spans.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .unicode_classes(true)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/unicode_classes.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .emit_tokenizers(true)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/tokenize.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .emit_state_trace(true)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/state_trace.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .emit_state_trace(true)
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/state_trace_ascent.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .set_prefix("gen_")
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(&out_dir)
+        .process_file("options/custom_prefix.lalrpop")
+        .unwrap();
+
+    // One grammar, two dialects: each feature set gets its own parser.
+    lalrpop::Configuration::new()
+        .set_features(vec!["dialect-neg".to_string()])
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(format!("{}/dialect_neg", out_dir))
+        .process_file("options/dialect.lalrpop")
+        .unwrap();
+
+    lalrpop::Configuration::new()
+        .set_features(vec!["dialect-call".to_string()])
+        .force_build(true)
+        .unit_test()
+        .set_in_dir("options")
+        .set_out_dir(format!("{}/dialect_call", out_dir))
+        .process_file("options/dialect.lalrpop")
         .unwrap();
 }
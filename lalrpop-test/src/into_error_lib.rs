@@ -0,0 +1,13 @@
+use lalrpop_util::ParseError;
+
+#[derive(Debug, PartialEq)]
+pub enum CalcError {
+    Parse(ParseError<usize, String, &'static str>),
+    TooLarge(i32),
+}
+
+impl From<ParseError<usize, String, &'static str>> for CalcError {
+    fn from(error: ParseError<usize, String, &'static str>) -> Self {
+        CalcError::Parse(error)
+    }
+}
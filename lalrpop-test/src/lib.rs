@@ -78,6 +78,10 @@ lalrpop_mod!(loc);
 lalrpop_mod!(loc_issue_90);
 mod loc_issue_90_lib;
 
+/// test for the `From` impl generated for `type IntoError`
+lalrpop_mod!(into_error);
+mod into_error_lib;
+
 /// tests that user can use `<mut v:E+> <e:T> => { v.push(e); v }` instead of
 /// `<v:E+> <e:T> => { let mut v = v; v.push(e); v }`
 lalrpop_mod!(mut_name);
@@ -145,8 +149,84 @@ lalrpop_mod!(
 
 lalrpop_mod!(comments);
 
+/// test for comments that nest
+lalrpop_mod!(nested_comments);
+
 lalrpop_mod!(sp_from_optional);
 
+/// test for collecting warnings from actions
+lalrpop_mod!(warnings);
+
+/// test for generating modules that refer to `::lalrpop_util` instead
+/// of declaring `extern crate`, included below the crate root
+mod nested {
+    lalrpop_mod!(pub no_extern_crate);
+}
+
+/// test for right-recursive expansion of `*` and `+`
+lalrpop_mod!(right_recursive);
+
+/// test for interning identifiers into a `StringTable` while parsing
+lalrpop_mod!(intern);
+
+/// test for an external lexer with `(line, column)` locations
+lalrpop_mod!(line_col_location);
+
+/// test for the number of location clones made by the parser
+lalrpop_mod!(counted_location);
+
+/// test for the `parse_items` method generated by `emit_item_parsers`
+lalrpop_mod!(items);
+
+/// test for the `parse_prefix` method generated by `emit_prefix_parsers`
+lalrpop_mod!(prefix);
+
+/// test for keywords taking priority over identifiers of the same length
+lalrpop_mod!(keyword_priority);
+
+/// test for the built-in `Comma`, `Sep` and `NonEmpty` macros
+lalrpop_mod!(builtin_macros);
+
+/// test for `User` errors with spans, generated by `user_error_spans`
+lalrpop_mod!(user_error_spans);
+
+/// test for regex terminals left unexpanded by `unicode_classes`
+lalrpop_mod!(unicode_classes);
+
+/// test for terminal names given with `#[display]`
+lalrpop_mod!(display_names);
+
+/// test for case-insensitive `i"..."` literals
+lalrpop_mod!(case_insensitive);
+
+/// test for tokenizers that read `&[u8]`, generated by `byte_input`
+lalrpop_mod!(bytes);
+
+/// test for the `tokenize` method generated by `emit_tokenizers`
+lalrpop_mod!(tokenize);
+
+/// test for conflicts resolved by `#[prefer_shift]` and `#[prefer_reduce]`
+lalrpop_mod!(prefer);
+
+/// test for the state tracing generated by `emit_state_trace`
+lalrpop_mod!(state_trace);
+lalrpop_mod!(state_trace_ascent);
+
+/// test for productions enabled by `#[cfg]` features: one grammar,
+/// generated once per dialect
+lalrpop_mod!(dialect_neg, "/dialect_neg/dialect.rs");
+lalrpop_mod!(dialect_call, "/dialect_call/dialect.rs");
+
+/// test for generated names that use the prefix given to `set_prefix`
+lalrpop_mod!(custom_prefix);
+
+/// test for keywords marked `#[boundary]`
+lalrpop_mod!(boundary);
+
+/// test for the `dump` and `parse_boxed` helpers generated by
+/// `emit_dump_helpers` and `emit_boxed_errors`
+lalrpop_mod!(dump);
+
 pub fn use_cfg_created_parser() {
     cfg::CreatedParser::new();
 }
@@ -1020,6 +1100,34 @@ fn comments() {
     );
 }
 
+#[test]
+fn comments_keep_locations() {
+    // skipped text still advances the location of later tokens
+    assert_eq!(
+        comments::TermParser::new().parse("  22 \t\n  3  ").unwrap(),
+        vec!["22", "3"]
+    );
+    assert_eq!(
+        comments::TermParser::new().parse("22  /* x */  3  *  5"),
+        Err(ParseError::InvalidToken { location: 16 })
+    );
+}
+
+#[test]
+fn nested_comments() {
+    assert_eq!(
+        nested_comments::TermParser::new()
+            .parse("22 /* 1 /* 2 /* 3 */ 4 */ 5 */ 3 /**/ 5 /* /* */ */ 13")
+            .unwrap(),
+        vec!["22", "3", "5", "13"]
+    );
+
+    assert_eq!(
+        nested_comments::TermParser::new().parse("22 /* 1 /* 2 */ 3"),
+        Err(ParseError::InvalidToken { location: 3 })
+    );
+}
+
 #[test]
 fn sp_from_optional() {
     assert_eq!(
@@ -1029,3 +1137,497 @@ fn sp_from_optional() {
         (9, "let", 12)
     );
 }
+
+#[test]
+fn warnings() {
+    let mut warnings = vec![];
+    let sum = warnings::SumParser::new()
+        .parse(&mut warnings, "1 + 2 plus 3")
+        .unwrap();
+    assert_eq!(sum, 6);
+    assert_eq!(warnings, vec![(6, "`plus` is deprecated, use `+`")]);
+}
+
+#[test]
+fn dump_helper() {
+    let parser = dump::ListParser::new();
+    assert_eq!(parser.dump("[1, 2, 3]"), format!("{:#?}", vec![1, 2, 3]));
+    assert!(parser.dump("[1, 2").starts_with("UnrecognizedEOF"));
+}
+
+#[test]
+fn boxed_errors() {
+    fn sum(input: &str) -> Result<i32, Box<dyn std::error::Error>> {
+        let list = dump::ListParser::new().parse_boxed(input)?;
+        Ok(list.iter().sum())
+    }
+    assert_eq!(sum("[1, 2, 3]").unwrap(), 6);
+    let err = sum("[1, 2").unwrap_err();
+    assert!(err.to_string().starts_with("Unrecognized EOF"));
+}
+
+#[test]
+fn intern_identifiers() {
+    let mut table = lalrpop_util::intern::StringTable::new();
+    let decls = intern::DeclsParser::new()
+        .parse(&mut table, "let x = y; let y = x;")
+        .unwrap();
+    assert_eq!(table.len(), 2);
+    let (x, y) = decls[0];
+    assert_eq!((table.resolve(x), table.resolve(y)), ("x", "y"));
+    assert_eq!(decls[1], (y, x));
+}
+
+#[test]
+fn right_recursive_repetitions() {
+    let parser = right_recursive::ListsParser::new();
+    assert_eq!(parser.parse("[] [1 2 3]").unwrap(), (vec![], vec![1, 2, 3]));
+    assert_eq!(parser.parse("[4 5] [6]").unwrap(), (vec![4, 5], vec![6]));
+    assert!(parser.parse("[1] []").is_err());
+}
+
+#[test]
+fn no_extern_crate() {
+    let parser = nested::no_extern_crate::SumParser::new();
+    assert_eq!(parser.parse("1 + 2 + 3").unwrap(), 6);
+}
+
+#[test]
+fn no_extern_crate_in_fn() {
+    // the same output, included inside a function body
+    lalrpop_mod!(no_extern_crate);
+
+    let parser = no_extern_crate::SumParser::new();
+    assert_eq!(parser.parse("1 + 2 + 3").unwrap(), 6);
+}
+
+#[test]
+fn byte_input() {
+    let parser = bytes::PairsParser::new();
+    assert_eq!(
+        parser.parse(b"a=1; bc = 23;").unwrap(),
+        vec![(&b"a"[..], &b"1"[..]), (&b"bc"[..], &b"23"[..])]
+    );
+
+    // the input need not be valid UTF-8, and locations are byte offsets
+    match parser.parse(b"a=1;\xff") {
+        Err(ParseError::InvalidToken { location }) => assert_eq!(location, 4),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let tokens: Vec<_> = parser
+        .tokenize(b"a=1;")
+        .map(|r| {
+            let (start, token, end) = r.unwrap();
+            (start, parser.terminal_name(&token), end)
+        })
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            (0, r##"r#"[a-z0-9]+"#"##, 1),
+            (1, r#""=""#, 2),
+            (2, r##"r#"[a-z0-9]+"#"##, 3),
+            (3, r#"";""#, 4),
+        ]
+    );
+}
+
+#[test]
+fn tokenize() {
+    let parser = tokenize::ProductParser::new();
+    let tokens: Vec<_> = parser
+        .tokenize("a*b * 12")
+        .map(|r| {
+            let (start, token, end) = r.unwrap();
+            (start, parser.terminal_name(&token), token.1, end)
+        })
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            (0, r##"r#"[a-z]+"#"##, "a", 1),
+            (1, r#""*""#, "*", 2),
+            (2, r##"r#"[a-z]+"#"##, "b", 3),
+            (4, r#""*""#, "*", 5),
+            (6, "NUM", "12", 8),
+        ]
+    );
+
+    // the lexer reports errors the same way as when parsing
+    match parser.tokenize("a $").nth(1) {
+        Some(Err(ParseError::InvalidToken { location })) => assert_eq!(location, 2),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    // or skips over what it does not recognize
+    let mut tokens = parser.tokenize("a?b").skip_errors();
+    let names: Vec<_> = tokens
+        .by_ref()
+        .map(|r| parser.terminal_name(&r.unwrap().1))
+        .collect();
+    assert_eq!(names, vec![r##"r#"[a-z]+"#"##, r##"r#"[a-z]+"#"##]);
+    assert_eq!(tokens.lex_errors(), &[(1, 2)]);
+}
+
+#[test]
+fn parse_tokens() {
+    let parser = tokenize::ProductParser::new();
+    let tokens = |input| -> Vec<_> { parser.tokenize(input).map(|r| r.unwrap()).collect() };
+
+    assert_eq!(parser.parse_tokens("a*b", tokens("a*b")), Ok(()));
+
+    // tokens that the lexer would never produce for the input
+    let mut missing_operator = tokens("a*b");
+    missing_operator.remove(1);
+    match parser.parse_tokens("a*b", missing_operator) {
+        Err(ParseError::UnrecognizedToken {
+            token: (2, tokenize::Token(_, "b"), 3),
+            expected,
+        }) => assert_eq!(expected, vec![r#""*""#]),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let mut missing_operand = tokens("a*b");
+    missing_operand.truncate(2);
+    match parser.parse_tokens("a*b", missing_operand) {
+        Err(ParseError::UnrecognizedEOF { location: 2, .. }) => {}
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    let mut leading_operator = tokens("a*b");
+    leading_operator.remove(0);
+    match parser.parse_tokens("a*b", leading_operator) {
+        Err(ParseError::UnrecognizedToken {
+            token: (1, tokenize::Token(_, "*"), 2),
+            ..
+        }) => {}
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    match parser.parse_tokens("a*b", vec![]) {
+        Err(ParseError::UnrecognizedEOF { location: 0, .. }) => {}
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn case_insensitive_literals() {
+    let parser = case_insensitive::QueryParser::new();
+    for input in &["select x", "Select x", "SELECT x"] {
+        // the token is spelled as in the grammar, whatever the input
+        assert_eq!(parser.parse(input).unwrap(), ("select", "x", None));
+    }
+    assert_eq!(
+        parser.parse("select x WHERE y").unwrap(),
+        ("select", "x", Some("y"))
+    );
+    assert!(parser.parse("selectx x").is_err());
+}
+
+#[test]
+fn counted_location() {
+    let tokens: Vec<_> = (0..3)
+        .map(|i| (util::CountedLoc(i), Tok::Plus, util::CountedLoc(i + 1)))
+        .collect();
+    let before = util::location_clones();
+    assert_eq!(counted_location::CountParser::new().parse(tokens), Ok(3));
+    // One clone per token, for the location of an unexpected EOF, and
+    // two per reduction that pushes a nonterminal (one `Count = ()` and
+    // three `Count = Count "+"`); the final reduction clones nothing.
+    assert_eq!(util::location_clones() - before, 3 + 2 * 4);
+}
+
+#[test]
+fn parse_items() {
+    let parser = items::SumParser::new();
+
+    let mut items = parser.parse_items("1 + 2 3 4 +\n5  ");
+    assert_eq!(items.next_item(), Some(Ok(3)));
+    assert_eq!(items.next_item(), Some(Ok(3)));
+    assert_eq!(items.next_item(), Some(Ok(9)));
+    assert_eq!(items.next_item(), None);
+    assert_eq!(items.next_item(), None);
+
+    // only whitespace: no items at all
+    assert_eq!(parser.parse_items("  ").next_item(), None);
+
+    // an error ends the sequence
+    let results: Vec<_> = parser.parse_items("1 2 + + 3 4").collect();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0], Ok(1));
+    match results[1] {
+        Err(ParseError::UnrecognizedToken {
+            token: (6, items::Token(_, "+"), 7),
+            ..
+        }) => {}
+        ref r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn parse_prefix() {
+    let parser = prefix::ProductParser::new();
+    assert_eq!(
+        parser.parse_prefix("a*b extra").unwrap(),
+        ("(a*b)".to_string(), 3)
+    );
+    assert!(parser.parse("a*b extra").is_err());
+
+    // the offset is the end of the last token consumed, before any
+    // whitespace that follows it
+    assert_eq!(parser.parse_prefix("a * b  c*d").unwrap().1, 5);
+    assert_eq!(
+        parser.parse_prefix("a*b  ").unwrap(),
+        ("(a*b)".to_string(), 3)
+    );
+
+    // text the lexer does not recognize also ends the prefix, so that
+    // it can be handed to another parser
+    assert_eq!(
+        parser.parse_prefix("a*b ; c").unwrap(),
+        ("(a*b)".to_string(), 3)
+    );
+    assert_eq!(parser.parse_prefix("a*b 1").unwrap().1, 3);
+    assert_eq!(
+        parser.parse_prefix("a* ;"),
+        Err(ParseError::InvalidToken { location: 3 })
+    );
+
+    // a token that can continue the prefix is consumed, so the prefix
+    // must still parse with it
+    assert!(parser.parse_prefix("a*b *").is_err());
+    assert!(parser.parse_prefix("*a").is_err());
+}
+
+#[test]
+fn keyword_priority() {
+    let parser = keyword_priority::StmtsParser::new();
+    assert_eq!(
+        parser.parse("if iffy iffy if ifs").unwrap(),
+        vec!["if iffy", "iffy", "if ifs"]
+    );
+    assert!(parser.parse("iffy if").is_err());
+}
+
+#[test]
+fn owned_results_outlive_input() {
+    fn parse_static(input: String) -> Vec<String> {
+        let parser = keyword_priority::StmtsParser::new();
+        let stmts = parser.parse(&input).unwrap();
+        drop(input);
+        stmts
+    }
+    fn assert_static<T: 'static>(_: &T) {}
+
+    let stmts = parse_static("if x y".to_string());
+    assert_static(&stmts);
+    assert_eq!(stmts, vec!["if x", "y"]);
+}
+
+#[test]
+fn builtin_macros() {
+    let parser = builtin_macros::IdentsParser::new();
+    assert_eq!(parser.parse("a, b, c").unwrap(), vec!["a", "b", "c"]);
+    assert_eq!(parser.parse("a, b, c,").unwrap(), vec!["a", "b", "c"]);
+    assert_eq!(parser.parse("").unwrap(), Vec::<&str>::new());
+
+    let parser = builtin_macros::PathParser::new();
+    assert_eq!(parser.parse("std::vec").unwrap(), vec!["std", "vec"]);
+
+    let parser = builtin_macros::ArgsParser::new();
+    assert_eq!(parser.parse("(a, b)").unwrap(), vec!["a", "b"]);
+    assert!(parser.parse("()").is_err());
+}
+
+#[test]
+fn user_error_spans() {
+    let parser = user_error_spans::ItemsParser::new();
+    assert_eq!(parser.parse("1 (2 + 3)").unwrap(), vec![1, 5]);
+
+    // an action with a single symbol
+    assert_eq!(
+        parser.parse("1 234 5"),
+        Err(ParseError::UserWithSpan {
+            error: "number too big",
+            span: (2, 5),
+        })
+    );
+
+    // an action with several symbols spans all of them
+    assert_eq!(
+        parser.parse("1 (50 + 50)"),
+        Err(ParseError::UserWithSpan {
+            error: "sum too big",
+            span: (2, 11),
+        })
+    );
+}
+
+#[test]
+fn line_col_location() {
+    let parser = line_col_location::ItemsParser::new();
+    let tokens = vec![((1, 0), Tok::Plus, (1, 1)), ((2, 4), Tok::Plus, (2, 5))];
+    assert_eq!(
+        parser.parse(tokens).unwrap(),
+        vec![((1, 0), (1, 1)), ((2, 4), (2, 5))]
+    );
+
+    // errors carry the same location type
+    let tokens = vec![((1, 0), Tok::Plus, (1, 1)), ((3, 2), Tok::Minus, (3, 3))];
+    match parser.parse(tokens) {
+        Err(ParseError::UnrecognizedToken {
+            token: ((3, 2), Tok::Minus, (3, 3)),
+            ..
+        }) => {}
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn unicode_classes() {
+    assert_eq!(
+        unicode_classes::IdentsParser::new()
+            .parse("héllo wörld_2 ι")
+            .unwrap(),
+        vec!["héllo", "wörld_2", "ι"]
+    );
+    assert!(unicode_classes::IdentsParser::new().parse("2x").is_err());
+
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/unicode_classes.rs"));
+    assert!(generated.contains(r"\\p{XID_Start}\\p{XID_Continue}*"));
+}
+
+#[test]
+fn display_names() {
+    let parser = display_names::SumParser::new();
+    assert_eq!(parser.parse("1 + 2 * 3"), Ok(7));
+
+    match parser.parse("1 +") {
+        Err(ParseError::UnrecognizedEOF { expected, .. }) => {
+            assert_eq!(expected, vec!["number".to_string()]);
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    // terminals without a `#[display]` name are shown as written
+    match parser.parse("1 2") {
+        Err(ParseError::UnrecognizedToken { mut expected, .. }) => {
+            expected.sort();
+            assert_eq!(
+                expected,
+                vec![r#""*""#.to_string(), "plus sign".to_string()]
+            );
+        }
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn prefer() {
+    let parser = prefer::StmtParser::new();
+    assert_eq!(
+        parser.parse("if a then if b then c else d").unwrap(),
+        "(if a (if b c d))"
+    );
+    assert_eq!(parser.parse("a - b - c").unwrap(), "((a - b) - c)");
+    assert_eq!(
+        parser.parse("if a - b then c - d - e").unwrap(),
+        "(if (a - b) ((c - d) - e))"
+    );
+}
+
+#[test]
+fn state_trace() {
+    // a single number skips the states for `Sum "+" Num`, while a sum
+    // covers every state
+    let parser = state_trace::SumParser::new();
+    assert_eq!(state_trace::SumParser::STATE_COUNT, 6);
+    assert_eq!(parser.parse("1").unwrap(), 1);
+    assert_eq!(util::visited_states(), vec![0, 2, 3, 4]);
+    assert_eq!(parser.parse("1 + 2").unwrap(), 3);
+    assert_eq!(util::visited_states(), vec![0, 1, 2, 3, 4, 5]);
+
+    let parser = state_trace_ascent::SumParser::new();
+    assert_eq!(state_trace_ascent::SumParser::STATE_COUNT, 6);
+    assert_eq!(parser.parse("1").unwrap(), 1);
+    assert_eq!(util::visited_states(), vec![0, 2, 3, 4]);
+    assert_eq!(parser.parse("1 + 2").unwrap(), 3);
+    assert_eq!(util::visited_states(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn cfg_dialects() {
+    let parser = dialect_neg::ExprParser::new();
+    assert_eq!(parser.parse("-3").unwrap(), -3);
+    assert!(parser.parse("abs(3)").is_err());
+
+    let parser = dialect_call::ExprParser::new();
+    assert_eq!(parser.parse("abs(abs(3))").unwrap(), 3);
+    assert!(parser.parse("-3").is_err());
+}
+
+#[test]
+fn custom_prefix() {
+    assert_eq!(
+        custom_prefix::ExprParser::new()
+            .parse(2, "1 + 2 * (3 + 4)")
+            .unwrap(),
+        2 + 4 * (6 + 8)
+    );
+
+    let source = include_str!(concat!(env!("OUT_DIR"), "/custom_prefix.rs"));
+    assert!(source.contains("fn gen_action0"));
+    assert!(!source.contains("__action"));
+}
+
+#[test]
+fn boundary_keywords() {
+    let parser = boundary::WordsParser::new();
+    assert_eq!(
+        parser.parse("let x = 1 in x").unwrap(),
+        vec!["<let>", "x", "=", "1", "<in>", "x"]
+    );
+    assert_eq!(
+        parser.parse("index in letx").unwrap(),
+        vec!["i", "n", "d", "e", "x", "<in>", "l", "e", "t", "x"]
+    );
+    assert_eq!(parser.parse("in=in").unwrap(), vec!["<in>", "=", "<in>"]);
+}
+
+#[test]
+fn into_error() {
+    use into_error_lib::CalcError;
+
+    fn checked_sum(input: &str) -> Result<i32, CalcError> {
+        let sum = into_error::SumParser::new().parse(input)?;
+        if sum > 100 {
+            return Err(CalcError::TooLarge(sum));
+        }
+        Ok(sum)
+    }
+
+    assert_eq!(checked_sum("1 + 2"), Ok(3));
+    assert_eq!(checked_sum("99 + 2"), Err(CalcError::TooLarge(101)));
+    assert_eq!(
+        checked_sum("1 + 99999999999"),
+        Err(CalcError::Parse(ParseError::User {
+            error: "number is too big"
+        }))
+    );
+    assert_eq!(
+        checked_sum("1 +"),
+        Err(CalcError::Parse(ParseError::UnrecognizedEOF {
+            location: 3,
+            expected: vec![r##"r#"[0-9]+"#"##.to_string()],
+        }))
+    );
+    // the tokens, which borrow from the input, become strings
+    assert_eq!(
+        checked_sum("1 + + 2"),
+        Err(CalcError::Parse(ParseError::UnrecognizedToken {
+            token: (4, "+".to_string(), 5),
+            expected: vec![r##"r#"[0-9]+"#"##.to_string()],
+        }))
+    );
+}
@@ -2,6 +2,7 @@
 
 use diff;
 use lalrpop_util::ParseError;
+use std::cell::{Cell, RefCell};
 use std::fmt::{Debug, Error, Formatter};
 use crate::util::tok::Tok;
 
@@ -109,3 +110,39 @@ pub fn compare_str(actual: &str, expected: &str, msg: &str) {
         panic!("{}", msg);
     }
 }
+
+thread_local! {
+    static LOCATION_CLONES: Cell<usize> = Cell::new(0);
+    static VISITED_STATES: RefCell<Vec<usize>> = RefCell::new(vec![]);
+}
+
+/// A location type that counts how often it is cloned, for checking
+/// how many clones the generated parsers make.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CountedLoc(pub usize);
+
+impl Clone for CountedLoc {
+    fn clone(&self) -> Self {
+        LOCATION_CLONES.with(|clones| clones.set(clones.get() + 1));
+        CountedLoc(self.0)
+    }
+}
+
+/// The number of `CountedLoc` clones made on this thread so far.
+pub fn location_clones() -> usize {
+    LOCATION_CLONES.with(|clones| clones.get())
+}
+
+/// The hook called by parsers generated with `emit_state_trace`.
+pub fn trace_state(state: usize) {
+    VISITED_STATES.with(|states| states.borrow_mut().push(state));
+}
+
+/// The distinct states passed to `trace_state` on this thread since
+/// the last call, in increasing order.
+pub fn visited_states() -> Vec<usize> {
+    let mut states = VISITED_STATES.with(|states| states.replace(vec![]));
+    states.sort();
+    states.dedup();
+    states
+}
@@ -0,0 +1,77 @@
+//! A string table for grammars whose actions intern identifiers while
+//! parsing. Pass a `&mut StringTable` as a grammar parameter and call
+//! `intern` from the actions of the terminals that should be interned.
+
+use std::collections::HashMap;
+
+/// A string interned in a `StringTable`. Symbols from the same table
+/// are equal exactly when their strings are.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(usize);
+
+impl Symbol {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StringTable {
+    symbols: HashMap<String, Symbol>,
+    strings: Vec<String>,
+}
+
+impl StringTable {
+    pub fn new() -> StringTable {
+        StringTable::default()
+    }
+
+    /// Returns the symbol for `s`, adding it to the table if this is
+    /// the first time it is seen.
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(s) {
+            return symbol;
+        }
+        let symbol = Symbol(self.strings.len());
+        self.strings.push(s.to_owned());
+        self.symbols.insert(s.to_owned(), symbol);
+        symbol
+    }
+
+    /// Returns the symbol for `s`, if it has been interned.
+    pub fn get(&self, s: &str) -> Option<Symbol> {
+        self.symbols.get(s).cloned()
+    }
+
+    /// Returns the string that `symbol` was interned from. Panics if
+    /// `symbol` came from a different table.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringTable;
+
+    #[test]
+    fn intern() {
+        let mut table = StringTable::new();
+        let a = table.intern("a");
+        let b = table.intern("b");
+        assert_ne!(a, b);
+        assert_eq!(table.intern("a"), a);
+        assert_eq!(table.get("b"), Some(b));
+        assert_eq!(table.get("c"), None);
+        assert_eq!(table.resolve(b), "b");
+        assert_eq!(table.len(), 2);
+    }
+}
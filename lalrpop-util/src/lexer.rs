@@ -1,8 +1,12 @@
-use std::{fmt, marker::PhantomData};
+use std::{ascii, fmt, marker::PhantomData};
 
 use crate::ParseError;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Token<'input>(pub usize, pub &'input str);
 impl<'a> fmt::Display for Token<'a> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
@@ -10,119 +14,564 @@ impl<'a> fmt::Display for Token<'a> {
     }
 }
 
-struct RegexEntry {
-    regex: regex::Regex,
+/// Like `Token`, but for parsers over `&[u8]` input.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ByteToken<'input>(pub usize, pub &'input [u8]);
+impl<'a> fmt::Display for ByteToken<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        for &byte in self.1 {
+            for c in ascii::escape_default(byte) {
+                fmt::Write::write_char(formatter, c as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+struct RegexEntry<R> {
+    regex: R,
     skip: bool,
+    nested: Option<NestedSkip>,
+    canonical: Option<&'static str>,
+    boundary: bool,
 }
 
-pub struct MatcherBuilder {
-    regex_set: regex::RegexSet,
-    regex_vec: Vec<RegexEntry>,
+/// Delimiters of a skipped block that may nest, like `/* /* */ */`.
+struct NestedSkip {
+    open: String,
+    close: String,
 }
 
-impl MatcherBuilder {
-    pub fn new<S>(
-        exprs: impl IntoIterator<Item = (S, bool)>,
-    ) -> Result<MatcherBuilder, regex::Error>
-    where
-        S: AsRef<str>,
-    {
-        let exprs = exprs.into_iter();
-        let mut regex_vec = Vec::with_capacity(exprs.size_hint().0);
-        let mut first_error = None;
-        let regex_set_result = regex::RegexSet::new(exprs.scan((), |_, (s, skip)| {
-            regex_vec.push(match regex::Regex::new(s.as_ref()) {
-                Ok(regex) => RegexEntry { regex, skip },
-                Err(err) => {
-                    first_error = Some(err);
-                    return None;
+macro_rules! matcher {
+    ($Builder:ident, $Matcher:ident, $Token:ident, $Text:ty, $($regex:ident)::+) => {
+        pub struct $Builder {
+            regex_set: $($regex)::+::RegexSet,
+            regex_vec: Vec<RegexEntry<$($regex)::+::Regex>>,
+        }
+
+        impl $Builder {
+            pub fn new<S>(
+                exprs: impl IntoIterator<Item = (S, bool)>,
+            ) -> Result<$Builder, regex::Error>
+            where
+                S: AsRef<str>,
+            {
+                let exprs = exprs.into_iter();
+                let mut regex_vec = Vec::with_capacity(exprs.size_hint().0);
+                let mut first_error = None;
+                let regex_set_result = $($regex)::+::RegexSet::new(exprs.scan((), |_, (s, skip)| {
+                    regex_vec.push(match $($regex)::+::Regex::new(s.as_ref()) {
+                        Ok(regex) => RegexEntry {
+                            regex,
+                            skip,
+                            nested: None,
+                            canonical: None,
+                            boundary: false,
+                        },
+                        Err(err) => {
+                            first_error = Some(err);
+                            return None;
+                        }
+                    });
+                    Some(s)
+                }));
+
+                if let Some(err) = first_error {
+                    return Err(err);
                 }
-            });
-            Some(s)
-        }));
+                let regex_set = regex_set_result?;
+
+                Ok($Builder {
+                    regex_set,
+                    regex_vec,
+                })
+            }
 
-        if let Some(err) = first_error {
-            return Err(err);
+            /// Turns the skipped entry at `index`, which must match exactly
+            /// `open`, into the start of a block that ends at the matching
+            /// `close`. Blocks may nest: each `open` inside the block must be
+            /// balanced by its own `close`.
+            pub fn nested_skip(mut self, index: usize, open: &str, close: &str) -> $Builder {
+                let entry = &mut self.regex_vec[index];
+                assert!(entry.skip, "nested blocks must be skipped");
+                entry.nested = Some(NestedSkip {
+                    open: open.to_owned(),
+                    close: close.to_owned(),
+                });
+                self
+            }
+
+            /// Makes the entry at `index` yield `text` as its token,
+            /// rather than the input it matched. Used for terminals like
+            /// `i"select"`, whose actions see the spelling from the
+            /// grammar whatever the case of the input.
+            pub fn canonical(mut self, index: usize, text: &'static str) -> $Builder {
+                self.regex_vec[index].canonical = Some(text);
+                self
+            }
+
+            /// Makes the entry at `index` match only where its match is
+            /// not directly followed by a letter, digit or `_`, so that a
+            /// keyword like `in` is never split off the front of a word
+            /// like `index`, even when no other entry matches all of it.
+            pub fn boundary(mut self, index: usize) -> $Builder {
+                self.regex_vec[index].boundary = true;
+                self
+            }
+
+            pub fn matcher<'input, 'builder, E>(
+                &'builder self,
+                s: &'input $Text,
+            ) -> $Matcher<'input, 'builder, E> {
+                self.matcher_at(s, 0)
+            }
+
+            /// Like `matcher`, but starts lexing at byte offset `start`
+            /// of `s`. Token locations are still offsets into all of
+            /// `s`, so lexing can be resumed after an edit from a
+            /// position saved with `$Matcher::position`. Panics if
+            /// `start` is not a valid place to split `s`.
+            pub fn matcher_at<'input, 'builder, E>(
+                &'builder self,
+                s: &'input $Text,
+                start: usize,
+            ) -> $Matcher<'input, 'builder, E> {
+                $Matcher {
+                    text: &s[start..],
+                    consumed: start,
+                    regex_set: &self.regex_set,
+                    regex_vec: &self.regex_vec,
+                    skip_errors: false,
+                    lex_errors: vec![],
+                    viable: None,
+                    _marker: PhantomData,
+                }
+            }
         }
-        let regex_set = regex_set_result?;
 
-        Ok(MatcherBuilder {
-            regex_set,
-            regex_vec,
-        })
-    }
-    pub fn matcher<'input, 'builder, E>(
-        &'builder self,
-        s: &'input str,
-    ) -> Matcher<'input, 'builder, E> {
-        Matcher {
-            text: s,
-            consumed: 0,
-            regex_set: &self.regex_set,
-            regex_vec: &self.regex_vec,
-            _marker: PhantomData,
+        pub struct $Matcher<'input, 'builder, E> {
+            text: &'input $Text,
+            consumed: usize,
+            regex_set: &'builder $($regex)::+::RegexSet,
+            regex_vec: &'builder Vec<RegexEntry<$($regex)::+::Regex>>,
+            skip_errors: bool,
+            lex_errors: Vec<(usize, usize)>,
+
+            /// In `skip_errors` mode, the entries that match anywhere
+            /// in the input left at the first unrecognized text. Only
+            /// these can end a run of such text, and trying the others
+            /// at every position of the run could take quadratic time.
+            viable: Option<Vec<usize>>,
+            _marker: PhantomData<fn() -> E>,
         }
-    }
-}
 
-pub struct Matcher<'input, 'builder, E> {
-    text: &'input str,
-    consumed: usize,
-    regex_set: &'builder regex::RegexSet,
-    regex_vec: &'builder Vec<RegexEntry>,
-    _marker: PhantomData<fn() -> E>,
-}
+        impl<'input, 'builder, E> $Matcher<'input, 'builder, E> {
+            /// The byte offset at which the next token will be looked
+            /// for, i.e. the end of the last token or skipped text.
+            pub fn position(&self) -> usize {
+                self.consumed
+            }
 
-impl<'input, 'builder, E> Iterator for Matcher<'input, 'builder, E> {
-    type Item = Result<(usize, Token<'input>, usize), ParseError<usize, Token<'input>, E>>;
+            /// Makes the matcher skip over text that no terminal
+            /// matches, rather than yielding an `InvalidToken` error
+            /// and stopping there. Each run of such text, up to the
+            /// next place where some terminal (or skipped text)
+            /// matches, is recorded in `lex_errors` and lexing goes
+            /// on. Useful for tools such as editors, which want all
+            /// the tokens of an input with a few bad characters.
+            pub fn skip_errors(mut self) -> Self {
+                self.skip_errors = true;
+                self
+            }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let text = self.text;
-            let start_offset = self.consumed;
-            if text.is_empty() {
-                self.consumed = start_offset;
-                return None;
-            } else {
-                let matches = self.regex_set.matches(text);
-                if !matches.matched_any() {
-                    return Some(Err(ParseError::InvalidToken {
-                        location: start_offset,
-                    }));
+            /// The `(start, end)` offsets of the runs of unrecognized
+            /// text skipped so far, in `skip_errors` mode.
+            pub fn lex_errors(&self) -> &[(usize, usize)] {
+                &self.lex_errors
+            }
+
+            /// The length of the match of entry `i` at the start of
+            /// `text`, or `None` if it does not match there.
+            fn match_len(&self, i: usize, text: &$Text) -> Option<usize> {
+                let entry = &self.regex_vec[i];
+                let len = entry.regex.find(text)?.end();
+                if entry.boundary && text.continues_word(len) {
+                    None
                 } else {
-                    let mut longest_match = 0;
-                    let mut index = 0;
-                    let mut skip = false;
-                    for i in matches.iter() {
-                        let entry = &self.regex_vec[i];
-                        let match_ = entry.regex.find(text).unwrap();
-                        let len = match_.end();
-                        if len >= longest_match {
-                            longest_match = len;
-                            index = i;
-                            skip = entry.skip;
-                        }
-                    }
+                    Some(len)
+                }
+            }
+
+            /// Whether one of the `viable` entries matches a non-empty
+            /// prefix of `text`.
+            fn recognizes(&self, viable: &[usize], text: &$Text) -> bool {
+                viable
+                    .iter()
+                    .any(|&i| self.match_len(i, text).map_or(false, |len| len > 0))
+            }
+
+            /// Finds the entries that match anywhere in what is left of
+            /// the input, by searching for all of them, unanchored, in a
+            /// single pass.
+            fn viable_entries(&self) -> Vec<usize> {
+                let unanchored = self
+                    .regex_set
+                    .patterns()
+                    .iter()
+                    .map(|pattern| pattern.trim_start_matches('^'));
+                $($regex)::+::RegexSet::new(unanchored)
+                    .expect("a valid pattern without its anchor")
+                    .matches(self.text)
+                    .into_iter()
+                    .collect()
+            }
+
+            /// Reports the unrecognized text at the current position:
+            /// as an error, or by skipping it in `skip_errors` mode.
+            fn unrecognized(&mut self) -> Option<ParseError<usize, $Token<'input>, E>> {
+                let start_offset = self.consumed;
+                if !self.skip_errors {
+                    return Some(ParseError::InvalidToken {
+                        location: start_offset,
+                    });
+                }
+
+                let viable = match self.viable.take() {
+                    Some(viable) => viable,
+                    None => self.viable_entries(),
+                };
+                let text = self.text;
+                let mut len = 1;
+                while len < text.len()
+                    && !(text.is_boundary(len) && self.recognizes(&viable, &text[len..]))
+                {
+                    len += 1;
+                }
+                self.viable = Some(viable);
+                self.skip_unrecognized(len);
+                None
+            }
+
+            /// Skips the next `len` bytes of the input, recording them as
+            /// unrecognized.
+            fn skip_unrecognized(&mut self, len: usize) {
+                let start_offset = self.consumed;
+                self.text = &self.text[len..];
+                self.consumed = start_offset + len;
+                self.lex_errors.push((start_offset, start_offset + len));
+            }
+        }
+
+        impl<'input, 'builder, E> Iterator for $Matcher<'input, 'builder, E> {
+            type Item = Result<(usize, $Token<'input>, usize), ParseError<usize, $Token<'input>, E>>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    let text = self.text;
+                    let start_offset = self.consumed;
+                    if text.is_empty() {
+                        self.consumed = start_offset;
+                        return None;
+                    } else {
+                        let matches = self.regex_set.matches(text);
+                        if !matches.matched_any() {
+                            match self.unrecognized() {
+                                Some(error) => return Some(Err(error)),
+                                None => continue,
+                            }
+                        } else {
+                            let mut longest_match = 0;
+                            let mut index = None;
+                            let mut skip = false;
+                            for i in matches.iter() {
+                                let len = match self.match_len(i, text) {
+                                    Some(len) => len,
+                                    None => continue,
+                                };
+                                if len >= longest_match {
+                                    longest_match = len;
+                                    index = Some(i);
+                                    skip = self.regex_vec[i].skip;
+                                }
+                            }
+
+                            // every entry that matched wants a boundary
+                            // that is not there
+                            let index = match index {
+                                Some(index) => index,
+                                None => match self.unrecognized() {
+                                    Some(error) => return Some(Err(error)),
+                                    None => continue,
+                                },
+                            };
+
+                            if let Some(ref nested) = self.regex_vec[index].nested {
+                                match nested.block_len(text.as_ref()) {
+                                    Some(len) => longest_match = len,
+                                    None if self.skip_errors => {
+                                        // unterminated block: it runs to the end
+                                        self.skip_unrecognized(text.len());
+                                        continue;
+                                    }
+                                    None => {
+                                        // unterminated block: report where it starts
+                                        return Some(Err(ParseError::InvalidToken {
+                                            location: start_offset,
+                                        }));
+                                    }
+                                }
+                            }
 
-                    let result = &text[..longest_match];
-                    let remaining = &text[longest_match..];
-                    let end_offset = start_offset + longest_match;
-                    self.text = remaining;
-                    self.consumed = end_offset;
-
-                    // Skip any whitespace matches
-                    if skip {
-                        if longest_match == 0 {
-                            return Some(Err(ParseError::InvalidToken {
-                                location: start_offset,
-                            }));
+                            // only skipped text matched, and it is empty
+                            if skip && longest_match == 0 {
+                                match self.unrecognized() {
+                                    Some(error) => return Some(Err(error)),
+                                    None => continue,
+                                }
+                            }
+
+                            let result: &'input $Text = match self.regex_vec[index].canonical {
+                                Some(canonical) => canonical.as_ref(),
+                                None => &text[..longest_match],
+                            };
+                            let remaining = &text[longest_match..];
+                            let end_offset = start_offset + longest_match;
+                            self.text = remaining;
+                            self.consumed = end_offset;
+
+                            // Skip any whitespace matches
+                            if skip {
+                                continue;
+                            }
+
+                            return Some(Ok((start_offset, $Token(index, result), end_offset)));
                         }
-                        continue;
                     }
+                }
+            }
+        }
+    };
+}
 
-                    return Some(Ok((start_offset, Token(index, result), end_offset)));
+matcher!(MatcherBuilder, Matcher, Token, str, regex);
+
+// Used by grammars built with `byte_input`: the same matcher, but over
+// `&[u8]` input that need not be valid UTF-8. Offsets are byte offsets.
+matcher!(
+    ByteMatcherBuilder,
+    ByteMatcher,
+    ByteToken,
+    [u8],
+    regex::bytes
+);
+
+impl NestedSkip {
+    /// Returns the length of the block at the start of `text`, which
+    /// begins with `open`, or `None` if the block is never closed.
+    fn block_len(&self, text: &[u8]) -> Option<usize> {
+        debug_assert!(text.starts_with(self.open.as_bytes()));
+        let mut depth = 1;
+        let mut consumed = self.open.len();
+        loop {
+            let rest = &text[consumed..];
+            let next_open = find(rest, self.open.as_bytes());
+            let next_close = find(rest, self.close.as_bytes())?;
+            match next_open {
+                Some(open) if open < next_close => {
+                    depth += 1;
+                    consumed += open + self.open.len();
+                }
+                _ => {
+                    depth -= 1;
+                    consumed += next_close + self.close.len();
+                    if depth == 0 {
+                        return Some(consumed);
+                    }
                 }
             }
         }
     }
 }
+
+/// The kinds of text the matchers read.
+trait Text {
+    /// Whether the text can be split at byte offset `index`.
+    fn is_boundary(&self, index: usize) -> bool;
+
+    /// Whether the text at byte offset `index` starts with a letter,
+    /// digit or `_`, i.e. a character that could continue a word.
+    fn continues_word(&self, index: usize) -> bool;
+}
+
+impl Text for str {
+    fn is_boundary(&self, index: usize) -> bool {
+        self.is_char_boundary(index)
+    }
+
+    fn continues_word(&self, index: usize) -> bool {
+        self[index..]
+            .chars()
+            .next()
+            .map_or(false, |c| c.is_alphanumeric() || c == '_')
+    }
+}
+
+impl Text for [u8] {
+    fn is_boundary(&self, _index: usize) -> bool {
+        true
+    }
+
+    fn continues_word(&self, index: usize) -> bool {
+        self.get(index)
+            .map_or(false, |&b| b.is_ascii_alphanumeric() || b == b'_')
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> MatcherBuilder {
+        MatcherBuilder::new(vec![
+            (r"^([0-9]+)", false),
+            (r"^([a-z]+)", false),
+            (r"^(\s*)", true),
+        ])
+        .unwrap()
+    }
+
+    fn lex<'input>(
+        matcher: impl Iterator<
+            Item = Result<(usize, Token<'input>, usize), ParseError<usize, Token<'input>, ()>>,
+        >,
+    ) -> Vec<(usize, usize, usize)> {
+        matcher
+            .map(|r| r.unwrap())
+            .map(|(start, Token(index, _), end)| (start, index, end))
+            .collect()
+    }
+
+    #[test]
+    fn matcher_at_resumes_with_absolute_offsets() {
+        let builder = builder();
+        let text = "ab 12  cd 345 e";
+        let all = lex(builder.matcher(text));
+        assert_eq!(all.len(), 5);
+
+        for &(start, _, _) in &all {
+            let rest: Vec<_> = all.iter().cloned().filter(|t| t.0 >= start).collect();
+            assert_eq!(lex(builder.matcher_at(text, start)), rest);
+        }
+    }
+
+    #[test]
+    fn position() {
+        let builder = builder();
+        let text = "ab 12  cd";
+        let mut matcher: Matcher<()> = builder.matcher(text);
+        assert_eq!(matcher.position(), 0);
+        matcher.next();
+        assert_eq!(matcher.position(), 2);
+        matcher.next();
+        assert_eq!(matcher.position(), 5);
+
+        // resuming from a checkpoint continues where the matcher was
+        let resumed: Matcher<()> = builder.matcher_at(text, matcher.position());
+        assert_eq!(lex(resumed), lex(matcher));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let text = "ab 12";
+        let tokens: Vec<(usize, Token, usize)> =
+            builder().matcher::<()>(text).map(|r| r.unwrap()).collect();
+        let json = serde_json::to_string(&tokens).unwrap();
+        assert_eq!(json, r#"[[0,[1,"ab"],2],[3,[0,"12"],5]]"#);
+        let read: Vec<(usize, Token, usize)> = serde_json::from_str(&json).unwrap();
+        assert_eq!(read, tokens);
+    }
+
+    #[test]
+    fn skip_errors() {
+        let builder = builder();
+        let mut matcher: Matcher<()> = builder.matcher("a?b").skip_errors();
+        assert_eq!(lex(matcher.by_ref()), vec![(0, 1, 1), (2, 1, 3)]);
+        assert_eq!(matcher.lex_errors(), &[(1, 2)]);
+
+        // a run of bad characters is one error, which ends where some
+        // terminal or skipped text matches; offsets are in bytes
+        let mut matcher: Matcher<()> = builder.matcher("1 ?\u{e9}! 2#").skip_errors();
+        assert_eq!(lex(matcher.by_ref()), vec![(0, 0, 1), (7, 0, 8)]);
+        assert_eq!(matcher.lex_errors(), &[(2, 6), (8, 9)]);
+
+        // an unterminated nested block is unrecognized up to the end
+        let nested = MatcherBuilder::new(vec![
+            (r"^([a-z]+)", false),
+            (r"^(\s*)", true),
+            (r"^(/\*)", true),
+        ])
+        .unwrap()
+        .nested_skip(2, "/*", "*/");
+        let mut matcher: Matcher<()> = nested.matcher("a /* b /* */ c").skip_errors();
+        assert_eq!(lex(matcher.by_ref()), vec![(0, 0, 1)]);
+        assert_eq!(matcher.lex_errors(), &[(2, 14)]);
+        assert!(matcher.next().is_none());
+
+        // a run ends where a terminal matches, even one that fails to
+        // match all along the run
+        let bang = MatcherBuilder::new(vec![(r"^([a-z]+!)", false), (r"^(\s*)", true)]).unwrap();
+        let mut matcher: Matcher<()> = bang.matcher("aaaa b!").skip_errors();
+        assert_eq!(lex(matcher.by_ref()), vec![(5, 0, 7)]);
+        assert_eq!(matcher.lex_errors(), &[(0, 4)]);
+
+        // without `skip_errors`, the first bad character is an error
+        let mut matcher: Matcher<()> = builder.matcher("a?b");
+        assert!(matcher.next().unwrap().is_ok());
+        match matcher.next() {
+            Some(Err(ParseError::InvalidToken { location: 1 })) => {}
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn boundary() {
+        // identifiers of one letter, so only `in` matches all of `in`
+        let entries = vec![(r"^(in)", false), (r"^([a-z])", false), (r"^(\s*)", true)];
+        let plain = MatcherBuilder::new(entries.clone()).unwrap();
+        let bounded = MatcherBuilder::new(entries).unwrap().boundary(0);
+
+        assert_eq!(
+            lex(plain.matcher("index")),
+            vec![(0, 0, 2), (2, 1, 3), (3, 1, 4), (4, 1, 5)]
+        );
+        assert_eq!(
+            lex(bounded.matcher("index")),
+            vec![(0, 1, 1), (1, 1, 2), (2, 1, 3), (3, 1, 4), (4, 1, 5)]
+        );
+
+        // followed by a space, a symbol or the end of input, `in` matches
+        assert_eq!(lex(bounded.matcher("in x")), vec![(0, 0, 2), (3, 1, 4)]);
+        assert_eq!(lex(bounded.matcher("in")), vec![(0, 0, 2)]);
+        let mut matcher: Matcher<()> = bounded.matcher("in+");
+        assert_eq!(matcher.next().unwrap().unwrap(), (0, Token(0, "in"), 2));
+
+        // when only a bounded entry matches, the text is not recognized
+        let keyword = MatcherBuilder::new(vec![(r"^(in)", false), (r"^(\s*)", true)])
+            .unwrap()
+            .boundary(0);
+        let mut matcher: Matcher<()> = keyword.matcher("in inx");
+        assert!(matcher.next().unwrap().is_ok());
+        match matcher.next() {
+            Some(Err(ParseError::InvalidToken { location: 3 })) => {}
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+}
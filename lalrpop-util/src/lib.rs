@@ -1,11 +1,44 @@
+//! Without the (default) `std` feature, this crate is `no_std` and
+//! only needs `alloc`. The `intern` and `lexer` modules require `std`.
+//! Note that parsers generated by LALRPOP still refer to `::std` paths
+//! and to the `std` prelude (`Vec`, `String`, `Box`), so for now they
+//! can only be compiled in crates that link `std`.
+//!
+//! With the `serde` feature, the tokens of the `lexer` module
+//! implement `Serialize` and `Deserialize`.
+//!
+//! With the `unicode-segmentation` feature, `offset_to_line_grapheme`
+//! reports columns in grapheme clusters.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "unicode-segmentation")]
+extern crate unicode_segmentation;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::error::Error;
-use std::fmt;
 
+#[cfg(feature = "std")]
+pub mod intern;
 #[cfg(feature = "lexer")]
 pub mod lexer;
 pub mod state_machine;
 
+/// An error returned by a generated parser.
+///
+/// New variants may be added in minor releases, so matches on it need a
+/// wildcard arm.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum ParseError<L, T, E> {
     /// Generated by the parser when it encounters a token (or EOF) it did not
     /// expect.
@@ -38,6 +71,17 @@ pub enum ParseError<L, T, E> {
 
     /// Custom error type.
     User { error: E },
+
+    /// Custom error type, together with the start and end locations
+    /// of the symbols consumed by the action that returned it. Only
+    /// produced by parsers generated with `user_error_spans` enabled.
+    UserWithSpan { error: E, span: (L, L) },
+
+    /// Generated by the parser when it reaches a point that its tables
+    /// rule out. This is a bug in LALRPOP; `message` names the state or
+    /// symbol involved, for the bug report. Parsers before 0.20 panicked
+    /// instead.
+    Internal { message: &'static str },
 }
 
 impl<L, T, E> ParseError<L, T, E> {
@@ -66,6 +110,14 @@ impl<L, T, E> ParseError<L, T, E> {
             ParseError::User { error } => ParseError::User {
                 error: err_op(error),
             },
+            ParseError::UserWithSpan {
+                error,
+                span: (start, end),
+            } => ParseError::UserWithSpan {
+                error: err_op(error),
+                span: (loc_op(start), loc_op(end)),
+            },
+            ParseError::Internal { message } => ParseError::Internal { message },
         }
     }
 
@@ -80,6 +132,263 @@ impl<L, T, E> ParseError<L, T, E> {
     pub fn map_error<EE>(self, op: impl FnOnce(E) -> EE) -> ParseError<L, T, EE> {
         self.map_intern(|x| x, |x| x, op)
     }
+
+    /// The `expected` tokens of this error in structured form. Errors
+    /// that carry no expected tokens yield an empty vector.
+    pub fn expected_tokens(&self) -> Vec<ExpectedToken> {
+        match *self {
+            ParseError::UnrecognizedEOF { ref expected, .. }
+            | ParseError::UnrecognizedToken { ref expected, .. } => {
+                expected.iter().map(|e| ExpectedToken::parse(e)).collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// The location up to which the input was parsed successfully:
+    /// the start of the offending token for `UnrecognizedToken` and
+    /// `ExtraToken`, and the reported location for `InvalidToken` and
+    /// `UnrecognizedEOF`. With the built-in lexer, this is the number
+    /// of bytes consumed. For `UserWithSpan`, it is the start of the
+    /// span. `User` and `Internal` errors carry no location, so this
+    /// returns `None` for them.
+    pub fn location(&self) -> Option<&L> {
+        match *self {
+            ParseError::InvalidToken { ref location }
+            | ParseError::UnrecognizedEOF { ref location, .. } => Some(location),
+            ParseError::UnrecognizedToken {
+                token: (ref start, _, _),
+                ..
+            }
+            | ParseError::ExtraToken {
+                token: (ref start, _, _),
+            }
+            | ParseError::UserWithSpan {
+                span: (ref start, _),
+                ..
+            } => Some(start),
+            ParseError::User { .. } | ParseError::Internal { .. } => None,
+        }
+    }
+
+    /// Turns a `User` error into a `UserWithSpan` error covering
+    /// `start..end`. Other errors already carry their locations and
+    /// are returned unchanged.
+    pub fn with_span(self, start: L, end: L) -> Self {
+        match self {
+            ParseError::User { error } => ParseError::UserWithSpan {
+                error,
+                span: (start, end),
+            },
+            error => error,
+        }
+    }
+}
+
+impl<T, E> ParseError<usize, T, E> {
+    /// Renders the line of `input` containing this error, with the
+    /// offending span underlined below it, e.g.
+    ///
+    /// ```text
+    /// 2 | let x = ;
+    ///   |         ^
+    /// ```
+    ///
+    /// The locations must be byte offsets into `input`, as produced
+    /// by the built-in lexer. A span running past the end of its line
+    /// is underlined up to the end of the line, and an empty span
+    /// (such as an unexpected EOF) gets a single caret. Returns `None`
+    /// for `User` and `Internal` errors, which carry no location, and
+    /// for locations that do not fall on a character boundary of
+    /// `input`.
+    pub fn excerpt(&self, input: &str) -> Option<String> {
+        let (start, end) = match *self {
+            ParseError::InvalidToken { location }
+            | ParseError::UnrecognizedEOF { location, .. } => (location, location),
+            ParseError::UnrecognizedToken {
+                token: (start, _, end),
+                ..
+            }
+            | ParseError::ExtraToken {
+                token: (start, _, end),
+            }
+            | ParseError::UserWithSpan {
+                span: (start, end), ..
+            } => (start, end),
+            ParseError::User { .. } | ParseError::Internal { .. } => return None,
+        };
+        if !input.is_char_boundary(start) {
+            return None;
+        }
+
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        let line = input[line_start..line_end].trim_end_matches('\r');
+        let end = if end > line_start + line.len() {
+            line_start + line.len()
+        } else {
+            end
+        };
+
+        let (line_number, column) = offset_to_line_col(input, start);
+        let width = input.get(start..end).map_or(0, |s| s.chars().count());
+
+        let number = line_number.to_string();
+        let mut excerpt = String::new();
+        excerpt.push_str(&number);
+        excerpt.push_str(" | ");
+        excerpt.push_str(line);
+        excerpt.push('\n');
+        excerpt.extend(core::iter::repeat(' ').take(number.len()));
+        excerpt.push_str(" | ");
+        excerpt.extend(core::iter::repeat(' ').take(column - 1));
+        excerpt.extend(core::iter::repeat('^').take(width.max(1)));
+        Some(excerpt)
+    }
+}
+
+/// Converts the byte offset `offset` into `input`, such as the
+/// locations reported by the built-in lexer, into a 1-based line and
+/// column. Columns count characters rather than bytes, and a `\r`
+/// before a `\n` counts as the last column of its line. Offsets past
+/// the end of `input` give the position just after its last character.
+///
+/// ```
+/// # use lalrpop_util::{offset_to_line_col, ParseError};
+/// let input = "a\nbc";
+/// let err = ParseError::InvalidToken::<usize, (), ()> { location: 3 };
+/// let position = err.location().map(|&offset| offset_to_line_col(input, offset));
+/// assert_eq!(position, Some((2, 2)));
+/// ```
+pub fn offset_to_line_col(input: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+    for (index, c) in input.char_indices() {
+        if index >= offset {
+            break;
+        }
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// Like `offset_to_line_col`, but columns count extended grapheme
+/// clusters, i.e. what a reader perceives as characters: `"e\u{301}"`
+/// (`e` with a combining acute accent) or a family emoji joined with
+/// zero-width joiners each take up a single column. An offset inside a
+/// cluster gives the column of that cluster.
+#[cfg(feature = "unicode-segmentation")]
+pub fn offset_to_line_grapheme(input: &str, offset: usize) -> (usize, usize) {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut line = 1;
+    let mut column = 1;
+    for (index, grapheme) in input.grapheme_indices(true) {
+        if index + grapheme.len() > offset {
+            break;
+        }
+        // `"\r\n"` is a single cluster
+        if grapheme.ends_with('\n') {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// A structured view of one of the `expected` entries of a
+/// `ParseError`, telling how the terminal was written in the grammar.
+/// Its `Display` impl yields back the original string.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExpectedToken {
+    /// A terminal written as a string literal, like `"+"`. Holds the
+    /// literal text, without quotes or escapes.
+    Literal(String),
+
+    /// A terminal written as a regular expression, like `r"[0-9]+"`.
+    /// Holds the regular expression.
+    Regex(String),
+
+    /// A terminal written as a case-insensitive string literal, like
+    /// `i"select"`. Holds the literal text, without quotes or escapes.
+    CaseInsensitive(String),
+
+    /// A terminal referred to by name, like `NUM` in an `extern` or
+    /// `match` block. Terminals given a name with `#[display(name = ...)]`
+    /// show up here too, whatever they were written as.
+    Named(String),
+}
+
+impl ExpectedToken {
+    /// Classifies one of the strings found in the `expected` field of a
+    /// `ParseError`.
+    pub fn parse(expected: &str) -> ExpectedToken {
+        if expected.len() >= 2 && expected.starts_with('"') && expected.ends_with('"') {
+            if let Some(text) = unescape(&expected[1..expected.len() - 1]) {
+                return ExpectedToken::Literal(text);
+            }
+        } else if expected.len() >= 5 && expected.starts_with("r#\"") && expected.ends_with("\"#") {
+            if let Some(text) = unescape(&expected[3..expected.len() - 2]) {
+                return ExpectedToken::Regex(text);
+            }
+        } else if expected.len() >= 3 && expected.starts_with("i\"") && expected.ends_with('"') {
+            if let Some(text) = unescape(&expected[2..expected.len() - 1]) {
+                return ExpectedToken::CaseInsensitive(text);
+            }
+        }
+        ExpectedToken::Named(expected.to_string())
+    }
+}
+
+impl fmt::Display for ExpectedToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExpectedToken::Literal(ref text) => write!(f, "{:?}", text),
+            ExpectedToken::Regex(ref text) => write!(f, "r#{:?}#", text),
+            ExpectedToken::CaseInsensitive(ref text) => write!(f, "i{:?}", text),
+            ExpectedToken::Named(ref name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Reverses the escaping done by the `Debug` impl of `str`, which is how
+/// LALRPOP prints literals and regular expressions in `expected`.
+fn unescape(text: &str) -> Option<String> {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        let c = match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            'u' => {
+                let rest = chars.as_str();
+                if !rest.starts_with('{') {
+                    return None;
+                }
+                let end = rest.find('}')?;
+                let code = u32::from_str_radix(&rest[1..end], 16).ok()?;
+                chars = rest[end + 1..].chars();
+                core::char::from_u32(code)?
+            }
+            c @ '\\' | c @ '"' | c @ '\'' => c,
+            _ => return None,
+        };
+        result.push(c);
+    }
+    Some(result)
 }
 
 /// Format a list of expected tokens.
@@ -108,7 +417,7 @@ where
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::ParseError::*;
         match *self {
-            User { ref error } => write!(f, "{}", error),
+            User { ref error } | UserWithSpan { ref error, .. } => write!(f, "{}", error),
             InvalidToken { ref location } => write!(f, "Invalid token at {}", location),
             UnrecognizedEOF {
                 ref location,
@@ -131,6 +440,12 @@ where
             ExtraToken {
                 token: (ref start, ref token, ref end),
             } => write!(f, "Extra token {} found at {}:{}", token, start, end),
+            Internal { message } => write!(
+                f,
+                "Internal error in the LALRPOP-generated parser: {}; \
+                 please report this as a bug",
+                message
+            ),
         }
     }
 }
@@ -141,6 +456,7 @@ impl<L, T, E> From<E> for ParseError<L, T, E> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<L, T, E> Error for ParseError<L, T, E>
 where
     L: fmt::Debug + fmt::Display,
@@ -206,4 +522,244 @@ mod tests {
              Expected one of t1, t2 or t3"
         );
     }
+
+    #[test]
+    fn display_other_variants() {
+        let err = ParseError::InvalidToken::<i32, &str, &str> { location: 3 };
+        assert_eq!(format!("{}", err), "Invalid token at 3");
+
+        let err = ParseError::UnrecognizedEOF::<i32, &str, &str> {
+            location: 4,
+            expected: vec!["\")\"".to_string()],
+        };
+        assert_eq!(
+            format!("{}", err),
+            "Unrecognized EOF found at 4\n\
+             Expected one of \")\""
+        );
+
+        let err = ParseError::ExtraToken::<i32, &str, &str> {
+            token: (5, "t0", 6),
+        };
+        assert_eq!(format!("{}", err), "Extra token t0 found at 5:6");
+
+        let err = ParseError::User::<i32, &str, &str> {
+            error: "custom error",
+        };
+        assert_eq!(format!("{}", err), "custom error");
+
+        let err = ParseError::Internal::<i32, &str, &str> {
+            message: "state 3 has no action",
+        };
+        assert_eq!(
+            format!("{}", err),
+            "Internal error in the LALRPOP-generated parser: state 3 has no action; \
+             please report this as a bug"
+        );
+    }
+
+    #[test]
+    fn location() {
+        let err = ParseError::InvalidToken::<i32, &str, &str> { location: 3 };
+        assert_eq!(err.location(), Some(&3));
+
+        let err = ParseError::UnrecognizedEOF::<i32, &str, &str> {
+            location: 4,
+            expected: vec![],
+        };
+        assert_eq!(err.location(), Some(&4));
+
+        let err = ParseError::UnrecognizedToken::<i32, &str, &str> {
+            token: (1, "t0", 2),
+            expected: vec![],
+        };
+        assert_eq!(err.location(), Some(&1));
+
+        let err = ParseError::ExtraToken::<i32, &str, &str> {
+            token: (5, "t0", 6),
+        };
+        assert_eq!(err.location(), Some(&5));
+
+        let err = ParseError::User::<i32, &str, &str> {
+            error: "custom error",
+        };
+        assert_eq!(err.location(), None);
+    }
+
+    #[test]
+    fn with_span() {
+        let err = ParseError::User::<i32, &str, &str> {
+            error: "custom error",
+        }
+        .with_span(3, 7);
+        assert_eq!(
+            err,
+            ParseError::UserWithSpan {
+                error: "custom error",
+                span: (3, 7),
+            }
+        );
+        assert_eq!(err.location(), Some(&3));
+        assert_eq!(format!("{}", err), "custom error");
+
+        let err = ParseError::InvalidToken::<i32, &str, &str> { location: 1 };
+        assert_eq!(err.clone().with_span(3, 7), err);
+    }
+
+    #[test]
+    fn boxed_error() {
+        fn parse() -> Result<(), ParseError<i32, &'static str, &'static str>> {
+            Err(ParseError::InvalidToken { location: 7 })
+        }
+
+        fn run() -> Result<(), Box<dyn Error>> {
+            parse()?;
+            Ok(())
+        }
+
+        assert_eq!(run().unwrap_err().to_string(), "Invalid token at 7");
+    }
+
+    #[test]
+    fn expected_tokens() {
+        let err = ParseError::UnrecognizedEOF::<i32, &str, &str> {
+            location: 1,
+            expected: vec![
+                r#""+""#,
+                r#""\"""#,
+                r##"r#"[0-9]+"#"##,
+                r##"r#"\\s*"#"##,
+                r#"i"select""#,
+                "NUM",
+            ]
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect(),
+        };
+        let expected = err.expected_tokens();
+        assert_eq!(
+            expected,
+            vec![
+                ExpectedToken::Literal("+".to_string()),
+                ExpectedToken::Literal("\"".to_string()),
+                ExpectedToken::Regex("[0-9]+".to_string()),
+                ExpectedToken::Regex(r"\s*".to_string()),
+                ExpectedToken::CaseInsensitive("select".to_string()),
+                ExpectedToken::Named("NUM".to_string()),
+            ]
+        );
+
+        let displayed: Vec<_> = expected.iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            displayed,
+            vec![
+                r#""+""#,
+                r#""\"""#,
+                r##"r#"[0-9]+"#"##,
+                r##"r#"\\s*"#"##,
+                r#"i"select""#,
+                "NUM"
+            ]
+        );
+    }
+
+    #[test]
+    fn excerpt_multi_line() {
+        let input = "let x = 1;\nlet y = ;\nlet z = 3;";
+        let err = ParseError::UnrecognizedToken::<usize, &str, &str> {
+            token: (19, ";", 20),
+            expected: vec![],
+        };
+        assert_eq!(
+            err.excerpt(input).unwrap(),
+            "2 | let y = ;\n\
+             \x20 |         ^"
+        );
+
+        let err = ParseError::ExtraToken::<usize, &str, &str> {
+            token: (16, "y", 17),
+        };
+        assert_eq!(
+            err.excerpt("let x = 1;\r\nlet y").unwrap(),
+            "2 | let y\n\
+             \x20 |     ^"
+        );
+
+        let err = ParseError::UserWithSpan::<usize, &str, &str> {
+            error: "bad",
+            span: (4, 15),
+        };
+        assert_eq!(
+            err.excerpt(input).unwrap(),
+            "1 | let x = 1;\n\
+             \x20 |     ^^^^^^"
+        );
+
+        let err = ParseError::User::<usize, &str, &str> { error: "bad" };
+        assert_eq!(err.excerpt(input), None);
+    }
+
+    #[test]
+    fn excerpt_at_eof() {
+        let input = "(1 +\n(2";
+        let err = ParseError::UnrecognizedEOF::<usize, &str, &str> {
+            location: input.len(),
+            expected: vec![],
+        };
+        assert_eq!(
+            err.excerpt(input).unwrap(),
+            "2 | (2\n\
+             \x20 |   ^"
+        );
+
+        let err = ParseError::InvalidToken::<usize, &str, &str> { location: 5 };
+        assert_eq!(err.excerpt("(1 +\n").unwrap(), "2 | \n  | ^");
+    }
+
+    #[test]
+    fn offset_to_line_col() {
+        let input = "ab\r\ncd\n";
+        assert_eq!(super::offset_to_line_col(input, 0), (1, 1));
+        assert_eq!(super::offset_to_line_col(input, 2), (1, 3));
+        assert_eq!(super::offset_to_line_col(input, 4), (2, 1));
+        assert_eq!(super::offset_to_line_col(input, 5), (2, 2));
+
+        // columns count characters, not bytes
+        let input = "x = \"h\u{e9}\u{1f600}\" +";
+        assert_eq!(super::offset_to_line_col(input, input.len() - 1), (1, 11));
+
+        // at and past EOF
+        assert_eq!(super::offset_to_line_col("a\nb", 3), (2, 2));
+        assert_eq!(super::offset_to_line_col("a\nb", 10), (2, 2));
+        assert_eq!(super::offset_to_line_col("a\n", 2), (2, 1));
+        assert_eq!(super::offset_to_line_col("", 0), (1, 1));
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn offset_to_line_grapheme() {
+        use super::offset_to_line_grapheme;
+
+        // `e` followed by a combining acute accent
+        let input = "cafe\u{301} x";
+        let x = input.find('x').unwrap();
+        assert_eq!(offset_to_line_grapheme(input, x), (1, 6));
+        assert_eq!(super::offset_to_line_col(input, x), (1, 7));
+
+        // a family of three joined with zero-width joiners
+        let input = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467} = x";
+        let x = input.find('x').unwrap();
+        assert_eq!(offset_to_line_grapheme(input, x), (1, 5));
+        assert_eq!(super::offset_to_line_col(input, x), (1, 9));
+
+        // an offset inside a cluster gives the column of the cluster
+        assert_eq!(offset_to_line_grapheme(input, 4), (1, 1));
+
+        // lines are still counted, including after `\r\n`
+        let input = "\u{1f600}\r\na\u{308}b";
+        let b = input.find('b').unwrap();
+        assert_eq!(offset_to_line_grapheme(input, b), (2, 2));
+        assert_eq!(offset_to_line_grapheme(input, input.len()), (2, 3));
+        assert_eq!(offset_to_line_grapheme(input, 100), (2, 3));
+    }
 }
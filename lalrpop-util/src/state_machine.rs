@@ -1,9 +1,12 @@
 #![allow(dead_code)]
 
-use std::fmt::Debug;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
 
 const DEBUG_ENABLED: bool = false;
 
+#[cfg(feature = "std")]
 macro_rules! debug {
     ($($args:expr),* $(,)*) => {
         if DEBUG_ENABLED {
@@ -12,6 +15,16 @@ macro_rules! debug {
     }
 }
 
+// there is nowhere to print to without `std`
+#[cfg(not(feature = "std"))]
+macro_rules! debug {
+    ($($args:expr),* $(,)*) => {
+        if DEBUG_ENABLED {
+            $(let _ = &$args;)*
+        }
+    }
+}
+
 pub trait ParserDefinition: Sized {
     /// Represents a location in the input text. If you are using the
     /// default tokenizer, this will be a `usize`.
@@ -93,9 +106,22 @@ pub trait ParserDefinition: Sized {
     /// grammars.
     fn goto(&self, state: Self::StateIndex, nt: Self::NonterminalIndex) -> Self::StateIndex;
 
+    /// Called each time the parser enters `state`: at the start, on
+    /// shifting a terminal or the error recovery symbol, and on the
+    /// goto that follows a reduction. Does nothing by default; parsers
+    /// generated with `emit_state_trace` report the state to the user.
+    #[inline]
+    fn enter_state(&self, _state: Self::StateIndex) {}
+
     /// "Upcast" a terminal into a symbol so we can push it onto the
-    /// parser stack.
-    fn token_to_symbol(&self, token_index: Self::TokenIndex, token: Self::Token) -> Self::Symbol;
+    /// parser stack. Fails with `ParseError::Internal` only if `token`
+    /// does not match the terminal that `token_index` was computed
+    /// for, which would be a bug in LALRPOP.
+    fn token_to_symbol(
+        &self,
+        token_index: Self::TokenIndex,
+        token: Self::Token,
+    ) -> Result<Self::Symbol, ParseError<Self>>;
 
     /// Returns the expected tokens in a given state. This is used for
     /// error reporting.
@@ -190,6 +216,23 @@ where
     states: Vec<D::StateIndex>,
     symbols: Vec<SymbolTriple<D>>,
     last_location: D::Location,
+
+    /// If true, a parse stops at the end of the first complete
+    /// instance of the start symbol rather than at EOF; see
+    /// `ItemParser`.
+    stop_after_item: bool,
+
+    /// The token that ended the previous item, to be consumed first.
+    pending: Option<(TokenTriple<D>, D::TokenIndex)>,
+
+    /// If true, an error reading the next token also ends the item,
+    /// provided the input so far is a complete one; see `drive_prefix`.
+    stop_at_bad_token: bool,
+
+    /// The end of the last symbol shifted, that is, of the input
+    /// consumed so far. Only tracked by `drive_prefix`, which reports
+    /// it.
+    consumed: Option<D::Location>,
 }
 
 enum NextToken<D: ParserDefinition> {
@@ -204,16 +247,42 @@ where
     I: Iterator<Item = Result<TokenTriple<D>, ParseError<D>>>,
 {
     pub fn drive(definition: D, tokens: I) -> ParseResult<D> {
+        Parser::new(definition, tokens, false).parse()
+    }
+
+    /// Like `drive`, but parses only a prefix of the tokens: the parse
+    /// stops at the end of the first complete instance of the start
+    /// symbol, once the next token cannot continue it or cannot be
+    /// read at all, rather than reporting that token as an error.
+    /// Returns the value along with the end of the last token
+    /// consumed.
+    pub fn drive_prefix(
+        definition: D,
+        tokens: I,
+    ) -> Result<(Success<D>, Location<D>), ParseError<D>> {
+        let start_location = definition.start_location();
+        let mut parser = Parser::new(definition, tokens, true);
+        parser.stop_at_bad_token = true;
+        parser.consumed = Some(start_location);
+        let value = parser.parse()?;
+        Ok((value, parser.consumed.unwrap()))
+    }
+
+    fn new(definition: D, tokens: I, stop_after_item: bool) -> Self {
         let last_location = definition.start_location();
         let start_state = definition.start_state();
+        definition.enter_state(start_state);
         Parser {
             definition,
             tokens,
             states: vec![start_state],
             symbols: vec![],
             last_location,
+            stop_after_item,
+            pending: None,
+            stop_at_bad_token: false,
+            consumed: None,
         }
-        .parse()
     }
 
     fn top_state(&self) -> D::StateIndex {
@@ -225,10 +294,24 @@ where
         // shift a new token from the input. We break from the loop
         // when the end of the input is reached (we return early if an
         // error occurs).
+        let mut shifted = false;
         'shift: loop {
-            let (mut lookahead, mut token_index) = match self.next_token() {
+            let next_token = match self.pending.take() {
+                Some((l, i)) => NextToken::FoundToken(l, i),
+                None => self.next_token(),
+            };
+            let (mut lookahead, mut token_index) = match next_token {
                 NextToken::FoundToken(l, i) => (l, i),
                 NextToken::EOF => return self.parse_eof(),
+                NextToken::Done(Err(_))
+                    if self.stop_at_bad_token && shifted && self.accepts_eof() =>
+                {
+                    // The input so far is a complete item, so whatever
+                    // follows it is left for someone else to read.
+                    debug!("\\ end of item at a bad token");
+
+                    return self.parse_eof();
+                }
                 NextToken::Done(e) => return e,
             };
 
@@ -245,20 +328,37 @@ where
                     debug!("\\ shift to: {:?}", target_state);
 
                     // Shift and transition to state `action - 1`
-                    let symbol = self.definition.token_to_symbol(token_index, lookahead.1);
-                    self.states.push(target_state);
+                    let symbol = self.definition.token_to_symbol(token_index, lookahead.1)?;
+                    self.push_state(target_state);
+                    if let Some(ref mut consumed) = self.consumed {
+                        *consumed = lookahead.2.clone();
+                    }
                     self.symbols.push((lookahead.0, symbol, lookahead.2));
+                    shifted = true;
                     continue 'shift;
                 } else if let Some(reduce_index) = action.as_reduce() {
                     debug!("\\ reduce to: {:?}", reduce_index);
 
                     if let Some(r) = self.reduce(reduce_index, Some(&lookahead.0)) {
                         return match r {
+                            Ok(v) if self.stop_after_item => {
+                                self.pending = Some((lookahead, token_index));
+                                Ok(v)
+                            }
                             // we reached eof, but still have lookahead
                             Ok(_) => Err(crate::ParseError::ExtraToken { token: lookahead }),
                             Err(e) => Err(e),
                         };
                     }
+                } else if self.stop_after_item && shifted && self.accepts_eof() {
+                    // The lookahead cannot continue this item, but the
+                    // input so far is a complete one: finish it as if
+                    // we were at EOF and keep the lookahead for the
+                    // next item.
+                    debug!("\\ end of item");
+
+                    self.pending = Some((lookahead, token_index));
+                    return self.parse_eof();
                 } else {
                     debug!("\\ error -- initiating error recovery!");
 
@@ -282,10 +382,7 @@ where
             let top_state = self.top_state();
             let action = self.definition.eof_action(top_state);
             if let Some(reduce_index) = action.as_reduce() {
-                if let Some(result) =
-                    self.definition
-                        .reduce(reduce_index, None, &mut self.states, &mut self.symbols)
-                {
+                if let Some(result) = self.reduce(reduce_index, None) {
                     return result;
                 }
             } else {
@@ -491,11 +588,14 @@ where
         let recover_state = self.states[top];
         let error_action = self.definition.error_action(recover_state);
         let error_state = error_action.as_shift().unwrap();
-        self.states.push(error_state);
+        self.push_state(error_state);
         let recovery = self.definition.error_recovery_symbol(crate::ErrorRecovery {
             error,
             dropped_tokens,
         });
+        if let Some(ref mut consumed) = self.consumed {
+            *consumed = end.clone();
+        }
         self.symbols.push((start, recovery, end));
 
         match (opt_lookahead, opt_token_index) {
@@ -505,6 +605,13 @@ where
         }
     }
 
+    /// True if reaching EOF in the current state would complete the
+    /// parse.
+    fn accepts_eof(&self) -> bool {
+        let (&top, states) = self.states.split_last().unwrap();
+        self.accepts(top, states, None)
+    }
+
     /// The `accepts` function has the job of figuring out whether the
     /// given error state would "accept" the given lookahead. We
     /// basically trace through the LR automaton looking for one of
@@ -577,13 +684,25 @@ where
         }
     }
 
+    fn push_state(&mut self, state: D::StateIndex) {
+        self.definition.enter_state(state);
+        self.states.push(state);
+    }
+
+    /// Executes a reduction, which also pushes the state reached by
+    /// the goto on the produced nonterminal.
     fn reduce(
         &mut self,
         action: D::ReduceIndex,
         lookahead_start: Option<&D::Location>,
     ) -> Option<ParseResult<D>> {
-        self.definition
-            .reduce(action, lookahead_start, &mut self.states, &mut self.symbols)
+        let result =
+            self.definition
+                .reduce(action, lookahead_start, &mut self.states, &mut self.symbols);
+        if result.is_none() {
+            self.definition.enter_state(self.top_state());
+        }
+        result
     }
 
     fn unrecognized_token_error(
@@ -668,3 +787,172 @@ macro_rules! integral_indices {
 integral_indices!(i32);
 integral_indices!(i16);
 integral_indices!(i8);
+
+/// Parses a sequence of instances of the start symbol from a single
+/// token stream, one at a time. Each call to `next_item` consumes
+/// tokens up to the end of the next complete instance, which is found
+/// when the following token cannot continue it; that token is kept
+/// for the next call. Once the tokens are exhausted, or after an
+/// error, `next_item` returns `None`.
+pub struct ItemParser<D, I>
+where
+    D: ParserDefinition,
+    I: Iterator<Item = Result<TokenTriple<D>, ParseError<D>>>,
+{
+    parser: Parser<D, I>,
+    done: bool,
+}
+
+impl<D, I> ItemParser<D, I>
+where
+    D: ParserDefinition,
+    I: Iterator<Item = Result<TokenTriple<D>, ParseError<D>>>,
+{
+    pub fn new(definition: D, tokens: I) -> Self {
+        ItemParser {
+            parser: Parser::new(definition, tokens, true),
+            done: false,
+        }
+    }
+
+    pub fn next_item(&mut self) -> Option<ParseResult<D>> {
+        if self.done {
+            return None;
+        }
+
+        let parser = &mut self.parser;
+        if parser.pending.is_none() {
+            match parser.next_token() {
+                NextToken::FoundToken(l, i) => parser.pending = Some((l, i)),
+                NextToken::EOF => {
+                    self.done = true;
+                    return None;
+                }
+                NextToken::Done(e) => {
+                    self.done = true;
+                    return Some(e);
+                }
+            }
+        }
+
+        let start_state = parser.definition.start_state();
+        parser.states = vec![start_state];
+        parser.symbols.clear();
+        let result = parser.parse();
+
+        // Without a pending token, the item ended at EOF or at an
+        // error; either way there is nothing more to parse.
+        self.done = parser.pending.is_none();
+        Some(result)
+    }
+}
+
+impl<D, I> Iterator for ItemParser<D, I>
+where
+    D: ParserDefinition,
+    I: Iterator<Item = Result<TokenTriple<D>, ParseError<D>>>,
+{
+    type Item = ParseResult<D>;
+
+    fn next(&mut self) -> Option<ParseResult<D>> {
+        self.next_item()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A parser whose `token_to_symbol` disagrees with `token_to_index`,
+    /// as a generator bug would make it.
+    struct Mismatched;
+
+    impl ParserDefinition for Mismatched {
+        type Location = usize;
+        type Error = ();
+        type Token = char;
+        type TokenIndex = usize;
+        type Symbol = ();
+        type Success = ();
+        type StateIndex = i8;
+        type Action = i8;
+        type ReduceIndex = i8;
+        type NonterminalIndex = usize;
+
+        fn start_location(&self) -> usize {
+            0
+        }
+
+        fn start_state(&self) -> i8 {
+            0
+        }
+
+        fn token_to_index(&self, _token: &char) -> Option<usize> {
+            Some(0)
+        }
+
+        fn action(&self, _state: i8, _token_index: usize) -> i8 {
+            // shift into state 1
+            2
+        }
+
+        fn error_action(&self, _state: i8) -> i8 {
+            0
+        }
+
+        fn eof_action(&self, _state: i8) -> i8 {
+            0
+        }
+
+        fn goto(&self, _state: i8, _nt: usize) -> i8 {
+            0
+        }
+
+        fn token_to_symbol(
+            &self,
+            _token_index: usize,
+            _token: char,
+        ) -> Result<(), ParseError<Self>> {
+            Err(crate::ParseError::Internal {
+                message: "token does not match the terminal of its index",
+            })
+        }
+
+        fn expected_tokens(&self, _state: i8) -> Vec<String> {
+            vec![]
+        }
+
+        fn uses_error_recovery(&self) -> bool {
+            false
+        }
+
+        fn error_recovery_symbol(&self, _recovery: ErrorRecovery<Self>) {
+            panic!("error recovery not enabled for this grammar")
+        }
+
+        fn reduce(
+            &mut self,
+            _reduce_index: i8,
+            _start_location: Option<&usize>,
+            _states: &mut Vec<i8>,
+            _symbols: &mut Vec<SymbolTriple<Self>>,
+        ) -> Option<ParseResult<Self>> {
+            None
+        }
+
+        fn simulate_reduce(&self, _action: i8) -> SimulatedReduce<Self> {
+            SimulatedReduce::Accept
+        }
+    }
+
+    #[test]
+    fn internal_error_is_returned() {
+        let tokens = vec![Ok((0, 'a', 1))];
+        assert_eq!(
+            Parser::drive(Mismatched, tokens.into_iter()),
+            Err(crate::ParseError::Internal {
+                message: "token does not match the terminal of its index",
+            })
+        );
+    }
+}
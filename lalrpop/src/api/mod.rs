@@ -1,4 +1,4 @@
-use crate::build;
+use crate::build::{self, Conflict, LookaheadSets};
 use crate::log::Level;
 use crate::session::{ColorConfig, Session};
 use std::default::Default;
@@ -132,6 +132,183 @@ impl Configuration {
         self
     }
 
+    /// If true, write the LR states of each public nonterminal `Foo`
+    /// of `bar.lalrpop` to `bar.Foo.dot`, next to the report file, as
+    /// a GraphViz graph. States with conflicts are drawn in red; this
+    /// is useful for seeing how a conflict arises. Default is false.
+    pub fn emit_dot(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_dot = val;
+        self
+    }
+
+    /// If true, report grammar conflicts on stdout as a JSON array
+    /// instead of as text, for editors and other tools. Each element
+    /// describes one conflict: the grammar file and the span of the
+    /// production being reduced in it (as byte offsets), how the
+    /// conflict was classified, the lookahead, and example inputs for
+    /// both actions. Default is false.
+    pub fn emit_json_conflicts(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_json_conflicts = val;
+        self
+    }
+
+    /// If true, report each grammar conflict as a single compiler-style
+    /// `error:` line naming the state, the kind of conflict, the
+    /// lookahead and the classification, instead of the explanation
+    /// with example pictures. Default is false.
+    pub fn compact_conflicts(&mut self, val: bool) -> &mut Configuration {
+        self.session.compact_conflicts = val;
+        self
+    }
+
+    /// If true, the examples in conflict reports show terminals that a
+    /// `match` block renames, such as `NUM` in `match { r"[0-9]+" =>
+    /// NUM }`, by the literal or regular expression they match
+    /// instead. Default is false.
+    pub fn literal_conflict_examples(&mut self, val: bool) -> &mut Configuration {
+        self.session.literal_conflict_examples = val;
+        self
+    }
+
+    /// If true, conflict reports show, below each example, the LR item
+    /// that the parser is in at the cursor: the production being
+    /// built, with `(*)` marking how far it has got, such as
+    /// `Expr = Expr "*" (*) Expr`. Default is false.
+    pub fn conflict_items(&mut self, val: bool) -> &mut Configuration {
+        self.session.conflict_items = val;
+        self
+    }
+
+    /// If false, the generated modules refer to `lalrpop_util` through
+    /// `::lalrpop_util` paths instead of declaring `extern crate
+    /// lalrpop_util` themselves, which is more robust when the output
+    /// is `include!`d into another module. This requires
+    /// `lalrpop_util` to be reachable as `::lalrpop_util`: always true
+    /// in the 2018 edition, and true in the 2015 edition if the crate
+    /// root declares `extern crate lalrpop_util`. Default is true.
+    pub fn emit_extern_crate(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_extern_crate = val;
+        self
+    }
+
+    /// If true, mark the generated `parse` methods with `#[must_use]`
+    /// and a message, so that ignoring the result of a parse triggers
+    /// a warning that explains why. Default is true.
+    pub fn emit_must_use(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_must_use = val;
+        self
+    }
+
+    /// If true, generate a `dump` method alongside each `parse`
+    /// method. `dump` parses its input and returns the result (or the
+    /// error) pretty-printed with `Debug`, which is handy for eyeballing
+    /// parse results in tests. The result type of the start symbol
+    /// must implement `Debug`. Default is false.
+    pub fn emit_dump_helpers(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_dump_helpers = val;
+        self
+    }
+
+    /// If true, generate a `parse_boxed` method alongside each `parse`
+    /// method. `parse_boxed` returns `Result<T, Box<dyn Error>>`, so
+    /// that quick scripts can use `?` without naming the full
+    /// `ParseError` type. The error is converted using its `Display`
+    /// impl. Default is false.
+    pub fn emit_boxed_errors(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_boxed_errors = val;
+        self
+    }
+
+    /// If true, generate a `parse_items` method alongside each `parse`
+    /// method, for grammars that use the built-in lexer. `parse_items`
+    /// returns a `lalrpop_util::state_machine::ItemParser`, whose
+    /// `next_item` method parses the input one instance of the start
+    /// symbol at a time: each item ends as soon as the next token
+    /// cannot continue it, and `None` is returned once only whitespace
+    /// is left. This is useful for REPLs and other inputs made of a
+    /// sequence of independent items. It requires the table-driven
+    /// parser, and is ignored for `#[recursive_ascent]` grammars.
+    /// Default is false.
+    pub fn emit_item_parsers(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_item_parsers = val;
+        self
+    }
+
+    /// If true, generate a `parse_prefix` method alongside each `parse`
+    /// method, for grammars that use the built-in lexer. Where `parse`
+    /// fails if input is left over, `parse_prefix` stops at the end of
+    /// the first complete instance of the start symbol, once the next
+    /// token cannot continue it or is not one the lexer recognizes, and
+    /// returns its value along with the byte offset just past the last
+    /// token it consumed. It requires the table-driven parser, and is ignored for
+    /// `#[recursive_ascent]` grammars. Default is false.
+    pub fn emit_prefix_parsers(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_prefix_parsers = val;
+        self
+    }
+
+    /// If true, generate `tokenize` and `terminal_name` methods on each
+    /// parser of a grammar that uses the built-in lexer. `tokenize`
+    /// runs just the lexer over the input, yielding `(start, token,
+    /// end)` triples, and `terminal_name` returns the terminal that a
+    /// token matched as written in the grammar (e.g. `"*"` or `NUM`).
+    /// This lets tools such as syntax highlighters reuse the grammar's
+    /// terminals. Table-driven parsers also get a `parse_tokens`
+    /// method that parses a given vector of such triples instead of
+    /// running the lexer, which keeps fuzzers and golden tests of the
+    /// parser independent of the lexer. Default is false.
+    pub fn emit_tokenizers(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_tokenizers = val;
+        self
+    }
+
+    /// If true, give each parser a `STATE_COUNT` associated constant
+    /// with the number of states of its LR(1) automaton, and make the
+    /// parser call `trace_state(index)` each time it enters the state
+    /// with that index (`0..STATE_COUNT`). The grammar must bring a
+    /// function `trace_state(usize)` into scope, e.g. with `use
+    /// crate::coverage::trace_state;`. Together these let tools measure
+    /// which states a test corpus exercises. When false, nothing is
+    /// generated and parsers pay nothing for it. Default is false.
+    pub fn emit_state_trace(&mut self, val: bool) -> &mut Configuration {
+        self.session.emit_state_trace = val;
+        self
+    }
+
+    /// If true, a `ParseError::User` error returned by a fallible
+    /// (`=>?`) action is turned into a `ParseError::UserWithSpan`
+    /// carrying the start and end locations of the symbols the action
+    /// consumed, so that the error can be shown in context. For an
+    /// empty production, both locations are where the production was
+    /// found. Default is false.
+    pub fn user_error_spans(&mut self, val: bool) -> &mut Configuration {
+        self.session.user_error_spans = val;
+        self
+    }
+
+    /// If true, regex terminals are copied into the generated
+    /// tokenizer as written. Otherwise they are copied in a normalized
+    /// form, in which Unicode classes such as `\p{XID_Start}` or `\w`
+    /// are spelled out as (often thousands of) character ranges from
+    /// the Unicode tables LALRPOP was built with. Leaving the classes
+    /// in place keeps the generated code small and uses the tables of
+    /// the `regex` crate the parser is built against, which then needs
+    /// its `unicode` feature (on by default). Default is false.
+    pub fn unicode_classes(&mut self, val: bool) -> &mut Configuration {
+        self.session.unicode_classes = val;
+        self
+    }
+
+    /// If true, grammars without an `extern` token declaration take
+    /// their input as `&[u8]` rather than `&str`, so that it need not
+    /// be valid UTF-8. Terminals are then `&[u8]` slices, tokens are
+    /// `lalrpop_util::lexer::ByteToken` (re-exported as `Token`), and
+    /// locations are byte offsets. Default is false.
+    pub fn byte_input(&mut self, val: bool) -> &mut Configuration {
+        self.session.byte_input = val;
+        self
+    }
+
     /// Minimal logs: only for errors that halt progress.
     pub fn log_quiet(&mut self) -> &mut Configuration {
         self.session.log.set_level(Level::Taciturn);
@@ -158,6 +335,20 @@ impl Configuration {
         self
     }
 
+    /// Sets the prefix of the names LALRPOP generates for action
+    /// functions, states, nonterminals and other internal items, e.g.
+    /// `gen_` for `gen_action0`. The prefix must be an identifier.
+    /// Underscores are appended to it until it does not occur anywhere
+    /// in the grammar, so generated names cannot clash with names used
+    /// in action code. Default is `__`.
+    pub fn set_prefix<S>(&mut self, prefix: S) -> &mut Configuration
+    where
+        S: Into<String>,
+    {
+        self.session.prefix = Some(prefix.into());
+        self
+    }
+
     /// Sets the features used during compilation, disables the use of cargo features.
     /// (Default: Loaded from `CARGO_FEATURE_{}` environment variables).
     pub fn set_features<I>(&mut self, iterable: I) -> &mut Configuration
@@ -168,6 +359,34 @@ impl Configuration {
         self
     }
 
+    /// Sets the maximum number of symbols in the example inputs shown
+    /// when reporting a conflict. Longer examples are only used if
+    /// there is no shorter one. Default is 20.
+    pub fn set_max_example_symbols(&mut self, val: usize) -> &mut Configuration {
+        self.session.max_example_symbols = val;
+        self
+    }
+
+    /// If non-zero, warn about each `#[inline]` nonterminal whose
+    /// inlining multiplies the number of parser states by more than
+    /// `val`. This builds the parse table once more for every such
+    /// nonterminal, so it slows down generation. Default is 0 (no
+    /// check).
+    pub fn set_max_inline_growth(&mut self, val: usize) -> &mut Configuration {
+        self.session.max_inline_growth = val;
+        self
+    }
+
+    /// If true, warn about each terminal that is declared in a `match`
+    /// or `extern` block but used by no production, which usually
+    /// means its name drifted from the name used in the rules, and
+    /// about each nonterminal that cannot be reached from any public
+    /// nonterminal. Default is false.
+    pub fn warn_unused(&mut self, val: bool) -> &mut Configuration {
+        self.session.warn_unused = val;
+        self
+    }
+
     /// Enables "unit-testing" configuration. This is only for
     /// lalrpop-test.
     #[doc(hidden)]
@@ -176,6 +395,46 @@ impl Configuration {
         self
     }
 
+    /// Returns the messages LALRPOP would print for the conflicts in
+    /// the grammar `source`, rendered as plain text, one string per
+    /// message. The result is empty if the grammar has no conflicts.
+    /// Locations in the messages refer to a file named
+    /// `grammar.lalrpop`.
+    ///
+    /// This is intended for snapshot tests that catch changes to a
+    /// grammar's diagnostics.
+    pub fn conflict_messages(&self, source: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        let session = Rc::new(self.session.clone());
+        Ok(build::conflict_messages(session, source)?)
+    }
+
+    /// Checks that the grammar `source` builds without conflicts, and
+    /// returns the messages `conflict_messages` gives for them if not
+    /// (or the error, if the grammar is not valid). Calling this from a
+    /// test or a build script makes an edit that introduces a conflict
+    /// fail there, with the messages in hand.
+    pub fn assert_no_conflicts(&self, source: &str) -> Result<(), Vec<String>> {
+        let session = Rc::new(self.session.clone());
+        build::assert_no_conflicts(session, source)
+    }
+
+    /// Returns the conflicts in the grammar `source` in structured
+    /// form, without rendering any messages. The result is empty if the
+    /// grammar has no conflicts. Useful for asserting, say, that a
+    /// grammar has exactly one `Precedence` conflict.
+    pub fn conflicts(&self, source: &str) -> Result<Vec<Conflict>, Box<dyn Error>> {
+        let session = Rc::new(self.session.clone());
+        Ok(build::conflicts(session, source)?)
+    }
+
+    /// Returns the FIRST and FOLLOW sets of each nonterminal of the
+    /// grammar `source`. These are what decide which lookahead tokens
+    /// appear in a conflict, so they help with understanding one.
+    pub fn lookahead_sets(&self, source: &str) -> Result<Vec<LookaheadSets>, Box<dyn Error>> {
+        let session = Rc::new(self.session.clone());
+        Ok(build::lookahead_sets(session, source)?)
+    }
+
     /// Process all files according to the `set_in_dir` and
     /// `set_out_dir` configuration.
     pub fn process(&self) -> Result<(), Box<dyn Error>> {
@@ -254,6 +513,13 @@ pub fn process_root() -> Result<(), Box<dyn Error>> {
     Configuration::new().process_current_dir()
 }
 
+/// Checks that the grammar `source` has no conflicts.
+///
+/// Equivalent to `Configuration::new().assert_no_conflicts(source)`.
+pub fn assert_no_conflicts(source: &str) -> Result<(), Vec<String>> {
+    Configuration::new().assert_no_conflicts(source)
+}
+
 /// Deprecated in favor of `Configuration`. Try:
 ///
 /// ```rust
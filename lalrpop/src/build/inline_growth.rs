@@ -0,0 +1,104 @@
+//! Warns about `#[inline]` nonterminals that make the parser much
+//! larger. Inlining copies the productions of a nonterminal into every
+//! place it is used, which can multiply the number of LR states.
+
+use crate::grammar::consts::INLINE;
+use crate::grammar::repr::*;
+use crate::lr1;
+use crate::message::builder::MessageBuilder;
+use crate::message::Message;
+use crate::normalize;
+use crate::tls::Tls;
+use string_cache::DefaultAtom as Atom;
+
+/// For each `#[inline]` nonterminal of `lowered`, compares the number
+/// of states of `inlined` (the fully inlined grammar) with the number
+/// we get when that one nonterminal is not inlined. Returns a warning
+/// for each nonterminal whose inlining multiplies the number of states
+/// by more than `max_growth`. Grammars with conflicts are skipped.
+pub fn check(lowered: &Grammar, inlined: &Grammar, max_growth: usize) -> Vec<Message> {
+    let inline = Atom::from(INLINE);
+    let mut messages = vec![];
+
+    let inlined_states = match state_count(inlined) {
+        Some(count) => count,
+        None => return messages,
+    };
+
+    for (name, data) in &lowered.nonterminals {
+        if !data.annotations.iter().any(|a| a.id == inline) {
+            continue;
+        }
+
+        let mut grammar = lowered.clone();
+        grammar
+            .nonterminals
+            .get_mut(name)
+            .unwrap()
+            .annotations
+            .retain(|a| a.id != inline);
+        let states = match normalize::inline_nonterminals(grammar)
+            .ok()
+            .and_then(|grammar| state_count(&grammar))
+        {
+            Some(count) => count,
+            None => continue,
+        };
+
+        if inlined_states > states * max_growth {
+            messages.push(report_growth(name, data.span, inlined_states, states));
+        }
+    }
+
+    messages
+}
+
+/// The number of states across all of the grammar's start symbols, or
+/// `None` if the grammar has conflicts.
+fn state_count(grammar: &Grammar) -> Option<usize> {
+    let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+    let mut count = 0;
+    for start_nt in grammar.start_nonterminals.values() {
+        count += lr1::build_states(grammar, start_nt.clone()).ok()?.len();
+    }
+    Some(count)
+}
+
+fn report_growth(
+    name: &NonterminalString,
+    span: Span,
+    inlined_states: usize,
+    states: usize,
+) -> Message {
+    MessageBuilder::new(span)
+        .heading()
+        .text(format!(
+            "Inlining `{}` makes the parser {} times larger",
+            name,
+            inlined_states / states
+        ))
+        .end()
+        .body()
+        .begin_wrap()
+        .text("With")
+        .push(name.clone())
+        .verbatimed()
+        .text(format!(
+            "inlined, the parser has {} states; without inlining it, it would have {}.",
+            inlined_states, states
+        ))
+        .end()
+        .begin_wrap()
+        .text("Hint:")
+        .styled(Tls::session().hint_text)
+        .text("Consider removing the")
+        .text("#[inline]")
+        .verbatimed()
+        .text("annotation from")
+        .push(name.clone())
+        .verbatimed()
+        .punctuated(".")
+        .end()
+        .end()
+        .end()
+}
@@ -4,16 +4,17 @@ use crate::file_text::FileText;
 use crate::grammar::parse_tree as pt;
 use crate::grammar::repr as r;
 use crate::lexer::intern_token;
+use crate::log::Level;
 use crate::lr1;
 use crate::message::builder::InlineBuilder;
 use crate::message::{Content, Message};
 use crate::normalize;
 use crate::parser;
 use crate::rust::RustWrite;
-use crate::session::{ColorConfig, Session};
+use crate::session::Session;
 use crate::tls::Tls;
 use crate::tok;
-use atty;
+use crate::util::Sep;
 use lalrpop_util::ParseError;
 use sha2::{Digest, Sha256};
 use term;
@@ -26,6 +27,11 @@ use std::rc::Rc;
 
 mod action;
 mod fake_term;
+mod inline_growth;
+mod unused;
+
+#[cfg(test)]
+mod test;
 
 use self::fake_term::FakeTerminal;
 
@@ -194,49 +200,114 @@ fn lalrpop_files<P: AsRef<Path>>(root_dir: P) -> io::Result<Vec<PathBuf>> {
 }
 
 fn parse_and_normalize_grammar(session: &Session, file_text: &FileText) -> io::Result<r::Grammar> {
-    let grammar = match parser::parse_grammar(file_text.text()) {
-        Ok(grammar) => grammar,
+    let grammar = if session.max_inline_growth > 0 {
+        // lower once, and keep the grammar from before inlining to
+        // compare against
+        let lowered = match parse_and_lower(session, file_text) {
+            Ok(grammar) => grammar,
+            Err((span, message)) => report_error(&file_text, span, &message),
+        };
+        let grammar = match normalize::inline_nonterminals(lowered.clone()) {
+            Ok(grammar) => grammar,
+            Err(error) => report_error(&file_text, error.span, &error.message),
+        };
+        let messages = inline_growth::check(&lowered, &grammar, session.max_inline_growth);
+        if !messages.is_empty() {
+            let _ = report_messages(messages);
+        }
+        grammar
+    } else {
+        match parse_and_normalize(session, file_text) {
+            Ok(grammar) => grammar,
+            Err((span, message)) => report_error(&file_text, span, &message),
+        }
+    };
 
-        Err(ParseError::InvalidToken { location }) => {
-            let ch = file_text.text()[location..].chars().next().unwrap();
-            report_error(
-                &file_text,
-                pt::Span(location, location),
-                &format!("invalid character `{}`", ch),
-            );
+    if session.warn_unused {
+        let messages = unused::check(&grammar);
+        if !messages.is_empty() {
+            let _ = report_messages(messages);
+        }
+    }
+
+    Ok(grammar)
+}
+
+/// Parses and normalizes the grammar, returning the span and message
+/// of the first error encountered.
+fn parse_and_normalize(
+    session: &Session,
+    file_text: &FileText,
+) -> Result<r::Grammar, (pt::Span, String)> {
+    let grammar = parse_grammar(session, file_text)?;
+    normalize::normalize(session, grammar).map_err(|error| (error.span, error.message))
+}
+
+/// Like `parse_and_normalize`, but leaves `#[inline]` nonterminals in
+/// place.
+fn parse_and_lower(
+    session: &Session,
+    file_text: &FileText,
+) -> Result<r::Grammar, (pt::Span, String)> {
+    let grammar = parse_grammar(session, file_text)?;
+    normalize::lower_without_inlining(session, grammar).map_err(|error| (error.span, error.message))
+}
+
+fn parse_grammar(
+    session: &Session,
+    file_text: &FileText,
+) -> Result<pt::Grammar, (pt::Span, String)> {
+    let mut grammar = parse_grammar_text(file_text)?;
+    if let Some(ref prefix) = session.prefix {
+        let mut chars = prefix.chars();
+        let valid = chars
+            .next()
+            .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err((
+                pt::Span(0, 0),
+                format!("the prefix `{}` is not a valid identifier", prefix),
+            ));
         }
+        grammar.prefix = parser::unique_prefix(file_text.text(), prefix);
+    }
+    Ok(grammar)
+}
 
-        Err(ParseError::UnrecognizedEOF { location, .. }) => {
-            report_error(
-                &file_text,
+fn parse_grammar_text(file_text: &FileText) -> Result<pt::Grammar, (pt::Span, String)> {
+    parser::parse_grammar(file_text.text()).map_err(|error| match error {
+        ParseError::InvalidToken { location } => {
+            let ch = file_text.text()[location..].chars().next().unwrap();
+            (
                 pt::Span(location, location),
-                "unexpected end of file",
-            );
+                format!("invalid character `{}`", ch),
+            )
         }
 
-        Err(ParseError::UnrecognizedToken {
+        ParseError::UnrecognizedEOF { location, .. } => (
+            pt::Span(location, location),
+            "unexpected end of file".to_owned(),
+        ),
+
+        ParseError::UnrecognizedToken {
             token: (lo, _, hi),
             expected,
-        }) => {
+        } => {
             let _ = expected; // didn't implement this yet :)
             let text = &file_text.text()[lo..hi];
-            report_error(
-                &file_text,
-                pt::Span(lo, hi),
-                &format!("unexpected token: `{}`", text),
-            );
+            (pt::Span(lo, hi), format!("unexpected token: `{}`", text))
         }
 
-        Err(ParseError::ExtraToken { token: (lo, _, hi) }) => {
+        ParseError::ExtraToken { token: (lo, _, hi) } => {
             let text = &file_text.text()[lo..hi];
-            report_error(
-                &file_text,
+            (
                 pt::Span(lo, hi),
-                &format!("extra token at end of input: `{}`", text),
-            );
+                format!("extra token at end of input: `{}`", text),
+            )
         }
 
-        Err(ParseError::User { error }) => {
+        ParseError::User { error } | ParseError::UserWithSpan { error, .. } => {
             let string = match error.code {
                 tok::ErrorCode::UnrecognizedToken => "unrecognized token",
                 tok::ErrorCode::UnterminatedEscape => "unterminated escape; missing '`'?",
@@ -256,18 +327,162 @@ fn parse_and_normalize_grammar(session: &Session, file_text: &FileText) -> io::R
                 }
             };
 
-            report_error(
-                &file_text,
+            (
                 pt::Span(error.location, error.location + 1),
-                string,
+                string.to_owned(),
             )
         }
-    };
 
-    match normalize::normalize(session, grammar) {
-        Ok(grammar) => Ok(grammar),
-        Err(error) => report_error(&file_text, error.span, &error.message),
+        ParseError::Internal { message } => {
+            (pt::Span(0, 0), format!("internal error: {}", message))
+        }
+
+        _ => (pt::Span(0, 0), "unexpected parse error".to_owned()),
+    })
+}
+
+/// Renders the messages that LALRPOP would report for the conflicts
+/// in the grammar `source`, one string per message and without
+/// colors. Returns an empty vector if the grammar has no conflicts,
+/// and the error message if it cannot be parsed or normalized.
+pub fn conflict_messages(session: Rc<Session>, source: &str) -> Result<Vec<String>, String> {
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+
+    let grammar = parse_and_normalize(&session, &file_text)
+        .map_err(|(span, message)| format!("{} error: {}", file_text.span_str(span), message))?;
+
+    let mut messages = vec![];
+    for start_nt in grammar.start_nonterminals.values() {
+        let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+        if let Err(error) = lr1::build_states(&grammar, start_nt.clone()) {
+            messages.extend(lr1::report_error(&grammar, &error));
+        }
     }
+
+    Ok(messages
+        .into_iter()
+        .map(|message| {
+            let rows: Vec<String> = message
+                .emit_to_canvas(80)
+                .to_strings()
+                .iter()
+                .map(|row| row.to_string().trim_end().to_owned())
+                .collect();
+            rows.join("\n")
+        })
+        .collect())
+}
+
+/// Checks that the grammar `source` has no conflicts. Otherwise,
+/// returns the messages of `conflict_messages`, or the error message
+/// alone if the grammar cannot be parsed or normalized.
+pub fn assert_no_conflicts(session: Rc<Session>, source: &str) -> Result<(), Vec<String>> {
+    match conflict_messages(session, source) {
+        Ok(ref messages) if messages.is_empty() => Ok(()),
+        Ok(messages) => Err(messages),
+        Err(message) => Err(vec![message]),
+    }
+}
+
+/// One conflict of a grammar, as `conflicts` reports it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conflict {
+    /// The kind of conflict, like `Precedence` or `SuggestInline`.
+    pub classification: String,
+    /// The LR state the conflict occurs in.
+    pub state: usize,
+    /// The lookahead the conflict occurs on: a terminal written as in
+    /// the grammar, `EOF` or `error`.
+    pub lookahead: String,
+    /// The byte offsets, into the grammar source, of the production
+    /// being reduced.
+    pub span: (usize, usize),
+}
+
+/// Classifies the conflicts of the grammar `source` without rendering
+/// any messages, so that they can be counted or matched on. Returns the
+/// error message if the grammar cannot be parsed or normalized.
+pub fn conflicts(session: Rc<Session>, source: &str) -> Result<Vec<Conflict>, String> {
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+
+    let grammar = parse_and_normalize(&session, &file_text)
+        .map_err(|(span, message)| format!("{} error: {}", file_text.span_str(span), message))?;
+
+    let mut conflicts = vec![];
+    for start_nt in grammar.start_nonterminals.values() {
+        let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+        if let Err(error) = lr1::build_states(&grammar, start_nt.clone()) {
+            conflicts.extend(lr1::collect_conflicts(&grammar, &error).into_iter().map(
+                |(state, lookahead, span, classification)| Conflict {
+                    classification: classification.name().to_owned(),
+                    state: state.0,
+                    lookahead: lookahead.to_string(),
+                    span: (span.0, span.1),
+                },
+            ));
+        }
+    }
+    Ok(conflicts)
+}
+
+/// The FIRST and FOLLOW sets of one nonterminal, as LALRPOP computes
+/// them for LR(1) lookahead. Terminals are written as in the grammar,
+/// and the end of the input as `EOF`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LookaheadSets {
+    pub nonterminal: String,
+    /// The terminals that can start the nonterminal.
+    pub first: Vec<String>,
+    /// Whether the nonterminal can match empty input.
+    pub nullable: bool,
+    /// The terminals, and possibly `EOF`, that can come right after
+    /// the nonterminal.
+    pub follow: Vec<String>,
+}
+
+/// Computes the `LookaheadSets` of every nonterminal of the grammar
+/// `source`, including the ones LALRPOP adds itself (e.g. for macros
+/// and `*`), in alphabetical order. Returns the error message if the
+/// grammar cannot be parsed or normalized.
+pub fn lookahead_sets(session: Rc<Session>, source: &str) -> Result<Vec<LookaheadSets>, String> {
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+
+    let grammar = parse_and_normalize(&session, &file_text)
+        .map_err(|(span, message)| format!("{} error: {}", file_text.span_str(span), message))?;
+
+    let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+    let first_sets = lr1::FirstSets::new(&grammar);
+    let follow_sets = lr1::FollowSets::new(&grammar, &first_sets);
+    Ok(grammar
+        .nonterminals
+        .keys()
+        .map(|nt| {
+            let mut first = first_sets.first0(Some(&r::Symbol::Nonterminal(nt.clone())));
+            let nullable = first.take_eof();
+            LookaheadSets {
+                nonterminal: nt.to_string(),
+                first: first.iter().map(|token| token.to_string()).collect(),
+                nullable,
+                follow: follow_sets
+                    .follow(nt)
+                    .iter()
+                    .map(|token| token.to_string())
+                    .collect(),
+            }
+        })
+        .collect())
 }
 
 fn report_error(file_text: &FileText, span: pt::Span, message: &str) -> ! {
@@ -293,13 +508,7 @@ fn report_content(content: &dyn Content) -> term::Result<()> {
     // FIXME -- can we query the size of the terminal somehow?
     let canvas = content.emit_to_canvas(80);
 
-    let try_colors = match Tls::session().color_config {
-        ColorConfig::Yes => true,
-        ColorConfig::No => false,
-        ColorConfig::IfTty => atty::is(atty::Stream::Stdout),
-    };
-
-    if try_colors {
+    if Tls::session().use_colors() {
         if let Some(mut stdout) = term::stdout() {
             return canvas.write_to(&mut *stdout);
         }
@@ -375,12 +584,42 @@ fn emit_recursive_ascent(
             let mut output_report_file = fs::File::create(&report_file)?;
             lr1::generate_report(&mut output_report_file, &lr1result)?;
         }
+        if session.emit_dot {
+            let states = match lr1result {
+                Ok(ref states) => states,
+                Err(ref error) => &error.states,
+            };
+            let dot_file = report_file.with_extension(format!("{}.dot", user_nt));
+            let mut output_dot_file = fs::File::create(&dot_file)?;
+            output_dot_file.write_all(lr1::states_to_dot(states, &grammar).as_bytes())?;
+        }
 
         let states = match lr1result {
             Ok(states) => states,
             Err(error) => {
-                let messages = lr1::report_error(&grammar, &error);
-                let _ = report_messages(messages);
+                session.log(Level::Verbose, || {
+                    let conflicts = lr1::collect_conflicts(&grammar, &error);
+                    let names: Vec<_> = conflicts
+                        .iter()
+                        .map(|&(_, _, _, ref classification)| classification.name())
+                        .collect();
+                    format!(
+                        "{} conflict(s) building states for `{}`: {}",
+                        conflicts.len(),
+                        user_nt,
+                        names.join(", ")
+                    )
+                });
+                if session.emit_json_conflicts {
+                    println!("{}", lr1::report_error_json(&grammar, &error));
+                } else if session.compact_conflicts {
+                    for line in lr1::report_error_compact(&grammar, &error) {
+                        println!("{}", line);
+                    }
+                } else {
+                    let messages = lr1::report_error(&grammar, &error);
+                    let _ = report_messages(messages);
+                }
                 exit(1) // FIXME -- propagate up instead of calling `exit`
             }
         };
@@ -426,8 +665,9 @@ fn emit_recursive_ascent(
         intern_token::compile(&grammar, intern_token, &mut rust)?;
         rust!(
             rust,
-            "pub use self::{}lalrpop_util::lexer::Token;",
-            grammar.prefix
+            "pub use self::{}lalrpop_util::lexer::{};",
+            grammar.prefix,
+            intern_token::token_import(intern_token)
         );
     }
 
@@ -435,9 +675,49 @@ fn emit_recursive_ascent(
 
     emit_to_triple_trait(grammar, &mut rust)?;
 
+    emit_into_error_impl(grammar, &mut rust)?;
+
     Ok(rust.into_inner())
 }
 
+/// If the grammar declares `type IntoError = E2`, emits an impl of
+/// `From<ParseError<L, T, E>>` for `E2`, so that `?` converts the errors
+/// of the generated parsers. The tokens are turned into strings first,
+/// since they borrow from the input, and `E2` is built with its own
+/// `From<ParseError<L, String, E>>` impl. Prevalidation only allows
+/// `IntoError` with the built-in lexer, whose tokens implement `Display`.
+fn emit_into_error_impl<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
+    let into_error_type = match grammar.types.opt_into_error_type() {
+        Some(ty) => ty,
+        None => return Ok(()),
+    };
+
+    rust!(rust, "");
+    rust!(
+        rust,
+        "impl<{utp}> ::std::convert::From<{parse_error}> for {into_error}",
+        utp = Sep(", ", &grammar.type_parameters),
+        parse_error = grammar.types.parse_error_type(),
+        into_error = into_error_type,
+    );
+    if !grammar.where_clauses.is_empty() {
+        rust!(rust, "where {}", Sep(", ", &grammar.where_clauses));
+    }
+    rust!(rust, "{{");
+    rust!(
+        rust,
+        "fn from(error: {parse_error}) -> Self {{",
+        parse_error = grammar.types.parse_error_type(),
+    );
+    rust!(
+        rust,
+        "::std::convert::From::from(error.map_token(|token| token.to_string()))"
+    );
+    rust!(rust, "}}"); // fn
+    rust!(rust, "}}"); // impl
+    Ok(())
+}
+
 fn emit_to_triple_trait<W: Write>(grammar: &r::Grammar, rust: &mut RustWrite<W>) -> io::Result<()> {
     #![allow(non_snake_case)]
 
@@ -0,0 +1,457 @@
+use crate::file_text::FileText;
+use crate::lr1;
+use crate::message::Content;
+use crate::rust::RustWrite;
+use crate::session::Session;
+use crate::tls::Tls;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use super::{
+    assert_no_conflicts, conflict_messages, conflicts, inline_growth, lookahead_sets,
+    parse_and_lower, parse_and_normalize, unused, Conflict, LookaheadSets,
+};
+
+#[test]
+fn conflict_messages_for_ambiguous_grammar() {
+    let messages = conflict_messages(
+        Rc::new(Session::test()),
+        r#"
+grammar;
+pub Ty: () = {
+    "int" => (),
+    <t1:Ty> "->" <t2:Ty> => (),
+};
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        messages[0],
+        "grammar.lalrpop:5:5: 5:30: 2 conflicts: 2 precedence\n\n  \
+         Each conflict is reported below."
+    );
+    assert_eq!(
+        messages[1],
+        r#"grammar.lalrpop:5:5: 5:30: Ambiguous grammar detected
+
+  The rule `Ty = Ty "->" Ty` conflicts with itself.
+
+  The following symbols can be reduced in two ways:
+    Ty "->" Ty "->" Ty
+
+  They could be reduced like so:
+    Ty "->" Ty "->" Ty
+    ├─Ty─────┘       │
+    └─Ty─────────────┘
+
+  Alternatively, they could be reduced like so:
+    Ty "->" Ty "->" Ty
+    │       └─Ty─────┤
+    └─Ty─────────────┘
+
+  For example, the input `int -> int -> int` is ambiguous.
+
+  Hint: This looks like a precedence error related to `Ty`. See the LALRPOP
+  manual for advice on encoding precedence."#
+    );
+}
+
+#[test]
+fn conflicts_for_ambiguous_grammar() {
+    let found = conflicts(
+        Rc::new(Session::test()),
+        r#"grammar; pub Ty: () = { "int", <Ty> "->" <Ty> };"#,
+    )
+    .unwrap();
+    assert!(!found.is_empty());
+    for conflict in &found {
+        assert_eq!(
+            *conflict,
+            Conflict {
+                classification: "Precedence".to_string(),
+                state: conflict.state,
+                lookahead: r#""->""#.to_string(),
+                span: (31, 45),
+            }
+        );
+    }
+
+    let found = conflicts(
+        Rc::new(Session::test()),
+        r#"grammar; pub Ty: () = { "int", <Ty> "->" "int" };"#,
+    )
+    .unwrap();
+    assert!(found.is_empty());
+}
+
+#[test]
+fn conflict_messages_for_lr1_grammar() {
+    let messages = conflict_messages(
+        Rc::new(Session::test()),
+        r#"grammar; pub Ty: () = { "int", <Ty> "->" "int" };"#,
+    )
+    .unwrap();
+    assert!(messages.is_empty());
+}
+
+#[test]
+fn conflict_messages_for_invalid_grammar() {
+    let error = conflict_messages(Rc::new(Session::test()), "grammar; pub Ty: () =").unwrap_err();
+    assert_eq!(
+        error,
+        "grammar.lalrpop:1:22: 1:21 error: unexpected end of file"
+    );
+}
+
+#[test]
+fn assert_no_conflicts_counts_messages() {
+    let session = || Rc::new(Session::test());
+    assert_eq!(
+        assert_no_conflicts(
+            session(),
+            r#"grammar; pub Ty: () = { "int", <Ty> "->" "int" };"#
+        ),
+        Ok(())
+    );
+
+    // a summary, then a message for each of the two conflicts
+    let messages = assert_no_conflicts(
+        session(),
+        r#"grammar; pub Ty: () = { "int", <Ty> "->" <Ty> };"#,
+    )
+    .unwrap_err();
+    assert_eq!(messages.len(), 3);
+    assert!(messages[0].contains("2 conflicts"));
+
+    let messages = assert_no_conflicts(session(), "grammar; pub Ty: () =").unwrap_err();
+    assert_eq!(
+        messages,
+        vec!["grammar.lalrpop:1:22: 1:21 error: unexpected end of file"]
+    );
+}
+
+#[test]
+fn inline_growth_warning() {
+    let source = r#"
+grammar;
+pub S: () = X X X X => ();
+#[inline] X: () = { "a", "b", "c" };
+"#;
+    let session = Rc::new(Session::test());
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+    let lowered = parse_and_lower(&session, &file_text).unwrap();
+    let inlined = parse_and_normalize(&session, &file_text).unwrap();
+
+    let messages = inline_growth::check(&lowered, &inlined, 2);
+    assert_eq!(messages.len(), 1);
+    let text = messages[0]
+        .emit_to_canvas(80)
+        .to_strings()
+        .iter()
+        .map(|row| row.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert!(text.contains("Inlining `X` makes the parser"), "{}", text);
+    assert!(
+        text.contains("Consider removing the `#[inline]`"),
+        "{}",
+        text
+    );
+
+    assert!(inline_growth::check(&lowered, &inlined, 100).is_empty());
+}
+
+#[test]
+fn unused_terminal_warning() {
+    let source = r#"
+grammar;
+match {
+    "a",
+    "b" => B,
+    r"[0-9]+" => NUM,
+}
+pub S: () = "a" NUM => ();
+"#;
+    let session = Rc::new(Session::test());
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+    let grammar = parse_and_normalize(&session, &file_text).unwrap();
+
+    let messages = unused::check(&grammar);
+    assert_eq!(messages.len(), 1);
+    let text = messages[0]
+        .emit_to_canvas(80)
+        .to_strings()
+        .iter()
+        .map(|row| row.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert!(
+        text.contains("warning: Terminal `B` is never used"),
+        "{}",
+        text
+    );
+}
+
+#[test]
+fn unreachable_nonterminal_warnings() {
+    let source = r#"
+grammar;
+pub S: () = { D, E };
+D: () = "d" => ();
+#[inline] E: () = "e" => ();
+A: () = { "a" B => (), "x" => () };
+B: () = "b" A => ();
+C: () = "c" => ();
+"#;
+    let session = Rc::new(Session::test());
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+    let grammar = parse_and_normalize(&session, &file_text).unwrap();
+
+    // the `A`/`B` cycle is reported as a whole; `D` and the inlined
+    // `E` are reachable
+    let headings: Vec<String> = unused::check(&grammar)
+        .iter()
+        .map(|message| {
+            message.emit_to_canvas(80).to_strings()[0]
+                .to_string()
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(
+        headings,
+        vec![
+            "grammar.lalrpop:6:1: 6:1: warning: Nonterminal `A` is unreachable",
+            "grammar.lalrpop:7:1: 7:1: warning: Nonterminal `B` is unreachable",
+            "grammar.lalrpop:8:1: 8:1: warning: Nonterminal `C` is unreachable",
+        ]
+    );
+}
+
+#[test]
+fn cfg_disabled_pub_nonterminals_are_reachable() {
+    let source = r#"
+grammar;
+pub A: () = "a";
+#[cfg(feature = "x")] pub B: () = Inner;
+Inner: () = "b";
+"#;
+    let session = Rc::new(Session::test());
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+    let grammar = parse_and_normalize(&session, &file_text).unwrap();
+    assert!(!grammar
+        .start_nonterminals
+        .values()
+        .any(|nt| &*nt.0 == "__B"));
+    assert!(unused::check(&grammar).is_empty());
+}
+
+#[test]
+fn custom_prefix() {
+    let source = r#"
+grammar;
+pub S: () = "a" => { let gen_ = (); gen_ };
+"#;
+    let mut session = Session::test();
+    session.prefix = Some("gen_".to_string());
+    let file_text = FileText::new(PathBuf::from("grammar.lalrpop"), source.to_owned());
+    let grammar = parse_and_normalize(&session, &file_text).unwrap();
+    assert_eq!(grammar.prefix, "gen__");
+
+    session.prefix = Some("1x".to_string());
+    let (_, message) = parse_and_normalize(&session, &file_text).unwrap_err();
+    assert_eq!(message, "the prefix `1x` is not a valid identifier");
+}
+
+#[test]
+fn lookahead_sets_for_expressions() {
+    let sets = lookahead_sets(
+        Rc::new(Session::test()),
+        r#"
+grammar;
+pub E = T Ep;
+Ep = { "+" T Ep, () };
+T = F Tp;
+Tp = { "*" F Tp, () };
+F = { "(" E ")", "id" };
+"#,
+    )
+    .unwrap();
+    let strings = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let ep = sets.iter().find(|s| s.nonterminal == "Ep").unwrap();
+    assert_eq!(
+        *ep,
+        LookaheadSets {
+            nonterminal: "Ep".to_string(),
+            first: strings(&[r#""+""#]),
+            nullable: true,
+            follow: strings(&[r#"")""#, "EOF"]),
+        }
+    );
+    let f = sets.iter().find(|s| s.nonterminal == "F").unwrap();
+    assert_eq!(f.first, strings(&[r#""(""#, r#""id""#]));
+    assert!(!f.nullable);
+    assert_eq!(f.follow, strings(&[r#"")""#, r#""*""#, r#""+""#, "EOF"]));
+}
+
+#[test]
+fn conflict_messages_with_items() {
+    let mut session = Session::test();
+    session.conflict_items = true;
+    let messages = conflict_messages(
+        Rc::new(session),
+        r#"
+grammar;
+pub Expr: () = {
+    "Id" => (),
+    "Id" "{" "}" => (),
+    "if" Expr "{" "}" => (),
+};
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        messages[1],
+        r#"grammar.lalrpop:4:5: 4:14: Local ambiguity detected
+
+  The rule `Expr = "Id"` conflicts with the rule `Expr = "Id" "{" "}"`.
+
+  The problem arises after having observed the following symbols in the input:
+    "if" "Id"
+  At that point, if the next token is a `"{"`, then the parser can proceed in
+  two different ways.
+
+  First, the parser could execute the production at grammar.lalrpop:4:5: 4:14,
+  which would consume the top 1 token(s) from the stack and produce a `Expr`.
+  This might then yield a parse tree like
+    "if" "Id"   ╷ "{" "}"
+    │    └─Expr─┘       │
+    └─Expr──────────────┘
+  At the cursor, the parser is in the item `Expr = "Id" (*)`.
+
+  Alternatively, the parser could shift the `"{"` token and later use it to
+  construct a `Expr`. This might then yield a parse tree like
+    "if" "Id" "{" "}" "{" "}"
+    │    └─Expr─────┘       │
+    └─Expr──────────────────┘
+  At the cursor, the parser is in the item `Expr = "Id" (*) "{" "}"`.
+
+  See the LALRPOP manual for advice on making your grammar LR(1)."#
+    );
+}
+
+#[test]
+fn conflict_messages_in_source_order() {
+    let grammar = r#"
+grammar;
+pub S: () = {
+    Ty => (),
+    "if" E "{" "}" => (),
+};
+Ty: () = {
+    "int" => (),
+    Ty "->" Ty => (),
+};
+E: () = {
+    "Id" => (),
+    "Id" "{" "}" => (),
+};
+"#;
+    let messages = conflict_messages(Rc::new(Session::test()), grammar).unwrap();
+    let positions: Vec<&str> = messages[1..]
+        .iter()
+        .map(|message| message.split(": ").next().unwrap())
+        .collect();
+    assert_eq!(
+        positions,
+        vec![
+            "grammar.lalrpop:9:5",
+            "grammar.lalrpop:12:5",
+            "grammar.lalrpop:12:5"
+        ]
+    );
+    assert_eq!(
+        messages,
+        conflict_messages(Rc::new(Session::test()), grammar).unwrap()
+    );
+}
+
+fn generated_parser(source: &str, recursive_ascent: bool) -> String {
+    let session = Rc::new(Session::test());
+    let file_text = Rc::new(FileText::new(
+        PathBuf::from("grammar.lalrpop"),
+        source.to_owned(),
+    ));
+    let _tls = Tls::install(session.clone(), file_text.clone());
+    let grammar = parse_and_normalize(&session, &file_text).unwrap();
+
+    let (user_nt, start_nt) = grammar.start_nonterminals.iter().next().unwrap();
+    let _lr1_tls = lr1::Lr1Tls::install(grammar.terminals.clone());
+    let states = lr1::build_states(&grammar, start_nt.clone()).unwrap();
+    let mut rust = RustWrite::new(vec![]);
+    let compile = if recursive_ascent {
+        lr1::codegen::ascent::compile
+    } else {
+        lr1::codegen::parse_table::compile
+    };
+    compile(
+        &grammar,
+        user_nt.clone(),
+        start_nt.clone(),
+        &states,
+        "super",
+        &mut rust,
+    )
+    .unwrap();
+    String::from_utf8(rust.into_inner()).unwrap()
+}
+
+#[test]
+fn ascent_entry_reports_internal_errors() {
+    let code = generated_parser(
+        r#"#[recursive_ascent] grammar; pub Sum: () = { Sum "+" "1", "1" };"#,
+        true,
+    );
+
+    // the entry point does not end in a bare `unreachable!()`
+    assert!(!code.contains("unreachable!()"));
+    assert!(code.contains(
+        "ParseError::Internal { message: \"parsing `Sum`: state 0 returned \
+         a nonterminal other than `__Sum` at the end of the input\" }"
+    ));
+}
+
+#[test]
+fn table_token_to_symbol_reports_internal_errors() {
+    let code = generated_parser(
+        r#"grammar; extern { enum Tok { "1" => Tok::One(<i32>), "+" => Tok::Plus } }
+           pub Sum: () = { Sum "+" "1", "1" };"#,
+        false,
+    );
+
+    assert!(!code.contains("unreachable!()"));
+    assert!(code.contains(
+        "_ => return Err(__lalrpop_util::ParseError::Internal { message: \
+         \"token does not match the terminal of its index\" }),"
+    ));
+    assert!(code.contains(
+        "_ => return Err(__lalrpop_util::ParseError::Internal { message: \
+         \"token index out of range\" }),"
+    ));
+}
@@ -0,0 +1,124 @@
+//! Warns about terminals that are declared but that no production
+//! uses, and about nonterminals that no public nonterminal derives.
+//! Either is usually a mistake: the name in the `match` or `extern`
+//! block has drifted from the one used in the rules, or a rule was
+//! left behind when its last use was removed.
+
+use crate::collections::set;
+use crate::grammar::consts::INLINE;
+use crate::grammar::repr::*;
+use crate::message::builder::MessageBuilder;
+use crate::message::Message;
+use string_cache::DefaultAtom as Atom;
+
+/// Returns a warning for each terminal of `grammar` that does not
+/// appear in any production, and for each nonterminal that cannot be
+/// reached from a start symbol.
+pub fn check(grammar: &Grammar) -> Vec<Message> {
+    let mut messages = unused_terminals(grammar);
+    messages.extend(unreachable_nonterminals(grammar));
+    messages
+}
+
+fn unused_terminals(grammar: &Grammar) -> Vec<Message> {
+    let mut used = set();
+    for data in grammar.nonterminals.values() {
+        for production in &data.productions {
+            for symbol in &production.symbols {
+                if let Symbol::Terminal(ref term) = *symbol {
+                    used.insert(term);
+                }
+            }
+        }
+    }
+
+    grammar
+        .terminals
+        .all
+        .iter()
+        .filter(|&term| *term != TerminalString::Error && !used.contains(term))
+        .map(|term| report_unused_terminal(grammar, term))
+        .collect()
+}
+
+fn unreachable_nonterminals(grammar: &Grammar) -> Vec<Message> {
+    // a `pub` nonterminal disabled by `#[cfg]` has no start symbol,
+    // but it is not unused: it has a parser in other configurations
+    let mut reached = set();
+    let mut stack: Vec<&NonterminalString> = grammar
+        .start_nonterminals
+        .values()
+        .chain(
+            grammar
+                .nonterminals
+                .values()
+                .filter(|data| data.visibility.is_pub())
+                .map(|data| &data.name),
+        )
+        .collect();
+    while let Some(nt) = stack.pop() {
+        if !reached.insert(nt) {
+            continue;
+        }
+        for production in grammar.productions_for(nt) {
+            for symbol in &production.symbols {
+                if let Symbol::Nonterminal(ref next) = *symbol {
+                    stack.push(next);
+                }
+            }
+        }
+    }
+
+    // `#[inline]` nonterminals are still defined after inlining, but
+    // their uses have been replaced by their productions
+    let inline = Atom::from(INLINE);
+    grammar
+        .nonterminals
+        .values()
+        .filter(|data| !reached.contains(&data.name))
+        .filter(|data| !data.annotations.iter().any(|a| a.id == inline))
+        .map(report_unreachable_nonterminal)
+        .collect()
+}
+
+fn report_unused_terminal(grammar: &Grammar, term: &TerminalString) -> Message {
+    // an extern token's pattern is the best place to point at; the
+    // entries of a `match` block have no span of their own
+    let span = grammar
+        .conversions
+        .get(term)
+        .map_or(grammar.token_span, |pattern| pattern.span);
+    MessageBuilder::new(span)
+        .heading()
+        .text(format!("warning: Terminal `{}` is never used", term))
+        .end()
+        .body()
+        .begin_wrap()
+        .text("The terminal")
+        .push(term.clone())
+        .verbatimed()
+        .text("is declared, but no production uses it.")
+        .end()
+        .end()
+        .end()
+}
+
+fn report_unreachable_nonterminal(data: &NonterminalData) -> Message {
+    MessageBuilder::new(data.span)
+        .heading()
+        .text(format!(
+            "warning: Nonterminal `{}` is unreachable",
+            data.name
+        ))
+        .end()
+        .body()
+        .begin_wrap()
+        .text("No public nonterminal derives")
+        .push(data.name.clone())
+        .verbatimed()
+        .punctuated(",")
+        .text("so no parser can use it.")
+        .end()
+        .end()
+        .end()
+}
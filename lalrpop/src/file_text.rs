@@ -2,7 +2,7 @@ use crate::grammar::parse_tree as pt;
 use std::fmt::{Display, Error, Formatter};
 use std::fs::File;
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct FileText {
     path: PathBuf,
@@ -41,6 +41,10 @@ impl FileText {
         Self::new(PathBuf::from("test.lalrpop"), String::from(""))
     }
 
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     pub fn text(&self) -> &String {
         &self.input_str
     }
@@ -76,7 +80,12 @@ impl FileText {
         (line, col)
     }
 
-    fn line_text(&self, line_num: usize) -> &str {
+    /// Returns the (zero-based) lines on which `span` starts and ends.
+    pub fn line_span(&self, span: pt::Span) -> (usize, usize) {
+        (self.line_col(span.0).0, self.line_col(span.1).0)
+    }
+
+    pub fn line_text(&self, line_num: usize) -> &str {
         let start_offset = self.newlines[line_num];
         if line_num == self.newlines.len() - 1 {
             &self.input_str[start_offset..]
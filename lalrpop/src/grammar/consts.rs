@@ -4,6 +4,10 @@ pub const LOCATION: &str = "Location";
 /// Recognized associated type for custom errors
 pub const ERROR: &str = "Error";
 
+/// Recognized associated type for an error type of the user's that
+/// parse errors get a `From` conversion into
+pub const INTO_ERROR: &str = "IntoError";
+
 /// The lifetime parameter injected when we do not have an external token enum
 pub const INPUT_LIFETIME: &str = "'input";
 
@@ -13,9 +17,28 @@ pub const INPUT_PARAMETER: &str = "input";
 /// The annotation to request inlining.
 pub const INLINE: &str = "inline";
 
+/// The annotation that resolves shift/reduce conflicts on reducing a
+/// nonterminal in favor of shifting. A grammar using it that is not
+/// otherwise LR(1) is built with canonical LR(1) instead of the lane
+/// table algorithm, which may produce many more states.
+pub const PREFER_SHIFT: &str = "prefer_shift";
+
+/// The annotation that resolves shift/reduce conflicts on reducing a
+/// nonterminal in favor of reducing. Like [`PREFER_SHIFT`], it makes a
+/// grammar that is not otherwise LR(1) use canonical LR(1).
+pub const PREFER_REDUCE: &str = "prefer_reduce";
+
 /// The annotation to request conditional compilation.
 pub const CFG: &str = "cfg";
 
+/// The annotation that gives a terminal the name shown in error
+/// messages, as in `#[display(name = "identifier")]`.
+pub const DISPLAY: &str = "display";
+
+/// The annotation on an entry of a `match` block that lets it match
+/// only where no letter, digit or `_` follows, as in `#[boundary] "in"`.
+pub const BOUNDARY: &str = "boundary";
+
 /// Annotation to request LALR.
 pub const LALR: &str = "LALR";
 
@@ -27,3 +50,7 @@ pub const RECURSIVE_ASCENT: &str = "recursive_ascent";
 
 /// Annotation to request test-all-style code generation.
 pub const TEST_ALL: &str = "test_all";
+
+/// Annotation to request that `X+` and `X*` expand to right-recursive
+/// rather than left-recursive productions.
+pub const RIGHT_RECURSIVE: &str = "right_recursive";
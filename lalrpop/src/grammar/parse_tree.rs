@@ -1,7 +1,9 @@
 //! The "parse-tree" is what is produced by the parser. We use it do
 //! some pre-expansion and so forth before creating the proper AST.
 
-use crate::grammar::consts::{INPUT_LIFETIME, LALR, RECURSIVE_ASCENT, TABLE_DRIVEN, TEST_ALL};
+use crate::grammar::consts::{
+    INPUT_LIFETIME, LALR, RECURSIVE_ASCENT, RIGHT_RECURSIVE, TABLE_DRIVEN, TEST_ALL,
+};
 use crate::grammar::pattern::Pattern;
 use crate::grammar::repr::{self as r, NominalTypeRepr, TypeRepr};
 use crate::lexer::dfa::DFA;
@@ -87,8 +89,8 @@ pub struct MatchContents {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MatchItem {
     CatchAll(Span),
-    Unmapped(MatchSymbol, Span),
-    Mapped(MatchSymbol, MatchMapping, Span),
+    Unmapped(MatchSymbol, Vec<Annotation>, Span),
+    Mapped(MatchSymbol, MatchMapping, Vec<Annotation>, Span),
 }
 
 impl MatchItem {
@@ -102,8 +104,26 @@ impl MatchItem {
     pub fn span(&self) -> Span {
         match *self {
             MatchItem::CatchAll(span) => span,
-            MatchItem::Unmapped(_, span) => span,
-            MatchItem::Mapped(_, _, span) => span,
+            MatchItem::Unmapped(_, _, span) => span,
+            MatchItem::Mapped(_, _, _, span) => span,
+        }
+    }
+
+    pub fn annotations(&self) -> &[Annotation] {
+        match *self {
+            MatchItem::CatchAll(_) => &[],
+            MatchItem::Unmapped(_, ref annotations, _) => annotations,
+            MatchItem::Mapped(_, _, ref annotations, _) => annotations,
+        }
+    }
+
+    /// The terminal that the grammar uses for this item, if any.
+    pub fn terminal(&self) -> Option<TerminalString> {
+        match *self {
+            MatchItem::CatchAll(_) => None,
+            MatchItem::Unmapped(ref sym, _, _) => Some(TerminalString::Literal(sym.clone())),
+            MatchItem::Mapped(_, MatchMapping::Terminal(ref term), _, _) => Some(term.clone()),
+            MatchItem::Mapped(..) => None,
         }
     }
 }
@@ -114,6 +134,9 @@ pub type MatchSymbol = TerminalLiteral;
 pub enum MatchMapping {
     Terminal(TerminalString),
     Skip,
+    /// Skip a block that starts with the match literal and ends with
+    /// the given closing delimiter. Blocks may nest, as in `/* /* */ */`.
+    SkipNested(Atom),
 }
 
 impl Debug for MatchMapping {
@@ -121,6 +144,7 @@ impl Debug for MatchMapping {
         match self {
             MatchMapping::Terminal(term) => write!(fmt, "{:?}", term),
             MatchMapping::Skip => write!(fmt, "{{ }}"),
+            MatchMapping::SkipNested(close) => write!(fmt, "{{ {:?} }}", &close[..]),
         }
     }
 }
@@ -129,6 +153,7 @@ impl Display for MatchMapping {
         match self {
             MatchMapping::Terminal(term) => write!(fmt, "{}", term),
             MatchMapping::Skip => write!(fmt, "{{ }}"),
+            MatchMapping::SkipNested(close) => write!(fmt, "{{ {:?} }}", &close[..]),
         }
     }
 }
@@ -142,6 +167,8 @@ pub struct InternToken {
     /// grammar. Sorted by order of increasing precedence.
     pub match_entries: Vec<MatchEntry>,
     pub dfa: DFA,
+    /// If true, the tokenizer reads `&[u8]` rather than `&str`.
+    pub bytes: bool,
 }
 
 /// In `token_check`, as we prepare to generate a tokenizer, we
@@ -181,6 +208,9 @@ pub struct MatchEntry {
     pub precedence: usize,
     pub match_literal: TerminalLiteral,
     pub user_name: MatchMapping,
+    /// If true, the entry only matches where it is not followed by a
+    /// letter, digit or `_` (see `#[boundary]`).
+    pub boundary: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -207,6 +237,7 @@ pub struct EnumToken {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Conversion {
     pub span: Span,
+    pub annotations: Vec<Annotation>,
     pub from: TerminalString,
     pub to: Pattern<TypeRef>,
 }
@@ -402,6 +433,9 @@ pub struct Annotation {
 pub struct Alternative {
     pub span: Span,
 
+    // #[cfg(feature = "...")]
+    pub annotations: Vec<Annotation>,
+
     pub expr: ExprSymbol,
 
     // if C, only legal in macros
@@ -517,6 +551,8 @@ impl TerminalString {
 pub enum TerminalLiteral {
     Quoted(Atom),
     Regex(Atom),
+    /// `i"foo"`: like `Quoted`, but ASCII letters match in either case.
+    CaseInsensitive(Atom),
 }
 
 impl TerminalLiteral {
@@ -525,7 +561,7 @@ impl TerminalLiteral {
     /// precedence over regex matches.
     pub fn base_precedence(&self) -> usize {
         match *self {
-            TerminalLiteral::Quoted(_) => 1,
+            TerminalLiteral::Quoted(_) | TerminalLiteral::CaseInsensitive(_) => 1,
             TerminalLiteral::Regex(_) => 0,
         }
     }
@@ -534,6 +570,7 @@ impl TerminalLiteral {
         match *self {
             TerminalLiteral::Quoted(ref x) => x.len(),
             TerminalLiteral::Regex(ref x) => x.len() + "####r".len(),
+            TerminalLiteral::CaseInsensitive(ref x) => x.len() + "i".len(),
         }
     }
 }
@@ -901,6 +938,7 @@ impl Display for TerminalLiteral {
         match *self {
             TerminalLiteral::Quoted(ref s) => write!(fmt, "{:?}", s.as_ref()), // the Debug impl adds the `"` and escaping
             TerminalLiteral::Regex(ref s) => write!(fmt, "r#{:?}#", s.as_ref()), // FIXME -- need to determine proper number of #
+            TerminalLiteral::CaseInsensitive(ref s) => write!(fmt, "i{:?}", s.as_ref()),
         }
     }
 }
@@ -1206,6 +1244,8 @@ pub fn read_algorithm(annotations: &[Annotation], algorithm: &mut r::Algorithm)
             algorithm.codegen = r::LrCodeGeneration::RecursiveAscent;
         } else if annotation.id == Atom::from(TEST_ALL) {
             algorithm.codegen = r::LrCodeGeneration::TestAll;
+        } else if annotation.id == Atom::from(RIGHT_RECURSIVE) {
+            // handled during macro expansion
         } else {
             panic!(
                 "validation permitted unknown annotation: {:?}",
@@ -81,6 +81,21 @@ pub enum WhereClause {
 pub struct TerminalSet {
     pub all: Vec<TerminalString>,
     pub bits: Map<TerminalString, usize>,
+
+    /// Names given with `#[display(name = "...")]`.
+    pub names: Map<TerminalString, String>,
+}
+
+impl TerminalSet {
+    /// The name to show for `terminal` in error messages: the one
+    /// given with `#[display]`, or else the terminal as written in the
+    /// grammar (for a literal like `"*"`, the literal itself).
+    pub fn name(&self, terminal: &TerminalString) -> String {
+        match self.names.get(terminal) {
+            Some(name) => name.clone(),
+            None => terminal.to_string(),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -235,6 +250,19 @@ impl TypeRepr {
         })
     }
 
+    /// The type of the text that a generated tokenizer reads: `str`,
+    /// or `[u8]` when reading bytes.
+    pub fn input_text(bytes: bool) -> TypeRepr {
+        if bytes {
+            TypeRepr::Slice(Box::new(TypeRepr::Nominal(NominalTypeRepr {
+                path: Path::from_id(Atom::from("u8")),
+                types: vec![],
+            })))
+        } else {
+            TypeRepr::str()
+        }
+    }
+
     pub fn bottom_up(&self, op: &mut impl FnMut(TypeRepr) -> TypeRepr) -> Self {
         let result = match self {
             TypeRepr::Tuple(types) => {
@@ -363,6 +391,7 @@ pub struct Types {
     terminal_token_type: TypeRepr,
     terminal_loc_type: Option<TypeRepr>,
     error_type: Option<TypeRepr>,
+    into_error_type: Option<TypeRepr>,
     terminal_types: Map<TerminalString, TypeRepr>,
     nonterminal_types: Map<NonterminalString, TypeRepr>,
     parse_error_type: TypeRepr,
@@ -379,6 +408,7 @@ impl Types {
         let mut types = Types {
             terminal_loc_type,
             error_type,
+            into_error_type: None,
             terminal_token_type,
             terminal_types: map(),
             nonterminal_types: map(),
@@ -426,6 +456,10 @@ impl Types {
         assert!(self.terminal_types.insert(term, ty).is_none());
     }
 
+    pub fn set_into_error_type(&mut self, ty: TypeRepr) {
+        self.into_error_type = Some(ty);
+    }
+
     pub fn terminal_token_type(&self) -> &TypeRepr {
         &self.terminal_token_type
     }
@@ -448,6 +482,12 @@ impl Types {
         })
     }
 
+    /// The user's error type, declared as `type IntoError`, that parse
+    /// errors are converted into by a generated `From` impl, if any.
+    pub fn opt_into_error_type(&self) -> Option<&TypeRepr> {
+        self.into_error_type.as_ref()
+    }
+
     pub fn terminal_type(&self, id: &TerminalString) -> &TypeRepr {
         self.terminal_types
             .get(&id)
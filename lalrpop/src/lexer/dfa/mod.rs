@@ -1,7 +1,7 @@
 //! Constructs a DFA which picks the longest matching regular
 //! expression from the input.
 
-use crate::collections::Set;
+use crate::collections::{map, Map, Set};
 use crate::kernel_set::{Kernel, KernelSet};
 use crate::lexer::nfa::{self, NFAConstructionError, NFAStateIndex, Test, NFA};
 use crate::lexer::re;
@@ -19,6 +19,11 @@ mod overlap;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DFA {
     pub states: Vec<State>,
+
+    /// Regexs that no state accepts, because whenever they match, some
+    /// regex with higher precedence matches the same text. Each one is
+    /// paired with such a regex.
+    pub shadowed: Vec<(NFAIndex, NFAIndex)>,
 }
 
 #[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq)]
@@ -113,6 +118,11 @@ impl<'nfa> DFABuilder<'nfa> {
         let start_state_index = self.start_state(&mut kernel_set);
         assert_eq!(start_state_index, START);
 
+        // for each regex, whether some state accepts it, and otherwise
+        // a regex that took precedence over it
+        let mut picked = vec![false; self.nfas.len()];
+        let mut shadowed_by: Map<NFAIndex, NFAIndex> = map();
+
         while let Some(item_set) = kernel_set.next() {
             // collect all the specific tests we expect from any of
             // the items in this state
@@ -161,8 +171,14 @@ impl<'nfa> DFABuilder<'nfa> {
                         match1: next_nfa,
                     });
                 }
+                for &(_, nfa) in &all_accepts[..all_accepts.len() - 1] {
+                    shadowed_by.entry(nfa).or_insert(best_nfa);
+                }
                 Kind::Accepts(best_nfa)
             };
+            if let Kind::Accepts(nfa) = kind {
+                picked[nfa.0] = true;
+            }
 
             // for each specific test, find what happens if we see a
             // character matching that test
@@ -210,7 +226,12 @@ impl<'nfa> DFABuilder<'nfa> {
             states.push(state);
         }
 
-        Ok(DFA { states })
+        let shadowed = shadowed_by
+            .into_iter()
+            .filter(|&(nfa, _)| !picked[nfa.0])
+            .collect();
+
+        Ok(DFA { states, shadowed })
     }
 
     fn start_state(&self, kernel_set: &mut DFAKernelSet) -> DFAStateIndex {
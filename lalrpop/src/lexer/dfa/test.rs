@@ -42,6 +42,18 @@ fn ambiguous_regex() {
     assert!(dfa(&[(r#"class"#, P0), (r#"[a-zA-Z_][a-zA-Z0-9_]*"#, P0)]).is_err());
 }
 
+#[test]
+fn shadowed_regex() {
+    // every identifier is lexed by the higher-precedence regex, so the
+    // keyword can never win
+    let dfa0 = dfa(&[(r#"class"#, P0), (r#"[a-zA-Z_][a-zA-Z0-9_]*"#, P1)]).unwrap();
+    assert_eq!(dfa0.shadowed, vec![(NFAIndex(0), NFAIndex(1))]);
+
+    // the identifier still wins on inputs that are not keywords
+    let dfa1 = dfa(&[(r#"class"#, P1), (r#"[a-zA-Z_][a-zA-Z0-9_]*"#, P0)]).unwrap();
+    assert!(dfa1.shadowed.is_empty());
+}
+
 #[test]
 fn issue_32() {
     assert!(dfa(&[(r#"."#, P0)]).is_ok());
@@ -4,6 +4,7 @@ use crate::grammar::parse_tree::{InternToken, MatchMapping};
 use crate::grammar::repr::{Grammar, TerminalLiteral};
 use crate::lexer::re;
 use crate::rust::RustWrite;
+use crate::tls::Tls;
 use std::io::{self, Write};
 
 pub fn compile<W: Write>(
@@ -12,6 +13,7 @@ pub fn compile<W: Write>(
     out: &mut RustWrite<W>,
 ) -> io::Result<()> {
     let prefix = &grammar.prefix;
+    let unicode_classes = Tls::session().unicode_classes;
 
     rust!(out, "#[cfg_attr(rustfmt, rustfmt_skip)]");
     rust!(out, "mod {}intern_token {{", prefix);
@@ -19,8 +21,9 @@ pub fn compile<W: Write>(
     out.write_uses("", &grammar)?;
     rust!(
         out,
-        "pub fn new_builder() -> {}lalrpop_util::lexer::MatcherBuilder {{",
-        prefix
+        "pub fn new_builder() -> {}lalrpop_util::lexer::{} {{",
+        prefix,
+        builder_type(intern_token)
     );
 
     // create a vector of rust string literals with the text of each
@@ -31,12 +34,18 @@ pub fn compile<W: Write>(
         .map(|match_entry| {
             (
                 match match_entry.match_literal {
-                    TerminalLiteral::Quoted(ref s) => re::parse_literal(&s),
-                    TerminalLiteral::Regex(ref s) => re::parse_regex(&s).unwrap(),
+                    TerminalLiteral::Quoted(ref s) => re::parse_literal(&s).to_string(),
+                    // the regex was validated when the grammar was
+                    // normalized, so only its text is needed here
+                    TerminalLiteral::Regex(ref s) if unicode_classes => s.to_string(),
+                    TerminalLiteral::Regex(ref s) => re::parse_regex(&s).unwrap().to_string(),
+                    TerminalLiteral::CaseInsensitive(ref s) => {
+                        re::parse_case_insensitive_literal(&s).to_string()
+                    }
                 },
                 match match_entry.user_name {
                     MatchMapping::Terminal(_) => false,
-                    MatchMapping::Skip => true,
+                    MatchMapping::Skip | MatchMapping::SkipNested(_) => true,
                 },
             )
         })
@@ -66,11 +75,71 @@ pub fn compile<W: Write>(
 
     rust!(
         out,
-        "{p}lalrpop_util::lexer::MatcherBuilder::new({p}strs.iter().copied()).unwrap()",
-        p = prefix
+        "{p}lalrpop_util::lexer::{b}::new({p}strs.iter().copied()).unwrap()",
+        p = prefix,
+        b = builder_type(intern_token)
     );
 
+    // blocks that may nest cannot be matched by a regex alone, so the
+    // matcher has to count the delimiters itself
+    for (index, match_entry) in intern_token.match_entries.iter().enumerate() {
+        if let (TerminalLiteral::Quoted(ref open), MatchMapping::SkipNested(ref close)) =
+            (&match_entry.match_literal, &match_entry.user_name)
+        {
+            rust!(
+                out,
+                ".nested_skip({}, {:?}, {:?})",
+                index,
+                &open[..],
+                &close[..]
+            );
+        }
+    }
+
+    // case-insensitive literals produce the spelling from the grammar,
+    // not the one from the input
+    for (index, match_entry) in intern_token.match_entries.iter().enumerate() {
+        if let TerminalLiteral::CaseInsensitive(ref text) = match_entry.match_literal {
+            rust!(out, ".canonical({}, {:?})", index, &text[..]);
+        }
+    }
+
+    // terminals marked `#[boundary]` must not run into a following word
+    for (index, match_entry) in intern_token.match_entries.iter().enumerate() {
+        if match_entry.boundary {
+            rust!(out, ".boundary({})", index);
+        }
+    }
+
     rust!(out, "}}"); // fn
     rust!(out, "}}"); // mod
     Ok(())
 }
+
+/// The `lalrpop_util::lexer` type that builds the matcher.
+pub fn builder_type(intern_token: &InternToken) -> &'static str {
+    if intern_token.bytes {
+        "ByteMatcherBuilder"
+    } else {
+        "MatcherBuilder"
+    }
+}
+
+/// The `lalrpop_util::lexer` type that `builder_type` produces.
+pub fn matcher_type(intern_token: &InternToken) -> &'static str {
+    if intern_token.bytes {
+        "ByteMatcher"
+    } else {
+        "Matcher"
+    }
+}
+
+/// What to import from `lalrpop_util::lexer` for the tokens the
+/// matcher yields. The generated code always refers to them as `Token`.
+pub fn token_import(intern_token: &InternToken) -> &'static str {
+    if intern_token.bytes {
+        "ByteToken as Token"
+    } else {
+        "Token"
+    }
+}
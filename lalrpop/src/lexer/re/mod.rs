@@ -1,6 +1,6 @@
 //! A parser and representation of regular expressions.
 
-use regex_syntax::hir::Hir;
+use regex_syntax::hir::{Class, Hir, HirKind, Literal, RepetitionKind, RepetitionRange};
 use regex_syntax::{self, Error, Parser};
 
 #[cfg(test)]
@@ -17,8 +17,95 @@ pub fn parse_literal(s: &str) -> Regex {
     }
 }
 
+/// Convert a string literal into a regular expression that matches
+/// ASCII letters in either case, and everything else exactly.
+pub fn parse_case_insensitive_literal(s: &str) -> Regex {
+    let mut regex = String::new();
+    for c in s.chars() {
+        if c.is_ascii_alphabetic() {
+            regex.push('[');
+            regex.push(c.to_ascii_lowercase());
+            regex.push(c.to_ascii_uppercase());
+            regex.push(']');
+        } else {
+            regex.push_str(&regex_syntax::escape(c.encode_utf8(&mut [0; 4])));
+        }
+    }
+    match parse_regex(&regex) {
+        Ok(v) => v,
+        Err(_) => panic!("failed to parse case-insensitive literal regular expression"),
+    }
+}
+
 /// Parse a regular expression like `a+` etc.
 pub fn parse_regex(s: &str) -> Result<Regex, RegexError> {
     let expr = Parser::new().parse(s)?;
     Ok(expr)
 }
+
+/// A shortest string matched by `regex`, for use in messages. Where a
+/// class leaves a choice, lowercase letters are preferred, then digits
+/// and uppercase letters, so that `[a-zA-Z_]+` gives `a`. Returns
+/// `None` if no matching string is printable.
+pub fn shortest_match(regex: &Regex) -> Option<String> {
+    match *regex.kind() {
+        HirKind::Empty | HirKind::Anchor(_) | HirKind::WordBoundary(_) => Some(String::new()),
+        HirKind::Literal(Literal::Unicode(c)) => Some(c.to_string()),
+        HirKind::Literal(Literal::Byte(b)) => printable(b as char),
+        HirKind::Class(Class::Unicode(ref class)) => {
+            let ranges: Vec<_> = class.iter().map(|r| (r.start(), r.end())).collect();
+            class_sample(&ranges)
+        }
+        HirKind::Class(Class::Bytes(ref class)) => {
+            let ranges: Vec<_> = class
+                .iter()
+                .map(|r| (r.start() as char, r.end() as char))
+                .collect();
+            class_sample(&ranges)
+        }
+        HirKind::Repetition(ref repetition) => {
+            let count = match repetition.kind {
+                RepetitionKind::ZeroOrOne | RepetitionKind::ZeroOrMore => 0,
+                RepetitionKind::OneOrMore => 1,
+                RepetitionKind::Range(RepetitionRange::Exactly(n))
+                | RepetitionKind::Range(RepetitionRange::AtLeast(n))
+                | RepetitionKind::Range(RepetitionRange::Bounded(n, _)) => n as usize,
+            };
+            if count == 0 {
+                Some(String::new())
+            } else {
+                shortest_match(&repetition.hir).map(|s| s.repeat(count))
+            }
+        }
+        HirKind::Group(ref group) => shortest_match(&group.hir),
+        HirKind::Concat(ref hirs) => hirs.iter().map(shortest_match).collect(),
+        HirKind::Alternation(ref hirs) => hirs
+            .iter()
+            .filter_map(shortest_match)
+            .min_by_key(|s| s.chars().count()),
+    }
+}
+
+fn class_sample(ranges: &[(char, char)]) -> Option<String> {
+    for &(lo, hi) in &[('a', 'z'), ('0', '9'), ('A', 'Z')] {
+        let sample = ranges
+            .iter()
+            .map(|&(start, end)| (start.max(lo), end.min(hi)))
+            .find(|&(start, end)| start <= end);
+        if let Some((c, _)) = sample {
+            return Some(c.to_string());
+        }
+    }
+    ranges
+        .iter()
+        .flat_map(|&(start, end)| (start..=end).take(128))
+        .find_map(printable)
+}
+
+fn printable(c: char) -> Option<String> {
+    if c.is_control() || c.is_whitespace() {
+        None
+    } else {
+        Some(c.to_string())
+    }
+}
@@ -9,3 +9,13 @@ fn parse_unclosed_group() {
 fn alt_oom() {
     parse_regex(r"(%%|[^%])+").unwrap();
 }
+
+#[test]
+fn shortest_matches() {
+    let sample = |s: &str| shortest_match(&parse_regex(s).unwrap());
+    assert_eq!(sample(r"[a-zA-Z_][a-zA-Z0-9_]*"), Some("a".to_string()));
+    assert_eq!(sample(r"[0-9]+(\.[0-9]+)?"), Some("0".to_string()));
+    assert_eq!(sample(r"x{3}|yy"), Some("yy".to_string()));
+    assert_eq!(sample(r"[^a-z]"), Some("0".to_string()));
+    assert_eq!(sample(r"\s+"), None);
+}
@@ -52,7 +52,10 @@ mod generate;
 #[cfg(test)]
 mod test_util;
 
+pub use crate::api::assert_no_conflicts;
 pub use crate::api::process_root;
 pub use crate::api::process_root_unconditionally;
 pub use crate::api::Configuration;
+pub use crate::build::Conflict;
+pub use crate::build::LookaheadSets;
 use ascii_canvas::style;
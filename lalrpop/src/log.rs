@@ -27,11 +27,16 @@ impl Log {
         self.level = level;
     }
 
+    /// Whether messages logged at `level` are shown.
+    pub fn enabled(&self, level: Level) -> bool {
+        self.level >= level
+    }
+
     pub fn log<M>(&self, level: Level, message: M)
     where
         M: FnOnce() -> String,
     {
-        if self.level >= level {
+        if self.enabled(level) {
             println!("{}", message());
         }
     }
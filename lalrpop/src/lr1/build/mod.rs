@@ -1,6 +1,7 @@
 //! LR(1) state construction algorithm.
 
-use crate::collections::{map, Multimap};
+use crate::collections::{map, Map, Multimap};
+use crate::grammar::consts::{PREFER_REDUCE, PREFER_SHIFT};
 use crate::grammar::repr::*;
 use crate::kernel_set;
 use crate::lr1::core::*;
@@ -9,6 +10,7 @@ use crate::lr1::lane_table::*;
 use crate::lr1::lookahead::*;
 use crate::tls::Tls;
 use std::env;
+use string_cache::DefaultAtom as Atom;
 
 #[cfg(test)]
 mod test;
@@ -43,15 +45,65 @@ pub fn build_lr1_states<'grammar>(
         ("legacy", build_lr1_states_legacy as ConstructionFunction)
     };
 
-    profile! {
+    let result = profile! {
         &Tls::session(),
         format!("LR(1) state construction ({})", method_name),
         {
-            method_fn(grammar, start)
+            method_fn(grammar, start.clone())
         }
+    };
+
+    // The lane table algorithm only splits states; it cannot drop the
+    // actions that `#[prefer_shift]` and `#[prefer_reduce]` rule out.
+    // Canonical LR(1) resolves those conflicts as it builds each state,
+    // at the cost of possibly many more states.
+    match result {
+        Err(_) if method_name == "lane" && !preferences(grammar).is_empty() => {
+            log!(
+                Tls::session(),
+                Informative,
+                "Lane table construction failed for `{}`; falling back to \
+                 canonical LR(1) to apply `#[{}]`/`#[{}]`, which may \
+                 produce many more states",
+                start,
+                PREFER_SHIFT,
+                PREFER_REDUCE
+            );
+            build_lr1_states_legacy(grammar, start)
+        }
+        result => result,
     }
 }
 
+/// Which action wins a shift/reduce conflict on reducing a
+/// nonterminal, as requested by its annotations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Preference {
+    Shift,
+    Reduce,
+}
+
+/// The nonterminals marked `#[prefer_shift]` or `#[prefer_reduce]`.
+fn preferences(grammar: &Grammar) -> Map<NonterminalString, Preference> {
+    let prefer_shift = Atom::from(PREFER_SHIFT);
+    let prefer_reduce = Atom::from(PREFER_REDUCE);
+    grammar
+        .nonterminals
+        .iter()
+        .filter_map(|(name, data)| {
+            data.annotations.iter().find_map(|annotation| {
+                if annotation.id == prefer_shift {
+                    Some((name.clone(), Preference::Shift))
+                } else if annotation.id == prefer_reduce {
+                    Some((name.clone(), Preference::Reduce))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect()
+}
+
 pub fn build_lr0_states<'grammar>(
     grammar: &'grammar Grammar,
     start: NonterminalString,
@@ -66,6 +118,7 @@ pub struct LR<'grammar, L: LookaheadBuild> {
     start_nt: NonterminalString,
     start_lookahead: L,
     permit_early_stop: bool,
+    preferences: Map<NonterminalString, Preference>,
 }
 
 impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
@@ -76,6 +129,7 @@ impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
             start_nt,
             start_lookahead,
             permit_early_stop: false,
+            preferences: preferences(grammar),
         }
     }
 
@@ -163,6 +217,8 @@ impl<'grammar, L: LookaheadBuild> LR<'grammar, L> {
                     .push((item.lookahead.clone(), item.production));
             }
 
+            L::resolve_preferences(self, &mut this_state);
+
             // check for shift-reduce conflicts (reduce-reduce detected above)
             conflicts.extend(L::conflicts(&this_state));
 
@@ -326,6 +382,11 @@ pub trait LookaheadBuild: Lookahead {
         remainder: &[Symbol],
         lookahead: &Self,
     ) -> Vec<Item<'grammar, Self>>;
+
+    // Drops the actions of `state` that lose a shift/reduce conflict
+    // to the `#[prefer_shift]` or `#[prefer_reduce]` annotation of the
+    // nonterminal being reduced.
+    fn resolve_preferences<'grammar>(lr: &LR<'grammar, Self>, state: &mut State<'grammar, Self>);
 }
 
 impl LookaheadBuild for Nil {
@@ -337,6 +398,11 @@ impl LookaheadBuild for Nil {
     ) -> Vec<LR0Item<'grammar>> {
         lr.items(nt, 0, &lookahead)
     }
+
+    fn resolve_preferences<'grammar>(_lr: &LR<'grammar, Self>, _state: &mut LR0State<'grammar>) {
+        // Without lookahead there is nothing to resolve the conflicts
+        // on, and the lane table algorithm needs to see them.
+    }
 }
 
 impl LookaheadBuild for TokenSet {
@@ -349,4 +415,34 @@ impl LookaheadBuild for TokenSet {
         let first_set = lr.first_sets.first1(remainder, lookahead);
         lr.items(nt, 0, &first_set)
     }
+
+    fn resolve_preferences<'grammar>(lr: &LR<'grammar, Self>, state: &mut LR1State<'grammar>) {
+        if lr.preferences.is_empty() {
+            return;
+        }
+
+        let mut shifted = TokenSet::new();
+        for terminal in state.shifts.keys() {
+            shifted.insert(Token::Terminal(terminal.clone()));
+        }
+
+        let mut dropped_shifts = vec![];
+        for &mut (ref mut tokens, production) in &mut state.reductions {
+            let conflicting = tokens.intersection(&shifted);
+            match lr.preferences.get(&production.nonterminal) {
+                Some(Preference::Shift) => *tokens = tokens.difference(&conflicting),
+                Some(Preference::Reduce) => dropped_shifts.extend(
+                    conflicting
+                        .iter()
+                        .filter_map(|token| token.as_terminal().cloned()),
+                ),
+                None => {}
+            }
+        }
+
+        for terminal in dropped_shifts {
+            state.shifts.remove(&terminal);
+        }
+        state.reductions.retain(|&(ref tokens, _)| tokens.len() > 0);
+    }
 }
@@ -352,3 +352,135 @@ pub Query = SELECT r"[a-z]+";
     let states = build_lr0_states(&grammar, nt("Query")).expect("build states");
     println!("states: {:?}", states);
 }
+
+#[test]
+fn states_to_dot_marks_conflicts() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+        grammar;
+        extern { enum Tok { "L" => .., "&" => .., } }
+        pub E: () = {
+            "L",
+            "&" OPT_L E
+        };
+        OPT_L: () = {
+            (),
+            "L"
+        };
+    "#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("E")].clone();
+    let error = build_lr1_states(&grammar, start).unwrap_err();
+    let dot = states_to_dot(&error.states, &grammar);
+
+    assert!(dot.starts_with("digraph states {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains(r#"S0 -> S2 [label="\"&\""];"#));
+    assert!(dot.contains(r#"OPT_L = (*) \"L\" [\"&\", \"L\", EOF]\l"#));
+    assert_eq!(dot.matches(", color=red").count(), 1);
+    assert_eq!(dot.matches("peripheries=2").count(), 1);
+}
+
+#[test]
+fn action_on_shifts_and_reductions() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+        grammar;
+        extern { enum Tok { "N" => .., "+" => .., } }
+        pub E: () = {
+            E "+" "N",
+            "N"
+        };
+    "#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let start = grammar.start_nonterminals[&nt("E")].clone();
+    let states = build_lr1_states(&grammar, start).unwrap();
+    let n = Token::Terminal(TerminalString::quoted(Atom::from("N")));
+    let plus = Token::Terminal(TerminalString::quoted(Atom::from("+")));
+
+    // the start state can only shift `N`
+    let after_n = match states[0].action_on(&n) {
+        Some(Action::Shift(_, next)) => next,
+        r => panic!("unexpected action {:?}", r),
+    };
+    assert_eq!(states[0].action_on(&plus), None);
+    assert_eq!(states[0].action_on(&EOF), None);
+
+    // after `N`, the parser reduces `E = "N"` on `+` or EOF
+    let after_n = &states[after_n.0];
+    for token in &[plus.clone(), EOF] {
+        match after_n.action_on(token) {
+            Some(Action::Reduce(production)) => {
+                assert_eq!(production.nonterminal, nt("E"));
+                assert_eq!(production.symbols.len(), 1);
+            }
+            r => panic!("unexpected action {:?}", r),
+        }
+    }
+    assert_eq!(after_n.action_on(&n), None);
+}
+
+#[test]
+fn prefer_shift_resolves_dangling_else() {
+    let _tls = Tls::test();
+
+    let grammar = normalized_grammar(
+        r#"
+        grammar;
+        extern { enum Tok { "if" => .., "then" => .., "else" => .., "X" => .. } }
+        #[prefer_shift]
+        S: () = {
+            "if" "X" "then" S,
+            "if" "X" "then" S "else" S,
+            "X",
+        };
+    "#,
+    );
+
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+
+    let states = build_lr1_states(&grammar, nt("S")).unwrap();
+
+    // the `else` goes with the innermost `if`
+    let tree = interpret(
+        &states,
+        tokens!["if", "X", "then", "if", "X", "then", "X", "else", "X"],
+    )
+    .unwrap();
+    assert_eq!(
+        &format!("{}", tree)[..],
+        r#"[S: "if", "X", "then", [S: "if", "X", "then", [S: "X"], "else", [S: "X"]]]"#
+    );
+}
+
+#[test]
+fn prefer_reduce_makes_operator_left_associative() {
+    let _tls = Tls::test();
+
+    let grammar = normalized_grammar(
+        r#"
+        grammar;
+        extern { enum Tok { "N" => .., "-" => .. } }
+        S: () = E;
+        #[prefer_reduce]
+        E: () = {
+            E "-" E,
+            "N",
+        };
+    "#,
+    );
+
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+
+    let states = build_lr1_states(&grammar, nt("S")).unwrap();
+
+    let tree = interpret(&states, tokens!["N", "-", "N", "-", "N"]).unwrap();
+    assert_eq!(
+        &format!("{}", tree)[..],
+        r#"[S: [E: [E: [E: "N"], "-", [E: "N"]], "-", [E: "N"]]]"#
+    );
+}
@@ -167,6 +167,8 @@ impl<'ascent, 'grammar, W: Write>
             this.write_start_fn()?;
             rust!(this.out, "");
             this.write_return_type_defn()?;
+            rust!(this.out, "");
+            this.write_next_token_fn()?;
             for i in 0..this.states.len() {
                 this.write_state_fn(StateIndex(i))?;
             }
@@ -218,7 +220,8 @@ impl<'ascent, 'grammar, W: Write>
         self.start_parser_fn()?;
         self.define_tokens()?;
 
-        self.next_token("lookahead", "tokens")?;
+        let tokens = format!("&mut {}tokens", self.prefix);
+        self.next_token("lookahead", &tokens)?;
         rust!(
             self.out,
             "match {}state0({}&mut {}tokens, {}lookahead, {})? {{",
@@ -250,8 +253,19 @@ impl<'ascent, 'grammar, W: Write>
         rust!(self.out, "Ok({}nt)", self.prefix);
         rust!(self.out, "}}");
 
-        // nothing else should be possible
-        rust!(self.out, "_ => unreachable!(),");
+        // nothing else should be possible; if it happens anyway, say
+        // enough to make a useful bug report
+        let message = format!(
+            "parsing `{}`: state 0 returned a nonterminal other than `{}` \
+             at the end of the input",
+            self.user_start_symbol, self.start_symbol
+        );
+        rust!(
+            self.out,
+            "_ => Err({}lalrpop_util::ParseError::Internal {{ message: {:?} }}),",
+            self.prefix,
+            message
+        );
         rust!(self.out, "}}");
 
         self.end_parser_fn()
@@ -308,6 +322,10 @@ impl<'ascent, 'grammar, W: Write>
 
         self.emit_state_fn_header("state", this_index.0, inputs)?;
 
+        if Tls::session().emit_state_trace {
+            rust!(self.out, "trace_state({});", this_index.0);
+        }
+
         // possibly move some fixed inputs into optional stack slots
         let stack_suffix = self.adjust_inputs(this_index, inputs)?;
 
@@ -378,7 +396,11 @@ impl<'ascent, 'grammar, W: Write>
 
         rust!(self.out, "let {}expected = vec![", self.prefix);
         for terminal in successful_terminals {
-            rust!(self.out, "r###\"{}\"###.to_string(),", terminal);
+            rust!(
+                self.out,
+                "r###\"{}\"###.to_string(),",
+                self.grammar.terminals.name(terminal)
+            );
         }
         rust!(self.out, "];");
 
@@ -588,7 +610,8 @@ impl<'ascent, 'grammar, W: Write>
 
         // shift lookahead is necessary; see `starts_with_terminal` above
         if starts_with_terminal {
-            self.next_token("lookahead", "tokens")?;
+            let tokens = format!("{}tokens", self.prefix);
+            self.next_token("lookahead", &tokens)?;
         }
 
         Ok(())
@@ -875,7 +898,7 @@ impl<'ascent, 'grammar, W: Write>
                     top = top
                 );
             }
-            rust!(self.out, "let {p}end = {p}start;", p = self.prefix);
+            rust!(self.out, "let {p}end = {p}start.clone();", p = self.prefix);
         } else {
             // this only occurs in the start state
             rust!(
@@ -884,7 +907,7 @@ impl<'ascent, 'grammar, W: Write>
                 self.prefix,
                 loc_type
             );
-            rust!(self.out, "let {p}end = {p}start;", p = self.prefix);
+            rust!(self.out, "let {p}end = {p}start.clone();", p = self.prefix);
         }
 
         let transfered_syms = transfer_syms.len();
@@ -897,7 +920,26 @@ impl<'ascent, 'grammar, W: Write>
 
         // invoke the action code
         let is_fallible = self.grammar.action_is_fallible(production.action);
-        if is_fallible {
+        if is_fallible && Tls::session().user_error_spans {
+            rust!(
+                self.out,
+                "let {}nt = match {}::{}action{}::<{}>({}{}) {{",
+                self.prefix,
+                self.action_module,
+                self.prefix,
+                production.action.index(),
+                Sep(", ", &self.grammar.non_lifetime_type_parameters()),
+                self.grammar.user_parameter_refs(),
+                Sep(", ", &args)
+            );
+            rust!(self.out, "Ok(v) => v,");
+            rust!(
+                self.out,
+                "Err(e) => return Err(e.with_span({p}start, {p}end)),",
+                p = self.prefix
+            );
+            rust!(self.out, "}};");
+        } else if is_fallible {
             rust!(
                 self.out,
                 "let {}nt = {}::{}action{}::<{}>({}{})?;",
@@ -992,19 +1034,43 @@ impl<'ascent, 'grammar, W: Write>
         self.types.triple_type()
     }
 
+    // Generates the `__next_token` helper that every state uses to
+    // read its lookahead, rather than repeating the `match` in each
+    // state function.
+    fn write_next_token_fn(&mut self) -> io::Result<()> {
+        let p = self.prefix;
+        rust!(self.out, "#[inline]");
+        rust!(
+            self.out,
+            "fn {p}next_token<{p}T, {p}E, {p}I>({p}tokens: &mut {p}I) -> Result<Option<{p}T>, {p}E>",
+            p = p
+        );
+        rust!(
+            self.out,
+            "where {p}I: Iterator<Item = Result<{p}T, {p}E>>",
+            p = p
+        );
+        rust!(self.out, "{{");
+        rust!(self.out, "match {}tokens.next() {{", p);
+        rust!(self.out, "Some(Ok(v)) => Ok(Some(v)),");
+        rust!(self.out, "Some(Err(e)) => Err(e),");
+        rust!(self.out, "None => Ok(None),");
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    // `tokens` is an expression of type `&mut I`, where `I` is the
+    // token iterator.
     fn next_token(&mut self, lookahead: &str, tokens: &str) -> io::Result<()> {
         rust!(
             self.out,
-            "let {}{} = match {}{}.next() {{",
+            "let {}{} = {}next_token({})?;",
             self.prefix,
             lookahead,
             self.prefix,
             tokens
         );
-        rust!(self.out, "Some(Ok(v)) => Some(v),");
-        rust!(self.out, "Some(Err(e)) => return Err(e),");
-        rust!(self.out, "None => None,");
-        rust!(self.out, "}};");
         Ok(())
     }
 }
@@ -1,12 +1,17 @@
 //! Base helper routines for a code generator.
 
 use crate::collections::Set;
+use crate::grammar::consts::INPUT_PARAMETER;
 use crate::grammar::free_variables::FreeVariables;
+use crate::grammar::parse_tree::MatchMapping;
 use crate::grammar::repr::*;
+use crate::lexer::intern_token;
 use crate::lr1::core::*;
 use crate::rust::RustWrite;
+use crate::tls::Tls;
 use std::io::{self, Write};
 use crate::util::Sep;
+use string_cache::DefaultAtom as Atom;
 
 /// Base struct for various kinds of code generator. The flavor of
 /// code generator is customized by supplying distinct types for `C`
@@ -181,11 +186,12 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
         self.out
             .write_uses(&format!("{}::", self.action_module), &self.grammar)?;
 
-        if self.grammar.intern_token.is_some() {
+        if let Some(ref intern_token) = self.grammar.intern_token {
             rust!(
                 self.out,
-                "use self::{}lalrpop_util::lexer::Token;",
-                self.prefix
+                "use self::{}lalrpop_util::lexer::{};",
+                self.prefix,
+                intern_token::token_import(intern_token)
             );
         } else {
             rust!(
@@ -199,54 +205,59 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
         Ok(())
     }
 
-    pub fn start_parser_fn(&mut self) -> io::Result<()> {
-        let parse_error_type = self.types.parse_error_type();
-
-        let (type_parameters, parameters, mut where_clauses);
-
-        let intern_token = self.grammar.intern_token.is_some();
-        if intern_token {
+    /// Computes the type parameters, parameters and where-clauses
+    /// that the generated `parse` method adds on top of the ones
+    /// declared by the grammar itself.
+    fn parser_fn_signature(&self) -> (Vec<String>, Vec<String>, Vec<String>) {
+        if self.grammar.intern_token.is_some() {
             // if we are generating the tokenizer, we just need the
             // input, and that has already been added as one of the
             // user parameters
-            type_parameters = vec![];
-            parameters = vec![];
-            where_clauses = vec![];
-        } else {
-            // otherwise, we need an iterator of type `TOKENS`
-            let mut user_type_parameters = String::new();
-            for type_parameter in &self.grammar.type_parameters {
-                user_type_parameters.push_str(&format!("{}, ", type_parameter));
-            }
-            type_parameters = vec![
-                format!(
-                    "{}TOKEN: {}ToTriple<{}>",
-                    self.prefix, self.prefix, user_type_parameters,
-                ),
-                format!(
-                    "{}TOKENS: IntoIterator<Item={}TOKEN>",
-                    self.prefix, self.prefix
-                ),
-            ];
-            parameters = vec![format!("{}tokens0: {}TOKENS", self.prefix, self.prefix)];
-            where_clauses = vec![];
-
-            if self.repeatable {
-                where_clauses.push(format!("{}TOKENS: Clone", self.prefix));
-            }
+            return (vec![], vec![], vec![]);
+        }
+
+        // otherwise, we need an iterator of type `TOKENS`
+        let mut user_type_parameters = String::new();
+        for type_parameter in &self.grammar.type_parameters {
+            user_type_parameters.push_str(&format!("{}, ", type_parameter));
+        }
+        let type_parameters = vec![
+            format!(
+                "{}TOKEN: {}ToTriple<{}>",
+                self.prefix, self.prefix, user_type_parameters,
+            ),
+            format!(
+                "{}TOKENS: IntoIterator<Item={}TOKEN>",
+                self.prefix, self.prefix
+            ),
+        ];
+        let parameters = vec![format!("{}tokens0: {}TOKENS", self.prefix, self.prefix)];
+        let mut where_clauses = vec![];
+
+        if self.repeatable {
+            where_clauses.push(format!("{}TOKENS: Clone", self.prefix));
         }
 
+        (type_parameters, parameters, where_clauses)
+    }
+
+    pub fn start_parser_fn(&mut self) -> io::Result<()> {
+        let parse_error_type = self.types.parse_error_type();
+        let intern_token = self.grammar.intern_token.as_ref();
+        let (type_parameters, parameters, where_clauses) = self.parser_fn_signature();
+
         rust!(
             self.out,
             "{}struct {}Parser {{",
             self.grammar.nonterminals[&self.start_symbol].visibility,
             self.user_start_symbol
         );
-        if intern_token {
+        if let Some(intern_token) = intern_token {
             rust!(
                 self.out,
-                "builder: {}lalrpop_util::lexer::MatcherBuilder,",
+                "builder: {}lalrpop_util::lexer::{},",
                 self.prefix,
+                intern_token::builder_type(intern_token)
             );
         }
         rust!(self.out, "_priv: (),");
@@ -260,7 +271,7 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
             self.grammar.nonterminals[&self.start_symbol].visibility,
             self.user_start_symbol
         );
-        if intern_token {
+        if intern_token.is_some() {
             rust!(
                 self.out,
                 "let {0}builder = {1}::{0}intern_token::new_builder();",
@@ -269,7 +280,7 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
             );
         }
         rust!(self.out, "{}Parser {{", self.user_start_symbol);
-        if intern_token {
+        if intern_token.is_some() {
             rust!(self.out, "builder: {}builder,", self.prefix);
         }
         rust!(self.out, "_priv: (),");
@@ -278,6 +289,14 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
         rust!(self.out, "");
 
         rust!(self.out, "#[allow(dead_code)]");
+        if Tls::session().emit_must_use {
+            // `Result` is `#[must_use]` already, so a bare attribute
+            // would only trip clippy's `double_must_use`
+            rust!(
+                self.out,
+                "#[must_use = \"parsing has no effect other than returning its result\"]"
+            );
+        }
         self.out
             .fn_header(
                 &self.grammar.nonterminals[&self.start_symbol].visibility,
@@ -333,11 +352,246 @@ impl<'codegen, 'grammar, W: Write, C> CodeGenerator<'codegen, 'grammar, W, C> {
     }
 
     pub fn end_parser_fn(&mut self) -> io::Result<()> {
+        self.end_parse_fn()?;
+        self.end_parser_impl()
+    }
+
+    /// Closes the `parse` method and emits the helper methods that
+    /// forward to it. Code generators that add methods of their own
+    /// can emit them after this, before `end_parser_impl`.
+    pub fn end_parse_fn(&mut self) -> io::Result<()> {
         rust!(self.out, "}}"); // fn
+        if Tls::session().emit_dump_helpers {
+            self.write_dump_fn()?;
+        }
+        if Tls::session().emit_boxed_errors {
+            self.write_boxed_fn()?;
+        }
+        if Tls::session().emit_tokenizers && self.grammar.intern_token.is_some() {
+            self.write_tokenize_fns()?;
+        }
+        if Tls::session().emit_state_trace {
+            rust!(self.out, "");
+            rust!(
+                self.out,
+                "{}const STATE_COUNT: usize = {};",
+                self.grammar.nonterminals[&self.start_symbol].visibility,
+                self.states.len()
+            );
+        }
+        Ok(())
+    }
+
+    pub fn end_parser_impl(&mut self) -> io::Result<()> {
         rust!(self.out, "}}"); // impl
         Ok(())
     }
 
+    /// Emits a `dump` method that forwards to `parse` and renders the
+    /// outcome with `Debug`, for use when eyeballing parse results in
+    /// tests.
+    fn write_dump_fn(&mut self) -> io::Result<()> {
+        let (type_parameters, parameters, mut where_clauses) = self.parser_fn_signature();
+        where_clauses.push(format!(
+            "{}: ::std::fmt::Debug",
+            self.types.nonterminal_type(&self.start_symbol)
+        ));
+        where_clauses.push(format!(
+            "{}: ::std::fmt::Debug",
+            self.types.parse_error_type()
+        ));
+
+        let (turbofish, arguments) = self.parse_call_arguments();
+
+        rust!(self.out, "");
+        rust!(self.out, "#[allow(dead_code)]");
+        self.out
+            .fn_header(
+                &self.grammar.nonterminals[&self.start_symbol].visibility,
+                "dump".to_owned(),
+            )
+            .with_parameters(Some("&self".to_owned()))
+            .with_grammar(self.grammar)
+            .with_type_parameters(type_parameters)
+            .with_parameters(parameters)
+            .with_return_type("String")
+            .with_where_clauses(where_clauses)
+            .emit()?;
+        rust!(self.out, "{{");
+        rust!(self.out, "match self.parse{}({}) {{", turbofish, arguments);
+        rust!(self.out, "Ok(v) => format!(\"{{:#?}}\", v),");
+        rust!(self.out, "Err(e) => format!(\"{{:#?}}\", e),");
+        rust!(self.out, "}}");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Emits a `parse_boxed` method that forwards to `parse` and
+    /// erases the error type, so callers can use `?` in functions
+    /// returning `Box<dyn Error>`. The error is rendered to a string
+    /// because the tokens it carries may borrow from the input.
+    fn write_boxed_fn(&mut self) -> io::Result<()> {
+        let (type_parameters, parameters, mut where_clauses) = self.parser_fn_signature();
+        where_clauses.push(format!(
+            "{}: ::std::fmt::Display",
+            self.types.parse_error_type()
+        ));
+        let (turbofish, arguments) = self.parse_call_arguments();
+
+        rust!(self.out, "");
+        rust!(self.out, "#[allow(dead_code)]");
+        self.out
+            .fn_header(
+                &self.grammar.nonterminals[&self.start_symbol].visibility,
+                "parse_boxed".to_owned(),
+            )
+            .with_parameters(Some("&self".to_owned()))
+            .with_grammar(self.grammar)
+            .with_type_parameters(type_parameters)
+            .with_parameters(parameters)
+            // the parentheses keep `dyn` a keyword in 2015-edition crates
+            .with_return_type(format!(
+                "Result<{}, Box<dyn (::std::error::Error)>>",
+                self.types.nonterminal_type(&self.start_symbol)
+            ))
+            .with_where_clauses(where_clauses)
+            .emit()?;
+        rust!(self.out, "{{");
+        rust!(
+            self.out,
+            "self.parse{}({}).map_err(|e| e.to_string().into())",
+            turbofish,
+            arguments
+        );
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Emits a `tokenize` method that runs only the lexer over the
+    /// input, and a `terminal_name` method that says which terminal
+    /// of the grammar a token is.
+    fn write_tokenize_fns(&mut self) -> io::Result<()> {
+        let intern_token = self.grammar.intern_token.as_ref().unwrap();
+        let input = self
+            .grammar
+            .parameters
+            .iter()
+            .find(|parameter| parameter.name == Atom::from(INPUT_PARAMETER))
+            .unwrap();
+        let token_type = if intern_token.bytes {
+            "ByteToken"
+        } else {
+            "Token"
+        };
+        let visibility = &self.grammar.nonterminals[&self.start_symbol].visibility;
+
+        rust!(self.out, "");
+        rust!(self.out, "#[allow(dead_code)]");
+        self.out
+            .fn_header(visibility, "tokenize".to_owned())
+            .with_type_parameters(vec!["'input", "'builder"])
+            .with_parameters(Some("&'builder self".to_owned()))
+            .with_parameters(Some(input))
+            .with_return_type(format!(
+                "{p}lalrpop_util::lexer::{matcher}<'input, 'builder, {error}>",
+                p = self.prefix,
+                matcher = intern_token::matcher_type(intern_token),
+                error = self.types.error_type(),
+            ))
+            .emit()?;
+        rust!(self.out, "{{");
+        rust!(self.out, "self.builder.matcher({})", INPUT_PARAMETER);
+        rust!(self.out, "}}");
+
+        // the index in a token is that of its match entry; skipped
+        // entries never produce tokens
+        rust!(self.out, "");
+        rust!(self.out, "#[allow(dead_code)]");
+        self.out
+            .fn_header(visibility, "terminal_name".to_owned())
+            .with_parameters(Some("&self".to_owned()))
+            .with_parameters(Some(format!(
+                "token: &{}lalrpop_util::lexer::{}",
+                self.prefix, token_type
+            )))
+            .with_return_type("&'static str")
+            .emit()?;
+        rust!(self.out, "{{");
+        rust!(self.out, "const {}NAMES: &[&str] = &[", self.prefix);
+        for match_entry in &intern_token.match_entries {
+            let name = match match_entry.user_name {
+                MatchMapping::Terminal(ref terminal) => terminal.to_string(),
+                MatchMapping::Skip | MatchMapping::SkipNested(_) => String::new(),
+            };
+            rust!(self.out, "{:?},", name);
+        }
+        rust!(self.out, "];");
+        rust!(self.out, "{}NAMES[token.0]", self.prefix);
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Opens a `parse_tokens` method, which parses a vector of tokens
+    /// (such as those produced by `tokenize`) instead of running the
+    /// lexer over the input. The input is still taken, as actions may
+    /// refer to it. Code generators supply the body.
+    pub fn start_parse_tokens_fn(&mut self) -> io::Result<()> {
+        let intern_token = self.grammar.intern_token.as_ref().unwrap();
+        let token_type = if intern_token.bytes {
+            "ByteToken"
+        } else {
+            "Token"
+        };
+
+        rust!(self.out, "");
+        rust!(self.out, "#[allow(dead_code)]");
+        self.out
+            .fn_header(
+                &self.grammar.nonterminals[&self.start_symbol].visibility,
+                "parse_tokens".to_owned(),
+            )
+            .with_parameters(Some("&self".to_owned()))
+            .with_grammar(self.grammar)
+            .with_parameters(Some(format!(
+                "{p}tokens0: Vec<(usize, {p}lalrpop_util::lexer::{token_type}<'input>, usize)>",
+                p = self.prefix,
+                token_type = token_type,
+            )))
+            .with_return_type(format!(
+                "Result<{}, {}>",
+                self.types.nonterminal_type(&self.start_symbol),
+                self.types.parse_error_type()
+            ))
+            .emit()?;
+        rust!(self.out, "{{");
+        Ok(())
+    }
+
+    /// Returns the turbofish and argument list for calling `parse`
+    /// from another method on the parser with the same signature.
+    fn parse_call_arguments(&self) -> (String, String) {
+        // spell out the type parameters, since those that only appear
+        // in the result type cannot be inferred from the arguments
+        let mut type_arguments: Vec<String> = self
+            .grammar
+            .non_lifetime_type_parameters()
+            .iter()
+            .map(|tp| tp.to_string())
+            .collect();
+        let mut arguments = self.grammar.user_parameter_refs();
+        if self.grammar.intern_token.is_none() {
+            type_arguments.push(format!("{}TOKEN", self.prefix));
+            type_arguments.push(format!("{}TOKENS", self.prefix));
+            arguments.push_str(&format!("{}tokens0", self.prefix));
+        }
+        let turbofish = if type_arguments.is_empty() {
+            String::new()
+        } else {
+            format!("::<{}>", Sep(", ", &type_arguments))
+        };
+        (turbofish, arguments)
+    }
+
     /// Returns phantom data type that captures the user-declared type
     /// parameters in a phantom-data. This helps with ensuring that
     /// all type parameters are constrained, even if they are not
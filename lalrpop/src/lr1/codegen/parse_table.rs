@@ -2,6 +2,7 @@
 
 use crate::collections::{Entry, Map, Set};
 use crate::grammar::repr::*;
+use crate::lexer::intern_token;
 use crate::lr1::core::*;
 use crate::lr1::lookahead::Token;
 use crate::rust::RustWrite;
@@ -284,10 +285,27 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out, "{}goto(state, nt)", self.prefix);
         rust!(self.out, "}}");
 
+        if Tls::session().emit_state_trace {
+            rust!(self.out, "");
+            rust!(self.out, "#[inline]");
+            rust!(
+                self.out,
+                "fn enter_state(&self, state: {state_type}) {{",
+                state_type = state_type,
+            );
+            rust!(self.out, "trace_state(state as usize)");
+            rust!(self.out, "}}");
+        }
+
         rust!(self.out, "");
+        rust!(self.out, "fn token_to_symbol(");
+        rust!(self.out, "&self,");
+        rust!(self.out, "token_index: usize,");
+        rust!(self.out, "token: Self::Token,");
         rust!(
             self.out,
-            "fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {{"
+            ") -> Result<Self::Symbol, {p}state_machine::ParseError<Self>> {{",
+            p = self.prefix,
         );
         rust!(
             self.out,
@@ -378,7 +396,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             p = self.prefix,
             state_type = state_type,
         );
-        if self.grammar.uses_error_recovery {
+        if self.uses_simulate_reduce() {
             rust!(
                 self.out,
                 "{p}simulate_reduce(action, {phantom})",
@@ -711,7 +729,168 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         rust!(self.out, "{p}tokens,", p = self.prefix);
         rust!(self.out, ")");
 
-        self.end_parser_fn()
+        self.end_parse_fn()?;
+        if self.emits_items_fn() {
+            self.write_items_fn()?;
+        }
+        if self.emits_prefix_fn() {
+            self.write_prefix_fn()?;
+        }
+        if Tls::session().emit_tokenizers && self.grammar.intern_token.is_some() {
+            self.write_parse_tokens_fn()?;
+        }
+        self.end_parser_impl()
+    }
+
+    fn emits_items_fn(&self) -> bool {
+        Tls::session().emit_item_parsers && self.grammar.intern_token.is_some()
+    }
+
+    fn emits_prefix_fn(&self) -> bool {
+        Tls::session().emit_prefix_parsers && self.grammar.intern_token.is_some()
+    }
+
+    /// Error recovery, `parse_items` and `parse_prefix` all need to
+    /// simulate reductions, to see whether a state can accept a
+    /// lookahead.
+    fn uses_simulate_reduce(&self) -> bool {
+        self.grammar.uses_error_recovery || self.emits_items_fn() || self.emits_prefix_fn()
+    }
+
+    /// Emits a `parse_items` method, which returns an `ItemParser`
+    /// that parses the input one instance of the start symbol at a
+    /// time. The method takes the same parameters as the state
+    /// machine, so that it can name the state machine type in its
+    /// result.
+    fn write_items_fn(&mut self) -> io::Result<()> {
+        let intern_token = self.grammar.intern_token.as_ref().unwrap();
+        let phantom_data_expr = self.phantom_data_expr();
+        let machine = self.custom.machine.clone();
+        let builder_lifetime = format!("'{}builder", self.prefix);
+        let type_parameters = Some(builder_lifetime.clone())
+            .into_iter()
+            .chain(machine.type_parameters.iter().map(|tp| tp.to_string()));
+
+        rust!(self.out, "");
+        rust!(self.out, "#[allow(dead_code)]");
+        self.out
+            .fn_header(
+                &self.grammar.nonterminals[&self.start_symbol].visibility,
+                "parse_items".to_owned(),
+            )
+            .with_parameters(Some(format!("&{} self", builder_lifetime)))
+            .with_type_parameters(type_parameters)
+            .with_parameters(&machine.fields)
+            .with_where_clauses(&machine.where_clauses)
+            .with_return_type(format!(
+                "{p}state_machine::ItemParser<{p}StateMachine<{mtp}>, \
+                 {p}lalrpop_util::lexer::{matcher}<'input, {builder}, {error}>>",
+                p = self.prefix,
+                mtp = Sep(", ", &machine.type_parameters),
+                matcher = intern_token::matcher_type(intern_token),
+                builder = builder_lifetime,
+                error = self.types.error_type(),
+            ))
+            .emit()?;
+        rust!(self.out, "{{");
+        self.define_tokens()?;
+        rust!(
+            self.out,
+            "{p}state_machine::ItemParser::new(",
+            p = self.prefix,
+        );
+        rust!(self.out, "{p}StateMachine {{", p = self.prefix);
+        for Parameter { name, .. } in &self.grammar.parameters {
+            rust!(self.out, "{},", name);
+        }
+        rust!(
+            self.out,
+            "{p}phantom: {phantom},",
+            p = self.prefix,
+            phantom = phantom_data_expr,
+        );
+        rust!(self.out, "}},");
+        rust!(self.out, "{p}tokens,", p = self.prefix);
+        rust!(self.out, ")");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Emits a `parse_prefix` method, which parses the first complete
+    /// instance of the start symbol and returns it along with the
+    /// offset of the end of the input it consumed.
+    fn write_prefix_fn(&mut self) -> io::Result<()> {
+        let phantom_data_expr = self.phantom_data_expr();
+
+        rust!(self.out, "");
+        rust!(self.out, "#[allow(dead_code)]");
+        self.out
+            .fn_header(
+                &self.grammar.nonterminals[&self.start_symbol].visibility,
+                "parse_prefix".to_owned(),
+            )
+            .with_parameters(Some("&self".to_owned()))
+            .with_grammar(self.grammar)
+            .with_return_type(format!(
+                "Result<({}, usize), {}>",
+                self.types.nonterminal_type(&self.start_symbol),
+                self.types.parse_error_type()
+            ))
+            .emit()?;
+        rust!(self.out, "{{");
+        self.define_tokens()?;
+        rust!(
+            self.out,
+            "{p}state_machine::Parser::drive_prefix(",
+            p = self.prefix,
+        );
+        rust!(self.out, "{p}StateMachine {{", p = self.prefix);
+        for Parameter { name, .. } in &self.grammar.parameters {
+            rust!(self.out, "{},", name);
+        }
+        rust!(
+            self.out,
+            "{p}phantom: {phantom},",
+            p = self.prefix,
+            phantom = phantom_data_expr,
+        );
+        rust!(self.out, "}},");
+        rust!(self.out, "{p}tokens,", p = self.prefix);
+        rust!(self.out, ")");
+        rust!(self.out, "}}");
+        Ok(())
+    }
+
+    /// Emits a `parse_tokens` method, which drives the state machine
+    /// with a vector of tokens rather than the lexer.
+    fn write_parse_tokens_fn(&mut self) -> io::Result<()> {
+        let phantom_data_expr = self.phantom_data_expr();
+        self.start_parse_tokens_fn()?;
+        rust!(
+            self.out,
+            "let {p}tokens = {p}tokens0.into_iter().map(Ok);",
+            p = self.prefix
+        );
+        rust!(
+            self.out,
+            "{p}state_machine::Parser::drive(",
+            p = self.prefix,
+        );
+        rust!(self.out, "{p}StateMachine {{", p = self.prefix);
+        for Parameter { name, .. } in &self.grammar.parameters {
+            rust!(self.out, "{},", name);
+        }
+        rust!(
+            self.out,
+            "{p}phantom: {phantom},",
+            p = self.prefix,
+            phantom = phantom_data_expr,
+        );
+        rust!(self.out, "}},");
+        rust!(self.out, "{p}tokens,", p = self.prefix);
+        rust!(self.out, ")");
+        rust!(self.out, "}}");
+        Ok(())
     }
 
     fn write_token_to_integer_fn(&mut self) -> io::Result<()> {
@@ -763,6 +942,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
 
     fn write_token_to_symbol_fn(&mut self) -> io::Result<()> {
         let symbol_type = self.symbol_type();
+        let parse_error_type = self.types.parse_error_type();
         let token_type = self.types.terminal_token_type();
 
         let parameters = vec![
@@ -783,11 +963,14 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             .with_type_parameters(&self.grammar.type_parameters)
             .with_where_clauses(&self.grammar.where_clauses)
             .with_parameters(parameters)
-            .with_return_type(symbol_type)
+            .with_return_type(format!("Result<{}, {}>", symbol_type, parse_error_type))
             .emit()?;
         rust!(self.out, "{{");
 
-        rust!(self.out, "match {p}token_index {{", p = self.prefix,);
+        // `token_index` was computed from `token` by `token_to_index`,
+        // so the fallback arms below cannot match unless the two
+        // functions disagree, which would be a bug in the generator
+        rust!(self.out, "Ok(match {p}token_index {{", p = self.prefix,);
 
         let mut token_to_symbol_mapping = Vec::new();
 
@@ -847,7 +1030,12 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                     close = if pattern_names.len() > 1 { ")" } else { "" },
                     pattern_names = pattern_names.join(", "),
                 );
-                rust!(self.out, "_ => unreachable!(),");
+                rust!(
+                    self.out,
+                    "_ => return Err({p}lalrpop_util::ParseError::Internal {{ message: \
+                     \"token does not match the terminal of its index\" }}),",
+                    p = self.prefix,
+                );
                 rust!(self.out, "}},");
             } else {
                 rust!(
@@ -860,9 +1048,14 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             }
         }
 
-        rust!(self.out, "_ => unreachable!(),");
+        rust!(
+            self.out,
+            "_ => return Err({p}lalrpop_util::ParseError::Internal {{ message: \
+             \"token index out of range\" }}),",
+            p = self.prefix,
+        );
 
-        rust!(self.out, "}}");
+        rust!(self.out, "}})");
         rust!(self.out, "}}");
         Ok(())
     }
@@ -1059,12 +1252,21 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
             .map(|i| format!("{}sym{}", self.prefix, i))
             .collect();
 
+        // The final reduction (always `__S = S`) returns the value
+        // without pushing it, so it has no use for the start and end
+        // locations, and cloning them can be costly.
+        let is_final = production.nonterminal == self.start_symbol;
+
         // Execute the action fn
         // identify the "start" and "end" location for this production; this
         // is typically the start of the first symbol and end of the last symbol we are
         // reducing; but in the case of an empty production, it will come from the
         // lookahead
-        if let (Some(first_sym), Some(last_sym)) = (transfer_syms.first(), transfer_syms.last()) {
+        if is_final {
+            assert!(!transfer_syms.is_empty());
+        } else if let (Some(first_sym), Some(last_sym)) =
+            (transfer_syms.first(), transfer_syms.last())
+        {
             rust!(
                 self.out,
                 "let {}start = {}.0.clone();",
@@ -1107,7 +1309,15 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
                 Sep(", ", &args)
             );
             rust!(self.out, "Ok(v) => v,");
-            rust!(self.out, "Err(e) => return Some(Err(e)),");
+            if Tls::session().user_error_spans {
+                rust!(
+                    self.out,
+                    "Err(e) => return Some(Err(e.with_span({p}start, {p}end))),",
+                    p = self.prefix
+                );
+            } else {
+                rust!(self.out, "Err(e) => return Some(Err(e)),");
+            }
             rust!(self.out, "}};");
         } else {
             rust!(
@@ -1124,7 +1334,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         }
 
         // if this is the final state, return it
-        if production.nonterminal == self.start_symbol {
+        if is_final {
             rust!(self.out, "return Some(Ok({}nt));", self.prefix);
             return Ok(());
         }
@@ -1225,7 +1435,7 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
     }
 
     fn write_simulate_reduce_fn(&mut self) -> io::Result<()> {
-        if !self.grammar.uses_error_recovery {
+        if !self.uses_simulate_reduce() {
             return Ok(());
         }
         let state_type = self.custom.state_type;
@@ -1535,7 +1745,11 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TableDrive
         for terminal in all_terminals {
             // Three # should hopefully be enough to prevent any
             // reasonable terminal from escaping the literal
-            rust!(self.out, "r###\"{}\"###,", terminal);
+            rust!(
+                self.out,
+                "r###\"{}\"###,",
+                self.grammar.terminals.name(terminal)
+            );
         }
         rust!(self.out, "];");
 
@@ -5,6 +5,7 @@
 use crate::grammar::repr::{Grammar, NonterminalString, TypeParameter};
 use crate::lr1::core::*;
 use crate::rust::RustWrite;
+use crate::tls::Tls;
 use std::io::{self, Write};
 use crate::util::Sep;
 
@@ -105,9 +106,24 @@ impl<'ascent, 'grammar, W: Write> CodeGenerator<'ascent, 'grammar, W, TestAll> {
 
         rust!(self.out, "return {}ascent;", self.prefix);
 
-        self.end_parser_fn()?;
-
-        Ok(())
+        self.end_parse_fn()?;
+        if Tls::session().emit_tokenizers && self.grammar.intern_token.is_some() {
+            // only the table-driven parser has `parse_tokens`
+            self.start_parse_tokens_fn()?;
+            rust!(
+                self.out,
+                "{p}parse_table::{}Parser::new().parse_tokens(",
+                self.user_start_symbol,
+                p = self.prefix
+            );
+            for parameter in &self.grammar.parameters {
+                rust!(self.out, "{},", parameter.name);
+            }
+            rust!(self.out, "{}tokens0,", self.prefix);
+            rust!(self.out, ")");
+            rust!(self.out, "}}");
+        }
+        self.end_parser_impl()
     }
 
     fn call_delegate(&mut self, delegate: &str) -> io::Result<()> {
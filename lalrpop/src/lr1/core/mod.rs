@@ -1,10 +1,10 @@
 //! Core LR(1) types.
 
-use crate::collections::Map;
+use crate::collections::{Map, Set};
 use crate::grammar::repr::*;
 use crate::util::Prefix;
 use itertools::Itertools;
-use std::fmt::{Debug, Display, Error, Formatter};
+use std::fmt::{Debug, Display, Error, Formatter, Write};
 
 use super::lookahead::*;
 
@@ -314,6 +314,85 @@ impl<'grammar, L: Lookahead> State<'grammar, L> {
     }
 }
 
+impl<'grammar> State<'grammar, TokenSet> {
+    /// Returns what this state does when the next token is `token`:
+    /// shift it, if some item can shift it, or otherwise reduce the
+    /// production whose lookahead contains it. Returns `None` if the
+    /// token is an error in this state. In a state with a conflict on
+    /// `token`, which only a failed build produces, the shift is
+    /// returned.
+    pub fn action_on(&self, token: &Token) -> Option<Action<'grammar>> {
+        if let Token::Terminal(ref terminal) = *token {
+            if let Some(&next) = self.shifts.get(terminal) {
+                return Some(Action::Shift(terminal.clone(), next));
+            }
+        }
+        self.reductions
+            .iter()
+            .find(|&&(ref lookahead, _)| lookahead.contains(token))
+            .map(|&(_, production)| Action::Reduce(production))
+    }
+}
+
+/// Renders `states` as a GraphViz graph, for viewing with `dot
+/// -Tpng`. Each state is a box listing its items, and each edge is
+/// labeled with the terminal or nonterminal that leads from one state
+/// to the next. States with conflicts are red, and states that accept
+/// the input (by reducing one of the grammar's start symbols) have a
+/// double border.
+pub fn states_to_dot<L: Lookahead>(states: &[State<'_, L>], grammar: &Grammar) -> String {
+    let start_nonterminals: Set<&NonterminalString> = grammar.start_nonterminals.values().collect();
+    let mut dot = String::new();
+    writeln!(dot, "digraph states {{").unwrap();
+    writeln!(dot, "    node [shape=box, fontname=monospace];").unwrap();
+
+    for state in states {
+        let mut label = format!("{:?}\\l", state.index);
+        for item in &state.items.vec {
+            write!(label, "{}\\l", dot_escape(&format!("{:?}", item))).unwrap();
+        }
+        let mut attributes = vec![format!("label=\"{}\"", label)];
+        if !L::conflicts(state).is_empty() {
+            attributes.push(String::from("color=red, fontcolor=red"));
+        }
+        if state.items.vec.iter().any(|item| {
+            item.can_reduce() && start_nonterminals.contains(&item.production.nonterminal)
+        }) {
+            attributes.push(String::from("peripheries=2"));
+        }
+        writeln!(dot, "    {:?} [{}];", state.index, attributes.join(", ")).unwrap();
+    }
+
+    for state in states {
+        let shifts = state
+            .shifts
+            .iter()
+            .map(|(terminal, next)| (terminal.to_string(), next));
+        let gotos = state
+            .gotos
+            .iter()
+            .map(|(nonterminal, next)| (nonterminal.to_string(), next));
+        for (symbol, next) in shifts.chain(gotos) {
+            writeln!(
+                dot,
+                "    {:?} -> {:?} [label=\"{}\"];",
+                state.index,
+                next,
+                dot_escape(&symbol)
+            )
+            .unwrap();
+        }
+    }
+
+    writeln!(dot, "}}").unwrap();
+    dot
+}
+
+/// Escapes `text` for use inside a double-quoted DOT string.
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 /// `A = B C (*) D E F` or `A = B C (*)`
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct SymbolSets<'grammar> {
@@ -1,16 +1,21 @@
 //! Error reporting. For now very stupid and simplistic.
 
-use crate::collections::{set, Set};
+use crate::collections::{set, Map, Set};
 use crate::grammar::repr::*;
 use itertools::Itertools;
 use crate::lr1::core::*;
 use crate::lr1::example::{Example, ExampleStyles, ExampleSymbol};
 use crate::lr1::first::FirstSets;
+use crate::lr1::left_recursion::{hidden_left_recursion, HiddenLeftRecursion};
 use crate::lr1::lookahead::{Token, TokenSet};
+use crate::log::Level;
 use crate::lr1::trace::Tracer;
 use crate::message::builder::{BodyCharacter, Builder, Character, MessageBuilder};
 use crate::message::Message;
 use crate::tls::Tls;
+use crate::tok::{Tok, Tokenizer};
+use crate::util::Prefix;
+use std::cmp;
 
 #[cfg(test)]
 mod test;
@@ -20,6 +25,42 @@ pub fn report_error(grammar: &Grammar, error: &LR1TableConstructionError) -> Vec
     cx.report_errors()
 }
 
+/// Like `report_error`, but describes the conflicts as a JSON array,
+/// for tools that want to process them rather than show them.
+pub fn report_error_json(grammar: &Grammar, error: &LR1TableConstructionError) -> String {
+    let mut cx = ErrorReportingCx::new(grammar, &error.states, &error.conflicts);
+    cx.report_errors_json()
+}
+
+/// Like `report_error`, but describes each conflict on one line, in
+/// the `file:line:col: error: ...` form used by compilers.
+pub fn report_error_compact(grammar: &Grammar, error: &LR1TableConstructionError) -> Vec<String> {
+    let mut cx = ErrorReportingCx::new(grammar, &error.states, &error.conflicts);
+    cx.report_errors_compact()
+}
+
+/// Classifies the conflicts in `error` without rendering any messages,
+/// so that callers can count or inspect them. Each conflict is given
+/// with the state it occurs in, the lookahead it occurs on and the span
+/// of the production being reduced.
+pub fn collect_conflicts(
+    grammar: &Grammar,
+    error: &LR1TableConstructionError,
+) -> Vec<(StateIndex, Token, Span, ConflictClassification)> {
+    let mut cx = ErrorReportingCx::new(grammar, &error.states, &error.conflicts);
+    cx.classify_conflicts()
+        .into_iter()
+        .map(|(conflict, classification)| {
+            (
+                conflict.state,
+                conflict.lookahead,
+                conflict.production.span,
+                classification,
+            )
+        })
+        .collect()
+}
+
 struct ErrorReportingCx<'cx, 'grammar: 'cx> {
     grammar: &'grammar Grammar,
     first_sets: FirstSets,
@@ -28,7 +69,7 @@ struct ErrorReportingCx<'cx, 'grammar: 'cx> {
 }
 
 #[derive(Debug)]
-enum ConflictClassification {
+pub enum ConflictClassification {
     /// The grammar is ambiguous. This means we have two examples of
     /// precisely the same set of symbols which can be reduced in two
     /// distinct ways.
@@ -63,6 +104,30 @@ enum ConflictClassification {
         symbol: Symbol,
     },
 
+    /// Like the previous, but suggest replacing `nonterminal` with
+    /// `symbol+`. Makes sense if `nonterminal` is a hand-written,
+    /// right-recursive list, `X = symbol | symbol X`.
+    SuggestStar {
+        shift: Example,
+        reduce: Example,
+        nonterminal: NonterminalString,
+        symbol: Symbol,
+    },
+
+    /// The classic "dangling else": we could reduce `X = open ...`,
+    /// or shift `close` to continue with `X = open ... close ...`,
+    /// i.e., an optional trailing clause of the same construct.
+    DanglingElse {
+        shift: Example,
+        reduce: Example,
+        open: Symbol,
+        close: Symbol,
+    },
+
+    /// Two different productions can be reduced on the same
+    /// lookahead, and the grammar does not look ambiguous.
+    ReduceReduce { action: Example, reduce: Example },
+
     /// Can't say much beyond that a conflict occurred.
     InsufficientLookahead { action: Example, reduce: Example },
 
@@ -72,6 +137,67 @@ enum ConflictClassification {
 
 type TokenConflict<'grammar> = Conflict<'grammar, Token>;
 
+impl ConflictClassification {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            ConflictClassification::Ambiguity { .. } => "Ambiguity",
+            ConflictClassification::Precedence { .. } => "Precedence",
+            ConflictClassification::SuggestInline { .. } => "SuggestInline",
+            ConflictClassification::SuggestQuestion { .. } => "SuggestQuestion",
+            ConflictClassification::SuggestStar { .. } => "SuggestStar",
+            ConflictClassification::DanglingElse { .. } => "DanglingElse",
+            ConflictClassification::ReduceReduce { .. } => "ReduceReduce",
+            ConflictClassification::InsufficientLookahead { .. } => "InsufficientLookahead",
+            ConflictClassification::Naive => "Naive",
+        }
+    }
+
+    /// The examples for the conflicting action and for the reduction,
+    /// if any were found.
+    fn examples(&self) -> Option<(&Example, &Example)> {
+        match *self {
+            ConflictClassification::Ambiguity {
+                ref action,
+                ref reduce,
+            }
+            | ConflictClassification::ReduceReduce {
+                ref action,
+                ref reduce,
+            }
+            | ConflictClassification::InsufficientLookahead {
+                ref action,
+                ref reduce,
+            } => Some((action, reduce)),
+            ConflictClassification::Precedence {
+                ref shift,
+                ref reduce,
+                ..
+            }
+            | ConflictClassification::SuggestInline {
+                ref shift,
+                ref reduce,
+                ..
+            }
+            | ConflictClassification::SuggestQuestion {
+                ref shift,
+                ref reduce,
+                ..
+            }
+            | ConflictClassification::SuggestStar {
+                ref shift,
+                ref reduce,
+                ..
+            }
+            | ConflictClassification::DanglingElse {
+                ref shift,
+                ref reduce,
+                ..
+            } => Some((shift, reduce)),
+            ConflictClassification::Naive => None,
+        }
+    }
+}
+
 impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
     fn new(
         grammar: &'grammar Grammar,
@@ -86,15 +212,291 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         }
     }
 
+    /// Classifies the conflicts, ordered by the position of the
+    /// production being reduced in the grammar source (and then by
+    /// classification), so that reports do not depend on the order of
+    /// the states.
+    fn classify_conflicts(&mut self) -> Vec<(TokenConflict<'grammar>, ConflictClassification)> {
+        let mut classified: Vec<_> = token_conflicts(self.conflicts)
+            .into_iter()
+            .map(|conflict| {
+                let classification = self.classify(&conflict);
+                (conflict, classification)
+            })
+            .collect();
+        classified.sort_by_key(|&(ref conflict, ref classification)| {
+            (conflict.production.span, classification.name())
+        });
+        classified
+    }
+
     fn report_errors(&mut self) -> Vec<Message> {
-        token_conflicts(self.conflicts)
+        // Hidden left recursion always causes conflicts, and those are
+        // much harder to make sense of than the recursion itself, so it
+        // is reported first.
+        let recursions: Vec<_> = self
+            .hidden_left_recursion()
+            .into_iter()
+            .map(|recursion| self.report_hidden_left_recursion(recursion))
+            .collect();
+
+        let classified = self.classify_conflicts();
+        let summary = if classified.len() > 1 {
+            Some(self.report_summary(&classified))
+        } else {
+            None
+        };
+        recursions
+            .into_iter()
+            .chain(summary)
+            .chain(
+                classified
+                    .into_iter()
+                    .map(|(conflict, classification)| self.report_error(&conflict, classification)),
+            )
+            .collect()
+    }
+
+    /// A message counting the conflicts of each kind, such as "12
+    /// conflicts: 3 ambiguity, 9 insufficient-lookahead", to lead the
+    /// individual reports.
+    fn report_summary(
+        &self,
+        classified: &[(TokenConflict<'grammar>, ConflictClassification)],
+    ) -> Message {
+        let mut tally: Vec<(&'static str, usize)> = vec![];
+        for &(_, ref classification) in classified {
+            let name = classification.name();
+            match tally.iter_mut().find(|&&mut (n, _)| n == name) {
+                Some(&mut (_, ref mut count)) => *count += 1,
+                None => tally.push((name, 1)),
+            }
+        }
+        let counts: Vec<String> = tally
+            .into_iter()
+            .map(|(name, count)| format!("{} {}", count, kebab_case(name)))
+            .collect();
+        MessageBuilder::new(classified[0].0.production.span)
+            .heading()
+            .text(format!(
+                "{} conflicts: {}",
+                classified.len(),
+                counts.join(", ")
+            ))
+            .end()
+            .body()
+            .begin_wrap()
+            .text("Each conflict is reported below.")
+            .end()
+            .end()
+            .end()
+    }
+
+    fn report_errors_json(&mut self) -> String {
+        let mut entries: Vec<String> = self
+            .hidden_left_recursion()
+            .into_iter()
+            .map(|recursion| self.report_hidden_left_recursion_json(&recursion))
+            .collect();
+        let conflicts = self.classify_conflicts();
+        entries.extend(
+            conflicts.into_iter().map(|(conflict, classification)| {
+                self.report_error_json(&conflict, classification)
+            }),
+        );
+        format!("[{}]", entries.join(","))
+    }
+
+    fn report_errors_compact(&mut self) -> Vec<String> {
+        let file_text = Tls::file_text();
+        let recursions = self.hidden_left_recursion().into_iter().map(|recursion| {
+            format!(
+                "{} error: `{}` is left-recursive through the nullable `{}`",
+                file_text.span_str(recursion.production.span),
+                recursion.nonterminal,
+                recursion.nullable
+            )
+        });
+        let conflicts = self
+            .classify_conflicts()
+            .into_iter()
+            .map(|(conflict, classification)| {
+                format!(
+                    "{} error: {}",
+                    file_text.span_str(conflict.production.span),
+                    self.format_compact(&conflict, &classification)
+                )
+            });
+        recursions.chain(conflicts).collect()
+    }
+
+    /// Describes a conflict in one line, like `state 7: shift/reduce
+    /// on "*" (precedence: `Expr`)`.
+    fn format_compact(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        classification: &ConflictClassification,
+    ) -> String {
+        let kind = match conflict.action {
+            Action::Shift(..) => "shift/reduce",
+            Action::Reduce(_) => "reduce/reduce",
+        };
+        let lookahead = match conflict.lookahead {
+            Token::Terminal(ref term) => self.grammar.terminals.name(term),
+            Token::EOF => "end of input".to_owned(),
+            Token::Error => "error".to_owned(),
+        };
+        let detail = match *classification {
+            ConflictClassification::Precedence {
+                ref nonterminal, ..
+            }
+            | ConflictClassification::SuggestInline {
+                ref nonterminal, ..
+            } => format!(": `{}`", nonterminal),
+            ConflictClassification::SuggestQuestion {
+                ref nonterminal,
+                ref symbol,
+                ..
+            } => format!(": `{}` -> `{}?`", nonterminal, symbol),
+            ConflictClassification::SuggestStar {
+                ref nonterminal,
+                ref symbol,
+                ..
+            } => format!(": `{}` -> `{}+`", nonterminal, symbol),
+            ConflictClassification::DanglingElse {
+                ref open,
+                ref close,
+                ..
+            } => format!(": `{}` ... `{}`", open, close),
+            ConflictClassification::Ambiguity { .. }
+            | ConflictClassification::ReduceReduce { .. }
+            | ConflictClassification::InsufficientLookahead { .. }
+            | ConflictClassification::Naive => String::new(),
+        };
+        format!(
+            "state {}: {} on {} ({}{})",
+            conflict.state.0,
+            kind,
+            lookahead,
+            kebab_case(classification.name()),
+            detail
+        )
+    }
+
+    /// The hidden left recursion in the part of the grammar that these
+    /// states were built for.
+    fn hidden_left_recursion(&self) -> Vec<HiddenLeftRecursion<'grammar>> {
+        let reachable: Set<&NonterminalString> = self
+            .states
             .iter()
-            .map(|conflict| self.report_error(conflict))
+            .flat_map(|state| &state.items.vec)
+            .map(|item| &item.production.nonterminal)
+            .collect();
+        hidden_left_recursion(self.grammar, &self.first_sets)
+            .into_iter()
+            .filter(|recursion| reachable.contains(&recursion.nonterminal))
             .collect()
     }
 
-    fn report_error(&mut self, conflict: &TokenConflict<'grammar>) -> Message {
-        match self.classify(conflict) {
+    fn report_hidden_left_recursion(&self, recursion: HiddenLeftRecursion<'grammar>) -> Message {
+        MessageBuilder::new(recursion.production.span)
+            .heading()
+            .text("Left recursion through a nullable symbol")
+            .end()
+            .body()
+            .begin_wrap()
+            .text("The nonterminal")
+            .push(recursion.nonterminal.clone())
+            .verbatimed()
+            .text("is left-recursive through the nullable")
+            .push(recursion.nullable.clone())
+            .verbatimed()
+            .text("in the rule")
+            .text(production_text(recursion.production))
+            .verbatimed()
+            .punctuated(".")
+            .end()
+            .begin_wrap()
+            .text("When")
+            .push(recursion.nullable.clone())
+            .verbatimed()
+            .text("matches nothing,")
+            .push(recursion.nonterminal.clone())
+            .verbatimed()
+            .text("can start with")
+            .push(recursion.nonterminal)
+            .verbatimed()
+            .text("again, so the parser would have to decide how deep the recursion")
+            .text("goes before seeing any of the input. LR parsers cannot do this,")
+            .text("so this always leads to conflicts.")
+            .end()
+            .begin_wrap()
+            .text("Consider making")
+            .push(recursion.nullable)
+            .verbatimed()
+            .text("non-nullable, and writing out the case where it is empty as")
+            .text("separate alternatives of the rules that use it.")
+            .end()
+            .end()
+            .end()
+    }
+
+    /// Describes hidden left recursion as a JSON object, in the same
+    /// shape as the conflicts, with the classification
+    /// `HiddenLeftRecursion`.
+    fn report_hidden_left_recursion_json(
+        &self,
+        recursion: &HiddenLeftRecursion<'grammar>,
+    ) -> String {
+        format!(
+            "{{\"file\":{},\"span\":{{\"start\":{},\"end\":{}}},\"classification\":\"HiddenLeftRecursion\",\
+             \"nonterminal\":{},\"nullable\":{}}}",
+            json_string(&Tls::file_text().path().display().to_string()),
+            recursion.production.span.0,
+            recursion.production.span.1,
+            json_string(&recursion.nonterminal.to_string()),
+            json_string(&recursion.nullable.to_string())
+        )
+    }
+
+    /// Describes one conflict as a JSON object. The span is in byte
+    /// offsets into the grammar source.
+    fn report_error_json(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        classification: ConflictClassification,
+    ) -> String {
+        let lookahead = match conflict.lookahead {
+            Token::Terminal(ref term) => format!(
+                "{{\"kind\":\"terminal\",\"name\":{}}}",
+                json_string(&term.to_string())
+            ),
+            Token::EOF => "{\"kind\":\"eof\"}".to_owned(),
+            Token::Error => "{\"kind\":\"error\"}".to_owned(),
+        };
+        let (action, reduce) = match classification.examples() {
+            Some((action, reduce)) => (example_json(action), example_json(reduce)),
+            None => ("null".to_owned(), "null".to_owned()),
+        };
+        format!(
+            "{{\"file\":{},\"span\":{{\"start\":{},\"end\":{}}},\"classification\":{},\
+             \"lookahead\":{},\"action\":{},\"reduce\":{}}}",
+            json_string(&Tls::file_text().path().display().to_string()),
+            conflict.production.span.0,
+            conflict.production.span.1,
+            json_string(classification.name()),
+            lookahead,
+            action,
+            reduce
+        )
+    }
+
+    fn report_error(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        classification: ConflictClassification,
+    ) -> Message {
+        match classification {
             ConflictClassification::Ambiguity { action, reduce } => {
                 self.report_error_ambiguity(conflict, action, reduce)
             }
@@ -114,6 +516,21 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                 nonterminal,
                 symbol,
             } => self.report_error_suggest_question(conflict, shift, reduce, nonterminal, symbol),
+            ConflictClassification::SuggestStar {
+                shift,
+                reduce,
+                nonterminal,
+                symbol,
+            } => self.report_error_suggest_star(conflict, shift, reduce, nonterminal, symbol),
+            ConflictClassification::DanglingElse {
+                shift,
+                reduce,
+                open,
+                close,
+            } => self.report_error_dangling_else(conflict, shift, reduce, open, close),
+            ConflictClassification::ReduceReduce { action, reduce } => {
+                self.report_error_reduce_reduce(conflict, action, reduce)
+            }
             ConflictClassification::InsufficientLookahead { action, reduce } => {
                 self.report_error_insufficient_lookahead(conflict, action, reduce)
             }
@@ -128,11 +545,16 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         reduce: Example,
     ) -> Builder<BodyCharacter> {
         let styles = ExampleStyles::ambig();
-        MessageBuilder::new(conflict.production.span)
+        let input = reduce.to_input_text(self.grammar);
+        let shift = self.spell_example(shift);
+        let reduce = self.spell_example(reduce);
+        let builder = MessageBuilder::new(conflict.production.span)
             .heading()
             .text("Ambiguous grammar detected")
             .end()
-            .body()
+            .body();
+        let builder = self
+            .push_conflicting_rules(builder, conflict)
             .begin_lines()
             .wrap_text("The following symbols can be reduced in two ways:")
             .push(reduce.to_symbol_list(reduce.symbols.len(), styles))
@@ -144,7 +566,17 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .begin_lines()
             .wrap_text("Alternatively, they could be reduced like so:")
             .push(shift.into_picture(styles))
-            .end()
+            .end();
+        match input {
+            Some(ref input) if !input.is_empty() => builder
+                .begin_wrap()
+                .text("For example, the input")
+                .text(input)
+                .verbatimed()
+                .text("is ambiguous.")
+                .end(),
+            _ => builder,
+        }
     }
 
     fn report_error_ambiguity(
@@ -155,9 +587,11 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
     ) -> Message {
         self.report_error_ambiguity_core(conflict, shift, reduce)
             .wrap_text(
-                "LALRPOP does not yet support ambiguous grammars. \
-                 See the LALRPOP manual for advice on \
-                 making your grammar unambiguous.",
+                "LALRPOP cannot choose between the interpretations of an \
+                 ambiguous grammar on its own. See the LALRPOP manual for \
+                 advice on making your grammar unambiguous, or annotate the \
+                 nonterminal being reduced with `#[prefer_shift]` or \
+                 `#[prefer_reduce]` to pick one.",
             )
             .end()
             .end()
@@ -191,13 +625,16 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         reduce: Example,
     ) -> Builder<BodyCharacter> {
         let styles = ExampleStyles::new();
+        let action = self.spell_example(action);
+        let reduce = self.spell_example(reduce);
         let builder = MessageBuilder::new(conflict.production.span)
             .heading()
             .text("Local ambiguity detected")
             .end()
             .body();
 
-        let builder = builder
+        let builder = self
+            .push_conflicting_rules(builder, conflict)
             .begin_lines()
             .begin_wrap()
             .text("The problem arises after having observed the following symbols")
@@ -213,7 +650,8 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         let builder = match conflict.lookahead {
             Token::Terminal(ref term) => builder
                 .text("At that point, if the next token is a")
-                .push(term.clone())
+                .text(self.grammar.terminals.name(term))
+                .styled(Tls::session().terminal_symbol)
                 .verbatimed()
                 .styled(Tls::session().cursor_symbol)
                 .punctuated(","),
@@ -230,7 +668,19 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
 
         match conflict.action {
             Action::Shift(ref lookahead, _) => {
-                self.describe_shift(builder, styles, lookahead.clone(), action, "Alternatively")
+                let state = &self.states[conflict.state.0];
+                let item = self
+                    .conflicting_shift_items(state, conflict)
+                    .into_iter()
+                    .next();
+                self.describe_shift(
+                    builder,
+                    styles,
+                    lookahead.clone(),
+                    item,
+                    action,
+                    "Alternatively",
+                )
             }
             Action::Reduce(production) => {
                 self.describe_reduce(builder, styles, production, action, "Alternatively")
@@ -238,11 +688,72 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         }
     }
 
+    /// Rewrites `example` with literal terminals if the session asks
+    /// for them.
+    fn spell_example(&self, example: Example) -> Example {
+        if Tls::session().literal_conflict_examples {
+            example.with_literal_terminals(self.grammar)
+        } else {
+            example
+        }
+    }
+
+    /// Leads a message with the pair of rules that are actually in
+    /// conflict, so that the user can see at a glance which two
+    /// productions clash before reading the examples.
+    fn push_conflicting_rules<C: Character>(
+        &self,
+        builder: Builder<C>,
+        conflict: &TokenConflict<'grammar>,
+    ) -> Builder<C> {
+        let builder = builder
+            .begin_wrap()
+            .text("The rule")
+            .text(production_text(conflict.production))
+            .verbatimed();
+        match self.counterpart_production(conflict) {
+            // e.g. `E = E "+" E` with the lookahead `"+"`
+            Some(production) if production == conflict.production => {
+                builder.text("conflicts with itself.").end()
+            }
+            Some(production) => builder
+                .text("conflicts with the rule")
+                .text(production_text(production))
+                .verbatimed()
+                .punctuated(".")
+                .end(),
+            None => builder
+                .text("conflicts with another action in the same state.")
+                .end(),
+        }
+    }
+
+    /// Finds the production that competes with `conflict.production`.
+    /// For a reduce/reduce conflict, this is the other production
+    /// being reduced; for a shift/reduce conflict, it is the
+    /// production of the (first) item that could shift the lookahead.
+    fn counterpart_production(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+    ) -> Option<&'grammar Production> {
+        match conflict.action {
+            Action::Reduce(production) => Some(production),
+            Action::Shift(..) => {
+                let state = &self.states[conflict.state.0];
+                self.conflicting_shift_items(state, conflict)
+                    .into_iter()
+                    .map(|item| item.production)
+                    .next()
+            }
+        }
+    }
+
     fn describe_shift<C: Character>(
         &self,
         builder: Builder<C>,
         styles: ExampleStyles,
         lookahead: TerminalString,
+        item: Option<LR0Item<'grammar>>,
         example: Example,
         intro_word: &str,
     ) -> Builder<C> {
@@ -258,13 +769,14 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
 
         let nt1 = example.reductions[0].nonterminal.clone();
 
-        builder
+        let builder = builder
             .begin_lines()
             .begin_wrap()
             .text(intro_word)
             .punctuated(",")
             .text("the parser could shift the")
-            .push(lookahead)
+            .text(self.grammar.terminals.name(&lookahead))
+            .styled(Tls::session().terminal_symbol)
             .verbatimed()
             .text("token and later use it to construct a")
             .push(nt1)
@@ -272,8 +784,12 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .punctuated(".")
             .text("This might then yield a parse tree like")
             .end()
-            .push(example.into_picture(styles))
-            .end()
+            .push(example.into_picture(styles));
+        match item {
+            Some(item) => self.push_item(builder, item.production, item.index),
+            None => builder,
+        }
+        .end()
     }
 
     fn describe_reduce<C: Character>(
@@ -284,7 +800,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         example: Example,
         intro_word: &str,
     ) -> Builder<C> {
-        builder
+        let builder = builder
             .begin_lines()
             .begin_wrap()
             .text(intro_word)
@@ -301,7 +817,28 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .punctuated(".")
             .text("This might then yield a parse tree like")
             .end()
-            .push(example.into_picture(styles))
+            .push(example.into_picture(styles));
+        self.push_item(builder, production, production.symbols.len())
+            .end()
+    }
+
+    /// Names the LR item `production` at `index` below an example
+    /// picture, if the session asks for it.
+    fn push_item<C: Character>(
+        &self,
+        builder: Builder<C>,
+        production: &Production,
+        index: usize,
+    ) -> Builder<C> {
+        if !Tls::session().conflict_items {
+            return builder;
+        }
+        builder
+            .begin_wrap()
+            .text("At the cursor, the parser is in the item")
+            .text(item_text(production, index))
+            .verbatimed()
+            .punctuated(".")
             .end()
     }
 
@@ -338,9 +875,15 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         nonterminal: NonterminalString,
         symbol: Symbol,
     ) -> Message {
+        let fix = if Tls::session().log.enabled(Level::Verbose) {
+            self.suggest_question_fix(&nonterminal, &symbol)
+        } else {
+            vec![]
+        };
+
         let builder = self.report_error_not_lr1_core(conflict, shift, reduce);
 
-        builder
+        let builder = builder
             .begin_wrap()
             .text("Hint:")
             .styled(Tls::session().hint_text)
@@ -359,11 +902,239 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             .punctuated(").")
             .text("For more information, see the section on inlining")
             .text("in the LALROP manual.")
+            .end();
+
+        if fix.is_empty() {
+            return builder.end().end();
+        }
+
+        fix.into_iter()
+            .fold(
+                builder
+                    .wrap_text("The following change to the grammar makes that replacement:")
+                    .begin_lines(),
+                |builder, line| builder.text(line),
+            )
+            .end()
+            .indented()
+            .end()
+            .end()
+    }
+
+    /// Computes a diff of the grammar source that deletes the
+    /// definition of `nonterminal` and replaces its uses with
+    /// `symbol?`. Uses that do not appear literally in the source,
+    /// such as those introduced by macro expansion, are left out.
+    fn suggest_question_fix(
+        &self,
+        nonterminal: &NonterminalString,
+        symbol: &Symbol,
+    ) -> Vec<String> {
+        let file_text = Tls::file_text();
+        let text = file_text.text();
+        let name = nonterminal.to_string();
+        let replacement = format!("{}?", symbol);
+
+        // The definition runs from the line with its name to the `;`
+        // after its last alternative.
+        let data = &self.grammar.nonterminals[nonterminal];
+        let last_alternative = data
+            .productions
+            .iter()
+            .map(|production| production.span.1)
+            .max()
+            .unwrap_or(data.span.1);
+        let definition_end = match text[last_alternative..].find(';') {
+            Some(offset) => last_alternative + offset,
+            None => return vec![],
+        };
+        let (definition_start, definition_end) =
+            file_text.line_span(Span(data.span.0, definition_end));
+        let mut edits: Map<usize, Vec<String>> = (definition_start..=definition_end)
+            .map(|line| (line, vec![format!("- {}", file_text.line_text(line))]))
+            .collect();
+
+        let use_symbol = Symbol::Nonterminal(nonterminal.clone());
+        let use_spans: Set<(usize, usize)> = self
+            .grammar
+            .nonterminals
+            .values()
+            .flat_map(|data| &data.productions)
+            .filter(|production| {
+                production.nonterminal != *nonterminal && production.symbols.contains(&use_symbol)
+            })
+            .flat_map(|production| symbol_uses(text, production.span, &name))
+            .collect();
+        let mut use_lines: Map<usize, Vec<(usize, usize)>> = Map::new();
+        for &(lo, hi) in &use_spans {
+            let (line, _) = file_text.line_span(Span(lo, hi));
+            use_lines
+                .entry(line)
+                .or_insert_with(Vec::new)
+                .push((lo, hi));
+        }
+        for (line, spans) in use_lines {
+            if edits.contains_key(&line) {
+                continue;
+            }
+            let old_text = file_text.line_text(line);
+            let mut new_text = String::new();
+            let mut last = text[..spans[0].0].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = last + old_text.len();
+            for (lo, hi) in spans {
+                new_text.push_str(&text[last..lo]);
+                new_text.push_str(&replacement);
+                last = hi;
+            }
+            new_text.push_str(&text[last..line_end]);
+            edits.insert(
+                line,
+                vec![format!("- {}", old_text), format!("+ {}", new_text)],
+            );
+        }
+
+        edits.into_iter().flat_map(|(_, lines)| lines).collect()
+    }
+
+    fn report_error_suggest_star(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        shift: Example,
+        reduce: Example,
+        nonterminal: NonterminalString,
+        symbol: Symbol,
+    ) -> Message {
+        let builder = self.report_error_not_lr1_core(conflict, shift, reduce);
+
+        builder
+            .begin_wrap()
+            .text("Hint:")
+            .styled(Tls::session().hint_text)
+            .text("It appears you could resolve this problem by replacing")
+            .text("uses of")
+            .push(nonterminal.clone())
+            .verbatimed()
+            .text("with")
+            .text(symbol.clone()) // intentionally disable coloring here, looks better
+            .adjacent_text("`", "+`")
+            .text("(or")
+            .text(symbol)
+            .adjacent_text("`", "*`")
+            .text("where the list may also be empty), since LALRPOP expands")
+            .text("those into left-recursive rules, which LR parsers")
+            .text("handle more easily than the right recursion in")
+            .push(nonterminal)
+            .verbatimed()
+            .punctuated(".")
             .end()
             .end()
             .end()
     }
 
+    fn report_error_dangling_else(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        shift: Example,
+        reduce: Example,
+        open: Symbol,
+        close: Symbol,
+    ) -> Message {
+        self.report_error_not_lr1_core(conflict, shift, reduce)
+            .begin_wrap()
+            .text("Hint:")
+            .styled(Tls::session().hint_text)
+            .text("This is a \"dangling else\" problem: when")
+            .text(open.clone())
+            .verbatimed()
+            .text("constructs are nested, the parser cannot tell whether a")
+            .text(close.clone())
+            .verbatimed()
+            .text("belongs to the innermost")
+            .text(open)
+            .verbatimed()
+            .text("or to an enclosing one. To attach the")
+            .text(close.clone())
+            .verbatimed()
+            .text("to the innermost construct, as most parser generators do,")
+            .text("annotate")
+            .push(conflict.production.nonterminal.clone())
+            .verbatimed()
+            .text("with")
+            .text("#[prefer_shift]")
+            .verbatimed()
+            .punctuated(".")
+            .text("Alternatively, split the nonterminal in two, so that only")
+            .text("constructs which cannot end in an unfinished one may")
+            .text("appear before")
+            .text(close)
+            .verbatimed()
+            .punctuated(".")
+            .end()
+            .end()
+            .end()
+    }
+
+    fn report_error_reduce_reduce(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        action: Example,
+        reduce: Example,
+    ) -> Message {
+        let styles = ExampleStyles::new();
+        let action = self.spell_example(action);
+        let reduce = self.spell_example(reduce);
+        let other = match conflict.action {
+            Action::Reduce(production) => production,
+            Action::Shift(..) => panic!("reduce/reduce report for a shift"),
+        };
+        let builder = MessageBuilder::new(conflict.production.span)
+            .heading()
+            .text("Reduce/reduce conflict detected")
+            .end()
+            .body();
+
+        let shared = shared_context(&action, &reduce);
+        let builder = self
+            .push_conflicting_rules(builder, conflict)
+            .begin_lines()
+            .wrap_text("Both reductions apply after the following symbols:")
+            .push(shared.to_symbol_list(shared.symbols.len(), styles))
+            .begin_wrap();
+
+        let builder = match conflict.lookahead {
+            Token::Terminal(ref term) => builder
+                .text("If the next token is a")
+                .text(self.grammar.terminals.name(term))
+                .styled(Tls::session().terminal_symbol)
+                .verbatimed()
+                .styled(Tls::session().cursor_symbol)
+                .punctuated(","),
+            Token::Error => builder.text("If an error has been found,"),
+            Token::EOF => builder.text("If the end of the input is reached,"),
+        };
+
+        let builder = builder
+            .text("two reductions are possible: the parser could produce a")
+            .push(conflict.production.nonterminal.clone())
+            .verbatimed()
+            .text("or a")
+            .push(other.nonterminal.clone())
+            .verbatimed()
+            .punctuated(",")
+            .text("and one token of lookahead is not enough to choose.")
+            .end()
+            .end();
+
+        let builder = self.describe_reduce(builder, styles, conflict.production, reduce, "First");
+        self.describe_reduce(builder, styles, other, action, "Alternatively")
+            .wrap_text(
+                "See the LALRPOP manual for advice on \
+                 making your grammar LR(1).",
+            )
+            .end()
+            .end()
+    }
+
     fn report_error_insufficient_lookahead(
         &self,
         conflict: &TokenConflict<'grammar>,
@@ -401,16 +1172,22 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
     /// Naive error reporting. This is a fallback path which (I think)
     /// never actually executes.
     fn report_error_naive(&self, conflict: &TokenConflict<'grammar>) -> Message {
-        let mut builder = MessageBuilder::new(conflict.production.span)
+        let builder = MessageBuilder::new(conflict.production.span)
             .heading()
             .text("Conflict detected")
             .end()
-            .body()
+            .body();
+        let mut builder = self
+            .push_conflicting_rules(builder, conflict)
             .begin_lines()
             .wrap_text("when in this state:")
             .indented();
         for item in self.states[conflict.state.0].items.vec.iter() {
-            builder = builder.text(format!("{:?}", item));
+            builder = builder.text(format!(
+                "{:?} with lookahead {}",
+                item.to_lr0(),
+                item.lookahead.display(self.grammar)
+            ));
         }
         let mut builder = builder
             .end()
@@ -432,7 +1209,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
     fn classify(&mut self, conflict: &TokenConflict<'grammar>) -> ConflictClassification {
         // Find examples from the conflicting action (either a shift
         // or a reduce).
-        let mut action_examples = match conflict.action {
+        let action_examples = match conflict.action {
             Action::Shift(..) => self.shift_examples(conflict),
             Action::Reduce(production) => {
                 self.reduce_examples(conflict.state, production, conflict.lookahead.clone())
@@ -440,15 +1217,35 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         };
 
         // Find examples from the conflicting reduce.
-        let mut reduce_examples = self.reduce_examples(
+        let reduce_examples = self.reduce_examples(
             conflict.state,
             conflict.production,
             conflict.lookahead.clone(),
         );
 
+        self.classify_examples(conflict, action_examples, reduce_examples)
+    }
+
+    fn classify_examples(
+        &mut self,
+        conflict: &TokenConflict<'grammar>,
+        mut action_examples: Vec<Example>,
+        mut reduce_examples: Vec<Example>,
+    ) -> ConflictClassification {
+        // The reports describe the first reduction of each example,
+        // so an example without one cannot be used.
+        action_examples.retain(|example| !example.reductions.is_empty());
+        reduce_examples.retain(|example| !example.reductions.is_empty());
+
         // Prefer shorter examples to longer ones.
-        action_examples.sort_by(|e, f| e.symbols.len().cmp(&f.symbols.len()));
-        reduce_examples.sort_by(|e, f| e.symbols.len().cmp(&f.symbols.len()));
+        action_examples.sort_by(example_order);
+        reduce_examples.sort_by(example_order);
+
+        // Very long examples make for unreadable pictures, so leave
+        // them out.
+        let max_symbols = Tls::session().max_example_symbols;
+        drop_long_examples(&mut action_examples, max_symbols);
+        drop_long_examples(&mut reduce_examples, max_symbols);
 
         // This really shouldn't happen, but if we've failed to come
         // up with examples, then report a "naive" error.
@@ -456,6 +1253,12 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             return ConflictClassification::Naive;
         }
 
+        if let Some(classification) =
+            self.try_classify_dangling_else(conflict, &action_examples, &reduce_examples)
+        {
+            return classification;
+        }
+
         if let Some(classification) =
             self.try_classify_ambiguity(conflict, &action_examples, &reduce_examples)
         {
@@ -468,6 +1271,12 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
             return classification;
         }
 
+        if let Some(classification) =
+            self.try_classify_star(conflict, &action_examples, &reduce_examples)
+        {
+            return classification;
+        }
+
         if let Some(classification) =
             self.try_classify_inline(conflict, &action_examples, &reduce_examples)
         {
@@ -477,16 +1286,82 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         // Give up. Just grab an example from each and pair them up.
         // If there aren't even two examples, something's pretty
         // bogus, but we'll just call it naive.
+        let reduce_reduce = match conflict.action {
+            Action::Reduce(_) => true,
+            Action::Shift(..) => false,
+        };
         action_examples
             .into_iter()
             .zip(reduce_examples)
             .next()
-            .map(
-                |(action, reduce)| ConflictClassification::InsufficientLookahead { action, reduce },
-            )
+            .map(|(action, reduce)| {
+                if reduce_reduce {
+                    ConflictClassification::ReduceReduce { action, reduce }
+                } else {
+                    ConflictClassification::InsufficientLookahead { action, reduce }
+                }
+            })
             .unwrap_or(ConflictClassification::Naive)
     }
 
+    fn try_classify_dangling_else(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        action_examples: &[Example],
+        reduce_examples: &[Example],
+    ) -> Option<ConflictClassification> {
+        // We are looking for a shift/reduce conflict between reducing
+        //
+        //     X = open ... X (*)
+        //
+        // and shifting `close` in
+        //
+        //     X = open ... X (*) close ...
+        //
+        // where the second production extends the first. The trailing
+        // `X` is what lets these constructs nest.
+        let close = match conflict.action {
+            Action::Shift(ref term, _) => Symbol::Terminal(term.clone()),
+            Action::Reduce(_) => return None,
+        };
+
+        let reduced = conflict.production;
+        if reduced.symbols.len() < 2
+            || reduced.symbols.last() != Some(&Symbol::Nonterminal(reduced.nonterminal.clone()))
+        {
+            return None;
+        }
+        let open = reduced.symbols[0].clone();
+        let state = &self.states[conflict.state.0];
+        let extends_reduced = |item: &LR0Item<'grammar>| {
+            item.production.nonterminal == reduced.nonterminal
+                && item.index == reduced.symbols.len()
+                && item.production.symbols[..item.index] == reduced.symbols[..]
+        };
+        if !self
+            .conflicting_shift_items(state, conflict)
+            .iter()
+            .any(extends_reduced)
+        {
+            return None;
+        }
+
+        // Prefer a pair of examples with the same symbols, which shows
+        // the two ways to read the same input.
+        let (shift, reduce) = action_examples
+            .iter()
+            .cartesian_product(reduce_examples)
+            .find(|&(action, reduce)| action.symbols == reduce.symbols)
+            .unwrap_or((&action_examples[0], &reduce_examples[0]));
+
+        Some(ConflictClassification::DanglingElse {
+            shift: shift.clone(),
+            reduce: reduce.clone(),
+            open,
+            close,
+        })
+    }
+
     fn try_classify_ambiguity(
         &self,
         conflict: &TokenConflict<'grammar>,
@@ -504,11 +1379,7 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
                 // `T = T S T` and shifting `S`.
                 if let Action::Shift(ref term, _) = conflict.action {
                     let nt = &conflict.production.nonterminal;
-                    if conflict.production.symbols.len() == 3
-                        && conflict.production.symbols[0] == Symbol::Nonterminal(nt.clone())
-                        && conflict.production.symbols[1] == Symbol::Terminal(term.clone())
-                        && conflict.production.symbols[2] == Symbol::Nonterminal(nt.clone())
-                    {
+                    if is_operator_production(conflict.production, nt, term) {
                         return ConflictClassification::Precedence {
                             shift: action.clone(),
                             reduce: reduce.clone(),
@@ -570,6 +1441,47 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         None
     }
 
+    fn try_classify_star(
+        &self,
+        conflict: &TokenConflict<'grammar>,
+        action_examples: &[Example],
+        reduce_examples: &[Example],
+    ) -> Option<ConflictClassification> {
+        // If we get a shift/reduce conflict and the reduce
+        // is of a nonterminal like:
+        //
+        //     T = { U | U T }
+        //
+        // then suggest replacing T with U+, which expands to a
+        // left-recursive nonterminal.
+
+        if let Action::Reduce(_) = conflict.action {
+            return None;
+        }
+
+        let nt = &conflict.production.nonterminal;
+        let nt_productions = self.grammar.productions_for(nt);
+        if nt_productions.len() == 2 {
+            for &(i, j) in &[(0, 1), (1, 0)] {
+                let (single, list) = (&nt_productions[i].symbols, &nt_productions[j].symbols);
+                if single.len() == 1
+                    && list.len() == 2
+                    && list[0] == single[0]
+                    && list[1] == Symbol::Nonterminal(nt.clone())
+                {
+                    return Some(ConflictClassification::SuggestStar {
+                        shift: action_examples[0].clone(),
+                        reduce: reduce_examples[0].clone(),
+                        nonterminal: nt.clone(),
+                        symbol: single[0].clone(),
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
     fn try_classify_inline(
         &self,
         conflict: &TokenConflict<'grammar>,
@@ -752,9 +1664,13 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
         state: &LR1State<'grammar>,
         conflict: &TokenConflict<'grammar>,
     ) -> Set<LR0Item<'grammar>> {
-        // Lookahead must be a terminal, not EOF.
+        // Only a terminal can be shifted; for EOF, there are no items
+        // to find, and the conflict gets a naive report.
+        let lookahead = match conflict.lookahead.as_terminal() {
+            Some(terminal) => Symbol::Terminal(terminal.clone()),
+            None => return set(),
+        };
         // Find an item J like `Bar = ... (*) L ...`.
-        let lookahead = Symbol::Terminal(conflict.lookahead.unwrap_terminal().clone());
         state
             .items
             .vec
@@ -766,6 +1682,158 @@ impl<'cx, 'grammar> ErrorReportingCx<'cx, 'grammar> {
     }
 }
 
+/// Converts a classification name like `InsufficientLookahead` to
+/// `insufficient-lookahead`.
+fn kebab_case(name: &str) -> String {
+    let mut result = String::new();
+    for c in name.chars() {
+        if c.is_uppercase() && !result.is_empty() {
+            result.push('-');
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
+
+/// Orders examples by length. Ties are broken by the names of the
+/// symbols and then by the reductions, so the example chosen for a
+/// report does not depend on the order the tracer found them in.
+fn example_order(e: &Example, f: &Example) -> cmp::Ordering {
+    fn names(example: &Example) -> Vec<String> {
+        example
+            .symbols
+            .iter()
+            .map(|symbol| match *symbol {
+                ExampleSymbol::Symbol(ref symbol) => symbol.to_string(),
+                ExampleSymbol::Epsilon => String::new(),
+            })
+            .collect()
+    }
+    fn reductions(example: &Example) -> Vec<(usize, usize, String)> {
+        example
+            .reductions
+            .iter()
+            .map(|r| (r.start, r.end, r.nonterminal.to_string()))
+            .collect()
+    }
+    e.symbols
+        .len()
+        .cmp(&f.symbols.len())
+        .then_with(|| names(e).cmp(&names(f)))
+        .then_with(|| e.cursor.cmp(&f.cursor))
+        .then_with(|| reductions(e).cmp(&reductions(f)))
+}
+
+/// Removes the examples with more than `max_symbols` symbols from
+/// `examples`, which must be sorted by length, except that the
+/// shortest one is always kept.
+fn drop_long_examples(examples: &mut Vec<Example>, max_symbols: usize) {
+    let short = examples
+        .iter()
+        .take_while(|example| example.symbols.len() <= max_symbols)
+        .count();
+    examples.truncate(cmp::max(short, 1));
+}
+
+/// The symbols that both examples have seen when they reach their
+/// cursors, i.e. the longest common suffix of the parts before the
+/// cursors. Returned as an example whose cursor is at the end.
+fn shared_context(a: &Example, b: &Example) -> Example {
+    let shared = a.symbols[..a.cursor]
+        .iter()
+        .rev()
+        .zip(b.symbols[..b.cursor].iter().rev())
+        .take_while(|&(x, y)| x == y)
+        .count();
+    Example {
+        symbols: a.symbols[a.cursor - shared..a.cursor].to_vec(),
+        cursor: shared,
+        reductions: vec![],
+    }
+}
+
+/// Describes an example as a JSON object listing its symbols, with
+/// `null` standing for an empty reduction, and the cursor position.
+fn example_json(example: &Example) -> String {
+    let symbols: Vec<String> = example
+        .symbols
+        .iter()
+        .map(|symbol| match *symbol {
+            ExampleSymbol::Symbol(ref symbol) => json_string(&symbol.to_string()),
+            ExampleSymbol::Epsilon => "null".to_owned(),
+        })
+        .collect();
+    format!(
+        "{{\"symbols\":[{}],\"cursor\":{}}}",
+        symbols.join(","),
+        example.cursor
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut result = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Renders a production like `Expr = Expr "+" Term`, for use in
+/// error messages.
+fn production_text(production: &Production) -> String {
+    if production.symbols.is_empty() {
+        format!("{} = ()", production.nonterminal)
+    } else {
+        format!(
+            "{} ={}",
+            production.nonterminal,
+            Prefix(" ", &production.symbols[..])
+        )
+    }
+}
+
+/// Whether `production` applies an operator `term` to operands of type
+/// `nt`: either `T = T term T`, or a longer production that starts and
+/// ends with `T`, such as `T = T "?" T ":" T` or `T = T "<" T "<" T`,
+/// in which `term` sits between two `T`s.
+fn is_operator_production(
+    production: &Production,
+    nt: &NonterminalString,
+    term: &TerminalString,
+) -> bool {
+    let operand = Symbol::Nonterminal(nt.clone());
+    let operator = Symbol::Terminal(term.clone());
+    let symbols = &production.symbols;
+    if symbols.len() == 3 {
+        return symbols[0] == operand && symbols[1] == operator && symbols[2] == operand;
+    }
+    symbols.len() > 3
+        && symbols[0] == operand
+        && symbols[symbols.len() - 1] == operand
+        && symbols
+            .windows(3)
+            .any(|w| w[0] == operand && w[1] == operator && w[2] == operand)
+}
+
+/// Writes the item `production` at `index` like `A = B (*) C`.
+fn item_text(production: &Production, index: usize) -> String {
+    format!(
+        "{} ={} (*){}",
+        production.nonterminal,
+        Prefix(" ", &production.symbols[..index]),
+        Prefix(" ", &production.symbols[index..])
+    )
+}
+
 fn token_conflicts<'grammar>(
     conflicts: &[Conflict<'grammar, TokenSet>],
 ) -> Vec<TokenConflict<'grammar>> {
@@ -836,3 +1904,22 @@ fn token_conflicts<'grammar>(
 //        }
 //    }
 //}
+
+/// The spans at which the alternative at `span` refers to the
+/// nonterminal `name`. Only its symbols count: names in action code,
+/// in string literals and bound with `<name:...>` are skipped.
+fn symbol_uses(text: &str, span: Span, name: &str) -> Vec<(usize, usize)> {
+    let tokens: Vec<_> = Tokenizer::new(&text[span.0..span.1], span.0)
+        .take_while(|token| token.is_ok())
+        .filter_map(|token| token.ok())
+        .collect();
+    tokens
+        .iter()
+        .enumerate()
+        .filter(|(index, (_, token, _))| {
+            *token == Tok::Id(name)
+                && tokens.get(index + 1).map(|(_, next, _)| next) != Some(&Tok::Colon)
+        })
+        .map(|(_, &(lo, _, hi))| (lo, hi))
+        .collect()
+}
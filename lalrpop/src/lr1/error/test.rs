@@ -1,9 +1,16 @@
+use crate::file_text::FileText;
 use crate::grammar::repr::*;
 use crate::lr1::build_states;
+use crate::lr1::core::Action;
+use crate::lr1::example::{Example, ExampleSymbol};
+use crate::lr1::lookahead::Token;
 use crate::lr1::tls::Lr1Tls;
+use crate::message::{Content, Message};
+use crate::session::Session;
 use string_cache::DefaultAtom as Atom;
 use crate::test_util::normalized_grammar;
 use crate::tls::Tls;
+use std::rc::Rc;
 
 use super::{ConflictClassification, ErrorReportingCx};
 
@@ -11,6 +18,15 @@ fn nt(t: &str) -> NonterminalString {
     NonterminalString(Atom::from(t))
 }
 
+fn render(message: Message) -> Vec<String> {
+    message
+        .emit_to_canvas(80)
+        .to_strings()
+        .iter()
+        .map(|row| row.to_string().trim_end().to_string())
+        .collect()
+}
+
 #[test]
 fn priority_conflict() {
     let _tls = Tls::test();
@@ -123,6 +139,105 @@ fn suggest_question_conflict() {
     }
 }
 
+#[test]
+fn suggest_question_fix() {
+    let text = r#"
+grammar;
+
+pub E: () = {
+    "L",
+    "&" OPT_L E => { let OPT_L = (); OPT_L }
+};
+
+OPT_L: () = {
+    (),
+    "L"
+};
+"#;
+    let _tls = Tls::test_string(text);
+    let grammar = normalized_grammar(text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("E")).unwrap_err();
+    let cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let symbol = Symbol::Terminal(TerminalString::quoted(Atom::from("L")));
+
+    assert_eq!(
+        cx.suggest_question_fix(&nt("OPT_L"), &symbol),
+        vec![
+            r#"-     "&" OPT_L E => { let OPT_L = (); OPT_L }"#,
+            r#"+     "&" "L"? E => { let OPT_L = (); OPT_L }"#,
+            r#"- OPT_L: () = {"#,
+            r#"-     (),"#,
+            r#"-     "L""#,
+            r#"- };"#,
+        ]
+    );
+}
+
+#[test]
+fn symbol_uses() {
+    let text = r#"A OPT OPT_L "OPT" <OPT:OPT> => OPT"#;
+    assert_eq!(
+        super::symbol_uses(text, Span(0, text.len()), "OPT"),
+        vec![(2, 5), (23, 26)]
+    );
+}
+
+#[test]
+fn dangling_else_conflict() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+grammar;
+
+pub Stmt: () = {
+    "if" Cond "then" Stmt,
+    "if" Cond "then" Stmt "else" Stmt,
+    "x",
+};
+
+Cond: () = "c";
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("Stmt")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let conflict = &conflicts[0];
+
+    println!("conflict={:?}", conflict);
+
+    match cx.classify(conflict) {
+        ConflictClassification::DanglingElse {
+            shift,
+            reduce,
+            open,
+            close,
+        } => {
+            assert_eq!(
+                open,
+                Symbol::Terminal(TerminalString::quoted(Atom::from("if")))
+            );
+            assert_eq!(
+                close,
+                Symbol::Terminal(TerminalString::quoted(Atom::from("else")))
+            );
+            assert_eq!(shift.symbols, reduce.symbols);
+        }
+        r => panic!("wrong classification {:#?}", r),
+    }
+
+    let classification = cx.classify(conflict);
+    let message = cx.report_error(conflict, classification);
+    let lines = render(message);
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("This is a \"dangling else\" problem")));
+    assert!(lines
+        .iter()
+        .any(|line| line.contains("`Stmt` with `#[prefer_shift]`")));
+}
+
 #[test]
 fn suggest_inline_conflict() {
     let _tls = Tls::test();
@@ -185,3 +300,672 @@ VarDecl = "let";
         cx.classify(conflict);
     }
 }
+
+#[test]
+fn report_names_conflicting_rules() {
+    let grammar_text = r#"
+grammar;
+pub Ty: () = {
+    "int" => (),
+    "bool" => (),
+    <t1:Ty> "->" <t2:Ty> => (),
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let classification = cx.classify(&conflicts[0]);
+    let lines = render(cx.report_error(&conflicts[0], classification));
+
+    // The rule pair comes first in the body, before any pictures.
+    let body = lines[2..].join(" ");
+    let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        body.starts_with(r#"The rule `Ty = Ty "->" Ty` conflicts with itself."#),
+        "unexpected message: {:#?}",
+        lines
+    );
+}
+
+#[test]
+fn report_ambiguity_with_example_input() {
+    let grammar_text = r#"
+grammar;
+pub E: () = {
+    E "*" E => (),
+    Id => (),
+};
+Id: () = r"[a-z][a-z0-9]*" => ();
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("E")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let classification = cx.classify(&conflicts[0]);
+    let lines = render(cx.report_error(&conflicts[0], classification));
+
+    // nonterminals are spelled by their shortest derivation, and
+    // regular expressions by a shortest match
+    let body = lines.join(" ");
+    let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        body.contains("For example, the input `a * a * a` is ambiguous."),
+        "unexpected message: {:#?}",
+        lines
+    );
+}
+
+#[test]
+fn report_conflicts_as_json() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+grammar;
+pub Ty: () = {
+    "int" => (),
+    <t1:Ty> "->" <t2:Ty> => (),
+};
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let json = super::report_error_json(&grammar, &err);
+
+    let symbols = r#"["Ty","\"->\"","Ty","\"->\"","Ty"]"#;
+    let expected = format!(
+        r#"{{"file":"test.lalrpop","span":{{"start":46,"end":72}},"classification":"Precedence","lookahead":{{"kind":"terminal","name":"\"->\""}},"action":{{"symbols":{0},"cursor":3}},"reduce":{{"symbols":{0},"cursor":3}}}}"#,
+        symbols
+    );
+    assert!(json.starts_with(&format!("[{},", expected)));
+}
+
+#[test]
+fn format_compact_conflicts() {
+    let grammar_text = r#"
+grammar;
+pub E: () = {
+    E "+" E,
+    "x"
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("E")).unwrap_err();
+    let cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let conflict = &conflicts[0];
+    let state = conflict.state.0;
+
+    let example = || Example {
+        symbols: vec![],
+        cursor: 0,
+        reductions: vec![],
+    };
+    let symbol = || Symbol::Terminal(TerminalString::quoted(Atom::from("x")));
+    let classifications = vec![
+        ConflictClassification::Ambiguity {
+            action: example(),
+            reduce: example(),
+        },
+        ConflictClassification::Precedence {
+            shift: example(),
+            reduce: example(),
+            nonterminal: nt("E"),
+        },
+        ConflictClassification::SuggestInline {
+            shift: example(),
+            reduce: example(),
+            nonterminal: nt("E"),
+        },
+        ConflictClassification::SuggestQuestion {
+            shift: example(),
+            reduce: example(),
+            nonterminal: nt("E"),
+            symbol: symbol(),
+        },
+        ConflictClassification::SuggestStar {
+            shift: example(),
+            reduce: example(),
+            nonterminal: nt("E"),
+            symbol: symbol(),
+        },
+        ConflictClassification::DanglingElse {
+            shift: example(),
+            reduce: example(),
+            open: symbol(),
+            close: Symbol::Terminal(TerminalString::quoted(Atom::from("+"))),
+        },
+        ConflictClassification::ReduceReduce {
+            action: example(),
+            reduce: example(),
+        },
+        ConflictClassification::InsufficientLookahead {
+            action: example(),
+            reduce: example(),
+        },
+        ConflictClassification::Naive,
+    ];
+    let lines: Vec<String> = classifications
+        .iter()
+        .map(|classification| cx.format_compact(conflict, classification))
+        .collect();
+    let prefix = format!(r#"state {}: shift/reduce on "+""#, state);
+    assert_eq!(
+        lines,
+        vec![
+            format!("{} (ambiguity)", prefix),
+            format!("{} (precedence: `E`)", prefix),
+            format!("{} (suggest-inline: `E`)", prefix),
+            format!(r#"{} (suggest-question: `E` -> `"x"?`)"#, prefix),
+            format!(r#"{} (suggest-star: `E` -> `"x"+`)"#, prefix),
+            format!(r#"{} (dangling-else: `"x"` ... `"+"`)"#, prefix),
+            format!("{} (reduce-reduce)", prefix),
+            format!("{} (insufficient-lookahead)", prefix),
+            format!("{} (naive)", prefix),
+        ]
+    );
+}
+
+#[test]
+fn report_conflicts_compactly() {
+    let grammar_text = r#"
+grammar;
+pub E: () = {
+    E "+" E,
+    "x"
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("E")).unwrap_err();
+    let lines = super::report_error_compact(&grammar, &err);
+    assert!(!lines.is_empty());
+    for line in &lines {
+        assert!(line.contains(r#"error: state "#), "{}", line);
+        assert!(
+            line.ends_with(r#": shift/reduce on "+" (precedence: `E`)"#),
+            "{}",
+            line
+        );
+    }
+}
+
+#[test]
+fn json_string() {
+    assert_eq!(super::json_string("a\"b\\c\n"), r#""a\"b\\c\n""#);
+}
+
+#[test]
+fn drop_long_examples() {
+    let example = |len: usize| Example {
+        symbols: vec![ExampleSymbol::Epsilon; len],
+        cursor: 0,
+        reductions: vec![],
+    };
+
+    let mut examples = vec![example(2), example(3), example(5)];
+    super::drop_long_examples(&mut examples, 3);
+    assert_eq!(examples.len(), 2);
+
+    // if every example is too long, keep the shortest
+    let mut examples = vec![example(4), example(5)];
+    super::drop_long_examples(&mut examples, 3);
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0].symbols.len(), 4);
+}
+
+#[test]
+fn example_order_breaks_ties_by_name() {
+    let example = |names: &[&str]| Example {
+        symbols: names
+            .iter()
+            .map(|&name| ExampleSymbol::Symbol(Symbol::Nonterminal(nt(name))))
+            .collect(),
+        cursor: 0,
+        reductions: vec![],
+    };
+    let names = |example: &Example| format!("{:?}", example.symbols);
+
+    let mut examples = vec![example(&["B", "A"]), example(&["C"]), example(&["A", "B"])];
+    examples.sort_by(super::example_order);
+    assert_eq!(
+        examples.iter().map(names).collect::<Vec<_>>(),
+        vec![
+            names(&example(&["C"])),
+            names(&example(&["A", "B"])),
+            names(&example(&["B", "A"])),
+        ]
+    );
+}
+
+#[test]
+fn classification_is_repeatable() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+grammar;
+pub E: () = {
+    E "+" E,
+    E "*" E,
+    "x"
+};
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("E")).unwrap_err();
+    let classify = || {
+        let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+        super::token_conflicts(&err.conflicts)
+            .iter()
+            .map(|conflict| format!("{:?}", cx.classify(conflict)))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(classify(), classify());
+}
+
+#[test]
+fn examples_without_reductions_are_naive() {
+    let grammar_text = r#"
+grammar;
+pub E: () = {
+    E "+" E,
+    "x"
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("E")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let conflict = &conflicts[0];
+    let example = Example {
+        symbols: vec![ExampleSymbol::Symbol(Symbol::Nonterminal(nt("E")))],
+        cursor: 1,
+        reductions: vec![],
+    };
+
+    let classification =
+        cx.classify_examples(conflict, vec![example.clone()], vec![example.clone()]);
+    match classification {
+        ConflictClassification::Naive => {}
+        r => panic!("wrong classification {:#?}", r),
+    }
+
+    // the naive report does not look at the examples at all
+    let lines = render(cx.report_error(conflict, classification));
+    assert!(lines[0].contains("Conflict detected"), "{:#?}", lines);
+}
+
+#[test]
+fn examples_longer_than_the_limit() {
+    let mut session = Session::test();
+    session.max_example_symbols = 3;
+    let _tls = Tls::install(Rc::new(session), Rc::new(FileText::test()));
+    let grammar = normalized_grammar(
+        r#"
+grammar;
+pub Ty: () = {
+    "int" => (),
+    <t1:Ty> "->" <t2:Ty> => (),
+};
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+
+    // All the examples are longer than the limit, but we still find
+    // the shortest ones rather than giving up.
+    match cx.classify(&conflicts[0]) {
+        ConflictClassification::Precedence { shift, reduce, .. } => {
+            assert_eq!(shift.symbols.len(), 5); // Ty -> Ty -> Ty
+            assert_eq!(reduce.symbols.len(), 5);
+        }
+        r => panic!("wrong classification {:#?}", r),
+    }
+}
+
+#[test]
+fn suggest_star_conflict() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+        grammar;
+
+        pub S: () = List "a" "b";
+
+        List: () = {
+            "a",
+            "a" List
+        };
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("S")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let conflict = &conflicts[0];
+
+    println!("conflict={:?}", conflict);
+
+    match cx.classify(conflict) {
+        ConflictClassification::SuggestStar {
+            shift: _,
+            reduce: _,
+            nonterminal,
+            symbol,
+        } => {
+            assert_eq!(nonterminal, nt("List"));
+            assert_eq!(
+                symbol,
+                Symbol::Terminal(TerminalString::quoted(Atom::from("a")))
+            );
+        }
+        r => panic!("wrong classification {:#?}", r),
+    }
+}
+
+#[test]
+fn collect_conflicts_without_messages() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+        grammar;
+
+        pub E: () = {
+            E "+" E,
+            "x"
+        };
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("E")).unwrap_err();
+    let conflicts = super::collect_conflicts(&grammar, &err);
+
+    // the same conflict can show up in more than one state, but it is
+    // always the precedence conflict on `+`
+    assert!(!conflicts.is_empty());
+    for conflict in &conflicts {
+        match *conflict {
+            (
+                _,
+                Token::Terminal(ref term),
+                _,
+                ConflictClassification::Precedence {
+                    ref nonterminal, ..
+                },
+            ) => {
+                assert_eq!(*term, TerminalString::quoted(Atom::from("+")));
+                assert_eq!(*nonterminal, nt("E"));
+            }
+            ref r => panic!("wrong conflict {:#?}", r),
+        }
+    }
+}
+
+#[test]
+fn reduce_reduce_conflict() {
+    let grammar_text = r#"
+grammar;
+pub S: () = {
+    A "x" "1" => (),
+    B "x" "2" => (),
+};
+A: () = "a" => ();
+B: () = "a" => ();
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("S")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let x = Token::Terminal(TerminalString::quoted(Atom::from("x")));
+    let conflict = conflicts.iter().find(|c| c.lookahead == x).unwrap();
+
+    let classification = cx.classify(conflict);
+    match classification {
+        ConflictClassification::ReduceReduce {
+            ref action,
+            ref reduce,
+        } => {
+            let shared = super::shared_context(action, reduce);
+            assert_eq!(
+                shared.symbols,
+                vec![ExampleSymbol::Symbol(Symbol::Terminal(
+                    TerminalString::quoted(Atom::from("a"))
+                ))]
+            );
+        }
+        ref r => panic!("wrong classification {:#?}", r),
+    }
+
+    let lines = render(cx.report_error(conflict, classification));
+    let body = lines.join(" ");
+    let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        body.contains(r#"If the next token is a `"x"`, two reductions are possible"#),
+        "unexpected message: {:#?}",
+        lines
+    );
+    assert!(
+        body.contains("the parser could produce a `A` or a `B`"),
+        "unexpected message: {:#?}",
+        lines
+    );
+}
+
+#[test]
+fn shift_names_terminal() {
+    let grammar_text = r#"
+grammar;
+pub Expr: () = {
+    "Id" => (),
+    "Id" "{" "}" => (),
+    "if" Expr "{" "}" => (),
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("Expr")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    let classification = cx.classify(&conflicts[0]);
+    match classification {
+        ConflictClassification::InsufficientLookahead { .. } => {}
+        ref r => panic!("wrong classification {:#?}", r),
+    }
+
+    let lines = render(cx.report_error(&conflicts[0], classification));
+    let body = lines.join(" ");
+    let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        body.contains(r#"Alternatively, the parser could shift the `"{"` token"#),
+        "unexpected message: {:#?}",
+        lines
+    );
+}
+
+#[test]
+fn hidden_left_recursion() {
+    let grammar_text = r#"
+grammar;
+pub X: () = {
+    Y X "a" => (),
+    "b" => (),
+};
+Y: () = {
+    => (),
+    "c" => (),
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("X")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+
+    let messages = cx.report_errors();
+    let conflicts = super::token_conflicts(&err.conflicts);
+    assert!(messages.len() > conflicts.len());
+    let lines = render(messages.into_iter().next().unwrap());
+    let body = lines.join(" ");
+    let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        body.contains(
+            r#"The nonterminal `X` is left-recursive through the nullable `Y` in the rule `X = Y X "a"`."#
+        ),
+        "unexpected message: {:#?}",
+        lines
+    );
+
+    let json = super::report_error_json(&grammar, &err);
+    assert!(
+        json.starts_with(
+            r#"[{"file":"tmp.txt","span":{"start":28,"end":41},"classification":"HiddenLeftRecursion","nonterminal":"X","nullable":"Y"},{"#
+        ),
+        "{}",
+        json
+    );
+
+    let compact = super::report_error_compact(&grammar, &err);
+    assert!(compact.len() > 1);
+    assert!(
+        compact[0].ends_with("error: `X` is left-recursive through the nullable `Y`"),
+        "{}",
+        compact[0]
+    );
+}
+
+#[test]
+fn summary_counts_conflicts() {
+    let grammar_text = r#"
+grammar;
+pub S: () = {
+    Ty => (),
+    "if" E "{" "}" => (),
+};
+Ty: () = {
+    "int" => (),
+    Ty "->" Ty => (),
+};
+E: () = {
+    "Id" => (),
+    "Id" "{" "}" => (),
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("S")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let messages = cx.report_errors();
+    let conflicts = super::token_conflicts(&err.conflicts);
+    assert_eq!(messages.len(), conflicts.len() + 1);
+    let lines = render(messages.into_iter().next().unwrap());
+    assert!(
+        lines[0].ends_with(": 3 conflicts: 1 precedence, 2 suggest-inline"),
+        "unexpected message: {:#?}",
+        lines
+    );
+}
+
+#[test]
+fn shift_conflict_on_eof_is_naive() {
+    let grammar_text = r#"
+grammar;
+pub Ty: () = {
+    "int" => (),
+    Ty "->" Ty => (),
+};
+"#;
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+
+    // A shift conflict can't really have EOF as its lookahead, but if
+    // one did, it should still be reported rather than crash.
+    let mut conflict = conflicts
+        .iter()
+        .find(|c| match c.action {
+            Action::Shift(..) => true,
+            Action::Reduce(_) => false,
+        })
+        .unwrap()
+        .clone();
+    conflict.lookahead = Token::EOF;
+    let classification = cx.classify(&conflict);
+    match classification {
+        ConflictClassification::Naive => {}
+        ref r => panic!("wrong classification {:#?}", r),
+    }
+    let lines = render(cx.report_error(&conflict, classification));
+    assert!(
+        lines[0].ends_with("Conflict detected"),
+        "unexpected message: {:#?}",
+        lines
+    );
+}
+
+fn classify_first(grammar_text: &str, start: &str) -> ConflictClassification {
+    let _tls = Tls::test_string(grammar_text);
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let err = build_states(&grammar, nt(start)).unwrap_err();
+    let mut cx = ErrorReportingCx::new(&grammar, &err.states, &err.conflicts);
+    let conflicts = super::token_conflicts(&err.conflicts);
+    cx.classify(&conflicts[0])
+}
+
+#[test]
+fn ternary_precedence_conflict() {
+    let classification = classify_first(
+        r#"
+grammar;
+pub E: () = {
+    E "?" E ":" E => (),
+    "x" => (),
+};
+"#,
+        "E",
+    );
+    match classification {
+        ConflictClassification::Precedence { nonterminal, .. } => {
+            assert_eq!(nonterminal, nt("E"));
+        }
+        r => panic!("wrong classification {:#?}", r),
+    }
+}
+
+#[test]
+fn comparison_chain_precedence_conflict() {
+    let classification = classify_first(
+        r#"
+grammar;
+pub E: () = {
+    E "<" E "<" E => (),
+    "x" => (),
+};
+"#,
+        "E",
+    );
+    match classification {
+        ConflictClassification::Precedence { nonterminal, .. } => {
+            assert_eq!(nonterminal, nt("E"));
+        }
+        r => panic!("wrong classification {:#?}", r),
+    }
+}
@@ -1,7 +1,10 @@
 //! Code to compute example inputs given a backtrace.
 
 use ascii_canvas::AsciiView;
+use crate::collections::{map, Map};
+use crate::grammar::parse_tree::{MatchMapping, TerminalLiteral};
 use crate::grammar::repr::*;
+use crate::lexer::re;
 use crate::message::builder::InlineBuilder;
 use crate::message::Content;
 use std::fmt::{Debug, Error, Formatter};
@@ -60,6 +63,8 @@ pub struct ExampleStyles {
     pub before_cursor: Style,
     pub on_cursor: Style,
     pub after_cursor: Style,
+    pub terminal: Style,
+    pub nonterminal: Style,
 }
 
 #[derive(Clone, Debug)]
@@ -70,6 +75,61 @@ pub struct Reduction {
 }
 
 impl Example {
+    /// Replaces each terminal that the grammar's `match` block renames
+    /// with the literal or regular expression it matches, so that the
+    /// example reads like the input the user would write. The picture
+    /// is laid out from the new symbols, so the alignment of the
+    /// reductions is unaffected.
+    pub fn with_literal_terminals(mut self, grammar: &Grammar) -> Example {
+        let entries = match grammar.intern_token {
+            Some(ref intern_token) => &intern_token.match_entries,
+            None => return self,
+        };
+        for symbol in &mut self.symbols {
+            if let ExampleSymbol::Symbol(Symbol::Terminal(ref mut term)) = *symbol {
+                let literal = entries.iter().find_map(|entry| match entry.user_name {
+                    MatchMapping::Terminal(ref user_name) if user_name == term => {
+                        Some(entry.match_literal.clone())
+                    }
+                    _ => None,
+                });
+                if let Some(literal) = literal {
+                    *term = TerminalString::Literal(literal);
+                }
+            }
+        }
+        self
+    }
+
+    /// Renders the example as text the user could feed to the parser:
+    /// terminals are replaced by what they match and nonterminals by
+    /// their shortest derivation. Returns `None` if some symbol has no
+    /// spelling, such as the `error` terminal.
+    pub fn to_input_text(&self, grammar: &Grammar) -> Option<String> {
+        let derivations = shortest_derivations(grammar);
+        let mut terminals = vec![];
+        for symbol in &self.symbols {
+            match *symbol {
+                ExampleSymbol::Symbol(Symbol::Terminal(ref term)) => terminals.push(term),
+                ExampleSymbol::Symbol(Symbol::Nonterminal(ref nt)) => {
+                    terminals.extend(derivations.get(nt)?);
+                }
+                ExampleSymbol::Epsilon => {}
+            }
+        }
+        let words = terminals
+            .into_iter()
+            .map(|term| terminal_input(grammar, term))
+            .collect::<Option<Vec<_>>>()?;
+        Some(
+            words
+                .into_iter()
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
     /// Length of each symbol. Each will need *at least* that amount
     /// of space. :) Measure in characters, under the assumption of a
     /// mono-spaced font. Also add a final `0` marker which will serve
@@ -270,7 +330,7 @@ impl Example {
     #[cfg(test)]
     pub fn paint_unstyled(&self) -> Vec<::ascii_canvas::Row> {
         let this = self.clone();
-        let content = this.into_picture(ExampleStyles::default());
+        let content = this.into_picture(ExampleStyles::plain());
         let min_width = content.min_width();
         let canvas = content.emit_to_canvas(min_width);
         canvas.to_strings()
@@ -290,7 +350,6 @@ impl Example {
         // Write the labels for each reduction. Do this after the
         // brackets so that ascii canvas can convert `|` to `+`
         // without interfering with the text (in case of weird overlap).
-        let session = Tls::session();
         for (index, reduction) in self.reductions.iter().enumerate() {
             let column = positions[reduction.start] + 2;
             let row = 1 + index;
@@ -298,7 +357,7 @@ impl Example {
                 row,
                 column,
                 reduction.nonterminal.to_string().chars(),
-                session.nonterminal_symbol,
+                styles.nonterminal,
             );
         }
 
@@ -314,7 +373,6 @@ impl Example {
         styles: &ExampleStyles,
         view: &mut dyn AsciiView,
     ) {
-        let session = Tls::session();
         for (index, ex_symbol) in symbols.iter().enumerate() {
             let style = if index < self.cursor {
                 styles.before_cursor
@@ -338,7 +396,7 @@ impl Example {
                         0,
                         column,
                         term.to_string().chars(),
-                        style.with(session.terminal_symbol),
+                        style.with(styles.terminal),
                     );
                 }
                 ExampleSymbol::Symbol(Symbol::Nonterminal(ref nt)) => {
@@ -346,7 +404,7 @@ impl Example {
                         0,
                         column,
                         nt.to_string().chars(),
-                        style.with(session.nonterminal_symbol),
+                        style.with(styles.nonterminal),
                     );
                 }
                 ExampleSymbol::Epsilon => {}
@@ -390,19 +448,102 @@ fn shift(positions: &mut [usize], amount: usize) {
 impl ExampleStyles {
     pub fn ambig() -> Self {
         let session = Tls::session();
+        if !session.use_colors() {
+            return ExampleStyles::plain();
+        }
         ExampleStyles {
             before_cursor: session.ambig_symbols,
             on_cursor: session.ambig_symbols,
             after_cursor: session.ambig_symbols,
+            terminal: session.terminal_symbol,
+            nonterminal: session.nonterminal_symbol,
         }
     }
 
     pub fn new() -> Self {
         let session = Tls::session();
+        if !session.use_colors() {
+            return ExampleStyles::plain();
+        }
         ExampleStyles {
             before_cursor: session.observed_symbols,
             on_cursor: session.cursor_symbol,
             after_cursor: session.unobserved_symbols,
+            terminal: session.terminal_symbol,
+            nonterminal: session.nonterminal_symbol,
+        }
+    }
+
+    /// No styling at all, for output that is not going to a terminal.
+    /// Pictures are laid out the same way with any styles.
+    pub fn plain() -> Self {
+        ExampleStyles::default()
+    }
+}
+
+/// For each nonterminal that derives some input, the shortest list of
+/// terminals it derives.
+fn shortest_derivations(grammar: &Grammar) -> Map<NonterminalString, Vec<TerminalString>> {
+    let mut derivations: Map<NonterminalString, Vec<TerminalString>> = map();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (nt, data) in &grammar.nonterminals {
+            for production in &data.productions {
+                let mut terminals = vec![];
+                let complete = production.symbols.iter().all(|symbol| match *symbol {
+                    Symbol::Terminal(ref term) => {
+                        terminals.push(term.clone());
+                        true
+                    }
+                    Symbol::Nonterminal(ref other) => match derivations.get(other) {
+                        Some(derivation) => {
+                            terminals.extend(derivation.iter().cloned());
+                            true
+                        }
+                        None => false,
+                    },
+                });
+                let shorter = derivations
+                    .get(nt)
+                    .map_or(true, |derivation| terminals.len() < derivation.len());
+                if complete && shorter {
+                    derivations.insert(nt.clone(), terminals);
+                    changed = true;
+                }
+            }
+        }
+    }
+    derivations
+}
+
+/// The text matched by `term`: the literal, or a shortest match of the
+/// regular expression, it stands for.
+fn terminal_input(grammar: &Grammar, term: &TerminalString) -> Option<String> {
+    let literal = grammar.intern_token.as_ref().and_then(|intern_token| {
+        intern_token
+            .match_entries
+            .iter()
+            .find(|entry| match entry.user_name {
+                MatchMapping::Terminal(ref user_name) => user_name == term,
+                _ => false,
+            })
+            .map(|entry| &entry.match_literal)
+    });
+    let literal = literal.or(match *term {
+        TerminalString::Literal(ref literal) => Some(literal),
+        _ => None,
+    });
+    match literal {
+        Some(&TerminalLiteral::Quoted(ref s)) | Some(&TerminalLiteral::CaseInsensitive(ref s)) => {
+            Some(s.to_string())
         }
+        Some(&TerminalLiteral::Regex(ref s)) => re::parse_regex(s)
+            .ok()
+            .and_then(|regex| re::shortest_match(&regex)),
+        None => match *term {
+            TerminalString::Bare(ref id) => Some(id.to_string()),
+            _ => None,
+        },
     }
 }
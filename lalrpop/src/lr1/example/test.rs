@@ -1,9 +1,15 @@
+use crate::file_text::FileText;
 use crate::grammar::repr::*;
+use crate::session::{ColorConfig, Session};
+use std::collections::HashMap;
+use std::rc::Rc;
 use string_cache::DefaultAtom as Atom;
+use term::terminfo::{TermInfo, TerminfoTerminal};
+use term::Terminal;
 use crate::test_util::expect_debug;
 use crate::tls::Tls;
 
-use super::{Example, ExampleSymbol, Reduction};
+use super::{Example, ExampleStyles, ExampleSymbol, Reduction};
 
 fn nt(t: &str) -> NonterminalString {
     NonterminalString(Atom::from(t))
@@ -200,3 +206,102 @@ fn single_token_strings() {
         .trim(),
     );
 }
+
+// E "PLUS" NUM, where the match block renames `"+"` to `"PLUS"` and
+// `r"[0-9]+"` to `NUM`, is shown with the literals the user wrote:
+//
+// E "+" r#"[0-9]+"#
+// |               |
+// +-E-------------+
+#[test]
+fn literal_terminals_strings() {
+    let _tls = Tls::test();
+    let grammar = crate::test_util::normalized_grammar(
+        r#"
+grammar;
+match { r"[0-9]+" => NUM, "+" => "PLUS" }
+pub E: () = { E "PLUS" NUM => (), NUM => () };
+"#,
+    );
+    let example = Example {
+        symbols: vec![
+            sym!(E),
+            ExampleSymbol::Symbol(Symbol::Terminal(term("PLUS"))),
+            ExampleSymbol::Symbol(Symbol::Terminal(TerminalString::Bare(Atom::from("NUM")))),
+        ],
+        cursor: 3,
+        reductions: vec![Reduction {
+            start: 0,
+            end: 3,
+            nonterminal: nt("E"),
+        }],
+    };
+    let strings = example.with_literal_terminals(&grammar).paint_unstyled();
+    expect_debug(
+        strings,
+        r##"
+[
+    "  E "+" r#"[0-9]+"#"
+    "  └─E─────────────┘"
+]
+"##
+        .trim(),
+    );
+}
+
+/// Writes `example` through a terminal that spells out every style
+/// change, such as `<fg10>` for bright green text, so that any styling shows
+/// up in the bytes.
+fn paint_to_terminal(example: Example, styles: ExampleStyles) -> String {
+    let mut terminfo = TermInfo {
+        names: vec![String::from("test")],
+        bools: HashMap::new(),
+        numbers: HashMap::new(),
+        strings: HashMap::new(),
+    };
+    terminfo.numbers.insert("colors", 16);
+    terminfo.strings.insert("sgr0", vec![]);
+    terminfo.strings.insert("setaf", b"<fg%p1%d>".to_vec());
+    terminfo.strings.insert("setab", b"<bg%p1%d>".to_vec());
+    terminfo.strings.insert("bold", b"<bold>".to_vec());
+    let mut terminal = TerminfoTerminal::new_with_terminfo(vec![], terminfo);
+
+    let content = example.into_picture(styles);
+    let canvas = content.emit_to_canvas(content.min_width());
+    canvas.write_to(&mut terminal).unwrap();
+    String::from_utf8(terminal.into_inner()).unwrap()
+}
+
+#[test]
+fn plain_styles_emit_no_escapes() {
+    let session = Session {
+        color_config: ColorConfig::No,
+        ..Session::new()
+    };
+    let _tls = Tls::install(Rc::new(session), Rc::new(FileText::test()));
+
+    assert_eq!(
+        paint_to_terminal(long_label_1_example(), ExampleStyles::new()),
+        "  A1   B2  C3  D4 E5 F6\n\
+         \x20 ├─LongLabel22─┘     │\n\
+         \x20 └─Label─────────────┘\n"
+    );
+}
+
+#[test]
+fn colored_styles_emit_escapes() {
+    let session = Session {
+        color_config: ColorConfig::Yes,
+        ..Session::new()
+    };
+    let _tls = Tls::install(Rc::new(session), Rc::new(FileText::test()));
+
+    let painted = paint_to_terminal(long_label_1_example(), ExampleStyles::new());
+    assert!(painted.contains("<fg10>A1"), "{}", painted);
+    assert_eq!(
+        paint_to_terminal(long_label_1_example(), ExampleStyles::plain()),
+        "  A1   B2  C3  D4 E5 F6\n\
+         \x20 ├─LongLabel22─┘     │\n\
+         \x20 └─Label─────────────┘\n"
+    );
+}
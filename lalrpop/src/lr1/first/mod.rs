@@ -95,3 +95,52 @@ impl FirstSets {
         set
     }
 }
+
+/// `FOLLOW(X)` for each nonterminal `X`: the terminals that can come
+/// right after `X`, with EOF if `X` can end the input.
+#[derive(Clone)]
+pub struct FollowSets {
+    map: Map<NonterminalString, TokenSet>,
+}
+
+impl FollowSets {
+    pub fn new(grammar: &Grammar, first_sets: &FirstSets) -> FollowSets {
+        let mut this = FollowSets { map: map() };
+        for start in grammar.start_nonterminals.values() {
+            this.map.insert(start.clone(), TokenSet::eof());
+        }
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for production in grammar.nonterminals.values().flat_map(|p| &p.productions) {
+                for (index, symbol) in production.symbols.iter().enumerate() {
+                    let nt = match *symbol {
+                        Symbol::Nonterminal(ref nt) => nt,
+                        Symbol::Terminal(_) => continue,
+                    };
+                    // `A = ...p B ...s`: whatever can start `...s`
+                    // follows `B`, and if `...s` can be empty, so does
+                    // whatever follows `A`.
+                    let mut follow = first_sets.first0(&production.symbols[index + 1..]);
+                    if follow.take_eof() {
+                        if let Some(set) = this.map.get(&production.nonterminal) {
+                            follow.union_with(set);
+                        }
+                    }
+                    let follow_set = this.map.entry(nt.clone()).or_insert_with(TokenSet::new);
+                    changed |= follow_set.union_with(&follow);
+                }
+            }
+        }
+        this
+    }
+
+    /// Returns `FOLLOW(nonterminal)`, which is empty if `nonterminal`
+    /// is not used anywhere.
+    pub fn follow(&self, nonterminal: &NonterminalString) -> TokenSet {
+        self.map
+            .get(nonterminal)
+            .cloned()
+            .unwrap_or_else(TokenSet::new)
+    }
+}
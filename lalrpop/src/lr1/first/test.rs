@@ -1,4 +1,4 @@
-use super::FirstSets;
+use super::{FirstSets, FollowSets};
 use crate::grammar::repr::*;
 use crate::lr1::lookahead::Token::EOF;
 use crate::lr1::lookahead::{Token, TokenSet};
@@ -91,3 +91,38 @@ fn basic_first0() {
 
     assert_eq!(first0(&first_sets, &[nt("X")]), vec![la("E")]);
 }
+
+fn follow(follow_sets: &FollowSets, nonterminal: &str) -> Vec<Token> {
+    let v = follow_sets.follow(&NonterminalString(Atom::from(nonterminal)));
+    v.iter().collect()
+}
+
+#[test]
+fn textbook_follow() {
+    let grammar = normalized_grammar(
+        r#"
+    grammar;
+    pub E = T Ep;
+    Ep = { "+" T Ep, () };
+    T = F Tp;
+    Tp = { "*" F Tp, () };
+    F = { "(" E ")", "id" };
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let first_sets = FirstSets::new(&grammar);
+    let follow_sets = FollowSets::new(&grammar, &first_sets);
+
+    assert_eq!(first0(&first_sets, &[nt("E")]), vec![la("("), la("id")]);
+    assert_eq!(first0(&first_sets, &[nt("Ep")]), vec![la("+"), EOF]);
+    assert_eq!(first0(&first_sets, &[nt("Tp")]), vec![la("*"), EOF]);
+
+    assert_eq!(follow(&follow_sets, "E"), vec![la(")"), EOF]);
+    assert_eq!(follow(&follow_sets, "Ep"), vec![la(")"), EOF]);
+    assert_eq!(follow(&follow_sets, "T"), vec![la(")"), la("+"), EOF]);
+    assert_eq!(follow(&follow_sets, "Tp"), vec![la(")"), la("+"), EOF]);
+    assert_eq!(
+        follow(&follow_sets, "F"),
+        vec![la(")"), la("*"), la("+"), EOF]
+    );
+}
@@ -0,0 +1,110 @@
+//! Detection of left recursion hidden behind nullable symbols, as in
+//!
+//!     X = Y X "a" | "b";
+//!     Y = "c"?;
+//!
+//! Once `Y` has matched nothing, `X` starts with `X` again. Direct left
+//! recursion is fine for an LR parser, but this kind is not: before
+//! each `X` the parser would have to decide whether to reduce an empty
+//! `Y`, and so how deep the recursion goes, without having seen any of
+//! the input that determines it. Such grammars always fail with
+//! conflicts, which are much harder to understand than the cycle.
+
+use crate::collections::{map, set, Map, Set};
+use crate::grammar::repr::*;
+use crate::lr1::first::FirstSets;
+
+#[cfg(test)]
+mod test;
+
+/// A production `X = ...n Y ...` where `...n` is non-empty but can
+/// match nothing, and `Y` can start with `X`.
+#[derive(Debug)]
+pub struct HiddenLeftRecursion<'grammar> {
+    /// The nonterminal `X` that is left-recursive.
+    pub nonterminal: NonterminalString,
+    /// The first symbol of `...n`.
+    pub nullable: NonterminalString,
+    pub production: &'grammar Production,
+}
+
+/// Finds the nonterminals of `grammar` that are left-recursive through
+/// a nullable symbol, with one production showing how for each.
+pub fn hidden_left_recursion<'grammar>(
+    grammar: &'grammar Grammar,
+    first_sets: &FirstSets,
+) -> Vec<HiddenLeftRecursion<'grammar>> {
+    let nullable = |symbol: &Symbol| match *symbol {
+        Symbol::Terminal(_) => false,
+        Symbol::Nonterminal(_) => first_sets.first0(Some(symbol)).contains_eof(),
+    };
+
+    // `left_corners[Y]` holds each `Z` such that `Y = ...n Z ...` for
+    // some (possibly empty) nullable `...n`.
+    let mut left_corners: Map<&NonterminalString, Set<&NonterminalString>> = map();
+    for production in grammar.nonterminals.values().flat_map(|nt| &nt.productions) {
+        let corners = left_corners
+            .entry(&production.nonterminal)
+            .or_insert_with(set);
+        for symbol in &production.symbols {
+            if let Symbol::Nonterminal(ref nt) = *symbol {
+                corners.insert(nt);
+            }
+            if !nullable(symbol) {
+                break;
+            }
+        }
+    }
+
+    let mut result = vec![];
+    for data in grammar.nonterminals.values() {
+        let target = &data.name;
+        let found = data.productions.iter().find(|production| {
+            // the symbols at index 1 up to the first one that is not
+            // nullable come after a non-empty nullable prefix
+            let symbols = &production.symbols;
+            let prefix_len = symbols.iter().take_while(|s| nullable(s)).count();
+            symbols
+                .iter()
+                .take(prefix_len + 1)
+                .skip(1)
+                .any(|symbol| match *symbol {
+                    Symbol::Nonterminal(ref nt) => starts_with(&left_corners, nt, target),
+                    Symbol::Terminal(_) => false,
+                })
+        });
+        if let Some(production) = found {
+            let nullable = match production.symbols[0] {
+                Symbol::Nonterminal(ref nt) => nt.clone(),
+                Symbol::Terminal(_) => unreachable!("terminals are never nullable"),
+            };
+            result.push(HiddenLeftRecursion {
+                nonterminal: target.clone(),
+                nullable,
+                production,
+            });
+        }
+    }
+    result
+}
+
+/// Whether `from` can derive something starting with `target`.
+fn starts_with(
+    left_corners: &Map<&NonterminalString, Set<&NonterminalString>>,
+    from: &NonterminalString,
+    target: &NonterminalString,
+) -> bool {
+    let mut visited = set();
+    let mut stack = vec![from];
+    while let Some(nt) = stack.pop() {
+        if nt == target {
+            return true;
+        }
+        if visited.insert(nt) {
+            if let Some(corners) = left_corners.get(nt) {
+                stack.extend(corners.iter().cloned());
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,88 @@
+use crate::lr1::first::FirstSets;
+use crate::lr1::tls::Lr1Tls;
+use crate::test_util::normalized_grammar;
+use crate::tls::Tls;
+
+use super::hidden_left_recursion;
+
+fn find(grammar_text: &str) -> Vec<(String, String, String)> {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(grammar_text);
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let first_sets = FirstSets::new(&grammar);
+    hidden_left_recursion(&grammar, &first_sets)
+        .into_iter()
+        .map(|r| {
+            (
+                r.nonterminal.to_string(),
+                r.nullable.to_string(),
+                format!("{:?}", r.production.symbols),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn direct() {
+    assert_eq!(
+        find(
+            r#"
+grammar;
+pub X: () = {
+    Y X "a" => (),
+    "b" => (),
+};
+Y: () = {
+    => (),
+    "c" => (),
+};
+"#
+        ),
+        vec![(
+            "X".to_string(),
+            "Y".to_string(),
+            r#"[Y, X, "a"]"#.to_string()
+        )]
+    );
+}
+
+#[test]
+fn through_another_nonterminal() {
+    assert_eq!(
+        find(
+            r#"
+grammar;
+pub X: () = {
+    Z "a" => (),
+    "b" => (),
+};
+Z: () = Y Y X => ();
+Y: () = {
+    => (),
+    "c" => (),
+};
+"#
+        ),
+        // `X` is only reported through the production that hides the
+        // recursion, which belongs to `Z`
+        vec![("Z".to_string(), "Y".to_string(), "[Y, Y, X]".to_string())]
+    );
+}
+
+#[test]
+fn plain_left_recursion_is_fine() {
+    assert!(find(
+        r#"
+grammar;
+pub X: () = {
+    X Y "a" => (),
+    Y "b" => (),
+};
+Y: () = {
+    => (),
+    "c" => (),
+};
+"#
+    )
+    .is_empty());
+}
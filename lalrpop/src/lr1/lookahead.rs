@@ -129,12 +129,11 @@ impl Lookahead for TokenSet {
 }
 
 impl Token {
-    pub fn unwrap_terminal(&self) -> &TerminalString {
+    /// The terminal, unless this is EOF or the error token.
+    pub fn as_terminal(&self) -> Option<&TerminalString> {
         match *self {
-            Token::Terminal(ref t) => t,
-            Token::EOF | Token::Error => {
-                panic!("`unwrap_terminal()` invoked but with EOF or Error")
-            }
+            Token::Terminal(ref t) => Some(t),
+            Token::EOF | Token::Error => None,
         }
     }
 }
@@ -214,6 +213,13 @@ impl TokenSet {
         TokenSet { bit_set }
     }
 
+    /// The tokens of this set that are not in `set`.
+    pub fn difference(&self, set: &TokenSet) -> TokenSet {
+        let mut bit_set = self.bit_set.clone();
+        bit_set.difference_with(&set.bit_set);
+        TokenSet { bit_set }
+    }
+
     pub fn contains(&self, token: &Token) -> bool {
         self.bit_set.contains(self.bit(token))
     }
@@ -239,6 +245,23 @@ impl TokenSet {
         !self.is_disjoint(other)
     }
 
+    /// Lists the tokens of this set for the user, with the terminals
+    /// in the order in which `grammar` declares them, followed by
+    /// `EOF` and `Error`.
+    pub fn display(&self, grammar: &Grammar) -> String {
+        let terminals = grammar.terminals.all.iter().cloned().map(Token::Terminal);
+        let tokens: Vec<String> = terminals
+            .chain(vec![Token::EOF, Token::Error])
+            .filter(|token| self.contains(token))
+            .map(|token| token.to_string())
+            .collect();
+        if tokens.is_empty() {
+            String::from("no tokens")
+        } else {
+            tokens.join(", ")
+        }
+    }
+
     pub fn iter(&self) -> TokenSetIter<'_> {
         TokenSetIter {
             bit_set: self.bit_set.iter(),
@@ -299,3 +322,33 @@ impl From<Token> for TokenSet {
         set
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::normalized_grammar;
+
+    #[test]
+    fn display_and_difference() {
+        let grammar = normalized_grammar(
+            r#"
+grammar;
+extern { enum Tok { "a" => .., "b" => .., "c" => .. } }
+pub S = { "c" "b" "a" };
+"#,
+        );
+        let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+        let token = |t: &str| Token::Terminal(TerminalString::quoted(t.into()));
+
+        let mut left = TokenSet::new();
+        left.insert(token("c"));
+        left.insert(token("a"));
+        left.insert_eof();
+        let mut right = TokenSet::new();
+        right.insert(token("a"));
+
+        assert_eq!(left.display(&grammar), r#""a", "c", EOF"#);
+        assert_eq!(left.difference(&right).display(&grammar), r#""c", EOF"#);
+        assert_eq!(right.difference(&left).display(&grammar), "no tokens");
+    }
+}
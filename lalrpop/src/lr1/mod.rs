@@ -10,6 +10,7 @@ mod error;
 mod example;
 mod first;
 mod lane_table;
+mod left_recursion;
 mod lookahead;
 mod report;
 mod state_graph;
@@ -20,8 +21,9 @@ use std::io::{self, Write};
 #[cfg(test)]
 mod interpret;
 
-pub use self::core::{LR1Result, LR1TableConstructionError};
-pub use self::error::report_error;
+pub use self::core::{states_to_dot, LR1Result, LR1TableConstructionError};
+pub use self::error::{collect_conflicts, report_error, report_error_compact, report_error_json};
+pub use self::first::{FirstSets, FollowSets};
 pub use self::tls::Lr1Tls;
 
 pub fn build_states<'grammar>(
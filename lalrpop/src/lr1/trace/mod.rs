@@ -14,6 +14,28 @@ pub struct Tracer<'trace, 'grammar: 'trace> {
     state_graph: StateGraph,
     trace_graph: TraceGraph<'grammar>,
     visited_set: Set<(StateIndex, NonterminalString)>,
+    #[cfg(test)]
+    observer: Option<Box<dyn FnMut(&TraceStep<'grammar>) + 'trace>>,
+}
+
+/// One step taken while building a backtrace. Every step is logged at
+/// the `Debug` level. In tests, it is also passed to the observer
+/// installed with `Tracer::observe`, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TraceStep<'grammar> {
+    /// Walked back from `state` over `symbols`, arriving in `preds`.
+    TraceBack {
+        state: StateIndex,
+        symbols: &'grammar [Symbol],
+        preds: Vec<StateIndex>,
+    },
+
+    /// Added the edge `from -labels-> to` to the trace graph.
+    Edge {
+        from: TraceGraphNode<'grammar>,
+        to: TraceGraphNode<'grammar>,
+        labels: SymbolSets<'grammar>,
+    },
 }
 
 impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
@@ -24,8 +46,52 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
             state_graph: StateGraph::new(states),
             trace_graph: TraceGraph::new(),
             visited_set: set(),
+            #[cfg(test)]
+            observer: None,
+        }
+    }
+
+    /// Calls `observer` with each step of the backtrace. This is
+    /// purely diagnostic; the resulting trace graph is the same. Only
+    /// tests need it, since every step is logged anyway.
+    #[cfg(test)]
+    pub fn observe<F>(mut self, observer: F) -> Self
+    where
+        F: FnMut(&TraceStep<'grammar>) + 'trace,
+    {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    fn step(&mut self, step: TraceStep<'grammar>) {
+        debug!("Tracer: {:?}", step);
+        #[cfg(test)]
+        {
+            if let Some(ref mut observer) = self.observer {
+                observer(&step);
+            }
         }
     }
+
+    fn add_edge<F, T>(&mut self, from: F, to: T, labels: SymbolSets<'grammar>)
+    where
+        F: Into<TraceGraphNode<'grammar>>,
+        T: Into<TraceGraphNode<'grammar>>,
+    {
+        let (from, to) = (from.into(), to.into());
+        self.trace_graph.add_edge(from.clone(), to.clone(), labels);
+        self.step(TraceStep::Edge { from, to, labels });
+    }
+
+    fn trace_back(&mut self, state: StateIndex, symbols: &'grammar [Symbol]) -> Vec<StateIndex> {
+        let preds = self.state_graph.trace_back(state, symbols);
+        self.step(TraceStep::TraceBack {
+            state,
+            symbols,
+            preds: preds.clone(),
+        });
+        preds
+    }
 }
 
-pub use self::trace_graph::TraceGraph;
+pub use self::trace_graph::{TraceGraph, TraceGraphNode};
@@ -34,13 +34,12 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
         //
         // because to reach that item we pushed `...p` from the start
         // of `X` and afterwards we expect to see `...s`.
-        self.trace_graph
-            .add_edge(nonterminal.clone(), item, item.symbol_sets());
+        self.add_edge(nonterminal.clone(), item, item.symbol_sets());
 
         // Walk back to the set of states S where we had:
         //
         //     X = (*) ...p
-        let pred_states = self.state_graph.trace_back(item_state, item.prefix());
+        let pred_states = self.trace_back(item_state, item.prefix());
 
         // Add in edges from [X] to all the places [X] can be consumed.
         for pred_state in pred_states {
@@ -89,8 +88,7 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
                 //    [Z = ...p (*) Y ...s] -(...p,Y,...s)-> [Y]
                 //
                 // and stop.
-                self.trace_graph
-                    .add_edge(pred_item.to_lr0(), nonterminal.clone(), symbol_sets);
+                self.add_edge(pred_item.to_lr0(), nonterminal.clone(), symbol_sets);
             } else {
                 // Add an edge
                 //
@@ -98,7 +96,7 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
                 //
                 // because we can reduce by consuming `...p`
                 // tokens, and continue tracing.
-                self.trace_graph.add_edge(
+                self.add_edge(
                     pred_item.production.nonterminal.clone(),
                     nonterminal.clone(),
                     symbol_sets,
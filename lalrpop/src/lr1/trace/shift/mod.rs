@@ -45,11 +45,10 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
         let symbol_sets = item.symbol_sets();
 
         // The states `S`
-        let pred_states = self.state_graph.trace_back(item_state, symbol_sets.prefix);
+        let pred_states = self.trace_back(item_state, symbol_sets.prefix);
 
         // Add the edge `[X] -{...p,Token,...s}-> [X = ...p (*) Token ...s]`
-        self.trace_graph
-            .add_edge(item.production.nonterminal.clone(), item, symbol_sets);
+        self.add_edge(item.production.nonterminal.clone(), item, symbol_sets);
 
         for pred_state in pred_states {
             self.trace_epsilon_edges(pred_state, &item.production.nonterminal);
@@ -79,15 +78,11 @@ impl<'trace, 'grammar> Tracer<'trace, 'grammar> {
                         // Add an edge:
                         //
                         //     [Z = ...p (*) Y ...s] -(...p,Y,...s)-> [Y]
-                        self.trace_graph.add_edge(
-                            pred_item,
-                            nonterminal.clone(),
-                            pred_item.symbol_sets(),
-                        );
+                        self.add_edge(pred_item, nonterminal.clone(), pred_item.symbol_sets());
                     } else {
                         // Trace back any incoming edges to [Z = ...p (*) Y ...].
                         let pred_nonterminal = &pred_item.production.nonterminal;
-                        self.trace_graph.add_edge(
+                        self.add_edge(
                             pred_nonterminal.clone(),
                             nonterminal.clone(),
                             pred_item.symbol_sets(),
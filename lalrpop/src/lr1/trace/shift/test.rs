@@ -7,7 +7,7 @@ use string_cache::DefaultAtom as Atom;
 use crate::test_util::{expect_debug, normalized_grammar};
 use crate::tls::Tls;
 
-use super::super::Tracer;
+use super::super::{TraceStep, Tracer};
 
 fn nt(t: &str) -> NonterminalString {
     NonterminalString(Atom::from(t))
@@ -78,3 +78,50 @@ pub Ty: () = {
         .trim(),
     );
 }
+
+#[test]
+fn shift_backtrace_steps() {
+    let _tls = Tls::test();
+    let grammar = normalized_grammar(
+        r#"
+grammar;
+pub Ty: () = {
+    "int" => (),
+    <t1:Ty> "->" <t2:Ty> => (),
+};
+"#,
+    );
+    let _lr1_tls = Lr1Tls::install(grammar.terminals.clone());
+    let first_sets = FirstSets::new(&grammar);
+    let err = build_states(&grammar, nt("Ty")).unwrap_err();
+    let conflict = err.conflicts[0].clone();
+    let item = Item::lr0(conflict.production, 1);
+
+    let plain = Tracer::new(&first_sets, &err.states).backtrace_shift(conflict.state, item);
+
+    let mut steps = vec![];
+    let graph = Tracer::new(&first_sets, &err.states)
+        .observe(|step| steps.push(step.clone()))
+        .backtrace_shift(conflict.state, item);
+
+    // Observing the trace does not change it.
+    assert_eq!(format!("{:?}", graph), format!("{:?}", plain));
+
+    // One step walks back over the prefix `Ty` of the item, and there
+    // is one step per edge of the graph.
+    let (back, edges): (Vec<_>, Vec<_>) = steps.iter().partition(|step| match step {
+        TraceStep::TraceBack { .. } => true,
+        TraceStep::Edge { .. } => false,
+    });
+    assert_eq!(back.len(), 1);
+    match back[0] {
+        TraceStep::TraceBack { symbols, .. } => assert_eq!(*symbols, item.prefix()),
+        _ => unreachable!(),
+    }
+    assert_eq!(edges.len(), format!("{:?}", graph).matches(")-> ").count());
+
+    // The trace yields the single example `Ty "->" Ty "->" Ty`.
+    let examples: Vec<_> = graph.lr0_examples(item).collect();
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples[0].symbols.len(), 5);
+}
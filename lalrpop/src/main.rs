@@ -59,6 +59,74 @@ fn main1() -> io::Result<()> {
         config.emit_report(true);
     }
 
+    if args.flag_dot {
+        config.emit_dot(true);
+    }
+
+    if args.flag_json_conflicts {
+        config.emit_json_conflicts(true);
+    }
+
+    if args.flag_compact_conflicts {
+        config.compact_conflicts(true);
+    }
+
+    if args.flag_literal_examples {
+        config.literal_conflict_examples(true);
+    }
+
+    if args.flag_conflict_items {
+        config.conflict_items(true);
+    }
+
+    if args.flag_no_extern_crate {
+        config.emit_extern_crate(false);
+    }
+
+    if args.flag_no_must_use {
+        config.emit_must_use(false);
+    }
+
+    if args.flag_dump_helpers {
+        config.emit_dump_helpers(true);
+    }
+
+    if args.flag_boxed_errors {
+        config.emit_boxed_errors(true);
+    }
+
+    if args.flag_item_parsers {
+        config.emit_item_parsers(true);
+    }
+
+    if args.flag_prefix_parsers {
+        config.emit_prefix_parsers(true);
+    }
+
+    if args.flag_tokenizers {
+        config.emit_tokenizers(true);
+    }
+
+    if args.flag_state_trace {
+        config.emit_state_trace(true);
+    }
+
+    if args.flag_byte_input {
+        config.byte_input(true);
+    }
+
+    if args.flag_user_error_spans {
+        config.user_error_spans(true);
+    }
+
+    if args.flag_unicode_classes {
+        config.unicode_classes(true);
+    }
+
+    if args.flag_warn_unused {
+        config.warn_unused(true);
+    }
+
     if args.arg_inputs.is_empty() {
         writeln!(
             stderr,
@@ -75,6 +143,10 @@ fn main1() -> io::Result<()> {
         config.set_features(flag_features.split(',').map(String::from));
     }
 
+    if let Some(ref prefix) = args.flag_prefix {
+        config.set_prefix(prefix.as_str());
+    }
+
     for arg in args.arg_inputs {
         match config.process_file(&arg) {
             Ok(()) => {}
@@ -99,11 +171,29 @@ Options:
                          Valid values: quiet, info, verbose, debug.
     -o, --out-dir DIR    Sets the directory in which to output the .rs file(s).
     --features FEATURES  Comma separated list of features for conditional compilation.
+    --prefix PREFIX      Prefix for the names of generated items. (Default: __)
     -f, --force          Force execution, even if the .lalrpop file is older than the .rs file.
     -c, --color          Force colorful output, even if this is not a TTY.
     --no-whitespace      Removes redundant whitespace from the generated file. (Default: false)
     --comments           Enable comments in the generated code.
     --report             Generate report files.
+    --dot                Generate GraphViz files of the LR states.
+    --json-conflicts     Report conflicts as JSON.
+    --compact-conflicts  Report each conflict on a single line.
+    --literal-examples   Spell renamed terminals as the text they match in conflict examples.
+    --conflict-items     Show the LR item at the cursor of each conflict example.
+    --no-extern-crate    Refer to `::lalrpop_util` instead of declaring `extern crate`.
+    --no-must-use        Do not mark the generated `parse` methods with `#[must_use]`.
+    --dump-helpers       Generate a `dump` method next to each `parse` method.
+    --boxed-errors       Generate a `parse_boxed` method returning `Box<dyn Error>`.
+    --item-parsers       Generate a `parse_items` method that parses one item at a time.
+    --prefix-parsers     Generate a `parse_prefix` method that may leave input unparsed.
+    --tokenizers         Generate a `tokenize` method that runs only the lexer.
+    --state-trace        Call `trace_state` on entering each parser state.
+    --byte-input         Generate tokenizers that read `&[u8]` instead of `&str`.
+    --user-error-spans   Attach locations to user errors from fallible actions.
+    --unicode-classes    Leave Unicode classes in regex terminals unexpanded.
+    --warn-unused        Warn about unused terminals and unreachable nonterminals.
 ";
 
 #[derive(Debug, Deserialize)]
@@ -111,12 +201,30 @@ struct Args {
     arg_inputs: Vec<String>,
     flag_out_dir: Option<PathBuf>,
     flag_features: Option<String>,
+    flag_prefix: Option<String>,
     flag_level: Option<LevelFlag>,
     flag_force: bool,
     flag_color: bool,
     flag_comments: bool,
     flag_no_whitespace: bool,
     flag_report: bool,
+    flag_dot: bool,
+    flag_json_conflicts: bool,
+    flag_compact_conflicts: bool,
+    flag_literal_examples: bool,
+    flag_conflict_items: bool,
+    flag_no_extern_crate: bool,
+    flag_no_must_use: bool,
+    flag_dump_helpers: bool,
+    flag_boxed_errors: bool,
+    flag_item_parsers: bool,
+    flag_prefix_parsers: bool,
+    flag_tokenizers: bool,
+    flag_state_trace: bool,
+    flag_byte_input: bool,
+    flag_user_error_spans: bool,
+    flag_unicode_classes: bool,
+    flag_warn_unused: bool,
     flag_version: bool,
 }
 
@@ -184,6 +292,15 @@ mod test {
         assert_eq!(args.flag_features, Some("test,abc".to_string()));
     }
 
+    #[test]
+    fn prefix() {
+        let argv = || vec!["lalrpop", "--prefix", "gen_", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert_eq!(args.flag_prefix, Some("gen_".to_string()));
+    }
+
     #[test]
     fn emit_whitespace() {
         let argv = || vec!["lalrpop", "--no-whitespace", "file.lalrpop"];
@@ -192,4 +309,158 @@ mod test {
             .unwrap();
         assert!(args.flag_no_whitespace, true);
     }
+
+    #[test]
+    fn no_must_use() {
+        let argv = || vec!["lalrpop", "--no-must-use", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_no_must_use);
+    }
+
+    #[test]
+    fn dot() {
+        let argv = || vec!["lalrpop", "--dot", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_dot);
+    }
+
+    #[test]
+    fn json_conflicts() {
+        let argv = || vec!["lalrpop", "--json-conflicts", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_json_conflicts);
+    }
+
+    #[test]
+    fn compact_conflicts() {
+        let argv = || vec!["lalrpop", "--compact-conflicts", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_compact_conflicts);
+    }
+
+    #[test]
+    fn literal_examples() {
+        let argv = || vec!["lalrpop", "--literal-examples", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_literal_examples);
+    }
+
+    #[test]
+    fn no_extern_crate() {
+        let argv = || vec!["lalrpop", "--no-extern-crate", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_no_extern_crate);
+    }
+
+    #[test]
+    fn dump_helpers() {
+        let argv = || vec!["lalrpop", "--dump-helpers", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_dump_helpers);
+    }
+
+    #[test]
+    fn boxed_errors() {
+        let argv = || vec!["lalrpop", "--boxed-errors", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_boxed_errors);
+    }
+
+    #[test]
+    fn item_parsers() {
+        let argv = || vec!["lalrpop", "--item-parsers", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_item_parsers);
+    }
+
+    #[test]
+    fn prefix_parsers() {
+        let argv = || vec!["lalrpop", "--prefix-parsers", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_prefix_parsers);
+        assert_eq!(args.flag_prefix, None);
+    }
+
+    #[test]
+    fn tokenizers() {
+        let argv = || vec!["lalrpop", "--tokenizers", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_tokenizers);
+    }
+
+    #[test]
+    fn state_trace() {
+        let argv = || vec!["lalrpop", "--state-trace", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_state_trace);
+    }
+
+    #[test]
+    fn byte_input() {
+        let argv = || vec!["lalrpop", "--byte-input", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_byte_input);
+    }
+
+    #[test]
+    fn user_error_spans() {
+        let argv = || vec!["lalrpop", "--user-error-spans", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_user_error_spans);
+    }
+
+    #[test]
+    fn unicode_classes() {
+        let argv = || vec!["lalrpop", "--unicode-classes", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_unicode_classes);
+    }
+
+    #[test]
+    fn warn_unused() {
+        let argv = || vec!["lalrpop", "--warn-unused", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_warn_unused);
+    }
+
+    #[test]
+    fn conflict_items() {
+        let argv = || vec!["lalrpop", "--conflict-items", "file.lalrpop"];
+        let args: Args = Docopt::new(USAGE)
+            .and_then(|d| d.argv(argv().into_iter()).deserialize())
+            .unwrap();
+        assert!(args.flag_conflict_items);
+    }
 }
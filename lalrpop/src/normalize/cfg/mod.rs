@@ -0,0 +1,112 @@
+//! Removes the parts of a grammar disabled by `#[cfg(feature = "...")]`
+//! annotations, so that one grammar can describe several dialects.
+//!
+//! An annotated alternative is kept only if its feature is enabled. An
+//! annotated private nonterminal whose feature is disabled is removed
+//! along with every alternative that refers to it, so it contributes no
+//! states to the parse table. A disabled `pub` nonterminal stays in the
+//! grammar, and only its parser is not generated (see `lower`).
+//! Removing alternatives must not leave a nonterminal with none.
+
+use super::{NormError, NormResult};
+use crate::collections::{set, Set};
+use crate::grammar::consts::CFG;
+use crate::grammar::parse_tree::{
+    Alternative, Annotation, Grammar, GrammarItem, NonterminalString, Symbol, SymbolKind,
+};
+use crate::session::Session;
+use string_cache::DefaultAtom as Atom;
+
+#[cfg(test)]
+mod test;
+
+pub fn remove_disabled(session: &Session, mut grammar: Grammar) -> NormResult<Grammar> {
+    let disabled: Set<NonterminalString> = grammar
+        .items
+        .iter()
+        .filter_map(GrammarItem::as_nonterminal)
+        .filter(|nt| !nt.visibility.is_pub() && !cfg_active(session, &nt.annotations))
+        .map(|nt| nt.name.clone())
+        .collect();
+
+    grammar.items.retain(|item| match *item {
+        GrammarItem::Nonterminal(ref data) => !disabled.contains(&data.name),
+        _ => true,
+    });
+
+    for item in &mut grammar.items {
+        if let GrammarItem::Nonterminal(ref mut data) = *item {
+            let had_alternatives = !data.alternatives.is_empty();
+            data.alternatives.retain(|alternative| {
+                cfg_active(session, &alternative.annotations)
+                    && !refers_to_any(alternative, &disabled)
+            });
+            if had_alternatives && data.alternatives.is_empty() {
+                return_err!(
+                    data.span,
+                    "every alternative of `{}` is disabled by `#[cfg]`",
+                    data.name
+                );
+            }
+        }
+    }
+
+    Ok(grammar)
+}
+
+/// True if every `cfg` annotation names an enabled feature.
+pub fn cfg_active(session: &Session, annotations: &[Annotation]) -> bool {
+    let cfg_atom = Atom::from(CFG);
+    annotations
+        .iter()
+        .filter(|ann| ann.id == cfg_atom)
+        .all(|ann| {
+            ann.arg.as_ref().map_or(false, |&(_, ref feature)| {
+                session
+                    .features
+                    .as_ref()
+                    .map_or(false, |features| features.contains(feature))
+            })
+        })
+}
+
+fn refers_to_any(alternative: &Alternative, names: &Set<NonterminalString>) -> bool {
+    if names.is_empty() {
+        return false;
+    }
+    let mut referenced = set();
+    for symbol in &alternative.expr.symbols {
+        referenced_names(symbol, &mut referenced);
+    }
+    referenced.iter().any(|name| names.contains(name))
+}
+
+fn referenced_names(symbol: &Symbol, out: &mut Set<NonterminalString>) {
+    match symbol.kind {
+        SymbolKind::Expr(ref expr) => {
+            for symbol in &expr.symbols {
+                referenced_names(symbol, out);
+            }
+        }
+        SymbolKind::AmbiguousId(ref id) => {
+            out.insert(NonterminalString(id.clone()));
+        }
+        SymbolKind::Nonterminal(ref name) => {
+            out.insert(name.clone());
+        }
+        SymbolKind::Macro(ref msym) => {
+            out.insert(msym.name.clone());
+            for arg in &msym.args {
+                referenced_names(arg, out);
+            }
+        }
+        SymbolKind::Repeat(ref repeat) => referenced_names(&repeat.symbol, out),
+        SymbolKind::Choose(ref symbol) | SymbolKind::Name(_, ref symbol) => {
+            referenced_names(symbol, out)
+        }
+        SymbolKind::Terminal(_)
+        | SymbolKind::Lookahead
+        | SymbolKind::Lookbehind
+        | SymbolKind::Error => {}
+    }
+}
@@ -0,0 +1,89 @@
+use crate::grammar::parse_tree::{Grammar, GrammarItem};
+use crate::normalize::NormResult;
+use crate::parser;
+use crate::session::Session;
+use crate::test_util::check_norm_err;
+
+use super::remove_disabled;
+
+fn remove_disabled_text(grammar: &str, features: &[&str]) -> NormResult<Grammar> {
+    let grammar = parser::parse_grammar(grammar).unwrap();
+    let mut session = Session::test();
+    session.features = Some(features.iter().map(|f| f.to_string()).collect());
+    remove_disabled(&session, grammar)
+}
+
+fn alternatives(features: &[&str]) -> Vec<(String, usize)> {
+    remove_disabled_text(
+        r#"grammar;
+           pub Expr = {
+               "x",
+               #[cfg(feature = "dialect-a")] "a",
+               Brackets,
+           };
+           #[cfg(feature = "dialect-b")]
+           Brackets = "[" Expr "]";
+        "#,
+        features,
+    )
+    .unwrap()
+    .items
+    .iter()
+    .filter_map(GrammarItem::as_nonterminal)
+    .map(|nt| (nt.name.to_string(), nt.alternatives.len()))
+    .collect()
+}
+
+#[test]
+fn disabled_alternatives_are_removed() {
+    assert_eq!(alternatives(&[]), vec![("Expr".to_string(), 1)]);
+    assert_eq!(alternatives(&["dialect-a"]), vec![("Expr".to_string(), 2)]);
+}
+
+#[test]
+fn disabled_nonterminals_are_removed_with_their_uses() {
+    assert_eq!(
+        alternatives(&["dialect-b"]),
+        vec![("Expr".to_string(), 2), ("Brackets".to_string(), 1)]
+    );
+    assert_eq!(
+        alternatives(&["dialect-a", "dialect-b"]),
+        vec![("Expr".to_string(), 3), ("Brackets".to_string(), 1)]
+    );
+}
+
+#[test]
+fn disabled_pub_nonterminals_are_kept() {
+    // only the parser for `Block` is left out, which `lower` takes care of
+    let grammar = remove_disabled_text(
+        r#"grammar;
+           pub Expr = { "x", Block };
+           #[cfg(feature = "blocks")]
+           pub Block = "{" Expr "}";
+        "#,
+        &[],
+    )
+    .unwrap();
+    let alternatives: Vec<_> = grammar
+        .items
+        .iter()
+        .filter_map(GrammarItem::as_nonterminal)
+        .map(|nt| (nt.name.to_string(), nt.alternatives.len()))
+        .collect();
+    assert_eq!(
+        alternatives,
+        vec![("Expr".to_string(), 2), ("Block".to_string(), 1)]
+    );
+}
+
+#[test]
+fn nonterminal_left_without_alternatives() {
+    let grammar = r#"grammar; pub Expr = { #[cfg(feature = "a")] "a", Call }; #[cfg(feature = "c")] Call = "f";"#;
+    assert!(remove_disabled_text(grammar, &["a"]).is_ok());
+    assert!(remove_disabled_text(grammar, &["c"]).is_ok());
+    check_norm_err(
+        "every alternative of `Expr` is disabled by `#\\[cfg\\]`",
+        r#"             ~~~~                                                                       "#,
+        remove_disabled_text(grammar, &[]).unwrap_err(),
+    );
+}
@@ -2,7 +2,7 @@
 //!
 
 use crate::collections::{map, Map};
-use crate::grammar::consts::CFG;
+use crate::grammar::consts::DISPLAY;
 use crate::grammar::parse_tree as pt;
 use crate::grammar::parse_tree::{
     read_algorithm, GrammarItem, InternToken, Lifetime, MatchMapping, Name, NonterminalString,
@@ -10,6 +10,7 @@ use crate::grammar::parse_tree::{
 };
 use crate::grammar::pattern::{Pattern, PatternKind};
 use crate::grammar::repr as r;
+use crate::normalize::cfg;
 use crate::normalize::norm_util::{self, Symbols};
 use crate::normalize::NormResult;
 use crate::session::Session;
@@ -26,6 +27,7 @@ struct LowerState<'s> {
     action_fn_defns: Vec<r::ActionFnDefn>,
     nonterminals: Map<NonterminalString, r::NonterminalData>,
     conversions: Vec<(TerminalString, Pattern<r::TypeRepr>)>,
+    terminal_names: Map<TerminalString, String>,
     intern_token: Option<InternToken>,
     types: r::Types,
     uses_error_recovery: bool,
@@ -39,6 +41,7 @@ impl<'s> LowerState<'s> {
             action_fn_defns: vec![],
             nonterminals: map(),
             conversions: vec![],
+            terminal_names: map(),
             types,
             intern_token: None,
             uses_error_recovery: false,
@@ -61,10 +64,17 @@ impl<'s> LowerState<'s> {
                     uses.push(data);
                 }
 
-                pt::GrammarItem::MatchToken(_) => {
+                pt::GrammarItem::MatchToken(data) => {
                     // The declarations in the match token are handled
-                    // fully by the `token_check` when it constructs the
-                    //  `InternToken` -- there is nothing left to do here.
+                    // by the `token_check` when it constructs the
+                    // `InternToken` -- all that is left are the names.
+                    for item in data.contents.iter().flat_map(|c| &c.items) {
+                        if let (Some(term), Some(name)) =
+                            (item.terminal(), display_name(item.annotations()))
+                        {
+                            self.terminal_names.insert(term, name);
+                        }
+                    }
                 }
 
                 pt::GrammarItem::InternToken(data) => {
@@ -73,10 +83,7 @@ impl<'s> LowerState<'s> {
                     let input_str = r::TypeRepr::Ref {
                         lifetime: Some(Lifetime::input()),
                         mutable: false,
-                        referent: Box::new(r::TypeRepr::Nominal(r::NominalTypeRepr {
-                            path: r::Path::str(),
-                            types: vec![],
-                        })),
+                        referent: Box::new(r::TypeRepr::input_text(data.bytes)),
                     };
                     self.conversions
                         .extend(data.match_entries.iter().enumerate().filter_map(
@@ -101,7 +108,7 @@ impl<'s> LowerState<'s> {
 
                                     Some((user_name.clone(), pattern))
                                 }
-                                MatchMapping::Skip => None,
+                                MatchMapping::Skip | MatchMapping::SkipNested(_) => None,
                             },
                         ));
                     self.intern_token = Some(data);
@@ -110,6 +117,11 @@ impl<'s> LowerState<'s> {
                 pt::GrammarItem::ExternToken(data) => {
                     if let Some(enum_token) = data.enum_token {
                         token_span = Some(enum_token.type_span);
+                        for conversion in &enum_token.conversions {
+                            if let Some(name) = display_name(&conversion.annotations) {
+                                self.terminal_names.insert(conversion.from.clone(), name);
+                            }
+                        }
                         self.conversions
                             .extend(enum_token.conversions.iter().map(|conversion| {
                                 (
@@ -208,6 +220,7 @@ impl<'s> LowerState<'s> {
             terminals: r::TerminalSet {
                 all: all_terminals,
                 bits: terminal_bits,
+                names: self.terminal_names,
             },
             module_attributes: grammar.module_attributes,
         })
@@ -223,7 +236,8 @@ impl<'s> LowerState<'s> {
             .iter()
             .filter_map(GrammarItem::as_nonterminal)
             .filter(|nt| nt.visibility.is_pub())
-            .filter(|nt| cfg_active(session, nt))
+            // no parser is generated for a `pub` nonterminal disabled by `#[cfg]`
+            .filter(|nt| cfg::cfg_active(session, &nt.annotations))
             .map(|nt| {
                 // create a synthetic symbol `__Foo` for each public symbol `Foo`
                 // with a rule like:
@@ -506,17 +520,12 @@ where
     result
 }
 
-fn cfg_active(session: &Session, nt: &pt::NonterminalData) -> bool {
-    let cfg_atom = Atom::from(CFG);
-    nt.annotations
+/// The name given by a `#[display(name = "...")]` annotation, if any.
+fn display_name(annotations: &[pt::Annotation]) -> Option<String> {
+    let display_atom = Atom::from(DISPLAY);
+    annotations
         .iter()
-        .filter(|ann| ann.id == cfg_atom)
-        .all(|ann| {
-            ann.arg.as_ref().map_or(false, |&(_, ref feature)| {
-                session
-                    .features
-                    .as_ref()
-                    .map_or(false, |features| features.contains(feature))
-            })
-        })
+        .find(|ann| ann.id == display_atom)
+        .and_then(|ann| ann.arg.as_ref())
+        .map(|&(_, ref name)| name.clone())
 }
@@ -1,4 +1,4 @@
-use crate::grammar::consts::INLINE;
+use crate::grammar::consts::{INLINE, RIGHT_RECURSIVE};
 use crate::grammar::parse_tree::{
     ActionKind, Alternative, Annotation, Condition, ConditionOp, ExprSymbol, Grammar, GrammarItem,
     MacroSymbol, Name, NonterminalData, NonterminalString, Path, RepeatOp, RepeatSymbol, Span,
@@ -31,7 +31,12 @@ pub fn expand_macros(input: Grammar) -> NormResult<Grammar> {
         })
         .collect();
 
-    let mut expander = MacroExpander::new(macro_defs);
+    let right_recursive = input
+        .annotations
+        .iter()
+        .any(|annotation| annotation.id == Atom::from(RIGHT_RECURSIVE));
+
+    let mut expander = MacroExpander::new(macro_defs, right_recursive);
     expander.expand(&mut items)?;
 
     Ok(Grammar { items, ..input })
@@ -41,14 +46,21 @@ struct MacroExpander {
     macro_defs: HashMap<NonterminalString, NonterminalData>,
     expansion_set: HashSet<NonterminalString>,
     expansion_stack: Vec<Symbol>,
+
+    // whether `X+` expands right-recursively; see `RIGHT_RECURSIVE`
+    right_recursive: bool,
 }
 
 impl MacroExpander {
-    fn new(macro_defs: HashMap<NonterminalString, NonterminalData>) -> MacroExpander {
+    fn new(
+        macro_defs: HashMap<NonterminalString, NonterminalData>,
+        right_recursive: bool,
+    ) -> MacroExpander {
         MacroExpander {
             macro_defs,
             expansion_stack: Vec::new(),
             expansion_set: HashSet::new(),
+            right_recursive,
         }
     }
 
@@ -75,7 +87,7 @@ impl MacroExpander {
                     }
                     SymbolKind::Expr(expr) => items.push(self.expand_expr_symbol(sym.span, expr)?),
                     SymbolKind::Repeat(repeat) => {
-                        items.push(self.expand_repeat_symbol(sym.span, *repeat)?)
+                        items.extend(self.expand_repeat_symbol(sym.span, *repeat)?)
                     }
                     SymbolKind::Lookahead => items.push(self.expand_lookaround_symbol(
                         sym.span,
@@ -198,6 +210,7 @@ impl MacroExpander {
             }
             alternatives.push(Alternative {
                 span,
+                annotations: vec![],
                 expr: self.macro_expand_expr_symbol(&args, &alternative.expr),
                 condition: None,
                 action: alternative.action.clone(),
@@ -422,6 +435,7 @@ impl MacroExpander {
             type_decl: Some(ty_ref),
             alternatives: vec![Alternative {
                 span,
+                annotations: vec![],
                 expr,
                 condition: None,
                 action,
@@ -436,7 +450,7 @@ impl MacroExpander {
         &mut self,
         span: Span,
         repeat: RepeatSymbol,
-    ) -> NormResult<GrammarItem> {
+    ) -> NormResult<Vec<GrammarItem>> {
         let name = NonterminalString(Atom::from(repeat.canonical_form()));
         let v = Atom::from("v");
         let e = Atom::from("e");
@@ -456,7 +470,7 @@ impl MacroExpander {
                     symbol: repeat.symbol.clone(),
                 });
 
-                Ok(GrammarItem::Nonterminal(NonterminalData {
+                Ok(vec![GrammarItem::Nonterminal(NonterminalData {
                     visibility: Visibility::Priv,
                     span,
                     name,
@@ -467,6 +481,7 @@ impl MacroExpander {
                         // X* =
                         Alternative {
                             span,
+                            annotations: vec![],
                             expr: ExprSymbol { symbols: vec![] },
                             condition: None,
                             action: action("vec![]"),
@@ -474,6 +489,7 @@ impl MacroExpander {
                         // X* = <v:X+>
                         Alternative {
                             span,
+                            annotations: vec![],
                             expr: ExprSymbol {
                                 symbols: vec![Symbol::new(
                                     span,
@@ -490,7 +506,100 @@ impl MacroExpander {
                             action: action("v"),
                         },
                     ],
-                }))
+                })])
+            }
+
+            RepeatOp::Plus if self.right_recursive => {
+                let path = Path::vec();
+                let ty_ref = TypeRef::Nominal {
+                    path,
+                    types: vec![base_symbol_ty],
+                };
+
+                // Right recursion reduces the last element first, so
+                // build the vector back to front and flip it once at
+                // the end rather than inserting at the front each time.
+                let reversed = NonterminalString(Atom::from(format!("{}_rev", name)));
+
+                Ok(vec![
+                    GrammarItem::Nonterminal(NonterminalData {
+                        visibility: Visibility::Priv,
+                        span,
+                        name,
+                        annotations: inline(span),
+                        args: vec![],
+                        type_decl: Some(ty_ref.clone()),
+                        alternatives: vec![
+                            // X+ = <v:X+_rev>
+                            Alternative {
+                                span,
+                                annotations: vec![],
+                                expr: ExprSymbol {
+                                    symbols: vec![Symbol::new(
+                                        span,
+                                        SymbolKind::Name(
+                                            Name::immut(v.clone()),
+                                            Box::new(Symbol::new(
+                                                span,
+                                                SymbolKind::Nonterminal(reversed.clone()),
+                                            )),
+                                        ),
+                                    )],
+                                },
+                                condition: None,
+                                action: action("{ let mut v = v; v.reverse(); v }"),
+                            },
+                        ],
+                    }),
+                    GrammarItem::Nonterminal(NonterminalData {
+                        visibility: Visibility::Priv,
+                        span,
+                        name: reversed.clone(),
+                        annotations: vec![],
+                        args: vec![],
+                        type_decl: Some(ty_ref),
+                        alternatives: vec![
+                            // X+_rev = X
+                            Alternative {
+                                span,
+                                annotations: vec![],
+                                expr: ExprSymbol {
+                                    symbols: vec![repeat.symbol.clone()],
+                                },
+                                condition: None,
+                                action: action("vec![<>]"),
+                            },
+                            // X+_rev = <e:X> <v:X+_rev>
+                            Alternative {
+                                span,
+                                annotations: vec![],
+                                expr: ExprSymbol {
+                                    symbols: vec![
+                                        Symbol::new(
+                                            span,
+                                            SymbolKind::Name(
+                                                Name::immut(e),
+                                                Box::new(repeat.symbol.clone()),
+                                            ),
+                                        ),
+                                        Symbol::new(
+                                            span,
+                                            SymbolKind::Name(
+                                                Name::immut(v),
+                                                Box::new(Symbol::new(
+                                                    span,
+                                                    SymbolKind::Nonterminal(reversed),
+                                                )),
+                                            ),
+                                        ),
+                                    ],
+                                },
+                                condition: None,
+                                action: action("{ let mut v = v; v.push(e); v }"),
+                            },
+                        ],
+                    }),
+                ])
             }
 
             RepeatOp::Plus => {
@@ -500,7 +609,7 @@ impl MacroExpander {
                     types: vec![base_symbol_ty],
                 };
 
-                Ok(GrammarItem::Nonterminal(NonterminalData {
+                Ok(vec![GrammarItem::Nonterminal(NonterminalData {
                     visibility: Visibility::Priv,
                     span,
                     name: name.clone(),
@@ -511,6 +620,7 @@ impl MacroExpander {
                         // X+ = X
                         Alternative {
                             span,
+                            annotations: vec![],
                             expr: ExprSymbol {
                                 symbols: vec![repeat.symbol.clone()],
                             },
@@ -520,6 +630,7 @@ impl MacroExpander {
                         // X+ = <v:X+> <e:X>
                         Alternative {
                             span,
+                            annotations: vec![],
                             expr: ExprSymbol {
                                 symbols: vec![
                                     Symbol::new(
@@ -545,7 +656,7 @@ impl MacroExpander {
                             action: action("{ let mut v = v; v.push(e); v }"),
                         },
                     ],
-                }))
+                })])
             }
 
             RepeatOp::Question => {
@@ -555,7 +666,7 @@ impl MacroExpander {
                     types: vec![base_symbol_ty],
                 };
 
-                Ok(GrammarItem::Nonterminal(NonterminalData {
+                Ok(vec![GrammarItem::Nonterminal(NonterminalData {
                     visibility: Visibility::Priv,
                     span,
                     name,
@@ -566,6 +677,7 @@ impl MacroExpander {
                         // X? = X => Some(<>)
                         Alternative {
                             span,
+                            annotations: vec![],
                             expr: ExprSymbol {
                                 symbols: vec![repeat.symbol.clone()],
                             },
@@ -575,12 +687,13 @@ impl MacroExpander {
                         // X? = { => None; }
                         Alternative {
                             span,
+                            annotations: vec![],
                             expr: ExprSymbol { symbols: vec![] },
                             condition: None,
                             action: action("None"),
                         },
                     ],
-                }))
+                })])
             }
         }
     }
@@ -601,6 +714,7 @@ impl MacroExpander {
             type_decl: None,
             alternatives: vec![Alternative {
                 span,
+                annotations: vec![],
                 expr: ExprSymbol { symbols: vec![] },
                 condition: None,
                 action: Some(action),
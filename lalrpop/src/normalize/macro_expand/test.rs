@@ -116,3 +116,38 @@ fn test_lookahead() {
 
     compare(actual, expected);
 }
+
+#[test]
+fn test_right_recursive_plus() {
+    let grammar = parser::parse_grammar(
+        r#"
+#[right_recursive]
+grammar;
+    Ids = "Id"+;
+"#,
+    )
+    .unwrap();
+
+    let actual = expand_macros(grammar).unwrap();
+
+    let expected = parser::parse_grammar(
+        r##"
+#[right_recursive]
+grammar;
+    Ids = `"Id"+`;
+
+    #[inline]
+    `"Id"+`: ::std::vec::Vec<#"Id"#> = {
+        <v:`"Id"+_rev`> => { let mut v = v; v.reverse(); v },
+    };
+
+    `"Id"+_rev`: ::std::vec::Vec<#"Id"#> = {
+        "Id" => vec![<>],
+        <e:"Id"> <v:`"Id"+_rev`> => { let mut v = v; v.push(e); v },
+    };
+"##,
+    )
+    .unwrap();
+
+    compare(actual, expected);
+}
@@ -5,6 +5,8 @@
 use crate::grammar::parse_tree as pt;
 use crate::grammar::repr as r;
 use crate::session::Session;
+use crate::tls::Tls;
+use std::io;
 
 pub type NormResult<T> = Result<T, NormError>;
 
@@ -23,10 +25,32 @@ macro_rules! return_err {
     }
 }
 
+/// Reports a problem that does not stop the grammar from being
+/// processed, in the same format as errors.
+fn report_warning(span: pt::Span, message: &str) {
+    let file_text = Tls::file_text();
+    println!("{} warning: {}", file_text.span_str(span), message);
+
+    let out = io::stderr();
+    let mut out = out.lock();
+    let _ = file_text.highlight(span, &mut out);
+}
+
 pub fn normalize(session: &Session, grammar: pt::Grammar) -> NormResult<r::Grammar> {
     normalize_helper(session, grammar, true)
 }
 
+/// Like `normalize`, but leaves `#[inline]` nonterminals in place.
+pub fn lower_without_inlining(session: &Session, grammar: pt::Grammar) -> NormResult<r::Grammar> {
+    lower_helper(session, grammar, true)
+}
+
+/// Inlines the `#[inline]` nonterminals of a grammar produced by
+/// `lower_without_inlining`.
+pub fn inline_nonterminals(grammar: r::Grammar) -> NormResult<r::Grammar> {
+    inline::inline(grammar)
+}
+
 /// for unit tests, it is convenient to skip the validation step, and supply a dummy session
 #[cfg(test)]
 pub fn normalize_without_validating(grammar: pt::Grammar) -> NormResult<r::Grammar> {
@@ -44,6 +68,11 @@ fn normalize_helper(
 }
 
 fn lower_helper(session: &Session, grammar: pt::Grammar, validate: bool) -> NormResult<r::Grammar> {
+    let grammar = profile!(
+        session,
+        "Built-in macros",
+        prelude::add_builtin_macros(grammar)
+    );
     profile!(
         session,
         "Grammar validation",
@@ -51,13 +80,22 @@ fn lower_helper(session: &Session, grammar: pt::Grammar, validate: bool) -> Norm
             prevalidate::validate(&grammar)?;
         }
     );
+    let grammar = profile!(
+        session,
+        "Feature selection",
+        cfg::remove_disabled(session, grammar)?
+    );
     let grammar = profile!(session, "Grammar resolution", resolve::resolve(grammar)?);
     let grammar = profile!(
         session,
         "Macro expansion",
         macro_expand::expand_macros(grammar)?
     );
-    let grammar = profile!(session, "Token check", token_check::validate(grammar)?);
+    let grammar = profile!(
+        session,
+        "Token check",
+        token_check::validate(session, grammar)?
+    );
     let types = profile!(session, "Infer types", tyinfer::infer_types(&grammar)?);
     let grammar = profile!(session, "Lowering", lower::lower(session, grammar, types)?);
     Ok(grammar)
@@ -65,9 +103,17 @@ fn lower_helper(session: &Session, grammar: pt::Grammar, validate: bool) -> Norm
 
 // These are executed *IN ORDER*:
 
+// Add the definitions of built-in macros like `Comma<T>` that the
+// grammar uses but does not define itself.
+mod prelude;
+
 // Check most safety conditions.
 mod prevalidate;
 
+// Remove the nonterminals and alternatives whose `#[cfg]` features
+// are not enabled.
+mod cfg;
+
 // Resolve identifiers into terminals/nonterminals etc.
 mod resolve;
 
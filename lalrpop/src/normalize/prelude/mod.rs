@@ -0,0 +1,145 @@
+//! Adds the built-in list macros (`Comma<T>`, `Sep<T, S>` and
+//! `NonEmpty<T>`) to grammars that use them without defining them.
+//!
+//! The definitions are ordinary LALRPOP macros (see `PRELUDE`), so they
+//! go through resolution and macro expansion like user-written ones. A
+//! grammar that declares a nonterminal of the same name, macro or not,
+//! keeps its own definition.
+
+use crate::grammar::parse_tree::{
+    Alternative, Grammar, GrammarItem, NonterminalData, NonterminalString, Span, Symbol, SymbolKind,
+};
+use crate::parser;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+mod test;
+
+/// The source of the built-in macros. Each one is self-contained, so
+/// shadowing one of them never changes what the others expand to.
+pub const PRELUDE: &str = r#"
+grammar;
+
+Comma<T>: Vec<T> = {
+    <mut v:(<T> ",")*> <e:T?> => match e {
+        None => v,
+        Some(e) => { v.push(e); v }
+    }
+};
+
+Sep<T, S>: Vec<T> = {
+    <mut v:(<T> S)*> <e:T?> => match e {
+        None => v,
+        Some(e) => { v.push(e); v }
+    }
+};
+
+NonEmpty<T>: Vec<T> = {
+    <mut v:(<T> ",")*> <e:T> => { v.push(e); v }
+};
+"#;
+
+pub fn add_builtin_macros(mut grammar: Grammar) -> Grammar {
+    let defined: HashSet<NonterminalString> = grammar
+        .items
+        .iter()
+        .filter_map(GrammarItem::as_nonterminal)
+        .map(|nt| nt.name.clone())
+        .collect();
+
+    // Remember where each macro is first used, so that errors in an
+    // expansion point at the user's grammar rather than at `PRELUDE`.
+    let mut uses = HashMap::new();
+    for nt in grammar.items.iter().filter_map(GrammarItem::as_nonterminal) {
+        for alternative in &nt.alternatives {
+            for symbol in &alternative.expr.symbols {
+                find_macro_uses(symbol, &mut uses);
+            }
+        }
+    }
+
+    let prelude = parser::parse_grammar(PRELUDE).expect("built-in macros failed to parse");
+    for item in prelude.items {
+        if let GrammarItem::Nonterminal(mut data) = item {
+            if defined.contains(&data.name) {
+                continue;
+            }
+            if let Some(&span) = uses.get(&data.name) {
+                respan_nonterminal(&mut data, span);
+                grammar.items.push(GrammarItem::Nonterminal(data));
+            }
+        }
+    }
+
+    grammar
+}
+
+fn find_macro_uses(symbol: &Symbol, uses: &mut HashMap<NonterminalString, Span>) {
+    match symbol.kind {
+        SymbolKind::Macro(ref msym) => {
+            uses.entry(msym.name.clone()).or_insert(symbol.span);
+            for arg in &msym.args {
+                find_macro_uses(arg, uses);
+            }
+        }
+        SymbolKind::Expr(ref expr) => {
+            for sym in &expr.symbols {
+                find_macro_uses(sym, uses);
+            }
+        }
+        SymbolKind::Repeat(ref repeat) => find_macro_uses(&repeat.symbol, uses),
+        SymbolKind::Choose(ref sym) | SymbolKind::Name(_, ref sym) => find_macro_uses(sym, uses),
+        SymbolKind::AmbiguousId(_)
+        | SymbolKind::Terminal(_)
+        | SymbolKind::Nonterminal(_)
+        | SymbolKind::Lookahead
+        | SymbolKind::Lookbehind
+        | SymbolKind::Error => {}
+    }
+}
+
+fn respan_nonterminal(data: &mut NonterminalData, span: Span) {
+    data.span = span;
+    for annotation in &mut data.annotations {
+        annotation.id_span = span;
+    }
+    for alternative in &mut data.alternatives {
+        respan_alternative(alternative, span);
+    }
+}
+
+fn respan_alternative(alternative: &mut Alternative, span: Span) {
+    alternative.span = span;
+    if let Some(ref mut condition) = alternative.condition {
+        condition.span = span;
+    }
+    for symbol in &mut alternative.expr.symbols {
+        respan_symbol(symbol, span);
+    }
+}
+
+fn respan_symbol(symbol: &mut Symbol, span: Span) {
+    symbol.span = span;
+    match symbol.kind {
+        SymbolKind::Macro(ref mut msym) => {
+            for arg in &mut msym.args {
+                respan_symbol(arg, span);
+            }
+        }
+        SymbolKind::Expr(ref mut expr) => {
+            for sym in &mut expr.symbols {
+                respan_symbol(sym, span);
+            }
+        }
+        SymbolKind::Repeat(ref mut repeat) => respan_symbol(&mut repeat.symbol, span),
+        SymbolKind::Choose(ref mut sym) | SymbolKind::Name(_, ref mut sym) => {
+            respan_symbol(sym, span)
+        }
+        SymbolKind::AmbiguousId(_)
+        | SymbolKind::Terminal(_)
+        | SymbolKind::Nonterminal(_)
+        | SymbolKind::Lookahead
+        | SymbolKind::Lookbehind
+        | SymbolKind::Error => {}
+    }
+}
@@ -0,0 +1,47 @@
+use crate::grammar::parse_tree::{GrammarItem, Span};
+use crate::parser;
+
+use super::add_builtin_macros;
+
+fn macro_defs(text: &str) -> Vec<(String, Span)> {
+    let grammar = parser::parse_grammar(text).unwrap();
+    add_builtin_macros(grammar)
+        .items
+        .iter()
+        .filter_map(GrammarItem::as_nonterminal)
+        .filter(|nt| nt.is_macro_def())
+        .map(|nt| (nt.name.to_string(), nt.span))
+        .collect()
+}
+
+#[test]
+fn only_used_macros_are_added() {
+    let text = r#"grammar; Ids = "[" Comma<"Id"> "]";"#;
+    let start = text.find("Comma").unwrap();
+    let end = text.rfind('>').unwrap() + 1;
+    assert_eq!(
+        macro_defs(text),
+        vec![("Comma".to_string(), Span(start, end))]
+    );
+}
+
+#[test]
+fn macro_arguments_are_searched() {
+    let defs = macro_defs(r#"grammar; Ids = Comma<NonEmpty<"Id">>;"#);
+    let names: Vec<_> = defs.into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["Comma", "NonEmpty"]);
+}
+
+#[test]
+fn user_definitions_shadow_builtins() {
+    let grammar = parser::parse_grammar(
+        r#"grammar;
+           Comma<T>: Vec<T> = T => vec![<>];
+           Sep = "a";
+           Ids = Comma<"Id"> Sep<"Id", ";">;"#,
+    )
+    .unwrap();
+    let before = grammar.items.clone();
+    let after = add_builtin_macros(grammar).items;
+    assert_eq!(before, after);
+}
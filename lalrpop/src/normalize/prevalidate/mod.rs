@@ -48,6 +48,7 @@ impl<'grammar> Validator<'grammar> {
             Atom::from(TABLE_DRIVEN),
             Atom::from(RECURSIVE_ASCENT),
             Atom::from(TEST_ALL),
+            Atom::from(RIGHT_RECURSIVE),
         ];
         for annotation in &self.grammar.annotations {
             if !allowed_names.contains(&annotation.id) {
@@ -86,6 +87,11 @@ impl<'grammar> Validator<'grammar> {
                             {
                                 return_err!(item.span(), "Catch all must be final item");
                             }
+                            self.validate_terminal_annotations(
+                                item.annotations(),
+                                item.terminal().is_some(),
+                                true,
+                            )?;
                         }
                     }
                 }
@@ -104,7 +110,21 @@ impl<'grammar> Validator<'grammar> {
                         }
                     }
 
-                    let allowed_names = vec![Atom::from(LOCATION), Atom::from(ERROR)];
+                    if let Some(ref enum_token) = data.enum_token {
+                        for conversion in &enum_token.conversions {
+                            self.validate_terminal_annotations(
+                                &conversion.annotations,
+                                true,
+                                false,
+                            )?;
+                        }
+                    }
+
+                    let allowed_names = vec![
+                        Atom::from(LOCATION),
+                        Atom::from(ERROR),
+                        Atom::from(INTO_ERROR),
+                    ];
                     let mut new_names = set();
                     for associated_type in &data.associated_types {
                         if !allowed_names.contains(&associated_type.type_name) {
@@ -121,6 +141,32 @@ impl<'grammar> Validator<'grammar> {
                                 "associated type `{}` already specified",
                                 associated_type.type_name
                             );
+                        } else if associated_type.type_name == Atom::from(INTO_ERROR)
+                            && data.enum_token.is_some()
+                        {
+                            // the generated `From` impl turns the tokens
+                            // into strings, which custom tokens need not
+                            // support
+                            return_err!(
+                                associated_type.type_span,
+                                "associated type `{}` can only be used with the \
+                                 built-in lexer, not with custom tokens",
+                                INTO_ERROR
+                            );
+                        } else if associated_type.type_name == Atom::from(INTO_ERROR)
+                            && self.grammar.type_parameters.iter().any(|tp| match *tp {
+                                TypeParameter::Lifetime(_) => false,
+                                TypeParameter::Id(_) => true,
+                            })
+                        {
+                            // the type parameters could not all be
+                            // inferred from the generated `From` impl
+                            return_err!(
+                                associated_type.type_span,
+                                "associated type `{}` cannot be used in a grammar \
+                                 with type parameters",
+                                INTO_ERROR
+                            );
                         }
                     }
                 }
@@ -130,7 +176,14 @@ impl<'grammar> Validator<'grammar> {
                     }
                     let inline_annotation = Atom::from(INLINE);
                     let cfg_annotation = Atom::from(CFG);
-                    let known_annotations = [inline_annotation.clone(), cfg_annotation.clone()];
+                    let prefer_shift_annotation = Atom::from(PREFER_SHIFT);
+                    let prefer_reduce_annotation = Atom::from(PREFER_REDUCE);
+                    let known_annotations = [
+                        inline_annotation.clone(),
+                        cfg_annotation.clone(),
+                        prefer_shift_annotation.clone(),
+                        prefer_reduce_annotation.clone(),
+                    ];
                     let mut found_annotations = set();
                     for annotation in &data.annotations {
                         if !known_annotations.contains(&annotation.id) {
@@ -145,26 +198,22 @@ impl<'grammar> Validator<'grammar> {
                                 "duplicate annotation `{}`",
                                 annotation.id
                             );
+                        } else if (annotation.id == prefer_shift_annotation
+                            && found_annotations.contains(&prefer_reduce_annotation))
+                            || (annotation.id == prefer_reduce_annotation
+                                && found_annotations.contains(&prefer_shift_annotation))
+                        {
+                            return_err!(
+                                annotation.id_span,
+                                "items cannot be marked both #[prefer_shift] and #[prefer_reduce]"
+                            );
                         } else if annotation.id == inline_annotation && data.visibility.is_pub() {
                             return_err!(
                                 annotation.id_span,
                                 "public items cannot be marked #[inline]"
                             );
                         } else if annotation.id == cfg_annotation {
-                            if data.visibility.is_pub() {
-                                match annotation.arg {
-                                    Some((ref name, _)) if name == "feature" => (),
-                                    _ => return_err!(
-                                        annotation.id_span,
-                                        r#"`cfg` annotations must have a `feature = "my_feature" argument"#
-                                    ),
-                                }
-                            } else {
-                                return_err!(
-                                    annotation.id_span,
-                                    "private items cannot be marked #[cfg]"
-                                );
-                            }
+                            self.validate_cfg_annotation(annotation)?;
                         }
                     }
 
@@ -178,7 +227,100 @@ impl<'grammar> Validator<'grammar> {
         Ok(())
     }
 
+    /// Checks the annotations on an entry of an `extern` or `match`
+    /// block. Only `display` and, in a `match` block, `boundary` are
+    /// allowed, and only if the entry defines a terminal (rather than
+    /// text to skip).
+    fn validate_terminal_annotations(
+        &self,
+        annotations: &[Annotation],
+        is_terminal: bool,
+        in_match: bool,
+    ) -> NormResult<()> {
+        let display_annotation = Atom::from(DISPLAY);
+        let boundary_annotation = Atom::from(BOUNDARY);
+        let mut found_annotations = set();
+        for annotation in annotations {
+            if annotation.id != display_annotation && annotation.id != boundary_annotation {
+                return_err!(
+                    annotation.id_span,
+                    "unrecognized annotation `{}`",
+                    annotation.id
+                );
+            } else if !found_annotations.insert(annotation.id.clone()) {
+                return_err!(
+                    annotation.id_span,
+                    "duplicate annotation `{}`",
+                    annotation.id
+                );
+            } else if !is_terminal {
+                return_err!(
+                    annotation.id_span,
+                    "only terminals can be marked #[{}]",
+                    annotation.id
+                );
+            }
+            if annotation.id == boundary_annotation {
+                if !in_match {
+                    return_err!(
+                        annotation.id_span,
+                        "`boundary` annotations are only allowed in a `match` block"
+                    );
+                }
+                if annotation.arg.is_some() {
+                    return_err!(
+                        annotation.id_span,
+                        "`boundary` annotations do not take arguments"
+                    );
+                }
+                continue;
+            }
+            match annotation.arg {
+                Some((ref name, _)) if name == "name" => (),
+                _ => return_err!(
+                    annotation.id_span,
+                    r#"`display` annotations must have a `name = "my name"` argument"#
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the annotations on an alternative. Only `cfg` is allowed.
+    fn validate_alternative_annotations(&self, alternative: &Alternative) -> NormResult<()> {
+        let cfg_annotation = Atom::from(CFG);
+        let mut found_annotations = set();
+        for annotation in &alternative.annotations {
+            if annotation.id != cfg_annotation {
+                return_err!(
+                    annotation.id_span,
+                    "unrecognized annotation `{}`",
+                    annotation.id
+                );
+            } else if !found_annotations.insert(annotation.id.clone()) {
+                return_err!(
+                    annotation.id_span,
+                    "duplicate annotation `{}`",
+                    annotation.id
+                );
+            }
+            self.validate_cfg_annotation(annotation)?;
+        }
+        Ok(())
+    }
+
+    fn validate_cfg_annotation(&self, annotation: &Annotation) -> NormResult<()> {
+        match annotation.arg {
+            Some((ref name, _)) if name == "feature" => Ok(()),
+            _ => return_err!(
+                annotation.id_span,
+                r#"`cfg` annotations must have a `feature = "my_feature" argument"#
+            ),
+        }
+    }
+
     fn validate_alternative(&self, alternative: &Alternative) -> NormResult<()> {
+        self.validate_alternative_annotations(alternative)?;
         self.validate_expr(&alternative.expr)?;
 
         match norm_util::analyze_expr(&alternative.expr) {
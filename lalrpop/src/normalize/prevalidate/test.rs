@@ -34,6 +34,24 @@ fn dup_assoc_type() {
     );
 }
 
+#[test]
+fn into_error_with_custom_tokens() {
+    check_err(
+        r#"associated type `IntoError` can only be used with the built-in lexer"#,
+        r#"grammar; extern { type IntoError = MyError; enum Tok { "a" => Tok::A } }"#,
+        r#"                       ~~~~~~~~~                                        "#,
+    );
+}
+
+#[test]
+fn into_error_with_type_parameters() {
+    check_err(
+        r#"associated type `IntoError` cannot be used in a grammar with type parameters"#,
+        r#"grammar<T>; extern { type IntoError = MyError; }"#,
+        r#"                          ~~~~~~~~~             "#,
+    );
+}
+
 #[test]
 fn lookahead_without_loc_type() {
     check_err(
@@ -79,6 +97,15 @@ fn pub_inline_annotation() {
     );
 }
 
+#[test]
+fn prefer_shift_and_reduce_annotation() {
+    check_err(
+        r#"items cannot be marked both #\[prefer_shift\] and #\[prefer_reduce\]"#,
+        r#"grammar; #[prefer_shift] #[prefer_reduce] Term = ();"#,
+        r#"                           ~~~~~~~~~~~~~            "#,
+    );
+}
+
 #[test]
 fn multiple_match_token() {
     check_err(
@@ -150,3 +177,66 @@ fn public_macros() {
         r#"             ~~~~~~~~               "#,
     );
 }
+
+#[test]
+fn unrecognized_terminal_annotation() {
+    check_err(
+        r#"unrecognized annotation `inline`"#,
+        r#"grammar; extern { enum Tok { #[inline] "a" => Tok::A } }"#,
+        r#"                               ~~~~~~                   "#,
+    );
+}
+
+#[test]
+fn display_without_name() {
+    check_err(
+        r#"`display` annotations must have a `name = "my name"` argument"#,
+        r#"grammar; match { #[display] "a" }"#,
+        r#"                   ~~~~~~~       "#,
+    );
+}
+
+#[test]
+fn display_on_skip() {
+    check_err(
+        r#"only terminals can be marked #\[display\]"#,
+        r#"grammar; match { #[display(name = "space")] r"\s*" => { } }"#,
+        r#"                   ~~~~~~~~~~~~~~~~~~~~~~~                  "#,
+    );
+}
+
+#[test]
+fn boundary_on_skip() {
+    check_err(
+        r#"only terminals can be marked #\[boundary\]"#,
+        r#"grammar; match { #[boundary] r"\s*" => { } }"#,
+        r#"                   ~~~~~~~~                  "#,
+    );
+}
+
+#[test]
+fn boundary_in_extern() {
+    check_err(
+        r#"`boundary` annotations are only allowed in a `match` block"#,
+        r#"grammar; extern { enum Tok { #[boundary] "a" => Tok::A } }"#,
+        r#"                               ~~~~~~~~                   "#,
+    );
+}
+
+#[test]
+fn unrecognized_alternative_annotation() {
+    check_err(
+        r#"unrecognized annotation `inline`"#,
+        r#"grammar; A = { #[inline] "a" => () };"#,
+        r#"                 ~~~~~~              "#,
+    );
+}
+
+#[test]
+fn alternative_cfg_without_feature() {
+    check_err(
+        r#"`cfg` annotations must have a `feature = "my_feature" argument"#,
+        r#"grammar; A = { #[cfg] "a" => () };"#,
+        r#"                 ~~~              "#,
+    );
+}
@@ -49,9 +49,12 @@ fn resolve_in_place(grammar: &mut Grammar) -> NormResult<()> {
             .flat_map(|match_token| &match_token.contents)
             .flat_map(|match_contents| &match_contents.items)
             .filter_map(|item| match *item {
-                MatchItem::Mapped(_, MatchMapping::Terminal(TerminalString::Bare(ref id)), _) => {
-                    Some((item.span(), id.clone(), Def::Terminal))
-                }
+                MatchItem::Mapped(
+                    _,
+                    MatchMapping::Terminal(TerminalString::Bare(ref id)),
+                    _,
+                    _,
+                ) => Some((item.span(), id.clone(), Def::Terminal)),
                 _ => None,
             });
 
@@ -5,7 +5,7 @@
 //! are only used in `if` conditions; we use string literals for
 //! those, but they do not have to have a defined conversion.
 
-use super::{NormError, NormResult};
+use super::{report_warning, NormError, NormResult};
 
 use crate::collections::{Map, Set};
 use crate::grammar::consts::*;
@@ -13,12 +13,25 @@ use crate::grammar::parse_tree::*;
 use crate::lexer::dfa::{self, DFAConstructionError, Precedence};
 use crate::lexer::nfa::NFAConstructionError::*;
 use crate::lexer::re;
+use crate::session::Session;
 use string_cache::DefaultAtom as Atom;
 
 #[cfg(test)]
 mod test;
 
-pub fn validate(mut grammar: Grammar) -> NormResult<Grammar> {
+pub fn validate(session: &Session, grammar: Grammar) -> NormResult<Grammar> {
+    let (grammar, warnings) = validate_with_warnings(session, grammar)?;
+    for warning in warnings {
+        report_warning(warning.span, &warning.message);
+    }
+    Ok(grammar)
+}
+
+/// Like `validate`, but returns the warnings instead of reporting them.
+fn validate_with_warnings(
+    session: &Session,
+    mut grammar: Grammar,
+) -> NormResult<(Grammar, Vec<NormError>)> {
     let mode = {
         let mode = if let Some(enum_token) = grammar.enum_token() {
             assert!(
@@ -55,17 +68,18 @@ pub fn validate(mut grammar: Grammar) -> NormResult<Grammar> {
         validator.mode
     };
 
-    match mode {
+    let warnings = match mode {
         TokenMode::Extern { .. } => {
             // If using an external tokenizer, we're all done at this point.
+            vec![]
         }
         TokenMode::Internal { match_block } => {
             // Otherwise, construct the `InternToken` item.
-            construct(&mut grammar, match_block)?;
+            construct(&mut grammar, match_block, session.byte_input)?
         }
-    }
+    };
 
-    Ok(grammar)
+    Ok((grammar, warnings))
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -129,19 +143,21 @@ impl MatchBlock {
                 let precedence = match_token.contents.len() - idx;
                 for item in &mc.items {
                     match *item {
-                        MatchItem::Unmapped(ref sym, span) => {
+                        MatchItem::Unmapped(ref sym, ref annotations, span) => {
                             match_block.add_match_entry(
                                 precedence,
                                 sym.clone(),
                                 MatchMapping::Terminal(TerminalString::Literal(sym.clone())),
+                                has_boundary(annotations),
                                 span,
                             )?;
                         }
-                        MatchItem::Mapped(ref sym, ref user, span) => {
+                        MatchItem::Mapped(ref sym, ref user, ref annotations, span) => {
                             match_block.add_match_entry(
                                 precedence,
                                 sym.clone(),
                                 user.clone(),
+                                has_boundary(annotations),
                                 span,
                             )?;
                         }
@@ -163,12 +179,26 @@ impl MatchBlock {
         match_group_precedence: usize,
         sym: TerminalLiteral,
         user_name: MatchMapping,
+        boundary: bool,
         span: Span,
     ) -> NormResult<()> {
         if let Some(_old_span) = self.spans.insert(sym.clone(), span) {
             return_err!(span, "multiple match entries for `{}`", sym);
         }
 
+        if let MatchMapping::SkipNested(close) = &user_name {
+            if let TerminalLiteral::Regex(_) | TerminalLiteral::CaseInsensitive(_) = sym {
+                return_err!(
+                    span,
+                    "nested skip blocks must start with a string literal, not `{}`",
+                    sym
+                );
+            }
+            if close.is_empty() {
+                return_err!(span, "the closing delimiter of `{}` cannot be empty", sym);
+            }
+        }
+
         // NB: It's legal for multiple regex to produce same terminal.
         if let MatchMapping::Terminal(user_name) = &user_name {
             self.match_user_names.insert(user_name.clone());
@@ -178,6 +208,7 @@ impl MatchBlock {
             precedence: match_group_precedence * 2 + sym.base_precedence(),
             match_literal: sym,
             user_name,
+            boundary,
         });
         Ok(())
     }
@@ -206,6 +237,7 @@ impl MatchBlock {
             precedence: sym.base_precedence(),
             match_literal: sym.clone(),
             user_name: MatchMapping::Terminal(TerminalString::Literal(sym.clone())),
+            boundary: false,
         });
 
         self.spans.insert(sym, span);
@@ -214,6 +246,14 @@ impl MatchBlock {
     }
 }
 
+/// Whether a `match` entry is marked `#[boundary]`.
+fn has_boundary(annotations: &[Annotation]) -> bool {
+    let boundary = Atom::from(BOUNDARY);
+    annotations
+        .iter()
+        .any(|annotation| annotation.id == boundary)
+}
+
 impl<'grammar> Validator<'grammar> {
     fn validate(&mut self) -> NormResult<()> {
         for item in &self.grammar.items {
@@ -316,7 +356,11 @@ impl<'grammar> Validator<'grammar> {
 // Construction phase -- if we are constructing a tokenizer, this
 // phase builds up an internal token DFA.
 
-fn construct(grammar: &mut Grammar, match_block: MatchBlock) -> NormResult<()> {
+fn construct(
+    grammar: &mut Grammar,
+    match_block: MatchBlock,
+    bytes: bool,
+) -> NormResult<Vec<NormError>> {
     let MatchBlock {
         mut match_entries,
         spans,
@@ -336,6 +380,9 @@ fn construct(grammar: &mut Grammar, match_block: MatchBlock) -> NormResult<()> {
             TerminalLiteral::Quoted(ref s) => {
                 regexs.push(re::parse_literal(&s));
             }
+            TerminalLiteral::CaseInsensitive(ref s) => {
+                regexs.push(re::parse_case_insensitive_literal(&s));
+            }
             TerminalLiteral::Regex(ref s) => {
                 match re::parse_regex(&s) {
                     Ok(regex) => regexs.push(regex),
@@ -382,11 +429,33 @@ fn construct(grammar: &mut Grammar, match_block: MatchBlock) -> NormResult<()> {
         }
     };
 
-    grammar
-        .items
-        .push(GrammarItem::InternToken(InternToken { match_entries, dfa }));
+    // A terminal that always loses to one from an earlier `match` block
+    // is almost certainly an ordering mistake. Within a block, literals
+    // are meant to take precedence over regexs, so those are fine.
+    let mut warnings = vec![];
+    for &(shadowed, by) in &dfa.shadowed {
+        let shadowed = &match_entries[shadowed.index()];
+        let by = &match_entries[by.index()];
+        if by.precedence / 2 > shadowed.precedence / 2 {
+            warnings.push(NormError {
+                span: spans[&shadowed.match_literal],
+                message: format!(
+                    "the terminal `{}` can never be matched, because the terminal `{}` \
+                     takes precedence over it on every input it matches",
+                    shadowed.match_literal, by.match_literal
+                ),
+            });
+        }
+    }
+
+    grammar.items.push(GrammarItem::InternToken(InternToken {
+        match_entries,
+        dfa,
+        bytes,
+    }));
 
-    // we need to inject a `'input` lifetime and `input: &'input str` parameter as well:
+    // we need to inject a `'input` lifetime and `input: &'input str` parameter as well
+    // (or `input: &'input [u8]`, when reading bytes):
 
     let input_lifetime = Lifetime::input();
     for parameter in &grammar.type_parameters {
@@ -422,10 +491,14 @@ fn construct(grammar: &mut Grammar, match_block: MatchBlock) -> NormResult<()> {
         ty: TypeRef::Ref {
             lifetime: Some(input_lifetime),
             mutable: false,
-            referent: Box::new(TypeRef::Id(Atom::from("str"))),
+            referent: Box::new(if bytes {
+                TypeRef::Slice(Box::new(TypeRef::Id(Atom::from("u8"))))
+            } else {
+                TypeRef::Id(Atom::from("str"))
+            }),
         },
     };
     grammar.parameters.push(parameter);
 
-    Ok(())
+    Ok(warnings)
 }
@@ -1,14 +1,20 @@
 use crate::grammar::parse_tree::Grammar;
 use crate::lexer::dfa::interpret;
 use crate::normalize::resolve::resolve;
-use crate::normalize::NormResult;
+use crate::normalize::{NormError, NormResult};
 use crate::parser;
+use crate::session::Session;
 use crate::test_util;
+use crate::tls::Tls;
 
 fn validate_grammar(grammar: &str) -> NormResult<Grammar> {
-    let parsed_grammar = parser::parse_grammar(&grammar).expect("parse grammar");
+    validate_grammar_with_warnings(grammar).map(|(grammar, _)| grammar)
+}
+
+fn validate_grammar_with_warnings(grammar: &str) -> NormResult<(Grammar, Vec<NormError>)> {
+    let parsed_grammar = parser::parse_grammar(grammar).expect("parse grammar");
     let parsed_grammar = resolve(parsed_grammar).expect("resolve");
-    super::validate(parsed_grammar)
+    super::validate_with_warnings(&Session::test(), parsed_grammar)
 }
 
 fn check_err(expected_err: &str, grammar: &str, span: &str) {
@@ -109,6 +115,21 @@ fn regex_literals() {
     );
 }
 
+#[test]
+fn case_insensitive_literals() {
+    check_intern_token(
+        r#"grammar; X = X i"select" i"café" r"[a-z]+";"#,
+        vec![
+            ("select", r#"Some((i"select", "select"))"#),
+            ("Select", r#"Some((i"select", "Select"))"#),
+            ("SELECT", r#"Some((i"select", "SELECT"))"#),
+            ("selects", r##"Some((r#"[a-z]+"#, "selects"))"##),
+            ("CAFé", r#"Some((i"café", "CAFé"))"#),
+            ("CAFÉ", r#"None"#),
+        ],
+    );
+}
+
 /// Basic test for match mappings.
 #[test]
 fn match_mappings() {
@@ -156,6 +177,16 @@ fn invalid_match_regex_literal() {
     );
 }
 
+/// Nested skip blocks are delimited by counting, so they cannot start with a regex.
+#[test]
+fn invalid_nested_skip_regex() {
+    check_err(
+        r#"nested skip blocks must start with a string literal, not `.*`"#,
+        r#"grammar; match { r"/\*+" => { "*/" }, _ } X = "foo";"#,
+        r#"                 ~~~~~~~~~~~~~~~~~~~                     "#,
+    );
+}
+
 /// Test that, with a catch-all, the previous two examples work.
 #[test]
 fn match_catch_all() {
@@ -190,6 +221,23 @@ fn ambiguity_within_match() {
     );
 }
 
+/// Test that a terminal which loses to a higher-precedence one on every
+/// input it matches is only warned about, not rejected.
+#[test]
+fn shadowed_by_match() {
+    let grammar = r#"grammar; match { r"[a-z]+" => ID } else { "if" } X = "if" ID;"#;
+    let _tls = Tls::test_string(grammar);
+    check_intern_token(grammar, vec![("if", r#"Some((ID, "if"))"#)]);
+
+    let (_, mut warnings) = validate_grammar_with_warnings(grammar).expect("validate");
+    assert_eq!(warnings.len(), 1);
+    test_util::check_norm_err(
+        r##"the terminal `"if"` can never be matched, because the terminal `r#"\[a-z\]\+"#`"##,
+        r#"                                          ~~~~                  "#,
+        warnings.remove(0),
+    );
+}
+
 /// Test that using the **exact same regular expression** twice is
 /// forbidden, even across multiple levels of the match expression.
 /// No good reason to do that.
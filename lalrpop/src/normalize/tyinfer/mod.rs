@@ -1,7 +1,7 @@
 use super::norm_util::{self, AlternativeAction, Symbols};
 use super::{NormError, NormResult};
 
-use crate::grammar::consts::{ERROR, LOCATION};
+use crate::grammar::consts::{ERROR, INTO_ERROR, LOCATION};
 use crate::grammar::parse_tree::{
     ActionKind, Alternative, Grammar, GrammarItem, Lifetime, MatchMapping, NonterminalData,
     NonterminalString, Path, Span, SymbolKind, TypeParameter, TypeRef,
@@ -75,14 +75,14 @@ impl<'grammar> TypeInferencer<'grammar> {
 
         // Determine location type and enum type. If using an internal
         // token, that's specified by us, not user.
-        if let Some(intern_token) = grammar.intern_token() {
+        let mut types = if let Some(intern_token) = grammar.intern_token() {
             let loc_type = // usize
                 TypeRepr::usize();
             let input_str = // &'input str
                 TypeRepr::Ref {
                     lifetime: Some(Lifetime::input()),
                     mutable: false,
-                    referent: Box::new(TypeRepr::str())
+                    referent: Box::new(TypeRepr::input_text(intern_token.bytes))
                 };
             let enum_type = // Token<'input>
                 TypeRepr::Nominal(NominalTypeRepr {
@@ -141,7 +141,18 @@ impl<'grammar> TypeInferencer<'grammar> {
             }
 
             types
+        };
+
+        let into_error_type = opt_extern_token.and_then(|extern_token| {
+            extern_token
+                .associated_type(Atom::from(INTO_ERROR))
+                .map(|tr| tr.type_ref.type_repr())
+        });
+        if let Some(into_error_type) = into_error_type {
+            types.set_into_error_type(into_error_type);
         }
+
+        types
     }
 
     fn infer_types(mut self) -> NormResult<Types> {
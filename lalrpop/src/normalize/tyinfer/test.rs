@@ -4,6 +4,7 @@ use crate::normalize::macro_expand::expand_macros;
 use crate::normalize::token_check;
 use crate::normalize::tyinfer::infer_types;
 use crate::parser;
+use crate::session::Session;
 use string_cache::DefaultAtom as Atom;
 
 fn type_repr(s: &str) -> TypeRepr {
@@ -14,7 +15,7 @@ fn type_repr(s: &str) -> TypeRepr {
 fn compare(g1: &str, expected: Vec<(&'static str, &'static str)>) {
     let grammar = parser::parse_grammar(g1).unwrap();
     let grammar = expand_macros(grammar).unwrap();
-    let grammar = token_check::validate(grammar).unwrap();
+    let grammar = token_check::validate(&Session::test(), grammar).unwrap();
     let types = infer_types(&grammar).unwrap();
 
     println!("types table: {:?}", types);
@@ -137,6 +138,26 @@ grammar;
     )
 }
 
+#[test]
+fn test_grouped_star_plus_question() {
+    compare(
+        r#"
+grammar;
+    extern { enum Tok { "Hi" => Hi(..), "Ho" => Ho(..) } }
+    A = ("Hi" "Ho")*;
+    B = ("Hi" "Ho")+;
+    C = ("Hi" "Ho")?;
+    D = ("Hi" <"Ho">)*;
+"#,
+        vec![
+            ("A", "::std::vec::Vec<(Tok, Tok)>"),
+            ("B", "::std::vec::Vec<(Tok, Tok)>"),
+            ("C", "::std::option::Option<(Tok, Tok)>"),
+            ("D", "::std::vec::Vec<Tok>"),
+        ],
+    )
+}
+
 #[test]
 fn test_lookahead() {
     compare(
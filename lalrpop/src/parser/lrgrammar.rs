@@ -1,5 +1,5 @@
-// auto-generated: "lalrpop 0.19.1"
-// sha256: 7429c0f7a93c938035ee9983794eb7106fc6980f07c91a8ea4971f93ee6af
+// auto-generated: "lalrpop 0.20.0"
+// sha256: f915e7d184c96ae7859b54e9a34532548bded18787b2f69575febbda97868
 use string_cache::DefaultAtom as Atom;
 use grammar::parse_tree::*;
 use grammar::pattern::*;
@@ -132,1192 +132,1232 @@ Variant95(Visibility),
 }
 const ___ACTION: &[i16] = &[
 // State 0
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,3,4,5,6,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,2,3,4,5,6,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 1
-0,0,0,281,282,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,0,0,283,0,0,0,0,
+0,0,0,290,291,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,10,0,0,0,0,0,292,0,0,0,0,
 // State 2
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 3
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,291,0,0,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,294,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,302,0,0,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,12,0,0,
 // State 4
-0,0,0,0,0,0,13,0,0,0,0,0,299,0,14,0,15,0,0,0,0,0,0,0,0,0,0,300,0,301,0,302,0,0,0,0,0,0,0,0,0,303,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,14,0,0,0,0,0,309,0,15,0,16,0,0,0,0,0,0,0,0,0,0,0,310,0,311,0,312,0,0,0,0,0,0,0,0,0,313,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 5
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 6
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,21,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,22,0,0,0,0,0,0,0,0,0,0,
 // State 7
-0,0,0,281,282,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,24,0,0,0,0,0,283,0,0,0,0,
+0,0,0,290,291,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,25,0,0,0,0,0,292,0,0,0,0,
 // State 8
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,26,0,0,0,0,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,27,0,0,0,0,0,292,0,0,0,0,
 // State 9
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,31,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,32,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 10
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,-146,0,0,0,0,0,0,0,0,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,316,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,-153,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,326,0,0,0,0,0,0,0,0,0,0,0,
 // State 11
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,328,0,
 // State 12
-0,0,0,0,0,0,13,-158,0,0,0,0,299,0,14,0,15,0,0,0,0,0,0,0,0,0,0,300,0,301,0,302,0,0,0,0,0,0,0,0,0,303,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 13
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,14,-165,0,0,0,0,309,0,15,0,16,0,0,0,0,0,0,0,0,0,0,0,310,0,311,0,312,0,0,0,0,0,0,0,0,0,313,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 14
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 15
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,0,0,0,0,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 16
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,43,0,0,0,0,0,0,0,
+341,0,0,0,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,0,0,0,0,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 17
-0,0,0,16,0,17,18,-174,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,44,0,0,0,0,0,0,0,
 // State 18
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,-181,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 19
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,341,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 20
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,47,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,352,0,0,0,0,0,0,0,0,0,0,0,
 // State 21
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,48,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,48,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 22
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,50,0,0,0,0,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,49,0,0,0,0,0,0,0,0,0,0,
 // State 23
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,53,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,51,0,0,0,0,0,292,0,0,0,0,
 // State 24
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,54,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,54,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 25
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,57,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,55,0,0,0,0,0,0,0,0,0,0,
 // State 26
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,58,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 27
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,59,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 28
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,61,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,60,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 29
-0,0,0,0,0,0,0,-142,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,62,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 30
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,-149,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 31
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-170,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 32
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,-148,0,0,0,0,0,0,0,0,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,316,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-177,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 33
-0,0,0,0,0,0,13,-158,0,0,0,0,299,0,14,0,15,0,0,0,0,0,0,0,0,0,0,300,0,301,0,302,0,0,0,0,0,0,0,0,0,303,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,-155,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,326,0,0,0,0,0,0,0,0,0,0,0,
 // State 34
-0,0,0,0,0,0,0,0,0,0,0,0,369,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,370,0,
+0,0,0,0,0,0,14,-165,0,0,0,0,309,0,15,0,16,0,0,0,0,0,0,0,0,0,0,0,310,0,311,0,312,0,0,0,0,0,0,0,0,0,313,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 35
-0,0,0,0,0,0,13,-160,0,0,0,0,299,0,14,0,15,0,0,0,0,0,0,0,0,0,0,300,0,301,0,302,0,0,0,0,0,0,0,0,0,303,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,381,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,382,0,
 // State 36
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,14,-167,0,0,0,0,309,0,15,0,16,0,0,0,0,0,0,0,0,0,0,0,310,0,311,0,312,0,0,0,0,0,0,0,0,0,313,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 37
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,-178,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 38
--429,0,0,-429,0,0,-429,-429,382,383,-429,0,0,0,0,-429,-429,0,0,-429,-429,-429,-429,-429,384,-429,-429,0,-429,-429,0,-429,-429,0,0,0,0,0,-429,0,0,0,0,0,0,0,0,0,-429,0,0,0,0,0,0,0,-429,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,-185,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 39
-330,0,0,0,0,0,40,-191,0,0,0,0,0,0,0,0,41,0,0,0,0,0,0,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-441,0,0,-441,0,0,-441,-441,394,395,-441,0,0,0,0,-441,-441,0,0,-441,-441,-441,-441,-441,396,-441,-441,-441,0,-441,-441,0,-441,-441,0,0,0,0,0,-441,0,0,0,0,0,0,0,0,0,-441,0,0,0,0,0,0,0,-441,0,
 // State 40
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,331,332,0,333,388,0,389,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,76,0,0,0,0,0,0,0,
+341,0,0,0,0,0,41,-199,0,0,0,0,0,0,0,0,42,0,0,0,0,0,0,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 41
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,77,0,0,0,0,0,0,0,
+341,0,0,0,0,0,41,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,342,343,301,0,344,400,0,401,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,77,0,0,0,0,0,0,0,
 // State 42
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,78,0,0,0,0,0,0,0,
 // State 43
-0,0,0,16,0,17,18,-176,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 44
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,81,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,17,0,18,19,-183,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 45
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,83,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,82,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 46
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,84,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 47
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,88,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 48
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,89,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,89,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 49
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,92,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,90,0,0,0,0,0,0,0,0,0,0,
 // State 50
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,93,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 51
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,95,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,94,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 52
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,96,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 53
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,100,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 54
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,101,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,101,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 55
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,103,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,102,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 56
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,104,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 57
-0,0,0,0,0,0,106,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,406,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 58
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,107,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,418,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 59
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,109,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 60
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,110,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 61
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 62
-0,0,0,0,0,0,0,-144,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 63
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,358,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-151,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 64
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,369,0,0,0,0,0,0,
 // State 65
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,416,417,0,335,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 66
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-172,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,428,429,0,346,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 67
-0,0,0,0,0,0,0,0,0,0,-406,0,0,0,0,-406,0,0,0,0,0,0,0,0,0,0,0,0,0,0,315,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-179,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 68
-0,0,0,0,0,0,0,0,0,0,-410,0,0,0,14,-410,0,0,0,0,0,0,0,0,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-417,0,0,0,0,-417,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,325,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 69
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-170,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-421,0,0,0,15,-421,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,441,0,0,0,0,0,0,0,0,0,0,0,
 // State 70
-0,0,0,0,0,0,0,0,0,0,0,0,433,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,434,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-177,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 71
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,-180,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,445,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,446,0,
 // State 72
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,0,0,0,0,-162,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,-187,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 73
-330,0,0,0,0,0,40,-192,0,0,0,0,0,0,0,0,41,0,0,0,0,0,0,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+341,0,0,0,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,0,0,0,0,-169,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 74
-0,0,0,0,0,0,0,0,382,383,0,0,0,0,0,0,0,0,0,0,0,0,0,446,384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+341,0,0,0,0,0,41,-200,0,0,0,0,0,0,0,0,42,0,0,0,0,0,0,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 75
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,394,395,0,0,0,0,0,0,0,0,0,0,0,0,0,458,396,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 76
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 77
-0,0,0,16,0,17,18,-174,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 78
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,-178,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,-181,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 79
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-170,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,-185,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 80
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-177,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 81
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,125,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 82
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,126,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 83
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 84
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 85
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,129,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 86
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,131,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,130,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 87
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,132,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 88
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,136,32,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 89
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,137,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,137,33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 90
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,139,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,138,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 91
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,140,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 92
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 93
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,144,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 94
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,145,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 95
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 96
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 97
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,148,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 98
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,150,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,149,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 99
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,151,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 100
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 101
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,155,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 102
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,156,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 103
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 104
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 105
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 106
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 107
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 108
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 109
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 110
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 111
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 112
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 113
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,416,417,0,335,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 114
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,169,0,0,0,0,0,0,0,170,0,0,0,469,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,428,429,0,346,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 115
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,292,0,0,0,0,0,293,0,0,475,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-150,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,170,0,0,0,0,0,0,0,171,0,0,0,481,0,
 // State 116
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,0,0,0,303,0,0,0,0,0,304,0,0,487,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-157,0,
 // State 117
-0,0,0,0,0,0,0,0,0,0,-408,0,0,0,0,-408,0,0,0,0,0,0,0,0,0,0,0,0,0,0,315,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 118
-0,0,0,0,0,0,0,0,0,0,-412,0,0,0,14,-412,0,0,0,0,0,0,0,0,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-419,0,0,0,0,-419,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,325,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 119
-0,0,0,0,0,0,13,0,0,0,0,0,299,0,14,0,15,0,0,0,0,0,0,0,0,0,0,300,0,301,0,302,0,0,0,0,0,0,0,0,0,303,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-423,0,0,0,15,-423,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,441,0,0,0,0,0,0,0,0,0,0,0,
 // State 120
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,0,0,0,0,-164,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,14,0,0,0,0,0,309,0,15,0,16,0,0,0,0,0,0,0,0,0,0,0,310,0,311,0,312,0,0,0,0,0,0,0,0,0,313,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 121
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+341,0,0,0,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,0,0,0,0,-171,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 122
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+341,0,0,0,0,0,41,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 123
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 124
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 125
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 126
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 127
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 128
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 129
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,185,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 130
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,187,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 131
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 132
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 133
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,189,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 134
-0,0,0,0,0,0,30,0,0,0,0,0,0,0,0,191,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,191,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 135
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,31,0,0,0,0,0,0,0,0,193,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 136
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 137
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,196,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 138
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,198,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 139
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 140
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 141
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 142
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 143
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 144
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 145
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 146
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 147
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 148
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,206,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 149
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,208,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 150
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 151
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 152
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 153
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 154
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 155
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 156
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 157
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 158
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 159
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 160
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 161
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 162
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,292,0,0,0,0,0,293,0,0,475,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-150,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 163
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-154,0,0,0,0,0,417,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,0,0,0,303,0,0,0,0,0,304,0,0,487,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-157,0,
 // State 164
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-161,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 165
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,505,506,507,508,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,219,0,0,0,0,0,0,220,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 166
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,169,0,0,0,0,0,0,0,170,0,0,0,510,0,
+341,0,0,290,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,222,0,0,0,0,0,0,223,0,0,
 // State 167
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,170,0,0,0,511,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,170,0,0,0,0,0,0,0,171,0,0,0,522,0,
 // State 168
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,171,0,0,0,523,0,
 // State 169
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 170
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,292,0,0,0,0,0,293,0,0,475,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-152,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 171
-0,0,0,16,0,17,18,-174,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,0,0,0,303,0,0,0,0,0,304,0,0,487,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-159,0,
 // State 172
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,-166,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,0,0,0,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 173
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-170,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,-181,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 174
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,-173,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 175
-0,0,0,0,0,0,0,0,382,383,0,0,0,0,0,0,0,0,0,0,0,0,0,530,384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-177,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 176
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 177
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,394,395,0,0,0,0,0,0,0,0,0,0,0,0,0,543,396,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 178
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+341,0,0,0,0,0,41,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 179
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 180
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 181
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 182
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 183
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 184
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 185
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 186
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 187
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 188
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 189
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,233,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 190
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 191
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,236,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,11,0,0,0,
 // State 192
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 193
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 194
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 195
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 196
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 197
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 198
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 199
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 200
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 201
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 202
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 203
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 204
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 205
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 206
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 207
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 208
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 209
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 210
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 211
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 212
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 213
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 214
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 215
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,505,506,507,508,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,219,0,0,0,0,0,0,220,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 216
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-156,0,0,0,0,0,417,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 217
-330,0,0,0,0,0,40,0,0,0,-111,0,0,0,0,-111,41,0,0,505,506,507,508,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,248,0,0,0,0,0,0,0,-111,0,
+341,0,0,290,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,222,0,0,0,0,0,0,223,0,0,
 // State 218
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,417,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-163,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 219
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,505,506,507,508,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,219,0,0,0,0,0,0,0,-134,0,
+341,0,0,290,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,252,0,0,0,0,0,0,0,0,0,
 // State 220
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,170,0,0,0,544,0,
+341,0,0,0,0,0,41,0,0,0,-114,0,0,0,0,-114,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,253,0,0,0,0,0,0,0,-114,0,
 // State 221
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,170,0,0,0,545,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 222
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,-168,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+341,0,0,290,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,222,0,0,0,0,0,0,0,-141,0,
 // State 223
-0,0,0,0,0,0,0,0,382,383,0,0,0,0,0,0,0,0,0,0,0,0,0,551,384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,171,0,0,0,558,0,
 // State 224
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,171,0,0,0,559,0,
 // State 225
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,-175,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 226
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,394,395,0,0,0,0,0,0,0,0,0,0,0,0,0,566,396,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 227
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 228
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 229
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 230
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 231
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 232
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 233
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 234
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 235
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 236
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 237
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 238
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 239
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 240
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 241
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 242
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 243
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 244
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 245
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 246
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,505,506,507,508,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,219,0,0,0,0,0,0,220,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 247
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,417,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 248
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,505,506,507,508,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 249
-0,557,558,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,559,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,560,
+341,0,0,290,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,222,0,0,0,0,0,0,223,0,0,
 // State 250
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,505,506,507,508,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,219,0,0,0,0,0,0,0,-136,0,
+341,0,0,0,0,0,41,0,0,0,-115,0,0,0,0,-115,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,272,0,0,0,0,0,0,0,-115,0,
 // State 251
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,170,0,0,0,564,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 252
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,291,0,0,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-138,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 253
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,517,518,519,520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 254
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,573,574,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,575,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,576,
 // State 255
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+341,0,0,290,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,222,0,0,0,0,0,0,0,-143,0,
 // State 256
-0,0,0,0,0,0,0,0,0,0,-410,0,0,0,14,-410,0,0,0,0,0,0,0,0,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,0,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,171,0,0,0,580,0,
 // State 257
-0,0,0,16,0,17,18,-174,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,302,0,0,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-145,0,
 // State 258
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 259
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 260
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 261
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-421,0,0,0,15,-421,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,0,0,0,0,0,0,0,441,0,0,0,0,0,0,0,0,0,0,0,
 // State 262
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,17,0,18,19,-181,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 263
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 264
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 265
-330,0,0,0,0,0,40,0,0,0,0,0,0,0,0,0,41,0,0,505,506,507,508,0,0,331,332,0,333,334,0,335,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,219,0,0,0,0,0,0,220,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 266
-0,0,0,0,0,0,0,0,0,0,-109,0,0,0,0,-109,0,0,0,505,506,507,508,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-109,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 267
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 268
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,291,0,0,292,0,0,0,0,0,293,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-140,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 269
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 270
-0,0,0,281,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-503,-503,0,-503,0,0,0,0,0,0,0,0,0,0,0,0,0,356,0,0,0,357,0,358,0,283,0,0,0,0,
+341,0,0,290,0,0,41,0,0,0,0,0,0,0,0,0,42,0,0,517,518,519,520,0,0,342,343,301,0,344,345,0,346,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,222,0,0,0,0,0,0,223,0,0,
 // State 271
-0,0,0,16,0,17,18,-174,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,429,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 272
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,-166,0,0,0,0,0,301,315,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,517,518,519,520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 273
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-110,0,0,0,0,-110,0,0,0,517,518,519,520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-110,0,
 // State 274
-0,0,0,16,0,17,18,0,0,0,0,0,0,0,14,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,302,0,0,0,0,0,0,0,19,0,0,20,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 275
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,302,0,0,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-147,0,
 // State 276
-0,0,0,-123,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-123,-123,0,-123,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-123,0,0,0,-123,0,0,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,301,0,0,302,0,0,303,0,0,0,0,0,304,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 277
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 278
-0,0,0,-423,-423,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-423,0,0,0,0,0,-423,0,0,0,0,
+0,0,0,290,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-515,-515,0,-515,0,0,0,0,0,0,0,0,0,0,0,0,0,367,0,0,0,368,0,369,0,292,0,0,0,0,
 // State 279
-0,0,0,-499,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-499,0,0,0,0,0,-499,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-111,0,0,0,0,-111,0,0,0,517,518,519,520,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-111,0,
 // State 280
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,27,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,-181,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 281
-0,0,0,-420,-420,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-420,0,0,0,0,0,-420,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,-173,0,0,0,0,0,0,311,325,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 282
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,310,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 283
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,17,0,18,19,0,0,0,0,0,0,0,15,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,311,0,312,0,0,0,0,0,0,0,20,0,0,21,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 284
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 285
--415,0,0,-415,0,0,-415,-415,-415,-415,-415,0,0,0,0,-415,-415,0,0,-415,-415,-415,-415,-415,-415,-415,-415,0,-415,-415,0,-415,-415,0,0,0,0,0,-415,0,0,0,0,0,0,0,0,0,-415,0,0,0,0,0,0,0,-415,0,
+-129,0,0,-129,0,0,-129,0,0,0,0,0,0,0,0,0,-129,0,0,-129,-129,-129,-129,0,0,-129,-129,-129,0,-129,-129,0,-129,-129,0,0,0,0,0,-129,0,0,0,0,0,0,0,0,-129,-129,0,0,-129,0,0,0,0,0,0,
 // State 286
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-447,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 287
--414,0,0,-414,0,0,-414,-414,-414,-414,-414,0,0,0,0,-414,-414,0,0,-414,-414,-414,-414,-414,-414,-414,-414,0,-414,-414,0,-414,-414,0,0,0,0,0,-414,0,0,0,0,0,0,0,0,0,-414,0,0,0,0,0,0,0,-414,0,
+0,0,0,-435,-435,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-435,0,0,0,0,0,-435,0,0,0,0,
 // State 288
--413,0,0,-413,0,0,-413,-413,-413,-413,-413,0,0,0,0,-413,-413,0,0,-413,-413,-413,-413,-413,-413,-413,-413,0,-413,-413,0,-413,-413,0,0,0,0,0,-413,0,0,0,0,0,0,0,0,0,-413,0,0,0,0,0,0,0,-413,0,
+0,0,0,-511,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-511,0,0,0,0,0,-511,0,0,0,0,
 // State 289
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,28,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 290
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-448,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-432,-432,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-432,0,0,0,0,0,-432,0,0,0,0,
 // State 291
--416,0,0,-416,0,0,-416,-416,-416,-416,-416,0,0,0,0,-416,-416,0,0,-416,-416,-416,-416,-416,-416,-416,-416,0,-416,-416,0,-416,-416,0,0,0,0,0,-416,0,0,0,0,0,0,0,0,0,-416,0,0,0,0,0,0,0,-416,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,320,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 292
--425,0,0,-425,0,0,-425,-425,-425,-425,-425,0,0,0,0,-425,-425,0,0,-425,-425,-425,-425,-425,-425,-425,-425,0,-425,-425,0,-425,-425,0,0,0,0,0,-425,0,0,0,0,0,0,0,0,0,-425,0,0,0,0,0,0,0,-425,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 293
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,317,0,
+-426,0,0,-426,0,0,-426,-426,-426,-426,-426,0,0,0,0,-426,-426,0,0,-426,-426,-426,-426,-426,-426,-426,-426,-426,0,-426,-426,0,-426,-426,0,0,0,0,0,-426,0,0,0,0,0,0,0,0,0,-426,0,0,0,0,0,0,0,-426,0,
 // State 294
-0,0,0,0,0,0,-387,-387,0,-387,-387,0,0,-387,319,-387,-387,-387,0,0,0,0,0,-387,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-387,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-387,-387,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 295
-0,0,0,0,0,0,34,-404,0,0,-404,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,35,-404,0,
+-427,0,0,-427,0,0,-427,-427,-427,-427,-427,0,0,0,0,-427,-427,0,0,-427,-427,-427,-427,-427,-427,-427,-427,-427,0,-427,-427,0,-427,-427,0,0,0,0,0,-427,0,0,0,0,0,0,0,0,0,-427,0,0,0,0,0,0,0,-427,0,
 // State 296
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-459,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 297
-0,0,0,0,0,0,0,-389,0,0,-389,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-389,0,
+-425,0,0,-425,0,0,-425,-425,-425,-425,-425,0,0,0,0,-425,-425,0,0,-425,-425,-425,-425,-425,-425,-425,-425,-425,0,-425,-425,0,-425,-425,0,0,0,0,0,-425,0,0,0,0,0,0,0,0,0,-425,0,0,0,0,0,0,0,-425,0,
 // State 298
-0,0,0,0,0,0,0,-400,0,0,-400,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-400,0,
+-424,0,0,-424,0,0,-424,-424,-424,-424,-424,0,0,0,0,-424,-424,0,0,-424,-424,-424,-424,-424,-424,-424,-424,-424,0,-424,-424,0,-424,-424,0,0,0,0,0,-424,0,0,0,0,0,0,0,0,0,-424,0,0,0,0,0,0,0,-424,0,
 // State 299
-0,0,0,0,0,0,0,-403,0,0,-403,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-403,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 300
-0,0,0,0,0,0,-357,-357,0,-357,-357,0,0,-357,-357,-357,-357,-357,0,0,0,0,0,-357,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-357,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-357,-357,0,
+-139,0,0,-139,0,0,-139,-139,-139,-139,-139,0,0,0,0,-139,-139,0,0,-139,-139,-139,-139,-139,-139,-139,-139,-139,0,-139,-139,0,-139,-139,0,0,0,0,0,-139,0,0,0,0,0,0,0,0,0,-139,0,0,0,0,0,0,0,-139,0,
 // State 301
-0,0,0,0,0,0,-358,-358,0,-358,-358,0,0,-358,-358,-358,-358,-358,0,0,0,0,0,-358,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-358,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-358,-358,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-460,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 302
-0,0,0,0,0,0,0,-399,0,0,-399,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-399,0,
+-428,0,0,-428,0,0,-428,-428,-428,-428,-428,0,0,0,0,-428,-428,0,0,-428,-428,-428,-428,-428,-428,-428,-428,-428,0,-428,-428,0,-428,-428,0,0,0,0,0,-428,0,0,0,0,0,0,0,0,0,-428,0,0,0,0,0,0,0,-428,0,
 // State 303
-0,0,0,0,0,0,0,-483,0,-483,-483,0,0,-483,0,-483,38,-483,0,0,0,0,0,-483,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-483,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-483,0,0,
+-437,0,0,-437,0,0,-437,-437,-437,-437,-437,0,0,0,0,-437,-437,0,0,-437,-437,-437,-437,-437,-437,-437,-437,-437,0,-437,-437,0,-437,-437,0,0,0,0,0,-437,0,0,0,0,0,0,0,0,0,-437,0,0,0,0,0,0,0,-437,0,
 // State 304
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,-398,-398,0,-398,-398,0,0,-398,330,-398,-398,-398,0,0,0,0,0,-398,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-398,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-398,-398,0,
 // State 305
-0,0,0,-124,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-124,-124,0,-124,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-124,0,0,0,-124,0,0,0,0,0,0,
+0,0,0,0,0,0,35,-415,0,0,-415,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,36,-415,0,
 // State 306
-0,0,0,-424,-424,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-424,0,0,0,0,0,-424,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 307
-0,0,0,-500,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-500,0,0,0,0,0,-500,0,0,0,0,
+0,0,0,0,0,0,0,-400,0,0,-400,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-400,0,
 // State 308
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,62,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-411,0,0,-411,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-411,0,
 // State 309
-0,0,0,-496,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-496,-496,0,-496,0,0,0,0,0,0,0,0,0,0,0,0,0,-496,0,-496,0,-496,0,-496,0,-496,0,0,0,0,
+0,0,0,0,0,0,0,-414,0,0,-414,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-414,0,
 // State 310
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-354,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,-365,-365,0,-365,-365,0,0,-365,-365,-365,-365,-365,0,0,0,0,0,-365,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-365,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-365,-365,0,
 // State 311
-0,0,0,0,0,0,0,0,0,0,364,0,0,0,0,-145,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,-366,-366,0,-366,-366,0,0,-366,-366,-366,-366,-366,0,0,0,0,0,-366,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-366,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-366,-366,0,
 // State 312
-0,0,0,0,0,0,0,0,0,0,0,0,0,68,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-410,0,0,-410,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-410,0,
 // State 313
-0,0,0,0,0,0,0,0,0,0,0,0,0,69,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-495,0,-495,-495,0,0,-495,0,-495,39,-495,0,0,0,0,0,-495,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-495,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-495,0,0,
 // State 314
-0,0,0,-359,0,-359,-359,0,0,-359,-359,0,0,-359,-359,-359,0,0,0,0,0,0,0,-359,0,0,0,0,0,-359,0,-359,0,0,0,0,0,0,0,-359,0,0,-359,0,0,0,0,0,0,0,-359,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 315
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,70,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-130,0,0,-130,0,0,-130,0,0,0,0,0,0,0,0,0,-130,0,0,-130,-130,-130,-130,0,0,-130,-130,-130,0,-130,-130,0,-130,-130,0,0,0,0,0,-130,0,0,0,0,0,0,0,0,-130,-130,0,0,-130,0,0,0,0,0,0,
 // State 316
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-436,-436,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-436,0,0,0,0,0,-436,0,0,0,0,
 // State 317
-0,0,0,0,0,0,-388,-388,0,-388,-388,0,0,-388,365,-388,-388,-388,0,0,0,0,0,-388,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-388,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-388,-388,0,
+0,0,0,-512,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-512,0,0,0,0,0,-512,0,0,0,0,
 // State 318
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-48,0,-48,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,63,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 319
-0,0,0,0,0,0,0,372,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-508,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-508,-508,0,-508,0,0,0,0,0,0,0,0,0,0,0,0,0,-508,0,-508,0,-508,0,-508,0,-508,0,0,0,0,
 // State 320
-0,0,0,0,0,0,0,-157,0,0,373,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-362,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 321
-0,0,0,0,0,0,-385,-385,0,-385,-385,0,0,-385,319,-385,-385,-385,0,0,0,0,0,-385,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-385,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-385,-385,0,
+0,0,0,0,0,0,0,0,0,0,375,0,0,0,0,-152,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 322
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,375,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,69,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 323
--442,0,0,-442,0,0,-442,-442,-442,-442,-442,0,0,0,0,-442,-442,0,0,-442,-442,-442,-442,-442,-442,-442,-442,0,-442,-442,0,-442,-442,0,0,0,0,0,-442,0,0,0,0,0,0,0,0,0,-442,0,0,0,0,0,0,0,-442,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,70,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 324
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,73,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-367,0,-367,-367,0,0,-367,-367,0,0,-367,-367,-367,0,0,0,0,0,0,0,-367,0,0,0,0,0,0,-367,0,-367,0,0,0,0,0,0,0,-367,0,0,-367,0,0,0,0,0,0,0,-367,0,0,0,0,0,0,0,
 // State 325
--440,0,0,-440,0,0,-440,-440,-440,-440,-440,0,0,0,0,-440,-440,0,0,-440,-440,-440,-440,-440,-440,-440,-440,0,-440,-440,0,-440,-440,0,0,0,0,0,-440,0,0,0,0,0,0,0,0,0,-440,0,0,0,0,0,0,0,-440,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,71,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 326
-0,0,0,380,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,376,0,
 // State 327
--434,0,0,-434,0,0,-434,-434,-434,-434,-434,0,0,0,0,-434,-434,0,0,-434,-434,-434,-434,-434,-434,-434,-434,0,-434,-434,0,-434,-434,0,0,0,0,0,-434,0,0,0,0,0,0,0,0,0,-434,0,0,0,0,0,0,0,-434,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 328
--436,0,0,-436,0,0,-436,-436,-436,-436,-436,0,0,0,0,-436,-436,0,0,-436,-436,-436,-436,-436,-436,-436,-436,0,-436,-436,0,-436,-436,0,0,0,0,0,-436,0,0,0,0,0,0,0,0,0,-436,0,0,0,0,0,0,0,-436,0,
+0,0,0,0,0,0,-399,-399,0,-399,-399,0,0,-399,377,-399,-399,-399,0,0,0,0,0,-399,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-399,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-399,-399,0,
 // State 329
--446,0,0,-446,0,0,-446,-446,-446,-446,-446,0,0,0,0,-446,-446,0,0,-446,-446,-446,-446,-446,-446,-446,-446,0,-446,-446,0,-446,-446,0,0,0,0,0,-446,0,0,0,0,0,0,0,0,0,-446,0,0,0,0,0,0,0,-446,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-48,0,-48,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 330
--444,0,0,-444,0,0,-444,-444,-444,-444,-444,0,0,0,0,-444,-444,0,0,-444,-444,-444,-444,-444,-444,-444,-444,0,-444,-444,0,-444,-444,0,0,0,0,0,-444,0,0,0,0,0,0,0,0,0,-444,0,0,0,0,0,0,0,-444,0,
+0,0,0,0,0,0,0,384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 331
--445,0,0,-445,0,0,-445,-445,-445,-445,-445,0,0,0,0,-445,-445,0,0,-445,-445,-445,-445,-445,-445,-445,-445,0,-445,-445,0,-445,-445,0,0,0,0,0,-445,0,0,0,0,0,0,0,0,0,-445,0,0,0,0,0,0,0,-445,0,
+0,0,0,0,0,0,0,-164,0,0,385,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 332
--190,0,0,-190,0,0,-190,-190,-190,-190,-190,0,0,0,0,-190,-190,0,0,-190,-190,-190,-190,-190,-190,-190,-190,0,-190,-190,0,-190,-190,0,0,0,0,0,-190,0,0,0,0,0,0,0,0,0,-190,0,0,0,0,0,0,0,-190,0,
+0,0,0,0,0,0,-396,-396,0,-396,-396,0,0,-396,330,-396,-396,-396,0,0,0,0,0,-396,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-396,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-396,-396,0,
 // State 333
--441,0,0,-441,0,0,-441,-441,-441,-441,-441,0,0,0,0,-441,-441,0,0,-441,-441,-441,-441,-441,-441,-441,-441,0,-441,-441,0,-441,-441,0,0,0,0,0,-441,0,0,0,0,0,0,0,0,0,-441,0,0,0,0,0,0,0,-441,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,387,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 334
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-362,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-454,0,0,-454,0,0,-454,-454,-454,-454,-454,0,0,0,0,-454,-454,0,0,-454,-454,-454,-454,-454,-454,-454,-454,-454,0,-454,-454,0,-454,-454,0,0,0,0,0,-454,0,0,0,0,0,0,0,0,0,-454,0,0,0,0,0,0,0,-454,0,
 // State 335
-0,0,0,0,0,0,0,-481,0,-481,-481,0,0,-481,0,-481,0,-481,0,0,0,0,0,-481,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-481,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-481,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,74,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 336
-0,0,0,0,0,0,0,393,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-452,0,0,-452,0,0,-452,-452,-452,-452,-452,0,0,0,0,-452,-452,0,0,-452,-452,-452,-452,-452,-452,-452,-452,-452,0,-452,-452,0,-452,-452,0,0,0,0,0,-452,0,0,0,0,0,0,0,0,0,-452,0,0,0,0,0,0,0,-452,0,
 // State 337
-0,0,0,0,0,0,0,-173,0,0,394,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,392,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 338
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,395,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-446,0,0,-446,0,0,-446,-446,-446,-446,-446,0,0,0,0,-446,-446,0,0,-446,-446,-446,-446,-446,-446,-446,-446,-446,0,-446,-446,0,-446,-446,0,0,0,0,0,-446,0,0,0,0,0,0,0,0,0,-446,0,0,0,0,0,0,0,-446,0,
 // State 339
-0,0,0,0,0,0,78,-485,0,-485,-485,0,0,-485,0,-485,79,-485,0,0,0,0,0,-485,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-485,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-485,0,0,
+-448,0,0,-448,0,0,-448,-448,-448,-448,-448,0,0,0,0,-448,-448,0,0,-448,-448,-448,-448,-448,-448,-448,-448,-448,0,-448,-448,0,-448,-448,0,0,0,0,0,-448,0,0,0,0,0,0,0,0,0,-448,0,0,0,0,0,0,0,-448,0,
 // State 340
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,80,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-458,0,0,-458,0,0,-458,-458,-458,-458,-458,0,0,0,0,-458,-458,0,0,-458,-458,-458,-458,-458,-458,-458,-458,-458,0,-458,-458,0,-458,-458,0,0,0,0,0,-458,0,0,0,0,0,0,0,0,0,-458,0,0,0,0,0,0,0,-458,0,
 // State 341
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,84,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-456,0,0,-456,0,0,-456,-456,-456,-456,-456,0,0,0,0,-456,-456,0,0,-456,-456,-456,-456,-456,-456,-456,-456,-456,0,-456,-456,0,-456,-456,0,0,0,0,0,-456,0,0,0,0,0,0,0,0,0,-456,0,0,0,0,0,0,0,-456,0,
 // State 342
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,96,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-457,0,0,-457,0,0,-457,-457,-457,-457,-457,0,0,0,0,-457,-457,0,0,-457,-457,-457,-457,-457,-457,-457,-457,-457,0,-457,-457,0,-457,-457,0,0,0,0,0,-457,0,0,0,0,0,0,0,0,0,-457,0,0,0,0,0,0,0,-457,0,
 // State 343
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,104,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-198,0,0,-198,0,0,-198,-198,-198,-198,-198,0,0,0,0,-198,-198,0,0,-198,-198,-198,-198,-198,-198,-198,-198,-198,0,-198,-198,0,-198,-198,0,0,0,0,0,-198,0,0,0,0,0,0,0,0,0,-198,0,0,0,0,0,0,0,-198,0,
 // State 344
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,107,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-453,0,0,-453,0,0,-453,-453,-453,-453,-453,0,0,0,0,-453,-453,0,0,-453,-453,-453,-453,-453,-453,-453,-453,-453,0,-453,-453,0,-453,-453,0,0,0,0,0,-453,0,0,0,0,0,0,0,0,0,-453,0,0,0,0,0,0,0,-453,0,
 // State 345
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,110,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-370,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 346
-0,0,0,0,0,0,0,409,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-493,0,-493,-493,0,0,-493,0,-493,0,-493,0,0,0,0,0,-493,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-493,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-493,0,0,
 // State 347
-0,0,0,0,0,0,0,-141,0,0,410,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,405,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 348
-0,0,0,0,0,0,0,0,0,0,0,0,0,113,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-180,0,0,406,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 349
-0,0,0,-334,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-334,-334,0,-334,0,0,0,0,0,0,0,0,0,0,0,0,0,-334,0,0,0,-334,0,-334,0,-334,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,407,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 350
-0,0,0,-338,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-338,-338,0,-338,0,0,0,0,0,0,0,0,0,0,0,0,0,-338,0,0,0,-338,0,-338,0,-338,0,0,0,0,
+0,0,0,0,0,0,79,-497,0,-497,-497,0,0,-497,0,-497,80,-497,0,0,0,0,0,-497,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-497,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-497,0,0,
 // State 351
-0,0,0,-333,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-333,-333,0,-333,0,0,0,0,0,0,0,0,0,0,0,0,0,-333,0,0,0,-333,0,-333,0,-333,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,81,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 352
-0,0,0,-372,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-372,-372,0,-372,0,0,0,0,0,0,0,0,0,0,0,412,0,-372,0,0,0,-372,0,-372,0,-372,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,85,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 353
-0,0,0,-335,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-335,-335,0,-335,0,0,0,0,0,0,0,0,0,0,0,0,0,-335,0,0,0,-335,0,-335,0,-335,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,97,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 354
-0,0,0,-332,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-332,-332,0,-332,0,0,0,0,0,0,0,0,0,0,0,0,0,-332,0,0,0,-332,0,-332,0,-332,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,105,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 355
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,115,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,108,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 356
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,116,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,111,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 357
-0,0,0,0,0,0,117,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-502,-502,0,-502,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,421,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 358
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,419,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-148,0,0,422,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 359
-0,0,0,0,0,0,0,0,0,0,-472,0,0,0,0,0,0,0,0,0,0,0,0,-472,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,114,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 360
-0,0,0,0,0,0,0,0,0,0,-471,0,0,0,0,0,0,0,0,0,0,0,0,-471,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-342,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-342,-342,0,-342,0,0,0,0,0,0,0,0,0,0,0,0,0,-342,0,0,0,-342,0,-342,0,-342,0,0,0,0,
 // State 361
-0,0,0,0,0,0,0,0,0,0,420,0,0,0,0,0,0,0,0,0,0,0,0,-169,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-346,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-346,-346,0,-346,0,0,0,0,0,0,0,0,0,0,0,0,0,-346,0,0,0,-346,0,-346,0,-346,0,0,0,0,
 // State 362
-0,0,0,0,0,0,0,0,0,0,421,0,0,0,0,-147,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-341,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-341,-341,0,-341,0,0,0,0,0,0,0,0,0,0,0,0,0,-341,0,0,0,-341,0,-341,0,-341,0,0,0,0,
 // State 363
-0,0,0,-43,0,-43,-43,0,0,0,0,0,0,0,-43,-43,0,0,0,0,0,0,0,0,0,0,0,0,0,-43,-43,-43,0,0,0,0,0,0,0,-43,0,0,-43,0,0,0,-43,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-383,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-383,-383,0,-383,0,0,0,0,0,0,0,0,0,0,0,424,0,-383,0,0,0,-383,0,-383,0,-383,0,0,0,0,
 // State 364
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-49,0,-49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-343,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-343,-343,0,-343,0,0,0,0,0,0,0,0,0,0,0,0,0,-343,0,0,0,-343,0,-343,0,-343,0,0,0,0,
 // State 365
-0,0,0,0,0,0,0,431,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-340,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-340,-340,0,-340,0,0,0,0,0,0,0,0,0,0,0,0,0,-340,0,0,0,-340,0,-340,0,-340,0,0,0,0,
 // State 366
-0,0,0,0,0,0,0,0,0,0,435,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,436,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,116,0,0,
 // State 367
-0,0,0,0,0,0,0,0,0,0,0,0,0,120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,117,0,0,
 // State 368
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,437,0,
+0,0,0,0,0,0,118,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-514,-514,0,-514,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 369
-0,0,0,0,0,0,0,-394,0,0,-394,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-394,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,431,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 370
-0,0,0,0,0,0,0,-159,0,0,438,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-484,0,0,0,0,0,0,0,0,0,0,0,0,-484,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 371
-0,0,0,0,0,0,0,-402,0,0,-402,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-402,0,
+0,0,0,0,0,0,0,0,0,0,-483,0,0,0,0,0,0,0,0,0,0,0,0,-483,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 372
-0,0,0,0,0,0,-68,-68,0,0,0,0,-68,0,-68,0,-68,0,0,0,0,0,0,0,0,0,0,-68,0,-68,0,-68,0,0,0,0,0,0,0,0,0,-68,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,432,0,0,0,0,0,0,0,0,0,0,0,0,-176,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 373
-0,0,0,0,0,0,-386,-386,0,-386,-386,0,0,-386,365,-386,-386,-386,0,0,0,0,0,-386,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-386,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-386,-386,0,
+0,0,0,0,0,0,0,0,0,0,433,0,0,0,0,-154,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 374
-0,0,0,0,0,0,0,-401,0,0,-401,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-401,0,
+0,0,0,-43,0,-43,-43,0,0,0,0,0,0,0,-43,-43,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-43,-43,-43,0,0,0,0,0,0,0,-43,0,0,-43,0,0,0,-43,0,0,0,0,0,0,0,0,0,0,0,
 // State 375
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,440,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 376
-0,0,0,0,0,0,0,0,0,0,-493,0,0,0,0,0,0,0,0,0,0,0,0,-493,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-49,0,-49,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 377
-0,0,0,0,0,0,0,0,0,0,-492,0,0,0,0,0,0,0,0,0,0,0,0,-492,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,443,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 378
-0,0,0,0,0,0,0,0,0,0,441,0,0,0,0,0,0,0,0,0,0,0,0,-177,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,447,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,448,0,
 // State 379
-0,0,0,0,0,0,0,-477,0,-477,-477,0,0,-477,0,-477,0,-477,0,0,0,0,0,-477,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-477,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-477,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,121,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 380
--435,0,0,-435,0,0,-435,-435,-435,-435,-435,0,0,0,0,-435,-435,0,0,-435,-435,-435,-435,-435,-435,-435,-435,0,-435,-435,0,-435,-435,0,0,0,0,0,-435,0,0,0,0,0,0,0,0,0,-435,0,0,0,0,0,0,0,-435,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,449,0,
 // State 381
--418,0,0,-418,0,0,-418,-418,-418,-418,-418,0,0,0,0,-418,-418,0,0,-418,-418,-418,-418,-418,-418,-418,-418,0,-418,-418,0,-418,-418,0,0,0,0,0,-418,0,0,0,0,0,0,0,0,0,-418,0,0,0,0,0,0,0,-418,0,
+0,0,0,0,0,0,0,-405,0,0,-405,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-405,0,
 // State 382
--417,0,0,-417,0,0,-417,-417,-417,-417,-417,0,0,0,0,-417,-417,0,0,-417,-417,-417,-417,-417,-417,-417,-417,0,-417,-417,0,-417,-417,0,0,0,0,0,-417,0,0,0,0,0,0,0,0,0,-417,0,0,0,0,0,0,0,-417,0,
+0,0,0,0,0,0,0,-166,0,0,450,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 383
--419,0,0,-419,0,0,-419,-419,-419,-419,-419,0,0,0,0,-419,-419,0,0,-419,-419,-419,-419,-419,-419,-419,-419,0,-419,-419,0,-419,-419,0,0,0,0,0,-419,0,0,0,0,0,0,0,0,0,-419,0,0,0,0,0,0,0,-419,0,
+0,0,0,0,0,0,0,-413,0,0,-413,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-413,0,
 // State 384
-0,0,0,0,0,0,0,444,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,-68,-68,0,0,0,0,-68,0,-68,0,-68,0,0,0,0,0,0,0,0,0,0,0,-68,0,-68,0,-68,0,0,0,0,0,0,0,0,0,-68,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 385
--432,0,0,0,0,0,-432,-432,0,0,-432,0,0,0,0,-432,-432,0,0,-432,-432,-432,-432,0,0,-432,-432,0,-432,-432,0,-432,-432,0,0,0,0,0,-432,0,0,0,0,0,0,0,0,0,-432,0,0,0,0,0,0,0,-432,0,
+0,0,0,0,0,0,-397,-397,0,-397,-397,0,0,-397,377,-397,-397,-397,0,0,0,0,0,-397,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-397,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-397,-397,0,
 // State 386
-0,0,0,0,0,0,0,0,0,0,0,0,0,122,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-412,0,0,-412,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-412,0,
 // State 387
-0,0,0,0,0,0,0,0,-441,-441,0,0,0,-357,0,0,0,0,0,0,0,0,0,-441,-441,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,452,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 388
-0,0,0,0,0,0,0,0,0,0,0,0,0,-358,0,0,-362,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-505,0,0,0,0,0,0,0,0,0,0,0,0,-505,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 389
-0,0,0,0,0,0,0,-480,0,-480,-480,0,0,-480,0,-480,0,-480,0,0,0,0,0,-480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-480,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-480,0,0,
+0,0,0,0,0,0,0,0,0,0,-504,0,0,0,0,0,0,0,0,0,0,0,0,-504,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 390
-0,0,0,0,0,0,0,-479,0,-479,-479,0,0,-479,0,-479,0,-479,0,0,0,0,0,-479,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-479,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-479,0,0,
+0,0,0,0,0,0,0,0,0,0,453,0,0,0,0,0,0,0,0,0,0,0,0,-184,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 391
-0,0,0,0,0,0,0,-175,0,0,449,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-489,0,-489,-489,0,0,-489,0,-489,0,-489,0,0,0,0,0,-489,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-489,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-489,0,0,
 // State 392
-0,0,0,0,0,0,0,-475,0,-475,-475,0,0,-475,0,-475,0,-475,0,0,0,0,0,-475,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-475,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-475,0,0,
+-447,0,0,-447,0,0,-447,-447,-447,-447,-447,0,0,0,0,-447,-447,0,0,-447,-447,-447,-447,-447,-447,-447,-447,-447,0,-447,-447,0,-447,-447,0,0,0,0,0,-447,0,0,0,0,0,0,0,0,0,-447,0,0,0,0,0,0,0,-447,0,
 // State 393
-0,0,0,-93,0,-93,-93,-93,0,0,0,0,0,0,-93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-93,0,-93,0,0,0,0,0,0,0,-93,0,0,-93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-430,0,0,-430,0,0,-430,-430,-430,-430,-430,0,0,0,0,-430,-430,0,0,-430,-430,-430,-430,-430,-430,-430,-430,-430,0,-430,-430,0,-430,-430,0,0,0,0,0,-430,0,0,0,0,0,0,0,0,0,-430,0,0,0,0,0,0,0,-430,0,
 // State 394
-0,0,0,0,0,0,0,-476,0,-476,-476,0,0,-476,0,-476,0,-476,0,0,0,0,0,-476,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-476,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-476,0,0,
+-429,0,0,-429,0,0,-429,-429,-429,-429,-429,0,0,0,0,-429,-429,0,0,-429,-429,-429,-429,-429,-429,-429,-429,-429,0,-429,-429,0,-429,-429,0,0,0,0,0,-429,0,0,0,0,0,0,0,0,0,-429,0,0,0,0,0,0,0,-429,0,
 // State 395
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,123,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-431,0,0,-431,0,0,-431,-431,-431,-431,-431,0,0,0,0,-431,-431,0,0,-431,-431,-431,-431,-431,-431,-431,-431,-431,0,-431,-431,0,-431,-431,0,0,0,0,0,-431,0,0,0,0,0,0,0,0,0,-431,0,0,0,0,0,0,0,-431,0,
 // State 396
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,126,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,456,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 397
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,132,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-444,0,0,0,0,0,-444,-444,0,0,-444,0,0,0,0,-444,-444,0,0,-444,-444,-444,-444,0,0,-444,-444,-444,0,-444,-444,0,-444,-444,0,0,0,0,0,-444,0,0,0,0,0,0,0,0,0,-444,0,0,0,0,0,0,0,-444,0,
 // State 398
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,140,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,123,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 399
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,142,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,-453,-453,0,0,0,-365,0,0,0,0,0,0,0,0,0,-453,-453,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 400
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,145,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,-366,0,0,-370,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 401
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,151,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-492,0,-492,-492,0,0,-492,0,-492,0,-492,0,0,0,0,0,-492,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-492,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-492,0,0,
 // State 402
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,153,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-491,0,-491,-491,0,0,-491,0,-491,0,-491,0,0,0,0,0,-491,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-491,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-491,0,0,
 // State 403
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,156,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-182,0,0,461,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 404
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,463,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-487,0,-487,-487,0,0,-487,0,-487,0,-487,0,0,0,0,0,-487,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-487,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-487,0,0,
 // State 405
-0,0,0,-120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-120,-120,0,-120,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-120,0,0,0,-120,0,0,0,0,0,0,
+0,0,0,-93,0,-93,-93,-93,0,0,0,0,0,0,-93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-93,0,-93,0,0,0,0,0,0,0,-93,0,0,-93,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 406
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,160,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-488,0,-488,-488,0,0,-488,0,-488,0,-488,0,0,0,0,0,-488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-488,0,0,
 // State 407
-0,0,0,0,0,0,0,-143,0,0,465,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,124,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 408
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-343,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-343,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,127,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 409
-0,0,0,0,0,0,0,-38,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-38,0,-38,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,133,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 410
-0,0,0,-339,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-339,-339,0,-339,0,0,0,0,0,0,0,0,0,0,0,0,0,-339,0,0,0,-339,0,-339,0,-339,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,141,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 411
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,163,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,143,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 412
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,164,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,146,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 413
-0,0,0,0,0,0,0,0,0,0,0,0,0,165,0,0,0,166,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,152,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 414
-0,0,0,0,0,0,0,0,0,0,0,0,0,-380,0,0,0,-380,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,154,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 415
-0,0,0,0,0,0,0,0,0,0,0,0,0,-381,0,0,0,-381,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,157,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 416
-0,-382,-382,0,0,0,0,0,0,0,-382,0,0,-382,0,0,0,-382,-382,0,0,0,0,-382,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-382,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,475,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 417
-0,0,0,0,0,0,0,0,0,0,477,0,0,0,0,0,0,0,0,0,0,0,0,-171,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-126,0,0,-126,0,0,-126,0,0,0,0,0,0,0,0,0,-126,0,0,-126,-126,-126,-126,0,0,-126,-126,-126,0,-126,-126,0,-126,-126,0,0,0,0,0,-126,0,0,0,0,0,0,0,0,-126,-126,0,0,-126,0,0,0,0,0,0,
 // State 418
-0,0,0,0,0,0,-346,0,0,0,0,0,0,0,0,-346,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-346,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,161,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 419
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-88,0,0,0,0,0,-88,-88,-88,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-150,0,0,477,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 420
-0,0,0,-44,0,-44,-44,0,0,0,0,0,0,0,-44,-44,0,0,0,0,0,0,0,0,0,0,0,0,0,-44,-44,-44,0,0,0,0,0,0,0,-44,0,0,-44,0,0,0,-44,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-351,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-351,0,0,0,
 // State 421
-0,0,0,0,0,0,0,0,0,479,-405,0,0,0,0,-405,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-38,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-38,0,-38,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 422
-0,0,0,0,0,0,0,0,0,0,-349,0,0,0,0,-349,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-347,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-347,-347,0,-347,0,0,0,0,0,0,0,0,0,0,0,0,0,-347,0,0,0,-347,0,-347,0,-347,0,0,0,0,
 // State 423
-0,0,0,0,0,0,0,0,0,-454,-454,0,0,0,0,-454,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,164,0,0,
 // State 424
-0,0,0,0,0,0,172,0,0,-462,-462,0,0,0,0,-462,173,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,165,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 425
-0,0,0,0,0,0,0,0,0,0,-470,0,0,0,0,-470,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,166,0,0,0,167,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 426
-0,0,0,0,0,0,0,0,0,481,-409,0,0,0,0,-409,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,-391,0,0,0,-391,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 427
-0,0,0,0,0,0,0,0,0,0,-351,0,0,0,0,-351,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,-392,0,0,0,-392,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 428
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,174,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,-393,-393,0,0,0,0,0,0,0,-393,0,0,-393,0,0,0,-393,-393,0,0,0,0,-393,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-393,
 // State 429
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,175,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,489,0,0,0,0,0,0,0,0,0,0,0,0,-178,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 430
-0,0,0,0,0,0,0,-392,0,0,-392,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-392,0,
+0,0,0,0,0,0,-354,0,0,0,0,0,0,0,0,-354,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-354,0,0,0,
 // State 431
-0,0,0,0,0,0,0,0,0,0,482,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,483,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-88,0,0,0,0,0,0,-88,-88,-88,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 432
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,484,0,
+0,0,0,-44,0,-44,-44,0,0,0,0,0,0,0,-44,-44,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-44,-44,-44,0,0,0,0,0,0,0,-44,0,0,-44,0,0,0,-44,0,0,0,0,0,0,0,0,0,0,0,
 // State 433
-0,0,0,0,0,0,0,-396,0,0,-396,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-396,0,
+0,0,0,0,0,0,0,0,0,491,-416,0,0,0,0,-416,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 434
-0,0,0,0,0,0,0,0,0,0,0,0,-33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-33,0,-33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-33,0,
+0,0,0,0,0,0,0,0,0,0,-357,0,0,0,0,-357,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 435
-0,0,0,0,0,0,0,-393,0,0,-393,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-393,0,
+0,0,0,0,0,0,0,0,0,-466,-466,0,0,0,0,-466,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 436
-0,0,0,0,0,0,0,-397,0,0,-397,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-397,0,
+0,0,0,0,0,0,174,0,0,-474,-474,0,0,0,0,-474,175,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 437
-0,0,0,0,0,0,-69,-69,0,0,0,0,-69,0,-69,0,-69,0,0,0,0,0,0,0,0,0,0,-69,0,-69,0,-69,0,0,0,0,0,0,0,0,0,-69,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-482,0,0,0,0,-482,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 438
-0,0,0,0,0,0,0,0,0,0,486,0,0,0,0,0,0,0,0,0,0,0,0,-179,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,493,-420,0,0,0,0,-420,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 439
-0,0,0,0,0,0,0,-482,0,-482,-482,0,0,-482,0,-482,0,-482,0,0,0,0,0,-482,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-482,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-482,0,0,
+0,0,0,0,0,0,0,0,0,0,-359,0,0,0,0,-359,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 440
-0,0,0,-98,0,-98,-98,0,0,0,0,0,0,0,-98,0,0,0,0,0,0,0,0,-98,0,0,0,0,0,-98,-98,-98,0,0,0,0,0,0,0,-98,0,0,-98,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,176,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 441
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,177,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 442
-0,0,0,0,0,0,0,0,0,0,489,0,0,0,0,0,0,0,0,0,0,0,0,-161,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-403,0,0,-403,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-403,0,
 // State 443
--443,0,0,-443,0,0,-443,-443,-443,-443,-443,0,0,0,0,-443,-443,0,0,-443,-443,-443,-443,-443,-443,-443,-443,0,-443,-443,0,-443,-443,0,0,0,0,0,-443,0,0,0,0,0,0,0,0,0,-443,0,0,0,0,0,0,0,-443,0,
+0,0,0,0,0,0,0,0,0,0,494,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,495,0,
 // State 444
--433,0,0,0,0,0,-433,-433,0,0,-433,0,0,0,0,-433,-433,0,0,-433,-433,-433,-433,0,0,-433,-433,0,-433,-433,0,-433,-433,0,0,0,0,0,-433,0,0,0,0,0,0,0,0,0,-433,0,0,0,0,0,0,0,-433,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,496,0,
 // State 445
--428,0,0,-428,0,0,-428,-428,0,0,-428,0,0,0,0,-428,-428,0,0,-428,-428,-428,-428,-428,0,-428,-428,0,-428,-428,0,-428,-428,0,0,0,0,0,-428,0,0,0,0,0,0,0,0,0,-428,0,0,0,0,0,0,0,-428,0,
+0,0,0,0,0,0,0,-407,0,0,-407,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-407,0,
 // State 446
-0,0,0,0,0,0,0,0,0,0,0,0,0,177,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,-33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-33,0,-33,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-33,0,
 // State 447
-0,0,0,0,0,0,0,-478,0,-478,-478,0,0,-478,0,-478,0,-478,0,0,0,0,0,-478,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-478,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-478,0,0,
+0,0,0,0,0,0,0,-404,0,0,-404,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-404,0,
 // State 448
-0,0,0,-94,0,-94,-94,-94,0,0,0,0,0,0,-94,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-94,0,-94,0,0,0,0,0,0,0,-94,0,0,-94,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-408,0,0,-408,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-408,0,
 // State 449
-0,0,0,0,0,0,0,490,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,-69,-69,0,0,0,0,-69,0,-69,0,-69,0,0,0,0,0,0,0,0,0,0,0,-69,0,-69,0,-69,0,0,0,0,0,0,0,0,0,-69,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 450
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,491,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,498,0,0,0,0,0,0,0,0,0,0,0,0,-186,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 451
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,178,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-494,0,-494,-494,0,0,-494,0,-494,0,-494,0,0,0,0,0,-494,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-494,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-494,0,0,
 // State 452
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,180,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-98,0,-98,-98,0,0,0,0,0,0,0,-98,0,0,0,0,0,0,0,0,-98,0,0,0,0,0,0,-98,-98,-98,0,0,0,0,0,0,0,-98,0,0,-98,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 453
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,183,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,500,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 454
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,186,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,501,0,0,0,0,0,0,0,0,0,0,0,0,-168,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 455
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,192,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-455,0,0,-455,0,0,-455,-455,-455,-455,-455,0,0,0,0,-455,-455,0,0,-455,-455,-455,-455,-455,-455,-455,-455,-455,0,-455,-455,0,-455,-455,0,0,0,0,0,-455,0,0,0,0,0,0,0,0,0,-455,0,0,0,0,0,0,0,-455,0,
 // State 456
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,194,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-445,0,0,0,0,0,-445,-445,0,0,-445,0,0,0,0,-445,-445,0,0,-445,-445,-445,-445,0,0,-445,-445,-445,0,-445,-445,0,-445,-445,0,0,0,0,0,-445,0,0,0,0,0,0,0,0,0,-445,0,0,0,0,0,0,0,-445,0,
 // State 457
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,197,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-440,0,0,-440,0,0,-440,-440,0,0,-440,0,0,0,0,-440,-440,0,0,-440,-440,-440,-440,-440,0,-440,-440,-440,0,-440,-440,0,-440,-440,0,0,0,0,0,-440,0,0,0,0,0,0,0,0,0,-440,0,0,0,0,0,0,0,-440,0,
 // State 458
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,201,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,179,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 459
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,204,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-490,0,-490,-490,0,0,-490,0,-490,0,-490,0,0,0,0,0,-490,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-490,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-490,0,0,
 // State 460
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,207,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-94,0,-94,-94,-94,0,0,0,0,0,0,-94,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-94,0,-94,0,0,0,0,0,0,0,-94,0,0,-94,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 461
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,211,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,502,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 462
-0,0,0,-119,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-119,-119,0,-119,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-119,0,0,0,-119,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,503,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 463
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,497,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,180,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 464
-0,0,0,0,0,0,0,-39,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-39,0,-39,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,182,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 465
-0,0,0,0,0,0,0,-340,0,0,-340,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,185,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 466
-0,0,0,0,0,0,0,0,0,0,0,0,0,215,0,0,0,216,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,188,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 467
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-131,0,0,0,0,0,0,0,-131,0,0,0,-131,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,194,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 468
-0,0,0,-197,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-197,-197,0,-197,0,0,0,0,0,0,0,0,0,0,0,0,0,-197,0,0,0,-197,0,-197,0,-197,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,196,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 469
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-363,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,199,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 470
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,515,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,203,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 471
-0,0,0,0,0,0,0,0,0,0,516,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-149,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,206,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 472
-0,0,0,0,0,0,0,0,0,0,-365,0,0,0,0,0,0,0,0,517,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-365,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,209,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 473
-0,0,0,0,0,0,0,0,0,0,-371,0,0,0,0,0,0,0,0,-371,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-371,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,213,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 474
-0,0,0,0,0,0,0,0,0,0,-364,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-364,0,
+-125,0,0,-125,0,0,-125,0,0,0,0,0,0,0,0,0,-125,0,0,-125,-125,-125,-125,0,0,-125,-125,-125,0,-125,-125,0,-125,-125,0,0,0,0,0,-125,0,0,0,0,0,0,0,0,-125,-125,0,0,-125,0,0,0,0,0,0,
 // State 475
-0,0,0,0,0,0,0,518,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,509,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 476
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-89,0,0,0,0,0,-89,-89,-89,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-39,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-39,0,-39,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 477
-0,0,0,0,0,0,0,0,0,519,-407,0,0,0,0,-407,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-348,0,0,-348,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 478
-0,0,0,0,0,0,0,0,0,0,-53,0,0,0,0,-53,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-53,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,217,0,0,0,218,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 479
-0,0,0,0,0,0,0,0,0,520,-411,0,0,0,0,-411,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-137,0,0,0,0,0,0,0,-137,0,0,0,-137,0,
 // State 480
-0,0,0,0,0,0,0,0,0,0,-78,0,0,0,-78,-78,0,0,0,0,0,0,0,0,0,0,0,0,0,-78,-78,-78,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-78,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-205,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-205,-205,0,-205,0,0,0,0,0,0,0,0,0,0,0,0,0,-205,0,0,0,-205,0,-205,0,-205,0,0,0,0,
 // State 481
-0,0,0,0,0,0,0,0,0,0,0,0,-34,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-34,0,-34,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-34,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-371,0,
 // State 482
-0,0,0,0,0,0,0,-395,0,0,-395,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-395,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,528,0,
 // State 483
-0,0,0,0,0,0,0,-398,0,0,-398,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-398,0,
+0,0,0,0,0,0,0,0,0,0,529,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-156,0,
 // State 484
-0,0,0,0,0,0,0,0,0,0,-199,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-199,0,
+0,0,0,0,0,0,0,0,0,0,-373,0,0,0,0,0,0,0,0,530,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-373,0,
 // State 485
-0,0,0,-99,0,-99,-99,0,0,0,0,0,0,0,-99,0,0,0,0,0,0,0,0,-99,0,0,0,0,0,-99,-99,-99,0,0,0,0,0,0,0,-99,0,0,-99,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-382,0,0,0,0,0,0,0,0,-382,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-382,0,
 // State 486
-0,0,0,0,0,0,0,0,0,0,529,0,0,0,0,0,0,0,0,0,0,0,0,-163,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-372,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-372,0,
 // State 487
--439,0,0,-439,0,0,-439,-439,-439,-439,-439,0,0,0,0,-439,-439,0,0,-439,-439,-439,-439,-439,-439,-439,-439,0,-439,-439,0,-439,-439,0,0,0,0,0,-439,0,0,0,0,0,0,0,0,0,-439,0,0,0,0,0,0,0,-439,0,
+0,0,0,0,0,0,0,531,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 488
--73,0,0,0,0,0,-73,0,0,0,0,0,0,0,0,0,-73,0,0,0,0,0,0,-73,0,-73,-73,0,-73,-73,0,-73,-73,0,0,0,0,0,-73,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-89,0,0,0,0,0,0,-89,-89,-89,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 489
-0,0,0,0,0,0,0,-489,0,-489,-489,225,0,-489,0,-489,0,-489,0,0,0,0,0,-489,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-489,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-489,0,0,
+0,0,0,0,0,0,0,0,0,532,-418,0,0,0,0,-418,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 490
-0,0,0,0,0,0,0,-484,0,-484,-484,0,0,-484,0,-484,0,-484,0,0,0,0,0,-484,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-484,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-484,0,0,
+0,0,0,0,0,0,0,0,0,0,-53,0,0,0,0,-53,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-53,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 491
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,228,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,533,-422,0,0,0,0,-422,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 492
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,231,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-78,0,0,0,-78,-78,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-78,-78,-78,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-78,0,0,0,0,0,0,0,0,0,0,0,
 // State 493
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,234,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,-34,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-34,0,-34,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-34,0,
 // State 494
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,238,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-406,0,0,-406,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-406,0,
 // State 495
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,243,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-409,0,0,-409,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-409,0,
 // State 496
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,533,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-207,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-207,0,
 // State 497
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,536,0,
+0,0,0,-99,0,-99,-99,0,0,0,0,0,0,0,-99,0,0,0,0,0,0,0,0,-99,0,0,0,0,0,0,-99,-99,-99,0,0,0,0,0,0,0,-99,0,0,-99,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 498
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,538,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,542,0,0,0,0,0,0,0,0,0,0,0,0,-170,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 499
-0,0,0,0,0,0,0,0,0,0,539,0,0,0,0,0,0,0,0,0,0,0,0,-153,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-451,0,0,-451,0,0,-451,-451,-451,-451,-451,0,0,0,0,-451,-451,0,0,-451,-451,-451,-451,-451,-451,-451,-451,-451,0,-451,-451,0,-451,-451,0,0,0,0,0,-451,0,0,0,0,0,0,0,0,0,-451,0,0,0,0,0,0,0,-451,0,
 // State 500
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,247,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+-73,0,0,0,0,0,-73,0,0,0,0,0,0,0,0,0,-73,0,0,0,0,0,0,-73,0,-73,-73,-73,0,-73,-73,0,-73,-73,0,0,0,0,0,-73,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 501
-0,0,0,0,0,0,0,0,0,0,-113,0,0,0,0,-113,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-113,0,
+0,0,0,0,0,0,0,-501,0,-501,-501,228,0,-501,0,-501,0,-501,0,0,0,0,0,-501,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-501,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-501,0,0,
 // State 502
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,540,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-496,0,-496,-496,0,0,-496,0,-496,0,-496,0,0,0,0,0,-496,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-496,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-496,0,0,
 // State 503
-0,0,0,-377,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-377,-377,0,-377,0,0,0,0,0,0,0,0,0,0,0,0,0,-377,0,0,0,-377,0,-377,0,-377,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,231,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 504
-0,0,0,0,0,0,0,0,0,0,-104,0,0,0,0,-104,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-104,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,234,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 505
-0,0,0,0,0,0,0,0,0,0,-105,0,0,0,0,-105,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-105,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,237,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 506
-0,0,0,0,0,0,0,0,0,0,-102,0,0,0,0,-102,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-102,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,241,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 507
-0,0,0,0,0,0,0,0,0,0,-103,0,0,0,0,-103,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-103,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,246,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 508
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-132,0,0,0,0,0,0,0,-132,0,0,0,-132,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,546,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 509
-0,0,0,-198,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-198,-198,0,-198,0,0,0,0,0,0,0,0,0,0,0,0,0,-198,0,0,0,-198,0,-198,0,-198,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,549,0,
 // State 510
-0,0,0,-193,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-193,-193,0,-193,0,0,0,0,0,0,0,0,0,0,0,0,0,-193,0,0,0,-193,0,-193,0,-193,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,551,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 511
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,253,0,0,
+0,0,0,0,0,0,0,0,0,0,552,0,0,0,0,0,0,0,0,0,0,0,0,-160,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 512
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,254,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,250,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 513
-0,0,0,0,0,0,0,0,0,0,546,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-151,0,
+0,0,0,0,0,0,0,0,0,0,-118,0,0,0,0,-118,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-118,0,
 // State 514
-0,0,0,-374,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-374,-374,0,-374,0,0,0,0,0,0,0,0,0,0,0,-374,0,-374,0,0,0,-374,0,-374,0,-374,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,553,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 515
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-58,0,0,0,0,0,-58,0,0,-58,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-58,0,
+0,0,0,-388,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-388,-388,0,-388,0,0,0,0,0,0,0,0,0,0,0,0,0,-388,0,0,0,-388,0,-388,0,-388,0,0,0,0,
 // State 516
-0,0,0,0,0,0,0,0,0,0,-366,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-366,0,
+0,0,0,0,0,0,0,0,0,0,-104,0,0,0,0,-104,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-104,0,
 // State 517
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-501,-501,0,-501,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-105,0,0,0,0,-105,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-105,0,
 // State 518
-0,0,0,0,0,0,0,0,0,0,-54,0,0,0,0,-54,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-54,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-102,0,0,0,0,-102,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-102,0,
 // State 519
-0,0,0,0,0,0,0,0,0,0,-79,0,0,0,-79,-79,0,0,0,0,0,0,0,0,0,0,0,0,0,-79,-79,-79,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-79,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-103,0,0,0,0,-103,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-103,0,
 // State 520
-0,0,0,0,0,0,0,547,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-138,0,0,0,0,0,0,0,-138,0,0,0,-138,0,
 // State 521
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,549,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-206,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-206,-206,0,-206,0,0,0,0,0,0,0,0,0,0,0,0,0,-206,0,0,0,-206,0,-206,0,-206,0,0,0,0,
 // State 522
-0,0,0,0,0,0,0,0,0,0,-387,0,0,0,319,0,-387,255,0,0,0,0,0,-387,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-201,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-201,-201,0,-201,0,0,0,0,0,0,0,0,0,0,0,0,0,-201,0,0,0,-201,0,-201,0,-201,0,0,0,0,
 // State 523
-0,0,0,0,0,0,0,0,0,0,-465,0,0,0,0,0,0,0,0,0,0,0,0,-465,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,258,0,0,
 // State 524
-0,0,0,0,0,0,0,0,0,0,550,0,0,0,0,0,0,0,0,0,0,0,0,-165,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,259,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 525
-0,0,0,0,0,0,0,0,0,0,-466,0,0,0,0,0,0,0,0,0,0,0,0,-466,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,560,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-158,0,
 // State 526
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,256,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-374,0,0,0,0,0,0,0,0,561,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-374,0,
 // State 527
-0,0,0,0,0,0,0,0,0,0,0,0,0,257,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-385,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-385,-385,0,-385,0,0,0,0,0,0,0,0,0,0,0,-385,0,-385,0,0,0,-385,0,-385,0,-385,0,0,0,0,
 // State 528
--74,0,0,0,0,0,-74,0,0,0,0,0,0,0,0,0,-74,0,0,0,0,0,0,-74,0,-74,-74,0,-74,-74,0,-74,-74,0,0,0,0,0,-74,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-58,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-58,0,0,0,0,0,-58,0,0,0,0,0,-58,0,0,-58,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-58,0,
 // State 529
--427,0,0,-427,0,0,-427,-427,0,0,-427,0,0,0,0,-427,-427,0,0,-427,-427,-427,-427,-427,0,-427,-427,0,-427,-427,0,-427,-427,0,0,0,0,0,-427,0,0,0,0,0,0,0,0,0,-427,0,0,0,0,0,0,0,-427,0,
+0,0,0,0,0,0,0,0,0,0,-375,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-375,0,
 // State 530
-0,0,0,0,0,0,258,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-513,-513,0,-513,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 531
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,261,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-54,0,0,0,0,-54,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-54,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 532
-0,0,0,0,0,0,0,553,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-79,0,0,0,-79,-79,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-79,-79,-79,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-79,0,0,0,0,0,0,0,0,0,0,0,
 // State 533
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,266,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,562,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 534
-0,0,0,-378,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-378,-378,0,-378,0,0,0,0,0,0,0,0,0,0,0,0,0,-378,0,0,0,-378,0,-378,0,-378,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,564,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 535
-0,0,0,-373,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-373,-373,0,-373,0,0,0,0,0,0,0,0,0,0,0,-373,0,-373,0,0,0,-373,0,-373,0,-373,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-398,0,0,0,330,0,-398,260,0,0,0,0,0,-398,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 536
-0,0,0,0,0,0,0,0,0,0,554,0,0,0,0,0,0,0,0,0,0,0,0,-155,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-477,0,0,0,0,0,0,0,0,0,0,0,0,-477,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 537
-0,0,0,0,0,0,0,0,0,0,0,0,0,-379,0,0,0,-379,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,565,0,0,0,0,0,0,0,0,0,0,0,0,-172,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 538
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-63,0,0,0,0,0,-63,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-478,0,0,0,0,0,0,0,0,0,0,0,0,-478,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 539
-0,0,0,-116,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-116,-116,0,-116,0,0,0,0,0,0,0,0,0,0,0,0,0,-116,0,0,0,-116,0,-116,0,-116,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,261,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 540
-0,0,0,0,0,0,0,0,0,0,-110,0,0,0,0,-110,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-110,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,262,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 541
-0,0,0,0,0,0,0,0,0,0,562,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-133,0,
+-74,0,0,0,0,0,-74,0,0,0,0,0,0,0,0,0,-74,0,0,0,0,0,0,-74,0,-74,-74,-74,0,-74,-74,0,-74,-74,0,0,0,0,0,-74,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 542
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,563,0,
+-439,0,0,-439,0,0,-439,-439,0,0,-439,0,0,0,0,-439,-439,0,0,-439,-439,-439,-439,-439,0,-439,-439,-439,0,-439,-439,0,-439,-439,0,0,0,0,0,-439,0,0,0,0,0,0,0,0,0,-439,0,0,0,0,0,0,0,-439,0,
 // State 543
-0,0,0,-195,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-195,-195,0,-195,0,0,0,0,0,0,0,0,0,0,0,0,0,-195,0,0,0,-195,0,-195,0,-195,0,0,0,0,
+0,0,0,0,0,0,263,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 544
-0,0,0,-194,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-194,-194,0,-194,0,0,0,0,0,0,0,0,0,0,0,0,0,-194,0,0,0,-194,0,-194,0,-194,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,266,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 545
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-59,0,0,0,0,0,-59,0,0,-59,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-59,0,
+0,0,0,0,0,0,0,568,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 546
-0,0,0,0,0,0,0,0,0,-458,-458,270,0,0,0,-458,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,271,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 547
-0,0,0,0,0,0,0,0,0,0,569,0,0,0,0,0,0,0,0,0,0,0,0,-167,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-389,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-389,-389,0,-389,0,0,0,0,0,0,0,0,0,0,0,0,0,-389,0,0,0,-389,0,-389,0,-389,0,0,0,0,
 // State 548
-0,0,0,0,0,0,0,0,0,-460,-460,0,0,0,0,-460,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-384,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-384,-384,0,-384,0,0,0,0,0,0,0,0,0,0,0,-384,0,-384,0,0,0,-384,0,-384,0,-384,0,0,0,0,
 // State 549
-0,0,0,-83,0,-83,-83,0,0,0,0,0,0,0,-83,0,0,0,0,0,0,0,0,-83,0,0,0,0,0,-83,-83,-83,0,0,0,0,0,0,0,-83,0,0,-83,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,569,0,0,0,0,0,0,0,0,0,0,0,0,-162,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 550
--426,0,0,-426,0,0,-426,-426,0,0,-426,0,0,0,0,-426,-426,0,0,-426,-426,-426,-426,-426,0,-426,-426,0,-426,-426,0,-426,-426,0,0,0,0,0,-426,0,0,0,0,0,0,0,0,0,-426,0,0,0,0,0,0,0,-426,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,-390,0,0,0,-390,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 551
-0,0,0,0,0,0,0,-487,0,-487,-487,0,0,-487,0,-487,0,-487,0,0,0,0,0,-487,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-487,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-487,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-63,0,0,0,0,0,0,-63,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 552
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-125,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-122,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-122,-122,0,-122,0,0,0,0,0,0,0,0,0,0,0,0,0,-122,0,0,0,-122,0,-122,0,-122,0,0,0,0,
 // State 553
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-64,0,0,0,0,0,-64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-119,0,0,0,0,-119,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-119,0,
 // State 554
-0,0,0,-375,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-375,-375,0,-375,0,0,0,0,0,0,0,0,0,0,0,0,0,-375,0,0,0,-375,0,-375,0,-375,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-112,0,0,0,0,-112,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-112,0,
 // State 555
-0,0,0,0,0,0,0,0,0,0,-112,0,0,0,0,-112,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-112,0,
+0,0,0,0,0,0,0,0,0,0,578,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-140,0,
 // State 556
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-183,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,579,0,
 // State 557
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-185,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-203,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-203,-203,0,-203,0,0,0,0,0,0,0,0,0,0,0,0,0,-203,0,0,0,-203,0,-203,0,-203,0,0,0,0,
 // State 558
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-182,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-202,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-202,-202,0,-202,0,0,0,0,0,0,0,0,0,0,0,0,0,-202,0,0,0,-202,0,-202,0,-202,0,0,0,0,
 // State 559
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-184,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-59,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-59,0,0,0,0,0,-59,0,0,0,0,0,-59,0,0,-59,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-59,0,
 // State 560
-0,0,0,0,0,0,0,0,0,0,577,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-135,0,
+0,0,0,0,0,0,0,0,0,0,-376,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-376,0,
 // State 561
--23,0,0,0,0,0,-23,0,0,0,0,0,0,0,0,0,-23,0,0,-23,-23,-23,-23,0,0,-23,-23,0,-23,-23,0,-23,-23,0,0,0,0,0,-23,0,0,0,0,0,0,0,0,0,-23,0,0,0,0,0,0,0,-23,0,
+0,0,0,0,0,0,0,0,0,-470,-470,278,0,0,0,-470,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 562
-0,0,0,-118,0,0,0,0,0,0,0,0,0,0,0,578,0,0,0,0,0,0,0,0,0,0,0,0,-118,-118,0,-118,0,0,0,0,0,0,0,0,0,0,0,0,0,-118,0,0,0,-118,0,-118,0,-118,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,585,0,0,0,0,0,0,0,0,0,0,0,0,-174,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 563
-0,0,0,-196,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-196,-196,0,-196,0,0,0,0,0,0,0,0,0,0,0,0,0,-196,0,0,0,-196,0,-196,0,-196,0,0,0,0,
+0,0,0,0,0,0,0,0,0,-472,-472,0,0,0,0,-472,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 564
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,580,0,
+0,0,0,-83,0,-83,-83,0,0,0,0,0,0,0,-83,0,0,0,0,0,0,0,0,-83,0,0,0,0,0,0,-83,-83,-83,0,0,0,0,0,0,0,-83,0,0,-83,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 565
-0,0,0,0,0,0,0,0,0,0,581,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-137,0,
+-438,0,0,-438,0,0,-438,-438,0,0,-438,0,0,0,0,-438,-438,0,0,-438,-438,-438,-438,-438,0,-438,-438,-438,0,-438,-438,0,-438,-438,0,0,0,0,0,-438,0,0,0,0,0,0,0,0,0,-438,0,0,0,0,0,0,0,-438,0,
 // State 566
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,582,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,-499,0,-499,-499,0,0,-499,0,-499,0,-499,0,0,0,0,0,-499,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-499,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-499,0,0,
 // State 567
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,583,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-131,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 568
-0,0,0,-84,0,-84,-84,0,0,0,0,0,0,0,-84,0,0,0,0,0,0,0,0,-84,0,0,0,0,0,-84,-84,-84,0,0,0,0,0,0,0,-84,0,0,-84,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-64,0,0,0,0,0,0,-64,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 569
-0,0,0,0,0,0,0,0,0,0,-467,0,0,0,0,0,0,0,0,0,0,0,0,-467,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-386,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-386,-386,0,-386,0,0,0,0,0,0,0,0,0,0,0,0,0,-386,0,0,0,-386,0,-386,0,-386,0,0,0,0,
 // State 570
-0,0,0,0,0,0,272,0,0,-461,-461,0,0,0,0,-461,273,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-113,0,0,0,0,-113,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-113,0,
 // State 571
-0,0,0,0,0,0,0,0,0,0,-350,0,0,0,0,-350,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-116,0,0,0,0,-116,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-116,0,
 // State 572
-0,0,0,0,0,0,0,585,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-190,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 573
-0,0,0,-376,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-376,-376,0,-376,0,0,0,0,0,0,0,0,0,0,0,0,0,-376,0,0,0,-376,0,-376,0,-376,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-192,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 574
-0,0,0,0,0,0,0,0,0,0,-108,0,0,0,0,-108,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-108,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-189,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 575
-0,0,0,0,0,0,0,0,0,0,-181,0,0,0,0,-181,0,0,0,-181,-181,-181,-181,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-181,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-191,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 576
--24,0,0,0,0,0,-24,0,0,0,0,0,0,0,0,0,-24,0,0,-24,-24,-24,-24,0,0,-24,-24,0,-24,-24,0,-24,-24,0,0,0,0,0,-24,0,0,0,0,0,0,0,0,0,-24,0,0,0,0,0,0,0,-24,0,
+0,0,0,0,0,0,0,0,0,0,594,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-142,0,
 // State 577
-0,0,0,-117,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-117,-117,0,-117,0,0,0,0,0,0,0,0,0,0,0,0,0,-117,0,0,0,-117,0,-117,0,-117,0,0,0,0,
+-23,0,0,-23,0,0,-23,0,0,0,0,0,0,0,0,0,-23,0,0,-23,-23,-23,-23,0,0,-23,-23,-23,0,-23,-23,0,-23,-23,0,0,0,0,0,-23,0,0,0,0,0,0,0,0,0,-23,0,0,0,0,0,0,0,-23,0,
 // State 578
-0,0,0,0,0,0,0,0,0,0,586,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-139,0,
+0,0,0,-124,0,0,0,0,0,0,0,0,0,0,0,595,0,0,0,0,0,0,0,0,0,0,0,0,0,-124,-124,0,-124,0,0,0,0,0,0,0,0,0,0,0,0,0,-124,0,0,0,-124,0,-124,0,-124,0,0,0,0,
 // State 579
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-189,0,0,0,-189,0,
+0,0,0,-204,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-204,-204,0,-204,0,0,0,0,0,0,0,0,0,0,0,0,0,-204,0,0,0,-204,0,-204,0,-204,0,0,0,0,
 // State 580
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-28,0,0,-28,0,0,0,0,0,-28,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-28,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,598,0,
 // State 581
-0,0,0,0,0,0,0,0,0,0,-186,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-186,0,
+0,0,0,0,0,0,0,0,0,0,599,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-144,0,
 // State 582
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-128,0,0,0,0,0,0,0,-128,0,0,0,-128,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,600,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 583
-0,0,0,0,0,0,0,0,0,-456,-456,0,0,0,0,-456,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,601,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 584
-0,0,0,0,0,0,0,-488,0,-488,-488,274,0,-488,0,-488,0,-488,0,0,0,0,0,-488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-488,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-488,0,0,
+0,0,0,-84,0,-84,-84,0,0,0,0,0,0,0,-84,0,0,0,0,0,0,0,0,-84,0,0,0,0,0,0,-84,-84,-84,0,0,0,0,0,0,0,-84,0,0,-84,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 585
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-29,0,0,-29,0,0,0,0,0,-29,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-29,0,
+0,0,0,0,0,0,0,0,0,0,-479,0,0,0,0,0,0,0,0,0,0,0,0,-479,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 586
-0,0,0,0,0,0,0,589,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,281,0,0,-473,-473,0,0,0,0,-473,282,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 587
-0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,590,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-358,0,0,0,0,-358,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 588
-0,0,0,0,0,0,0,0,0,-457,-457,275,0,0,0,-457,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,603,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 // State 589
-0,0,0,0,0,0,0,0,0,-459,-459,0,0,0,0,-459,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,-387,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-387,-387,0,-387,0,0,0,0,0,0,0,0,0,0,0,0,0,-387,0,0,0,-387,0,-387,0,-387,0,0,0,0,
 // State 590
-0,0,0,0,0,0,0,-486,0,-486,-486,0,0,-486,0,-486,0,-486,0,0,0,0,0,-486,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-486,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-486,0,0,
+0,0,0,0,0,0,0,0,0,0,-117,0,0,0,0,-117,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-117,0,
 // State 591
-0,0,0,0,0,0,0,0,0,-455,-455,0,0,0,0,-455,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+0,0,0,0,0,0,0,0,0,0,-108,0,0,0,0,-108,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-108,0,
+// State 592
+0,0,0,0,0,0,0,0,0,0,-188,0,0,0,0,-188,0,0,0,-188,-188,-188,-188,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-188,0,
+// State 593
+-24,0,0,-24,0,0,-24,0,0,0,0,0,0,0,0,0,-24,0,0,-24,-24,-24,-24,0,0,-24,-24,-24,0,-24,-24,0,-24,-24,0,0,0,0,0,-24,0,0,0,0,0,0,0,0,0,-24,0,0,0,0,0,0,0,-24,0,
+// State 594
+0,0,0,-123,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-123,-123,0,-123,0,0,0,0,0,0,0,0,0,0,0,0,0,-123,0,0,0,-123,0,-123,0,-123,0,0,0,0,
+// State 595
+0,0,0,0,0,0,0,0,0,0,605,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-146,0,
+// State 596
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,606,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+// State 597
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-197,0,0,0,-197,0,
+// State 598
+0,0,0,-28,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-28,0,0,-28,0,0,-28,0,0,0,0,0,-28,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-28,0,
+// State 599
+0,0,0,0,0,0,0,0,0,0,-193,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-193,0,
+// State 600
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-134,0,0,0,0,0,0,0,-134,0,0,0,-134,0,
+// State 601
+0,0,0,0,0,0,0,0,0,-468,-468,0,0,0,0,-468,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+// State 602
+0,0,0,0,0,0,0,-500,0,-500,-500,283,0,-500,0,-500,0,-500,0,0,0,0,0,-500,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-500,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-500,0,0,
+// State 603
+0,0,0,0,0,0,0,0,0,0,-109,0,0,0,0,-109,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-109,0,
+// State 604
+0,0,0,-29,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-29,0,0,-29,0,0,-29,0,0,0,0,0,-29,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-29,0,
+// State 605
+0,0,0,0,0,0,0,0,0,0,-194,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-194,0,
+// State 606
+0,0,0,0,0,0,0,609,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+// State 607
+0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,610,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+// State 608
+0,0,0,0,0,0,0,0,0,-469,-469,284,0,0,0,-469,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+// State 609
+0,0,0,0,0,0,0,0,0,-471,-471,0,0,0,0,-471,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+// State 610
+0,0,0,0,0,0,0,-498,0,-498,-498,0,0,-498,0,-498,0,-498,0,0,0,0,0,-498,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-498,0,0,0,0,0,0,0,0,0,0,0,0,0,0,-498,0,0,
+// State 611
+0,0,0,0,0,0,0,0,0,-467,-467,0,0,0,0,-467,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
 ];
 fn ___action(state: i16, integer: usize) -> i16 {
-___ACTION[(state as usize) * 58 + integer]
+___ACTION[(state as usize) * 59 + integer]
 }
 const ___EOF_ACTION: &[i16] = &[
 // State 0
@@ -1341,7 +1381,7 @@ const ___EOF_ACTION: &[i16] = &[
 // State 9
 0,
 // State 10
--146,
+-153,
 // State 11
 0,
 // State 12
@@ -1381,13 +1421,13 @@ const ___EOF_ACTION: &[i16] = &[
 // State 29
 0,
 // State 30
--232,
-// State 31
 0,
+// State 31
+-240,
 // State 32
--148,
-// State 33
 0,
+// State 33
+-155,
 // State 34
 0,
 // State 35
@@ -1413,9 +1453,9 @@ const ___EOF_ACTION: &[i16] = &[
 // State 45
 0,
 // State 46
--296,
-// State 47
 0,
+// State 47
+-304,
 // State 48
 0,
 // State 49
@@ -1425,41 +1465,41 @@ const ___EOF_ACTION: &[i16] = &[
 // State 51
 0,
 // State 52
--234,
-// State 53
 0,
+// State 53
+-242,
 // State 54
 0,
 // State 55
 0,
 // State 56
--233,
-// State 57
 0,
+// State 57
+-241,
 // State 58
--216,
-// State 59
 0,
-// State 60
+// State 59
 -224,
+// State 60
+0,
 // State 61
--228,
+-232,
 // State 62
-0,
+-236,
 // State 63
 0,
 // State 64
--264,
-// State 65
 0,
+// State 65
+-272,
 // State 66
 0,
 // State 67
--406,
+0,
 // State 68
--410,
+-417,
 // State 69
-0,
+-421,
 // State 70
 0,
 // State 71
@@ -1481,71 +1521,71 @@ const ___EOF_ACTION: &[i16] = &[
 // State 79
 0,
 // State 80
--280,
-// State 81
 0,
-// State 82
+// State 81
 -288,
+// State 82
+0,
 // State 83
--292,
+-296,
 // State 84
--328,
+-300,
 // State 85
-0,
+-336,
 // State 86
 0,
 // State 87
--298,
-// State 88
 0,
+// State 88
+-306,
 // State 89
 0,
 // State 90
 0,
 // State 91
--235,
+0,
 // State 92
--218,
+-243,
 // State 93
-0,
-// State 94
 -226,
+// State 94
+0,
 // State 95
--230,
+-234,
 // State 96
--266,
+-238,
 // State 97
-0,
+-274,
 // State 98
 0,
 // State 99
--297,
+0,
 // State 100
--217,
+-305,
 // State 101
-0,
-// State 102
 -225,
+// State 102
+0,
 // State 103
--229,
+-233,
 // State 104
--265,
+-237,
 // State 105
-0,
+-273,
 // State 106
--212,
+0,
 // State 107
--248,
+-220,
 // State 108
--208,
+-256,
 // State 109
--220,
+-216,
 // State 110
--256,
+-228,
 // State 111
--260,
+-264,
 // State 112
-0,
+-268,
 // State 113
 0,
 // State 114
@@ -1555,97 +1595,97 @@ const ___EOF_ACTION: &[i16] = &[
 // State 116
 0,
 // State 117
--408,
+0,
 // State 118
--412,
+-419,
 // State 119
-0,
+-423,
 // State 120
 0,
 // State 121
 0,
 // State 122
--276,
+0,
 // State 123
--312,
+-284,
 // State 124
--272,
+-320,
 // State 125
--284,
+-280,
 // State 126
--320,
+-292,
 // State 127
--324,
+-328,
 // State 128
--282,
+-332,
 // State 129
-0,
-// State 130
 -290,
+// State 130
+0,
 // State 131
--294,
+-298,
 // State 132
--330,
+-302,
 // State 133
-0,
+-338,
 // State 134
 0,
 // State 135
--299,
+0,
 // State 136
--219,
+-307,
 // State 137
-0,
-// State 138
 -227,
+// State 138
+0,
 // State 139
--231,
+-235,
 // State 140
--267,
+-239,
 // State 141
--214,
+-275,
 // State 142
--250,
+-222,
 // State 143
--210,
+-258,
 // State 144
--222,
+-218,
 // State 145
--258,
+-230,
 // State 146
--262,
+-266,
 // State 147
--281,
+-270,
 // State 148
-0,
-// State 149
 -289,
+// State 149
+0,
 // State 150
--293,
+-297,
 // State 151
--329,
+-301,
 // State 152
--213,
+-337,
 // State 153
--249,
+-221,
 // State 154
--209,
+-257,
 // State 155
--221,
+-217,
 // State 156
--257,
+-229,
 // State 157
--261,
+-265,
 // State 158
--244,
+-269,
 // State 159
--204,
+-252,
 // State 160
--240,
+-212,
 // State 161
--252,
+-248,
 // State 162
-0,
+-260,
 // State 163
 0,
 // State 164
@@ -1677,81 +1717,81 @@ const ___EOF_ACTION: &[i16] = &[
 // State 177
 0,
 // State 178
--308,
+0,
 // State 179
--268,
+0,
 // State 180
--304,
-// State 181
 -316,
+// State 181
+-276,
 // State 182
--278,
+-312,
 // State 183
--314,
+-324,
 // State 184
--274,
-// State 185
 -286,
-// State 186
+// State 185
 -322,
+// State 186
+-282,
 // State 187
--326,
+-294,
 // State 188
--283,
+-330,
 // State 189
-0,
+-334,
 // State 190
 -291,
 // State 191
--295,
+0,
 // State 192
--331,
+-299,
 // State 193
--215,
+-303,
 // State 194
--251,
+-339,
 // State 195
--211,
-// State 196
 -223,
-// State 197
+// State 196
 -259,
+// State 197
+-219,
 // State 198
--263,
+-231,
 // State 199
--246,
+-267,
 // State 200
--206,
+-271,
 // State 201
--242,
-// State 202
 -254,
+// State 202
+-214,
 // State 203
--277,
+-250,
 // State 204
--313,
+-262,
 // State 205
--273,
-// State 206
 -285,
-// State 207
+// State 206
 -321,
+// State 207
+-281,
 // State 208
--325,
+-293,
 // State 209
--245,
+-329,
 // State 210
--205,
+-333,
 // State 211
--241,
-// State 212
 -253,
+// State 212
+-213,
 // State 213
--236,
+-249,
 // State 214
-0,
+-261,
 // State 215
-0,
+-244,
 // State 216
 0,
 // State 217
@@ -1771,53 +1811,53 @@ const ___EOF_ACTION: &[i16] = &[
 // State 224
 0,
 // State 225
--300,
+0,
 // State 226
--310,
+0,
 // State 227
--270,
+0,
 // State 228
--306,
+-308,
 // State 229
 -318,
 // State 230
--279,
+-278,
 // State 231
--315,
+-314,
 // State 232
--275,
+-326,
 // State 233
 -287,
 // State 234
 -323,
 // State 235
--327,
+-283,
 // State 236
--247,
+-295,
 // State 237
--207,
+-331,
 // State 238
--243,
+-335,
 // State 239
 -255,
 // State 240
--238,
+-215,
 // State 241
--309,
+-251,
 // State 242
--269,
+-263,
 // State 243
--305,
+-246,
 // State 244
 -317,
 // State 245
--237,
+-277,
 // State 246
-0,
+-313,
 // State 247
-0,
+-325,
 // State 248
-0,
+-245,
 // State 249
 0,
 // State 250
@@ -1833,35 +1873,35 @@ const ___EOF_ACTION: &[i16] = &[
 // State 255
 0,
 // State 256
--410,
+0,
 // State 257
 0,
 // State 258
--302,
+0,
 // State 259
--311,
+0,
 // State 260
--271,
+0,
 // State 261
--307,
+-421,
 // State 262
--319,
+0,
 // State 263
--239,
+-310,
 // State 264
--301,
+-319,
 // State 265
-0,
+-279,
 // State 266
-0,
+-315,
 // State 267
-0,
+-327,
 // State 268
-0,
+-247,
 // State 269
-0,
+-309,
 // State 270
--303,
+0,
 // State 271
 0,
 // State 272
@@ -1871,13 +1911,13 @@ const ___EOF_ACTION: &[i16] = &[
 // State 274
 0,
 // State 275
--504,
+0,
 // State 276
 0,
 // State 277
--449,
-// State 278
 0,
+// State 278
+-311,
 // State 279
 0,
 // State 280
@@ -1887,97 +1927,97 @@ const ___EOF_ACTION: &[i16] = &[
 // State 282
 0,
 // State 283
--453,
+0,
 // State 284
--451,
+-516,
 // State 285
--415,
+0,
 // State 286
--447,
+-461,
 // State 287
--414,
+0,
 // State 288
--413,
+0,
 // State 289
--369,
+0,
 // State 290
--448,
+0,
 // State 291
--416,
+0,
 // State 292
--425,
+-465,
 // State 293
-0,
+-426,
 // State 294
--387,
+-463,
 // State 295
--404,
+-427,
 // State 296
--450,
+-459,
 // State 297
--389,
+-425,
 // State 298
--400,
+-424,
 // State 299
--403,
+-379,
 // State 300
--357,
+-139,
 // State 301
--358,
+-460,
 // State 302
--399,
+-428,
 // State 303
--483,
+-437,
 // State 304
--452,
+-398,
 // State 305
-0,
+-415,
 // State 306
-0,
+-462,
 // State 307
-0,
+-400,
 // State 308
-0,
+-411,
 // State 309
--496,
+-414,
 // State 310
--354,
+-365,
 // State 311
--145,
+-366,
 // State 312
-0,
+-410,
 // State 313
-0,
+-495,
 // State 314
--359,
+-464,
 // State 315
 0,
 // State 316
--370,
+0,
 // State 317
--388,
+0,
 // State 318
 0,
 // State 319
-0,
+-508,
 // State 320
-0,
+-362,
 // State 321
--385,
+-152,
 // State 322
 0,
 // State 323
 0,
 // State 324
-0,
+-367,
 // State 325
 0,
 // State 326
 0,
 // State 327
-0,
+-380,
 // State 328
-0,
+-399,
 // State 329
 0,
 // State 330
@@ -1985,13 +2025,13 @@ const ___EOF_ACTION: &[i16] = &[
 // State 331
 0,
 // State 332
-0,
+-396,
 // State 333
 0,
 // State 334
 0,
 // State 335
--481,
+0,
 // State 336
 0,
 // State 337
@@ -1999,7 +2039,7 @@ const ___EOF_ACTION: &[i16] = &[
 // State 338
 0,
 // State 339
--485,
+0,
 // State 340
 0,
 // State 341
@@ -2013,23 +2053,23 @@ const ___EOF_ACTION: &[i16] = &[
 // State 345
 0,
 // State 346
-0,
+-493,
 // State 347
 0,
 // State 348
 0,
 // State 349
--334,
+0,
 // State 350
--338,
+-497,
 // State 351
--333,
+0,
 // State 352
--372,
+0,
 // State 353
--335,
+0,
 // State 354
--332,
+0,
 // State 355
 0,
 // State 356
@@ -2041,17 +2081,17 @@ const ___EOF_ACTION: &[i16] = &[
 // State 359
 0,
 // State 360
-0,
+-342,
 // State 361
-0,
+-346,
 // State 362
--147,
+-341,
 // State 363
--43,
+-383,
 // State 364
-0,
+-343,
 // State 365
-0,
+-340,
 // State 366
 0,
 // State 367
@@ -2059,19 +2099,19 @@ const ___EOF_ACTION: &[i16] = &[
 // State 368
 0,
 // State 369
--394,
+0,
 // State 370
 0,
 // State 371
--402,
+0,
 // State 372
 0,
 // State 373
--386,
+-154,
 // State 374
--401,
+-43,
 // State 375
-0,
+-381,
 // State 376
 0,
 // State 377
@@ -2079,37 +2119,37 @@ const ___EOF_ACTION: &[i16] = &[
 // State 378
 0,
 // State 379
--477,
+0,
 // State 380
 0,
 // State 381
-0,
+-405,
 // State 382
 0,
 // State 383
-0,
+-413,
 // State 384
 0,
 // State 385
-0,
+-397,
 // State 386
-0,
+-412,
 // State 387
 0,
 // State 388
 0,
 // State 389
--480,
+0,
 // State 390
--479,
-// State 391
 0,
+// State 391
+-489,
 // State 392
--475,
+0,
 // State 393
 0,
 // State 394
--476,
+0,
 // State 395
 0,
 // State 396
@@ -2123,17 +2163,17 @@ const ___EOF_ACTION: &[i16] = &[
 // State 400
 0,
 // State 401
-0,
+-492,
 // State 402
-0,
+-491,
 // State 403
 0,
 // State 404
-0,
+-487,
 // State 405
 0,
 // State 406
-0,
+-488,
 // State 407
 0,
 // State 408
@@ -2141,7 +2181,7 @@ const ___EOF_ACTION: &[i16] = &[
 // State 409
 0,
 // State 410
--339,
+0,
 // State 411
 0,
 // State 412
@@ -2161,69 +2201,69 @@ const ___EOF_ACTION: &[i16] = &[
 // State 419
 0,
 // State 420
--44,
+0,
 // State 421
--405,
+0,
 // State 422
--349,
+-347,
 // State 423
--454,
+0,
 // State 424
--462,
+0,
 // State 425
--470,
+0,
 // State 426
--409,
+0,
 // State 427
--351,
+0,
 // State 428
 0,
 // State 429
 0,
 // State 430
--392,
+0,
 // State 431
 0,
 // State 432
-0,
+-44,
 // State 433
--396,
+-416,
 // State 434
-0,
+-357,
 // State 435
--393,
+-466,
 // State 436
--397,
+-474,
 // State 437
-0,
+-482,
 // State 438
-0,
+-420,
 // State 439
--482,
+-359,
 // State 440
 0,
 // State 441
 0,
 // State 442
-0,
+-403,
 // State 443
 0,
 // State 444
 0,
 // State 445
-0,
+-407,
 // State 446
 0,
 // State 447
--478,
+-404,
 // State 448
-0,
+-408,
 // State 449
 0,
 // State 450
 0,
 // State 451
-0,
+-494,
 // State 452
 0,
 // State 453
@@ -2239,7 +2279,7 @@ const ___EOF_ACTION: &[i16] = &[
 // State 458
 0,
 // State 459
-0,
+-490,
 // State 460
 0,
 // State 461
@@ -2257,7 +2297,7 @@ const ___EOF_ACTION: &[i16] = &[
 // State 467
 0,
 // State 468
--197,
+0,
 // State 469
 0,
 // State 470
@@ -2275,19 +2315,19 @@ const ___EOF_ACTION: &[i16] = &[
 // State 476
 0,
 // State 477
--407,
+0,
 // State 478
--53,
+0,
 // State 479
--411,
+0,
 // State 480
--78,
+-205,
 // State 481
 0,
 // State 482
--395,
+0,
 // State 483
--398,
+0,
 // State 484
 0,
 // State 485
@@ -2299,19 +2339,19 @@ const ___EOF_ACTION: &[i16] = &[
 // State 488
 0,
 // State 489
--489,
+-418,
 // State 490
--484,
+-53,
 // State 491
-0,
+-422,
 // State 492
-0,
+-78,
 // State 493
 0,
 // State 494
-0,
+-406,
 // State 495
-0,
+-409,
 // State 496
 0,
 // State 497
@@ -2323,11 +2363,11 @@ const ___EOF_ACTION: &[i16] = &[
 // State 500
 0,
 // State 501
-0,
+-501,
 // State 502
-0,
+-496,
 // State 503
--377,
+0,
 // State 504
 0,
 // State 505
@@ -2339,9 +2379,9 @@ const ___EOF_ACTION: &[i16] = &[
 // State 508
 0,
 // State 509
--198,
+0,
 // State 510
--193,
+0,
 // State 511
 0,
 // State 512
@@ -2349,23 +2389,23 @@ const ___EOF_ACTION: &[i16] = &[
 // State 513
 0,
 // State 514
--374,
-// State 515
 0,
+// State 515
+-388,
 // State 516
 0,
 // State 517
 0,
 // State 518
--54,
+0,
 // State 519
--79,
+0,
 // State 520
 0,
 // State 521
-0,
+-206,
 // State 522
-0,
+-201,
 // State 523
 0,
 // State 524
@@ -2375,7 +2415,7 @@ const ___EOF_ACTION: &[i16] = &[
 // State 526
 0,
 // State 527
-0,
+-385,
 // State 528
 0,
 // State 529
@@ -2383,15 +2423,15 @@ const ___EOF_ACTION: &[i16] = &[
 // State 530
 0,
 // State 531
-0,
+-54,
 // State 532
-0,
+-79,
 // State 533
 0,
 // State 534
--378,
+0,
 // State 535
--373,
+0,
 // State 536
 0,
 // State 537
@@ -2399,7 +2439,7 @@ const ___EOF_ACTION: &[i16] = &[
 // State 538
 0,
 // State 539
--116,
+0,
 // State 540
 0,
 // State 541
@@ -2407,67 +2447,67 @@ const ___EOF_ACTION: &[i16] = &[
 // State 542
 0,
 // State 543
--195,
+0,
 // State 544
--194,
+0,
 // State 545
 0,
 // State 546
--458,
-// State 547
 0,
+// State 547
+-389,
 // State 548
--460,
+-384,
 // State 549
 0,
 // State 550
 0,
 // State 551
--487,
-// State 552
 0,
+// State 552
+-122,
 // State 553
 0,
 // State 554
--375,
+0,
 // State 555
 0,
 // State 556
 0,
 // State 557
-0,
+-203,
 // State 558
-0,
+-202,
 // State 559
 0,
 // State 560
 0,
 // State 561
-0,
+-470,
 // State 562
--118,
+0,
 // State 563
--196,
+-472,
 // State 564
 0,
 // State 565
 0,
 // State 566
-0,
+-499,
 // State 567
 0,
 // State 568
 0,
 // State 569
-0,
+-386,
 // State 570
--461,
+0,
 // State 571
--350,
+0,
 // State 572
 0,
 // State 573
--376,
+0,
 // State 574
 0,
 // State 575
@@ -2475,11 +2515,11 @@ const ___EOF_ACTION: &[i16] = &[
 // State 576
 0,
 // State 577
--117,
-// State 578
 0,
+// State 578
+-124,
 // State 579
-0,
+-204,
 // State 580
 0,
 // State 581
@@ -2487,447 +2527,503 @@ const ___EOF_ACTION: &[i16] = &[
 // State 582
 0,
 // State 583
--456,
+0,
 // State 584
--488,
+0,
 // State 585
 0,
 // State 586
-0,
+-473,
 // State 587
-0,
+-358,
 // State 588
--457,
+0,
 // State 589
--459,
+-387,
 // State 590
--486,
+0,
 // State 591
--455,
+0,
+// State 592
+0,
+// State 593
+0,
+// State 594
+-123,
+// State 595
+0,
+// State 596
+0,
+// State 597
+0,
+// State 598
+0,
+// State 599
+0,
+// State 600
+0,
+// State 601
+-468,
+// State 602
+-500,
+// State 603
+0,
+// State 604
+0,
+// State 605
+0,
+// State 606
+0,
+// State 607
+0,
+// State 608
+-469,
+// State 609
+-471,
+// State 610
+-498,
+// State 611
+-467,
 ];
 fn ___goto(state: i16, nt: usize) -> i16 {
 match nt {
-14 => 250,
-17 => 268,
-20 => 70,
-23 => 62,
-26 => 32,
+14 => 255,
+17 => 275,
+20 => 71,
+23 => 63,
+26 => 33,
 29 => match state {
-13 => 36,
-_ => 11,
+14 => 37,
+_ => 12,
 },
-32 => 117,
-35 => 170,
-38 => 216,
-41 => 35,
-44 => 120,
-47 => 118,
-50 => 222,
-53 => 66,
-56 => 43,
-59 => 71,
+32 => 118,
+35 => 171,
+38 => 218,
+41 => 36,
+44 => 121,
+47 => 119,
+50 => 225,
+53 => 67,
+56 => 44,
+59 => 72,
 62 => match state {
-217 => 540,
-248 => 555,
-266 => 574,
-_ => 501,
+219 => 553,
+220 => 554,
+250 => 570,
+253 => 571,
+272 => 590,
+273 => 591,
+279 => 603,
+_ => 513,
 },
 64 => match state {
-219 => 541,
-250 => 560,
-_ => 502,
+222 => 555,
+255 => 576,
+_ => 514,
 },
 66 => match state {
-215 => 534,
-246 => 554,
-265 => 573,
-_ => 503,
+217 => 547,
+249 => 569,
+270 => 589,
+_ => 515,
 },
 67 => match state {
-6 | 21 | 24 | 48 | 63 => 305,
-_ => 276,
+6 | 22 | 25 | 49 | 64 | 172 | 219 | 276 => 315,
+_ => 285,
 },
 69 => match state {
 1 => 6,
-7 => 21,
-8 => 24,
-22 => 48,
-_ => 63,
+7 => 22,
+8 => 25,
+23 => 49,
+116 | 163 | 171 => 172,
+166 | 217 | 222 | 249 | 255 | 270 => 219,
+257 | 275 => 276,
+_ => 64,
 },
-70 => 404,
+70 => 416,
 72 => match state {
-166 | 221 | 251 => 508,
-_ => 467,
+167 | 224 | 256 => 520,
+_ => 479,
 },
 74 => match state {
-167 => 221,
-220 => 251,
-_ => 166,
-},
-75 => 542,
-76 => 564,
-77 => 346,
-78 => 310,
-79 => 469,
-80 => 498,
-81 => match state {
-33 => 365,
-_ => 319,
+168 => 224,
+223 => 256,
+_ => 167,
 },
-82 => 441,
-83 => match state {
-272 => 587,
-_ => 521,
+75 => 293,
+76 => 556,
+77 => 580,
+78 => 357,
+79 => 320,
+80 => 481,
+81 => 510,
+82 => match state {
+34 => 377,
+_ => 330,
 },
+83 => 453,
 84 => match state {
-69 => 429,
-79 => 451,
-173 => 526,
-_ => 358,
+281 => 607,
+_ => 534,
 },
 85 => match state {
-77 => 449,
-171 => 520,
-257 => 572,
-271 => 586,
-_ => 336,
+70 => 441,
+80 => 463,
+175 => 539,
+_ => 369,
 },
 86 => match state {
-78 => 450,
-_ => 375,
+78 => 461,
+173 => 533,
+262 => 588,
+280 => 606,
+_ => 347,
 },
 87 => match state {
-247 => 266,
-_ => 248,
+79 => 462,
+_ => 387,
 },
-88 => 267,
-89 => match state {
-268 => 578,
-_ => 565,
+88 => match state {
+251 => 272,
+252 => 273,
+271 => 279,
+_ => 253,
 },
-91 => match state {
-166 => 220,
-_ => 167,
+89 => 274,
+90 => match state {
+275 => 595,
+_ => 581,
+},
+92 => match state {
+167 => 223,
+_ => 168,
 },
-92 => 323,
-93 => 384,
-94 => 349,
-95 => match state {
-70 => 431,
-_ => 366,
+93 => 334,
+94 => 396,
+95 => 360,
+96 => match state {
+71 => 443,
+_ => 378,
 },
-98 => 277,
-99 => match state {
-64 | 84 | 96 | 104 | 107 | 110..=111 | 123 | 126..=127 | 132 | 140 | 142 | 145..=146 | 151 | 153 | 156..=158 | 160..=161 | 178 | 180..=181 | 183 | 186..=187 | 192 | 194 | 197..=199 | 201..=202 | 204 | 207..=209 | 211..=213 | 225..=226 | 228..=229 | 231 | 234..=236 | 238..=241 | 243..=245 | 258..=259 | 261..=264 | 270 => 410,
-_ => 350,
+99 => 286,
+100 => match state {
+65 | 85 | 97 | 105 | 108 | 111..=112 | 124 | 127..=128 | 133 | 141 | 143 | 146..=147 | 152 | 154 | 157..=159 | 161..=162 | 180 | 182..=183 | 185 | 188..=189 | 194 | 196 | 199..=201 | 203..=204 | 206 | 209..=211 | 213..=215 | 228..=229 | 231..=232 | 234 | 237..=239 | 241..=244 | 246..=248 | 263..=264 | 266..=269 | 278 => 422,
+_ => 361,
 },
-101 => match state {
-46 => 84,
-52 => 96,
-56 => 104,
-58 => 107,
-60 => 110,
+102 => match state {
+47 => 85,
+53 => 97,
+57 => 105,
+59 => 108,
 61 => 111,
-80 => 123,
-82 => 126,
+62 => 112,
+81 => 124,
 83 => 127,
-87 => 132,
-91 => 140,
-92 => 142,
-94 => 145,
+84 => 128,
+88 => 133,
+92 => 141,
+93 => 143,
 95 => 146,
-99 => 151,
-100 => 153,
-102 => 156,
+96 => 147,
+100 => 152,
+101 => 154,
 103 => 157,
-106 => 158,
-108 => 160,
+104 => 158,
+107 => 159,
 109 => 161,
-122 => 178,
-124 => 180,
-125 => 181,
-128 => 183,
-130 => 186,
-131 => 187,
-135 => 192,
+110 => 162,
+123 => 180,
+125 => 182,
+126 => 183,
+129 => 185,
+131 => 188,
+132 => 189,
 136 => 194,
-138 => 197,
-139 => 198,
-141 => 199,
-143 => 201,
-144 => 202,
-147 => 204,
-149 => 207,
-150 => 208,
-152 => 209,
-154 => 211,
-155 => 212,
-159 => 213,
-179 => 225,
-182 => 226,
-184 => 228,
-185 => 229,
-188 => 231,
+137 => 196,
+139 => 199,
+140 => 200,
+142 => 201,
+144 => 203,
+145 => 204,
+148 => 206,
+150 => 209,
+151 => 210,
+153 => 211,
+155 => 213,
+156 => 214,
+160 => 215,
+181 => 228,
+184 => 229,
+186 => 231,
+187 => 232,
 190 => 234,
-191 => 235,
-193 => 236,
-195 => 238,
-196 => 239,
-200 => 240,
-203 => 241,
-205 => 243,
-206 => 244,
-210 => 245,
-227 => 258,
-230 => 259,
-232 => 261,
-233 => 262,
-237 => 263,
-242 => 264,
-260 => 270,
-_ => 64,
-},
-102 => match state {
-62 => 407,
-_ => 347,
+192 => 237,
+193 => 238,
+195 => 239,
+197 => 241,
+198 => 242,
+202 => 243,
+205 => 244,
+207 => 246,
+208 => 247,
+212 => 248,
+230 => 263,
+233 => 264,
+235 => 266,
+236 => 267,
+240 => 268,
+245 => 269,
+265 => 278,
+_ => 65,
 },
-104 => match state {
-20 => 44,
-23 => 50,
-25 => 54,
-28 => 59,
-45 => 81,
-47 => 85,
-49 => 89,
-51 => 93,
-53 => 97,
-55 => 101,
-86 => 129,
-88 => 133,
-90 => 137,
-98 => 148,
-134 => 189,
-_ => 27,
+103 => match state {
+63 => 419,
+_ => 358,
 },
-106 => match state {
-20 => 45,
-23 => 51,
-25 => 55,
-47 => 86,
-49 => 90,
-53 => 98,
-88 => 134,
+105 => match state {
+21 => 45,
+24 => 51,
+26 => 55,
+29 => 60,
+46 => 82,
+48 => 86,
+50 => 90,
+52 => 94,
+54 => 98,
+56 => 102,
+87 => 130,
+89 => 134,
+91 => 138,
+99 => 149,
+135 => 191,
 _ => 28,
 },
-108 => match state {
-32 => 362,
-_ => 311,
+107 => match state {
+21 => 46,
+24 => 52,
+26 => 56,
+48 => 87,
+50 => 91,
+54 => 99,
+89 => 135,
+_ => 29,
 },
-110 => match state {
-9 => 308,
-20 => 341,
-23 => 342,
-25 => 343,
-27 => 344,
-28 => 345,
-44 => 395,
-45 => 396,
-47 => 397,
-49 => 398,
-50 => 399,
-51 => 400,
-53 => 401,
-54 => 402,
-55 => 403,
-59 => 406,
-81 => 452,
-85 => 453,
-86 => 454,
-88 => 455,
-89 => 456,
-90 => 457,
-93 => 458,
-97 => 459,
-98 => 460,
-101 => 461,
-129 => 491,
-133 => 492,
-134 => 493,
-137 => 494,
-148 => 495,
-189 => 531,
-_ => 283,
+109 => match state {
+33 => 373,
+_ => 321,
 },
-112 => match state {
-26 => 57,
-11 => 317,
-13 => 321,
-29 | 62 => 348,
-31 | 66 | 69 | 79 | 173 => 359,
-34 | 70 => 367,
-36 => 373,
-40 => 386,
-75 => 446,
-105 => 463,
-169 => 512,
-172 | 222 | 272 => 522,
-_ => 294,
+111 => match state {
+9 => 318,
+21 => 352,
+24 => 353,
+26 => 354,
+28 => 355,
+29 => 356,
+45 => 407,
+46 => 408,
+48 => 409,
+50 => 410,
+51 => 411,
+52 => 412,
+54 => 413,
+55 => 414,
+56 => 415,
+60 => 418,
+82 => 464,
+86 => 465,
+87 => 466,
+89 => 467,
+90 => 468,
+91 => 469,
+94 => 470,
+98 => 471,
+99 => 472,
+102 => 473,
+130 => 503,
+134 => 504,
+135 => 505,
+138 => 506,
+149 => 507,
+191 => 544,
+_ => 292,
 },
 113 => match state {
-16 => 41,
-10 | 32 => 312,
-37 | 71 | 78 => 376,
-67 => 421,
-68 | 118 | 256 => 423,
-117 => 477,
-172 | 222 | 272 => 523,
-_ => 360,
+27 => 58,
+12 => 328,
+14 => 332,
+30 | 63 => 359,
+32 | 67 | 70 | 80 | 175 => 370,
+35 | 71 => 379,
+37 => 385,
+41 => 398,
+76 => 458,
+106 => 475,
+170 => 524,
+174 | 225 | 281 => 535,
+_ => 304,
 },
-115 => match state {
-65 | 113 => 412,
-_ => 324,
+114 => match state {
+17 => 42,
+10 | 33 => 322,
+38 | 72 | 79 => 388,
+68 => 433,
+69 | 119 | 261 => 435,
+118 => 489,
+174 | 225 | 281 => 536,
+_ => 371,
 },
 116 => match state {
-162 => 497,
-_ => 470,
+66 | 114 => 424,
+_ => 335,
 },
 117 => match state {
-170 => 513,
-_ => 471,
+163 => 509,
+_ => 482,
+},
+118 => match state {
+171 => 525,
+_ => 483,
 },
-119 => 284,
-120 => 472,
-121 => 351,
-122 => 352,
-123 => 353,
-124 => match state {
-113 => 466,
-_ => 413,
+120 => 294,
+121 => match state {
+172 => 526,
+_ => 484,
 },
+122 => 362,
+123 => 363,
+124 => 364,
 125 => match state {
-65 | 113 => 414,
-163 => 499,
-216 => 536,
-_ => 249,
+114 => 478,
+_ => 425,
 },
-127 => match state {
-4 | 12 | 33 | 35 | 119 => 295,
-19 => 339,
-68 | 118 | 256 => 424,
-116 => 475,
-177 => 530,
-255 => 570,
-_ => 303,
+126 => match state {
+66 | 114 => 426,
+164 => 511,
+218 => 549,
+_ => 254,
 },
 128 => match state {
-4 => 296,
-35 => 370,
-119 => 484,
-_ => 320,
+4 | 13 | 34 | 36 | 120 => 305,
+20 => 350,
+69 | 119 | 261 => 436,
+117 => 487,
+179 => 543,
+260 => 586,
+_ => 313,
 },
-130 => 297,
-131 => 422,
-132 => 425,
-133 => match state {
-115 | 162 | 170 => 473,
-_ => 285,
+129 => match state {
+4 => 306,
+36 => 382,
+120 => 496,
+_ => 331,
 },
+131 => 307,
+132 => 434,
+133 => 437,
 134 => match state {
-3 | 252 | 268 => 286,
-_ => 325,
+116 | 163 | 171..=172 => 485,
+_ => 295,
 },
-135 => 287,
-136 => 380,
-137 => match state {
-7 => 306,
-_ => 278,
+135 => match state {
+3 | 257 | 275..=276 => 296,
+_ => 336,
 },
-139 => 7,
-140 => match state {
-267 => 575,
-_ => 288,
+136 => 297,
+137 => 392,
+138 => match state {
+7 => 316,
+_ => 287,
 },
+140 => 7,
 141 => match state {
-15 => 326,
-72 => 442,
-73 | 217 => 444,
-120 => 486,
-_ => 385,
+11 => 326,
+274 => 592,
+_ => 298,
 },
-143 => match state {
-39 => 73,
-_ => 217,
+142 => match state {
+16 => 337,
+73 => 454,
+74 | 220 | 250 => 456,
+121 => 498,
+_ => 397,
 },
 144 => match state {
 40 => 74,
-121 => 175,
-176 => 223,
-_ => 38,
+219 => 250,
+_ => 220,
 },
-145 => 327,
-147 => 328,
-148 => match state {
-3 => 289,
-_ => 566,
+145 => match state {
+41 => 75,
+122 => 177,
+178 => 226,
+_ => 39,
 },
-149 => 275,
-150 => match state {
-118 => 479,
-_ => 426,
+146 => 338,
+148 => 339,
+149 => match state {
+3 => 299,
+276 => 596,
+_ => 582,
 },
-152 => match state {
-222 => 547,
-_ => 524,
+150 => 284,
+151 => match state {
+119 => 491,
+_ => 438,
 },
-154 => match state {
-256 => 571,
-_ => 427,
+153 => match state {
+225 => 562,
+_ => 537,
 },
 155 => match state {
-66 => 417,
-_ => 361,
+261 => 587,
+_ => 439,
 },
-157 => match state {
-5 => 304,
-10 | 32 => 313,
-14 => 322,
-16 => 335,
-18 => 338,
-37 | 71 | 78 => 377,
-41 => 389,
-42 => 390,
-43 => 391,
-76 => 447,
-112 => 465,
-164 => 500,
-168 => 511,
-172 | 222 | 272 => 525,
-174 => 527,
-214 => 533,
-224 => 551,
-253 => 567,
-254 => 569,
-269 => 583,
-273 => 590,
-274 => 591,
-_ => 337,
+156 => match state {
+67 => 429,
+_ => 372,
 },
-159 => match state {
-71 => 438,
-_ => 378,
+158 => match state {
+5 => 314,
+10 | 33 => 323,
+15 => 333,
+17 => 346,
+19 => 349,
+38 | 72 | 79 => 389,
+42 => 401,
+43 => 402,
+44 => 403,
+77 => 459,
+113 => 477,
+165 => 512,
+169 => 523,
+174 | 225 | 281 => 538,
+176 => 540,
+216 => 546,
+227 => 566,
+258 => 583,
+259 => 585,
+277 => 601,
+282 => 610,
+283 => 611,
+_ => 348,
 },
-161 => match state {
-1 | 7 => 279,
-8 | 22 => 307,
-_ => 354,
+160 => match state {
+72 => 450,
+_ => 390,
 },
-163 => match state {
-7 => 22,
-_ => 8,
+162 => match state {
+1 | 7 => 288,
+8 | 23 => 317,
+_ => 365,
 },
 164 => match state {
-63 => 113,
-_ => 65,
+7 => 23,
+_ => 8,
+},
+165 => match state {
+64 => 114,
+_ => 66,
 },
 _ => 0,
 }
@@ -2961,6 +3057,7 @@ r###"">""###,
 r###""?""###,
 r###""@L""###,
 r###""@R""###,
+r###""CaseInsensitiveLiteral""###,
 r###""CharLiteral""###,
 r###""Escape""###,
 r###""Id""###,
@@ -3044,7 +3141,7 @@ ___action(state, integer)
 
 #[inline]
 fn error_action(&self, state: i16) -> i16 {
-___action(state, 58 - 1)
+___action(state, 59 - 1)
 }
 
 #[inline]
@@ -3057,7 +3154,11 @@ fn goto(&self, state: i16, nt: usize) -> i16 {
 ___goto(state, nt)
 }
 
-fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+fn token_to_symbol(
+&self,
+token_index: usize,
+token: Self::Token,
+) -> Result<Self::Symbol, ___state_machine::ParseError<Self>> {
 ___token_to_symbol(token_index, token, ::std::marker::PhantomData::<(&())>)
 }
 
@@ -3134,37 +3235,38 @@ Tok::GreaterThan if true => Some(23),
 Tok::Question if true => Some(24),
 Tok::Lookahead if true => Some(25),
 Tok::Lookbehind if true => Some(26),
-Tok::CharLiteral(_) if true => Some(27),
-Tok::Escape(_) if true => Some(28),
-Tok::Id(_) if true => Some(29),
-Tok::Lifetime(_) if true => Some(30),
-Tok::MacroId(_) if true => Some(31),
-Tok::RegexLiteral(_) if true => Some(32),
-Tok::StartGrammar if true => Some(33),
-Tok::StartGrammarWhereClauses if true => Some(34),
-Tok::StartMatchMapping if true => Some(35),
-Tok::StartPattern if true => Some(36),
-Tok::StartTypeRef if true => Some(37),
-Tok::StringLiteral(_) if true => Some(38),
-Tok::LeftBracket if true => Some(39),
-Tok::RightBracket if true => Some(40),
-Tok::Underscore if true => Some(41),
-Tok::Dyn if true => Some(42),
-Tok::Else if true => Some(43),
-Tok::Enum if true => Some(44),
-Tok::Extern if true => Some(45),
-Tok::For if true => Some(46),
-Tok::Grammar if true => Some(47),
-Tok::If if true => Some(48),
-Tok::Match if true => Some(49),
-Tok::Mut if true => Some(50),
-Tok::Pub if true => Some(51),
-Tok::Type if true => Some(52),
-Tok::Use(_) if true => Some(53),
-Tok::Where if true => Some(54),
-Tok::LeftBrace if true => Some(55),
-Tok::RightBrace if true => Some(56),
-Tok::TildeTilde if true => Some(57),
+Tok::CaseInsensitiveLiteral(_) if true => Some(27),
+Tok::CharLiteral(_) if true => Some(28),
+Tok::Escape(_) if true => Some(29),
+Tok::Id(_) if true => Some(30),
+Tok::Lifetime(_) if true => Some(31),
+Tok::MacroId(_) if true => Some(32),
+Tok::RegexLiteral(_) if true => Some(33),
+Tok::StartGrammar if true => Some(34),
+Tok::StartGrammarWhereClauses if true => Some(35),
+Tok::StartMatchMapping if true => Some(36),
+Tok::StartPattern if true => Some(37),
+Tok::StartTypeRef if true => Some(38),
+Tok::StringLiteral(_) if true => Some(39),
+Tok::LeftBracket if true => Some(40),
+Tok::RightBracket if true => Some(41),
+Tok::Underscore if true => Some(42),
+Tok::Dyn if true => Some(43),
+Tok::Else if true => Some(44),
+Tok::Enum if true => Some(45),
+Tok::Extern if true => Some(46),
+Tok::For if true => Some(47),
+Tok::Grammar if true => Some(48),
+Tok::If if true => Some(49),
+Tok::Match if true => Some(50),
+Tok::Mut if true => Some(51),
+Tok::Pub if true => Some(52),
+Tok::Type if true => Some(53),
+Tok::Use(_) if true => Some(54),
+Tok::Where if true => Some(55),
+Tok::LeftBrace if true => Some(56),
+Tok::RightBrace if true => Some(57),
+Tok::TildeTilde if true => Some(58),
 _ => None,
 }
 }
@@ -3174,16 +3276,16 @@ fn ___token_to_symbol<
 ___token_index: usize,
 ___token: Tok<'input>,
 _: ::std::marker::PhantomData<(&'input ())>,
-) -> ___Symbol<'input>
+) -> Result<___Symbol<'input>, ___lalrpop_util::ParseError<usize, Tok<'input>, tok::Error>>
 {
-match ___token_index {
-0 | 1 | 2 | 3 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 21 | 22 | 23 | 24 | 25 | 26 | 33 | 34 | 35 | 36 | 37 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 54 | 55 | 56 | 57 => ___Symbol::Variant0(___token),
-4 | 19 | 20 | 27 | 28 | 29 | 30 | 31 | 32 | 38 | 53 => match ___token {
-Tok::ShebangAttribute(___tok0) | Tok::EqualsGreaterThanCode(___tok0) | Tok::EqualsGreaterThanQuestionCode(___tok0) | Tok::CharLiteral(___tok0) | Tok::Escape(___tok0) | Tok::Id(___tok0) | Tok::Lifetime(___tok0) | Tok::MacroId(___tok0) | Tok::RegexLiteral(___tok0) | Tok::StringLiteral(___tok0) | Tok::Use(___tok0) if true => ___Symbol::Variant1(___tok0),
-_ => unreachable!(),
+Ok(match ___token_index {
+0 | 1 | 2 | 3 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 21 | 22 | 23 | 24 | 25 | 26 | 34 | 35 | 36 | 37 | 38 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 55 | 56 | 57 | 58 => ___Symbol::Variant0(___token),
+4 | 19 | 20 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 39 | 54 => match ___token {
+Tok::ShebangAttribute(___tok0) | Tok::EqualsGreaterThanCode(___tok0) | Tok::EqualsGreaterThanQuestionCode(___tok0) | Tok::CaseInsensitiveLiteral(___tok0) | Tok::CharLiteral(___tok0) | Tok::Escape(___tok0) | Tok::Id(___tok0) | Tok::Lifetime(___tok0) | Tok::MacroId(___tok0) | Tok::RegexLiteral(___tok0) | Tok::StringLiteral(___tok0) | Tok::Use(___tok0) if true => ___Symbol::Variant1(___tok0),
+_ => return Err(___lalrpop_util::ParseError::Internal { message: "token does not match the terminal of its index" }),
 },
-_ => unreachable!(),
-}
+_ => return Err(___lalrpop_util::ParseError::Internal { message: "token index out of range" }),
+})
 }
 pub struct TopParser {
 _priv: (),
@@ -3197,6 +3299,7 @@ _priv: (),
 }
 
 #[allow(dead_code)]
+#[must_use = "parsing has no effect other than returning its result"]
 pub fn parse<
     'input,
     ___TOKEN: ___ToTriple<'input, >,
@@ -3645,7 +3748,16 @@ ___reduce136(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce137(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 138 => {
-___reduce138(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+// CaseInsensitiveLiteral = "CaseInsensitiveLiteral" => ActionFn(403);
+let ___sym0 = ___pop_Variant1(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym0.2.clone();
+let ___nt = match super::___action403::<>(text, ___sym0) {
+Ok(v) => v,
+Err(e) => return Some(Err(e)),
+};
+___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
+(1, 75)
 }
 139 => {
 ___reduce139(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
@@ -3786,18 +3898,7 @@ ___reduce183(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce184(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 185 => {
-// Conversion = Terminal, "=>" => ActionFn(426);
-assert!(___symbols.len() >= 2);
-let ___sym1 = ___pop_Variant1(___symbols);
-let ___sym0 = ___pop_Variant85(___symbols);
-let ___start = ___sym0.0.clone();
-let ___end = ___sym1.2.clone();
-let ___nt = match super::___action426::<>(text, ___sym0, ___sym1) {
-Ok(v) => v,
-Err(e) => return Some(Err(e)),
-};
-___symbols.push((___start, ___Symbol::Variant12(___nt), ___end));
-(2, 89)
+___reduce185(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 186 => {
 ___reduce186(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
@@ -3818,10 +3919,33 @@ ___reduce190(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce191(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 192 => {
-___reduce192(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+// Conversion = Terminal, "=>" => ActionFn(468);
+assert!(___symbols.len() >= 2);
+let ___sym1 = ___pop_Variant1(___symbols);
+let ___sym0 = ___pop_Variant85(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym1.2.clone();
+let ___nt = match super::___action468::<>(text, ___sym0, ___sym1) {
+Ok(v) => v,
+Err(e) => return Some(Err(e)),
+};
+___symbols.push((___start, ___Symbol::Variant12(___nt), ___end));
+(2, 90)
 }
 193 => {
-___reduce193(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+// Conversion = Annotation+, Terminal, "=>" => ActionFn(469);
+assert!(___symbols.len() >= 3);
+let ___sym2 = ___pop_Variant1(___symbols);
+let ___sym1 = ___pop_Variant85(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym2.2.clone();
+let ___nt = match super::___action469::<>(text, ___sym0, ___sym1, ___sym2) {
+Ok(v) => v,
+Err(e) => return Some(Err(e)),
+};
+___symbols.push((___start, ___Symbol::Variant12(___nt), ___end));
+(3, 90)
 }
 194 => {
 ___reduce194(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
@@ -4337,18 +4461,7 @@ ___reduce363(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce364(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 365 => {
-// MatchItem = MatchSymbol, "=>" => ActionFn(434);
-assert!(___symbols.len() >= 2);
-let ___sym1 = ___pop_Variant1(___symbols);
-let ___sym0 = ___pop_Variant76(___symbols);
-let ___start = ___sym0.0.clone();
-let ___end = ___sym1.2.clone();
-let ___nt = match super::___action434::<>(text, ___sym0, ___sym1) {
-Ok(v) => v,
-Err(e) => return Some(Err(e)),
-};
-___symbols.push((___start, ___Symbol::Variant24(___nt), ___end));
-(2, 117)
+___reduce365(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 366 => {
 ___reduce366(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
@@ -4375,10 +4488,33 @@ ___reduce372(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce373(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 374 => {
-___reduce374(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+// MatchItem = MatchSymbol, "=>" => ActionFn(474);
+assert!(___symbols.len() >= 2);
+let ___sym1 = ___pop_Variant1(___symbols);
+let ___sym0 = ___pop_Variant76(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym1.2.clone();
+let ___nt = match super::___action474::<>(text, ___sym0, ___sym1) {
+Ok(v) => v,
+Err(e) => return Some(Err(e)),
+};
+___symbols.push((___start, ___Symbol::Variant24(___nt), ___end));
+(2, 118)
 }
 375 => {
-___reduce375(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+// MatchItem = Annotation+, MatchSymbol, "=>" => ActionFn(475);
+assert!(___symbols.len() >= 3);
+let ___sym2 = ___pop_Variant1(___symbols);
+let ___sym1 = ___pop_Variant76(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym2.2.clone();
+let ___nt = match super::___action475::<>(text, ___sym0, ___sym1, ___sym2) {
+Ok(v) => v,
+Err(e) => return Some(Err(e)),
+};
+___symbols.push((___start, ___Symbol::Variant24(___nt), ___end));
+(3, 118)
 }
 376 => {
 ___reduce376(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
@@ -4525,16 +4661,7 @@ ___reduce422(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce423(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 424 => {
-// StringLiteral = "StringLiteral" => ActionFn(414);
-let ___sym0 = ___pop_Variant1(___symbols);
-let ___start = ___sym0.0.clone();
-let ___end = ___sym0.2.clone();
-let ___nt = match super::___action414::<>(text, ___sym0) {
-Ok(v) => v,
-Err(e) => return Some(Err(e)),
-};
-___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
-(1, 140)
+___reduce424(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 425 => {
 ___reduce425(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
@@ -4570,7 +4697,16 @@ ___reduce434(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce435(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 436 => {
-___reduce436(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+// StringLiteral = "StringLiteral" => ActionFn(418);
+let ___sym0 = ___pop_Variant1(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym0.2.clone();
+let ___nt = match super::___action418::<>(text, ___sym0) {
+Ok(v) => v,
+Err(e) => return Some(Err(e)),
+};
+___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
+(1, 141)
 }
 437 => {
 ___reduce437(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
@@ -4771,10 +4907,44 @@ ___reduce501(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<
 ___reduce502(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
 }
 503 => {
+___reduce503(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+504 => {
+___reduce504(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+505 => {
+___reduce505(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+506 => {
+___reduce506(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+507 => {
+___reduce507(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+508 => {
+___reduce508(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+509 => {
+___reduce509(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+510 => {
+___reduce510(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+511 => {
+___reduce511(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+512 => {
+___reduce512(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+513 => {
+___reduce513(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+514 => {
+___reduce514(text, ___lookahead_start, ___symbols, ::std::marker::PhantomData::<(&())>)
+}
+515 => {
 // ___Top = Top => ActionFn(0);
 let ___sym0 = ___pop_Variant91(___symbols);
-let ___start = ___sym0.0.clone();
-let ___end = ___sym0.2.clone();
 let ___nt = super::___action0::<>(text, ___sym0);
 return Some(Ok(___nt));
 }
@@ -5856,11 +6026,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// "::"? = "::" => ActionFn(134);
+// "::"? = "::" => ActionFn(137);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action134::<>(text, ___sym0);
+let ___nt = super::___action137::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant2(___nt), ___end));
 (1, 0)
 }
@@ -5873,10 +6043,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// "::"? =  => ActionFn(135);
+// "::"? =  => ActionFn(138);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action135::<>(text, &___start, &___end);
+let ___nt = super::___action138::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant2(___nt), ___end));
 (0, 0)
 }
@@ -5889,11 +6059,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ";"? = ";" => ActionFn(151);
+// ";"? = ";" => ActionFn(154);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action151::<>(text, ___sym0);
+let ___nt = super::___action154::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant2(___nt), ___end));
 (1, 1)
 }
@@ -5906,10 +6076,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ";"? =  => ActionFn(152);
+// ";"? =  => ActionFn(155);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action152::<>(text, &___start, &___end);
+let ___nt = super::___action155::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant2(___nt), ___end));
 (0, 1)
 }
@@ -5922,11 +6092,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// "mut"? = "mut" => ActionFn(140);
+// "mut"? = "mut" => ActionFn(143);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action140::<>(text, ___sym0);
+let ___nt = super::___action143::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant2(___nt), ___end));
 (1, 2)
 }
@@ -5939,10 +6109,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// "mut"? =  => ActionFn(141);
+// "mut"? =  => ActionFn(144);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action141::<>(text, &___start, &___end);
+let ___nt = super::___action144::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant2(___nt), ___end));
 (0, 2)
 }
@@ -5955,13 +6125,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("->" <TypeRef>) = "->", TypeRef => ActionFn(167);
+// ("->" <TypeRef>) = "->", TypeRef => ActionFn(170);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action167::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action170::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
 (2, 3)
 }
@@ -5974,13 +6144,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("->" <TypeRef>)? = "->", TypeRef => ActionFn(308);
+// ("->" <TypeRef>)? = "->", TypeRef => ActionFn(311);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action308::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action311::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
 (2, 4)
 }
@@ -5993,10 +6163,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("->" <TypeRef>)? =  => ActionFn(166);
+// ("->" <TypeRef>)? =  => ActionFn(169);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action166::<>(text, &___start, &___end);
+let ___nt = super::___action169::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
 (0, 4)
 }
@@ -6009,13 +6179,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (":" <TypeRef>) = ":", TypeRef => ActionFn(159);
+// (":" <TypeRef>) = ":", TypeRef => ActionFn(162);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action159::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action162::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
 (2, 5)
 }
@@ -6028,13 +6198,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (":" <TypeRef>)? = ":", TypeRef => ActionFn(313);
+// (":" <TypeRef>)? = ":", TypeRef => ActionFn(316);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action313::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action316::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
 (2, 6)
 }
@@ -6047,10 +6217,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (":" <TypeRef>)? =  => ActionFn(158);
+// (":" <TypeRef>)? =  => ActionFn(161);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action158::<>(text, &___start, &___end);
+let ___nt = super::___action161::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
 (0, 6)
 }
@@ -6063,14 +6233,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("<" <Comma<TypeBoundParameter>> ">") = "<", Comma<TypeBoundParameter>, ">" => ActionFn(163);
+// ("<" <Comma<TypeBoundParameter>> ">") = "<", Comma<TypeBoundParameter>, ">" => ActionFn(166);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant5(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action163::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action166::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant5(___nt), ___end));
 (3, 7)
 }
@@ -6083,14 +6253,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("<" <Comma<TypeBoundParameter>> ">")? = "<", Comma<TypeBoundParameter>, ">" => ActionFn(316);
+// ("<" <Comma<TypeBoundParameter>> ">")? = "<", Comma<TypeBoundParameter>, ">" => ActionFn(319);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant5(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action316::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action319::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant6(___nt), ___end));
 (3, 8)
 }
@@ -6103,10 +6273,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("<" <Comma<TypeBoundParameter>> ">")? =  => ActionFn(162);
+// ("<" <Comma<TypeBoundParameter>> ">")? =  => ActionFn(165);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action162::<>(text, &___start, &___end);
+let ___nt = super::___action165::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant6(___nt), ___end));
 (0, 8)
 }
@@ -6119,13 +6289,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("if" <Cond>) = "if", Cond => ActionFn(148);
+// ("if" <Cond>) = "if", Cond => ActionFn(151);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant7(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action148::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action151::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant7(___nt), ___end));
 (2, 9)
 }
@@ -6138,13 +6308,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("if" <Cond>)? = "if", Cond => ActionFn(319);
+// ("if" <Cond>)? = "if", Cond => ActionFn(322);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant7(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action319::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action322::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant8(___nt), ___end));
 (2, 10)
 }
@@ -6157,10 +6327,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ("if" <Cond>)? =  => ActionFn(147);
+// ("if" <Cond>)? =  => ActionFn(150);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action147::<>(text, &___start, &___end);
+let ___nt = super::___action150::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant8(___nt), ___end));
 (0, 10)
 }
@@ -6173,10 +6343,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// () =  => ActionFn(170);
+// () =  => ActionFn(173);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action170::<>(text, &___start, &___end);
+let ___nt = super::___action173::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant9(___nt), ___end));
 (0, 11)
 }
@@ -6189,13 +6359,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Alternative> ",") = Alternative, "," => ActionFn(240);
+// (<Alternative> ",") = Alternative, "," => ActionFn(243);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant10(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action240::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action243::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
 (2, 12)
 }
@@ -6208,10 +6378,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Alternative> ",")* =  => ActionFn(238);
+// (<Alternative> ",")* =  => ActionFn(241);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action238::<>(text, &___start, &___end);
+let ___nt = super::___action241::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant11(___nt), ___end));
 (0, 13)
 }
@@ -6224,11 +6394,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Alternative> ",")* = (<Alternative> ",")+ => ActionFn(239);
+// (<Alternative> ",")* = (<Alternative> ",")+ => ActionFn(242);
 let ___sym0 = ___pop_Variant11(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action239::<>(text, ___sym0);
+let ___nt = super::___action242::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant11(___nt), ___end));
 (1, 13)
 }
@@ -6241,13 +6411,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Alternative> ",")+ = Alternative, "," => ActionFn(326);
+// (<Alternative> ",")+ = Alternative, "," => ActionFn(329);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant10(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action326::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action329::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant11(___nt), ___end));
 (2, 14)
 }
@@ -6260,14 +6430,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Alternative> ",")+ = (<Alternative> ",")+, Alternative, "," => ActionFn(327);
+// (<Alternative> ",")+ = (<Alternative> ",")+, Alternative, "," => ActionFn(330);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant10(___symbols);
 let ___sym0 = ___pop_Variant11(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action327::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action330::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant11(___nt), ___end));
 (3, 14)
 }
@@ -6280,13 +6450,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Conversion> ",") = Conversion, "," => ActionFn(264);
+// (<Conversion> ",") = Conversion, "," => ActionFn(267);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant12(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action264::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action267::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant12(___nt), ___end));
 (2, 15)
 }
@@ -6299,10 +6469,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Conversion> ",")* =  => ActionFn(262);
+// (<Conversion> ",")* =  => ActionFn(265);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action262::<>(text, &___start, &___end);
+let ___nt = super::___action265::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant13(___nt), ___end));
 (0, 16)
 }
@@ -6315,11 +6485,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Conversion> ",")* = (<Conversion> ",")+ => ActionFn(263);
+// (<Conversion> ",")* = (<Conversion> ",")+ => ActionFn(266);
 let ___sym0 = ___pop_Variant13(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action263::<>(text, ___sym0);
+let ___nt = super::___action266::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant13(___nt), ___end));
 (1, 16)
 }
@@ -6332,13 +6502,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Conversion> ",")+ = Conversion, "," => ActionFn(330);
+// (<Conversion> ",")+ = Conversion, "," => ActionFn(333);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant12(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action330::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action333::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant13(___nt), ___end));
 (2, 17)
 }
@@ -6351,14 +6521,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Conversion> ",")+ = (<Conversion> ",")+, Conversion, "," => ActionFn(331);
+// (<Conversion> ",")+ = (<Conversion> ",")+, Conversion, "," => ActionFn(334);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant12(___symbols);
 let ___sym0 = ___pop_Variant13(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action331::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action334::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant13(___nt), ___end));
 (3, 17)
 }
@@ -6371,13 +6541,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<FieldPattern> ",") = FieldPattern, "," => ActionFn(125);
+// (<FieldPattern> ",") = FieldPattern, "," => ActionFn(128);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant14(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action125::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action128::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant14(___nt), ___end));
 (2, 18)
 }
@@ -6390,10 +6560,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<FieldPattern> ",")* =  => ActionFn(123);
+// (<FieldPattern> ",")* =  => ActionFn(126);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action123::<>(text, &___start, &___end);
+let ___nt = super::___action126::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant15(___nt), ___end));
 (0, 19)
 }
@@ -6406,11 +6576,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<FieldPattern> ",")* = (<FieldPattern> ",")+ => ActionFn(124);
+// (<FieldPattern> ",")* = (<FieldPattern> ",")+ => ActionFn(127);
 let ___sym0 = ___pop_Variant15(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action124::<>(text, ___sym0);
+let ___nt = super::___action127::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant15(___nt), ___end));
 (1, 19)
 }
@@ -6423,13 +6593,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<FieldPattern> ",")+ = FieldPattern, "," => ActionFn(334);
+// (<FieldPattern> ",")+ = FieldPattern, "," => ActionFn(337);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant14(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action334::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action337::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant15(___nt), ___end));
 (2, 20)
 }
@@ -6442,14 +6612,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<FieldPattern> ",")+ = (<FieldPattern> ",")+, FieldPattern, "," => ActionFn(335);
+// (<FieldPattern> ",")+ = (<FieldPattern> ",")+, FieldPattern, "," => ActionFn(338);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant14(___symbols);
 let ___sym0 = ___pop_Variant15(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action335::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action338::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant15(___nt), ___end));
 (3, 20)
 }
@@ -6462,13 +6632,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarParameter> ",") = GrammarParameter, "," => ActionFn(230);
+// (<GrammarParameter> ",") = GrammarParameter, "," => ActionFn(233);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant16(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action230::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action233::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant16(___nt), ___end));
 (2, 21)
 }
@@ -6481,10 +6651,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarParameter> ",")* =  => ActionFn(228);
+// (<GrammarParameter> ",")* =  => ActionFn(231);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action228::<>(text, &___start, &___end);
+let ___nt = super::___action231::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant17(___nt), ___end));
 (0, 22)
 }
@@ -6497,11 +6667,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarParameter> ",")* = (<GrammarParameter> ",")+ => ActionFn(229);
+// (<GrammarParameter> ",")* = (<GrammarParameter> ",")+ => ActionFn(232);
 let ___sym0 = ___pop_Variant17(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action229::<>(text, ___sym0);
+let ___nt = super::___action232::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant17(___nt), ___end));
 (1, 22)
 }
@@ -6514,13 +6684,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarParameter> ",")+ = GrammarParameter, "," => ActionFn(340);
+// (<GrammarParameter> ",")+ = GrammarParameter, "," => ActionFn(343);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant16(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action340::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action343::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant17(___nt), ___end));
 (2, 23)
 }
@@ -6533,14 +6703,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarParameter> ",")+ = (<GrammarParameter> ",")+, GrammarParameter, "," => ActionFn(341);
+// (<GrammarParameter> ",")+ = (<GrammarParameter> ",")+, GrammarParameter, "," => ActionFn(344);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant16(___symbols);
 let ___sym0 = ___pop_Variant17(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action341::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action344::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant17(___nt), ___end));
 (3, 23)
 }
@@ -6553,13 +6723,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarWhereClause> ",") = GrammarWhereClause, "," => ActionFn(207);
+// (<GrammarWhereClause> ",") = GrammarWhereClause, "," => ActionFn(210);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant18(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action207::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action210::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant18(___nt), ___end));
 (2, 24)
 }
@@ -6572,10 +6742,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarWhereClause> ",")* =  => ActionFn(205);
+// (<GrammarWhereClause> ",")* =  => ActionFn(208);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action205::<>(text, &___start, &___end);
+let ___nt = super::___action208::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant19(___nt), ___end));
 (0, 25)
 }
@@ -6588,11 +6758,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarWhereClause> ",")* = (<GrammarWhereClause> ",")+ => ActionFn(206);
+// (<GrammarWhereClause> ",")* = (<GrammarWhereClause> ",")+ => ActionFn(209);
 let ___sym0 = ___pop_Variant19(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action206::<>(text, ___sym0);
+let ___nt = super::___action209::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant19(___nt), ___end));
 (1, 25)
 }
@@ -6605,13 +6775,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarWhereClause> ",")+ = GrammarWhereClause, "," => ActionFn(344);
+// (<GrammarWhereClause> ",")+ = GrammarWhereClause, "," => ActionFn(347);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant18(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action344::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action347::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant19(___nt), ___end));
 (2, 26)
 }
@@ -6624,14 +6794,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<GrammarWhereClause> ",")+ = (<GrammarWhereClause> ",")+, GrammarWhereClause, "," => ActionFn(345);
+// (<GrammarWhereClause> ",")+ = (<GrammarWhereClause> ",")+, GrammarWhereClause, "," => ActionFn(348);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant18(___symbols);
 let ___sym0 = ___pop_Variant19(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action345::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action348::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant19(___nt), ___end));
 (3, 26)
 }
@@ -6644,13 +6814,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Id> "::") = Id, "::" => ActionFn(133);
+// (<Id> "::") = Id, "::" => ActionFn(136);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant20(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action133::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action136::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
 (2, 27)
 }
@@ -6663,10 +6833,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Id> "::")* =  => ActionFn(131);
+// (<Id> "::")* =  => ActionFn(134);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action131::<>(text, &___start, &___end);
+let ___nt = super::___action134::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant21(___nt), ___end));
 (0, 28)
 }
@@ -6679,11 +6849,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Id> "::")* = (<Id> "::")+ => ActionFn(132);
+// (<Id> "::")* = (<Id> "::")+ => ActionFn(135);
 let ___sym0 = ___pop_Variant21(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action132::<>(text, ___sym0);
+let ___nt = super::___action135::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant21(___nt), ___end));
 (1, 28)
 }
@@ -6696,13 +6866,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Id> "::")+ = Id, "::" => ActionFn(348);
+// (<Id> "::")+ = Id, "::" => ActionFn(351);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant20(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action348::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action351::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant21(___nt), ___end));
 (2, 29)
 }
@@ -6715,14 +6885,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Id> "::")+ = (<Id> "::")+, Id, "::" => ActionFn(349);
+// (<Id> "::")+ = (<Id> "::")+, Id, "::" => ActionFn(352);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant20(___symbols);
 let ___sym0 = ___pop_Variant21(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action349::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action352::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant21(___nt), ___end));
 (3, 29)
 }
@@ -6735,13 +6905,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Lifetime> "+") = Lifetime, "+" => ActionFn(210);
+// (<Lifetime> "+") = Lifetime, "+" => ActionFn(213);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant22(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action210::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action213::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant22(___nt), ___end));
 (2, 30)
 }
@@ -6754,10 +6924,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Lifetime> "+")* =  => ActionFn(208);
+// (<Lifetime> "+")* =  => ActionFn(211);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action208::<>(text, &___start, &___end);
+let ___nt = super::___action211::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant23(___nt), ___end));
 (0, 31)
 }
@@ -6770,11 +6940,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Lifetime> "+")* = (<Lifetime> "+")+ => ActionFn(209);
+// (<Lifetime> "+")* = (<Lifetime> "+")+ => ActionFn(212);
 let ___sym0 = ___pop_Variant23(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action209::<>(text, ___sym0);
+let ___nt = super::___action212::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant23(___nt), ___end));
 (1, 31)
 }
@@ -6787,13 +6957,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Lifetime> "+")+ = Lifetime, "+" => ActionFn(354);
+// (<Lifetime> "+")+ = Lifetime, "+" => ActionFn(357);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant22(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action354::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action357::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant23(___nt), ___end));
 (2, 32)
 }
@@ -6806,14 +6976,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Lifetime> "+")+ = (<Lifetime> "+")+, Lifetime, "+" => ActionFn(355);
+// (<Lifetime> "+")+ = (<Lifetime> "+")+, Lifetime, "+" => ActionFn(358);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant22(___symbols);
 let ___sym0 = ___pop_Variant23(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action355::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action358::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant23(___nt), ___end));
 (3, 32)
 }
@@ -6826,13 +6996,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<MatchItem> ",") = MatchItem, "," => ActionFn(259);
+// (<MatchItem> ",") = MatchItem, "," => ActionFn(262);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant24(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action259::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action262::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant24(___nt), ___end));
 (2, 33)
 }
@@ -6845,10 +7015,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<MatchItem> ",")* =  => ActionFn(257);
+// (<MatchItem> ",")* =  => ActionFn(260);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action257::<>(text, &___start, &___end);
+let ___nt = super::___action260::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant25(___nt), ___end));
 (0, 34)
 }
@@ -6861,11 +7031,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<MatchItem> ",")* = (<MatchItem> ",")+ => ActionFn(258);
+// (<MatchItem> ",")* = (<MatchItem> ",")+ => ActionFn(261);
 let ___sym0 = ___pop_Variant25(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action258::<>(text, ___sym0);
+let ___nt = super::___action261::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant25(___nt), ___end));
 (1, 34)
 }
@@ -6878,13 +7048,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<MatchItem> ",")+ = MatchItem, "," => ActionFn(358);
+// (<MatchItem> ",")+ = MatchItem, "," => ActionFn(361);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant24(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action358::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action361::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant25(___nt), ___end));
 (2, 35)
 }
@@ -6897,14 +7067,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<MatchItem> ",")+ = (<MatchItem> ",")+, MatchItem, "," => ActionFn(359);
+// (<MatchItem> ",")+ = (<MatchItem> ",")+, MatchItem, "," => ActionFn(362);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant24(___symbols);
 let ___sym0 = ___pop_Variant25(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action359::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action362::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant25(___nt), ___end));
 (3, 35)
 }
@@ -6917,13 +7087,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<NotMacroId> ",") = NotMacroId, "," => ActionFn(235);
+// (<NotMacroId> ",") = NotMacroId, "," => ActionFn(238);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant26(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action235::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action238::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant26(___nt), ___end));
 (2, 36)
 }
@@ -6936,10 +7106,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<NotMacroId> ",")* =  => ActionFn(233);
+// (<NotMacroId> ",")* =  => ActionFn(236);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action233::<>(text, &___start, &___end);
+let ___nt = super::___action236::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant27(___nt), ___end));
 (0, 37)
 }
@@ -6952,11 +7122,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<NotMacroId> ",")* = (<NotMacroId> ",")+ => ActionFn(234);
+// (<NotMacroId> ",")* = (<NotMacroId> ",")+ => ActionFn(237);
 let ___sym0 = ___pop_Variant27(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action234::<>(text, ___sym0);
+let ___nt = super::___action237::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant27(___nt), ___end));
 (1, 37)
 }
@@ -6969,13 +7139,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<NotMacroId> ",")+ = NotMacroId, "," => ActionFn(362);
+// (<NotMacroId> ",")+ = NotMacroId, "," => ActionFn(365);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant26(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action362::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action365::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant27(___nt), ___end));
 (2, 38)
 }
@@ -6988,14 +7158,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<NotMacroId> ",")+ = (<NotMacroId> ",")+, NotMacroId, "," => ActionFn(363);
+// (<NotMacroId> ",")+ = (<NotMacroId> ",")+, NotMacroId, "," => ActionFn(366);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant26(___symbols);
 let ___sym0 = ___pop_Variant27(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action363::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action366::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant27(___nt), ___end));
 (3, 38)
 }
@@ -7008,13 +7178,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Pattern> ",") = Pattern, "," => ActionFn(269);
+// (<Pattern> ",") = Pattern, "," => ActionFn(272);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant28(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action269::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action272::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant28(___nt), ___end));
 (2, 39)
 }
@@ -7027,10 +7197,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Pattern> ",")* =  => ActionFn(267);
+// (<Pattern> ",")* =  => ActionFn(270);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action267::<>(text, &___start, &___end);
+let ___nt = super::___action270::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant29(___nt), ___end));
 (0, 40)
 }
@@ -7043,11 +7213,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Pattern> ",")* = (<Pattern> ",")+ => ActionFn(268);
+// (<Pattern> ",")* = (<Pattern> ",")+ => ActionFn(271);
 let ___sym0 = ___pop_Variant29(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action268::<>(text, ___sym0);
+let ___nt = super::___action271::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant29(___nt), ___end));
 (1, 40)
 }
@@ -7060,13 +7230,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Pattern> ",")+ = Pattern, "," => ActionFn(366);
+// (<Pattern> ",")+ = Pattern, "," => ActionFn(369);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant28(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action366::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action369::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant29(___nt), ___end));
 (2, 41)
 }
@@ -7079,14 +7249,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Pattern> ",")+ = (<Pattern> ",")+, Pattern, "," => ActionFn(367);
+// (<Pattern> ",")+ = (<Pattern> ",")+, Pattern, "," => ActionFn(370);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant28(___symbols);
 let ___sym0 = ___pop_Variant29(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action367::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action370::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant29(___nt), ___end));
 (3, 41)
 }
@@ -7099,13 +7269,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Symbol> ",") = Symbol, "," => ActionFn(245);
+// (<Symbol> ",") = Symbol, "," => ActionFn(248);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant30(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action245::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action248::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
 (2, 42)
 }
@@ -7118,10 +7288,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Symbol> ",")* =  => ActionFn(243);
+// (<Symbol> ",")* =  => ActionFn(246);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action243::<>(text, &___start, &___end);
+let ___nt = super::___action246::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
 (0, 43)
 }
@@ -7134,11 +7304,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Symbol> ",")* = (<Symbol> ",")+ => ActionFn(244);
+// (<Symbol> ",")* = (<Symbol> ",")+ => ActionFn(247);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action244::<>(text, ___sym0);
+let ___nt = super::___action247::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
 (1, 43)
 }
@@ -7151,13 +7321,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Symbol> ",")+ = Symbol, "," => ActionFn(370);
+// (<Symbol> ",")+ = Symbol, "," => ActionFn(373);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant30(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action370::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action373::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
 (2, 44)
 }
@@ -7170,14 +7340,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<Symbol> ",")+ = (<Symbol> ",")+, Symbol, "," => ActionFn(371);
+// (<Symbol> ",")+ = (<Symbol> ",")+, Symbol, "," => ActionFn(374);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant30(___symbols);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action371::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action374::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
 (3, 44)
 }
@@ -7190,13 +7360,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBound> "+") = TypeBound, "+" => ActionFn(215);
+// (<TypeBound> "+") = TypeBound, "+" => ActionFn(218);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant32(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action215::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action218::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
 (2, 45)
 }
@@ -7209,10 +7379,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBound> "+")* =  => ActionFn(213);
+// (<TypeBound> "+")* =  => ActionFn(216);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action213::<>(text, &___start, &___end);
+let ___nt = super::___action216::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant33(___nt), ___end));
 (0, 46)
 }
@@ -7225,11 +7395,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBound> "+")* = (<TypeBound> "+")+ => ActionFn(214);
+// (<TypeBound> "+")* = (<TypeBound> "+")+ => ActionFn(217);
 let ___sym0 = ___pop_Variant33(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action214::<>(text, ___sym0);
+let ___nt = super::___action217::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant33(___nt), ___end));
 (1, 46)
 }
@@ -7242,13 +7412,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBound> "+")+ = TypeBound, "+" => ActionFn(374);
+// (<TypeBound> "+")+ = TypeBound, "+" => ActionFn(377);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant32(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action374::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action377::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant33(___nt), ___end));
 (2, 47)
 }
@@ -7261,14 +7431,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBound> "+")+ = (<TypeBound> "+")+, TypeBound, "+" => ActionFn(375);
+// (<TypeBound> "+")+ = (<TypeBound> "+")+, TypeBound, "+" => ActionFn(378);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant32(___symbols);
 let ___sym0 = ___pop_Variant33(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action375::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action378::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant33(___nt), ___end));
 (3, 47)
 }
@@ -7281,13 +7451,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBoundParameter> ",") = TypeBoundParameter, "," => ActionFn(225);
+// (<TypeBoundParameter> ",") = TypeBoundParameter, "," => ActionFn(228);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant34(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action225::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action228::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant34(___nt), ___end));
 (2, 48)
 }
@@ -7300,10 +7470,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBoundParameter> ",")* =  => ActionFn(223);
+// (<TypeBoundParameter> ",")* =  => ActionFn(226);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action223::<>(text, &___start, &___end);
+let ___nt = super::___action226::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant35(___nt), ___end));
 (0, 49)
 }
@@ -7316,11 +7486,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBoundParameter> ",")* = (<TypeBoundParameter> ",")+ => ActionFn(224);
+// (<TypeBoundParameter> ",")* = (<TypeBoundParameter> ",")+ => ActionFn(227);
 let ___sym0 = ___pop_Variant35(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action224::<>(text, ___sym0);
+let ___nt = super::___action227::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant35(___nt), ___end));
 (1, 49)
 }
@@ -7333,13 +7503,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBoundParameter> ",")+ = TypeBoundParameter, "," => ActionFn(378);
+// (<TypeBoundParameter> ",")+ = TypeBoundParameter, "," => ActionFn(381);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant34(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action378::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action381::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant35(___nt), ___end));
 (2, 50)
 }
@@ -7352,14 +7522,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeBoundParameter> ",")+ = (<TypeBoundParameter> ",")+, TypeBoundParameter, "," => ActionFn(379);
+// (<TypeBoundParameter> ",")+ = (<TypeBoundParameter> ",")+, TypeBoundParameter, "," => ActionFn(382);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant34(___symbols);
 let ___sym0 = ___pop_Variant35(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action379::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action382::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant35(___nt), ___end));
 (3, 50)
 }
@@ -7372,13 +7542,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeParameter> ",") = TypeParameter, "," => ActionFn(202);
+// (<TypeParameter> ",") = TypeParameter, "," => ActionFn(205);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant36(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action202::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action205::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant36(___nt), ___end));
 (2, 51)
 }
@@ -7391,10 +7561,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeParameter> ",")* =  => ActionFn(200);
+// (<TypeParameter> ",")* =  => ActionFn(203);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action200::<>(text, &___start, &___end);
+let ___nt = super::___action203::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant37(___nt), ___end));
 (0, 52)
 }
@@ -7407,11 +7577,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeParameter> ",")* = (<TypeParameter> ",")+ => ActionFn(201);
+// (<TypeParameter> ",")* = (<TypeParameter> ",")+ => ActionFn(204);
 let ___sym0 = ___pop_Variant37(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action201::<>(text, ___sym0);
+let ___nt = super::___action204::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant37(___nt), ___end));
 (1, 52)
 }
@@ -7424,13 +7594,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeParameter> ",")+ = TypeParameter, "," => ActionFn(382);
+// (<TypeParameter> ",")+ = TypeParameter, "," => ActionFn(385);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant36(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action382::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action385::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant37(___nt), ___end));
 (2, 53)
 }
@@ -7443,14 +7613,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeParameter> ",")+ = (<TypeParameter> ",")+, TypeParameter, "," => ActionFn(383);
+// (<TypeParameter> ",")+ = (<TypeParameter> ",")+, TypeParameter, "," => ActionFn(386);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant36(___symbols);
 let ___sym0 = ___pop_Variant37(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action383::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action386::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant37(___nt), ___end));
 (3, 53)
 }
@@ -7463,13 +7633,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRef> ",") = TypeRef, "," => ActionFn(220);
+// (<TypeRef> ",") = TypeRef, "," => ActionFn(223);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action220::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action223::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
 (2, 54)
 }
@@ -7482,10 +7652,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRef> ",")* =  => ActionFn(218);
+// (<TypeRef> ",")* =  => ActionFn(221);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action218::<>(text, &___start, &___end);
+let ___nt = super::___action221::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (0, 55)
 }
@@ -7498,11 +7668,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRef> ",")* = (<TypeRef> ",")+ => ActionFn(219);
+// (<TypeRef> ",")* = (<TypeRef> ",")+ => ActionFn(222);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action219::<>(text, ___sym0);
+let ___nt = super::___action222::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (1, 55)
 }
@@ -7515,13 +7685,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRef> ",")+ = TypeRef, "," => ActionFn(386);
+// (<TypeRef> ",")+ = TypeRef, "," => ActionFn(389);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action386::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action389::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (2, 56)
 }
@@ -7534,14 +7704,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRef> ",")+ = (<TypeRef> ",")+, TypeRef, "," => ActionFn(387);
+// (<TypeRef> ",")+ = (<TypeRef> ",")+, TypeRef, "," => ActionFn(390);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action387::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action390::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (3, 56)
 }
@@ -7554,13 +7724,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRefOrLifetime> ",") = TypeRefOrLifetime, "," => ActionFn(250);
+// (<TypeRefOrLifetime> ",") = TypeRefOrLifetime, "," => ActionFn(253);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action250::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action253::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
 (2, 57)
 }
@@ -7573,10 +7743,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRefOrLifetime> ",")* =  => ActionFn(248);
+// (<TypeRefOrLifetime> ",")* =  => ActionFn(251);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action248::<>(text, &___start, &___end);
+let ___nt = super::___action251::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (0, 58)
 }
@@ -7589,11 +7759,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRefOrLifetime> ",")* = (<TypeRefOrLifetime> ",")+ => ActionFn(249);
+// (<TypeRefOrLifetime> ",")* = (<TypeRefOrLifetime> ",")+ => ActionFn(252);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action249::<>(text, ___sym0);
+let ___nt = super::___action252::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (1, 58)
 }
@@ -7606,13 +7776,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRefOrLifetime> ",")+ = TypeRefOrLifetime, "," => ActionFn(390);
+// (<TypeRefOrLifetime> ",")+ = TypeRefOrLifetime, "," => ActionFn(393);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action390::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action393::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (2, 59)
 }
@@ -7625,14 +7795,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// (<TypeRefOrLifetime> ",")+ = (<TypeRefOrLifetime> ",")+, TypeRefOrLifetime, "," => ActionFn(391);
+// (<TypeRefOrLifetime> ",")+ = (<TypeRefOrLifetime> ",")+, TypeRefOrLifetime, "," => ActionFn(394);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action391::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action394::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant38(___nt), ___end));
 (3, 59)
 }
@@ -7645,10 +7815,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// @L =  => ActionFn(183);
+// @L =  => ActionFn(186);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action183::<>(text, &___start, &___end);
+let ___nt = super::___action186::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant39(___nt), ___end));
 (0, 60)
 }
@@ -7661,10 +7831,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// @R =  => ActionFn(182);
+// @R =  => ActionFn(185);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action182::<>(text, &___start, &___end);
+let ___nt = super::___action185::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant39(___nt), ___end));
 (0, 61)
 }
@@ -7745,11 +7915,11 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Action? = Action => ActionFn(144);
+// Action? = Action => ActionFn(147);
 let ___sym0 = ___pop_Variant40(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action144::<>(text, ___sym0);
+let ___nt = super::___action147::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant41(___nt), ___end));
 (1, 63)
 }
@@ -7762,10 +7932,10 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Action? =  => ActionFn(145);
+// Action? =  => ActionFn(148);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action145::<>(text, &___start, &___end);
+let ___nt = super::___action148::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant41(___nt), ___end));
 (0, 63)
 }
@@ -7778,7 +7948,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative = Symbol+, "if", Cond, Action => ActionFn(444);
+// Alternative = Symbol+, "if", Cond, Action => ActionFn(456);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant40(___symbols);
 let ___sym2 = ___pop_Variant7(___symbols);
@@ -7786,7 +7956,7 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action444::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action456::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
 (4, 64)
 }
@@ -7799,16 +7969,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative = Symbol+, "if", Cond => ActionFn(445);
-assert!(___symbols.len() >= 3);
-let ___sym2 = ___pop_Variant7(___symbols);
-let ___sym1 = ___pop_Variant0(___symbols);
-let ___sym0 = ___pop_Variant31(___symbols);
+// Alternative = Annotation+, Symbol+, "if", Cond, Action => ActionFn(457);
+assert!(___symbols.len() >= 5);
+let ___sym4 = ___pop_Variant40(___symbols);
+let ___sym3 = ___pop_Variant7(___symbols);
+let ___sym2 = ___pop_Variant0(___symbols);
+let ___sym1 = ___pop_Variant31(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
-let ___end = ___sym2.2.clone();
-let ___nt = super::___action445::<>(text, ___sym0, ___sym1, ___sym2);
+let ___end = ___sym4.2.clone();
+let ___nt = super::___action457::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
-(3, 64)
+(5, 64)
 }
 pub(crate) fn ___reduce109<
     'input,
@@ -7819,15 +7991,16 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative = Symbol+, Action => ActionFn(446);
-assert!(___symbols.len() >= 2);
-let ___sym1 = ___pop_Variant40(___symbols);
+// Alternative = Symbol+, "if", Cond => ActionFn(458);
+assert!(___symbols.len() >= 3);
+let ___sym2 = ___pop_Variant7(___symbols);
+let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
-let ___end = ___sym1.2.clone();
-let ___nt = super::___action446::<>(text, ___sym0, ___sym1);
+let ___end = ___sym2.2.clone();
+let ___nt = super::___action458::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
-(2, 64)
+(3, 64)
 }
 pub(crate) fn ___reduce110<
     'input,
@@ -7838,13 +8011,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative = Symbol+ => ActionFn(447);
-let ___sym0 = ___pop_Variant31(___symbols);
+// Alternative = Annotation+, Symbol+, "if", Cond => ActionFn(459);
+assert!(___symbols.len() >= 4);
+let ___sym3 = ___pop_Variant7(___symbols);
+let ___sym2 = ___pop_Variant0(___symbols);
+let ___sym1 = ___pop_Variant31(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
-let ___end = ___sym0.2.clone();
-let ___nt = super::___action447::<>(text, ___sym0);
+let ___end = ___sym3.2.clone();
+let ___nt = super::___action459::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
-(1, 64)
+(4, 64)
 }
 pub(crate) fn ___reduce111<
     'input,
@@ -7855,16 +8032,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative = "if", Cond, Action => ActionFn(421);
-assert!(___symbols.len() >= 3);
-let ___sym2 = ___pop_Variant40(___symbols);
-let ___sym1 = ___pop_Variant7(___symbols);
-let ___sym0 = ___pop_Variant0(___symbols);
+// Alternative = Symbol+, Action => ActionFn(460);
+assert!(___symbols.len() >= 2);
+let ___sym1 = ___pop_Variant40(___symbols);
+let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
-let ___end = ___sym2.2.clone();
-let ___nt = super::___action421::<>(text, ___sym0, ___sym1, ___sym2);
+let ___end = ___sym1.2.clone();
+let ___nt = super::___action460::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
-(3, 64)
+(2, 64)
 }
 pub(crate) fn ___reduce112<
     'input,
@@ -7875,13 +8051,16 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative = Action => ActionFn(422);
-let ___sym0 = ___pop_Variant40(___symbols);
+// Alternative = Annotation+, Symbol+, Action => ActionFn(461);
+assert!(___symbols.len() >= 3);
+let ___sym2 = ___pop_Variant40(___symbols);
+let ___sym1 = ___pop_Variant31(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
-let ___end = ___sym0.2.clone();
-let ___nt = super::___action422::<>(text, ___sym0);
+let ___end = ___sym2.2.clone();
+let ___nt = super::___action461::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
-(1, 64)
+(3, 64)
 }
 pub(crate) fn ___reduce113<
     'input,
@@ -7892,13 +8071,13 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative? = Alternative => ActionFn(236);
-let ___sym0 = ___pop_Variant10(___symbols);
+// Alternative = Symbol+ => ActionFn(462);
+let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action236::<>(text, ___sym0);
-___symbols.push((___start, ___Symbol::Variant42(___nt), ___end));
-(1, 65)
+let ___nt = super::___action462::<>(text, ___sym0);
+___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
+(1, 64)
 }
 pub(crate) fn ___reduce114<
     'input,
@@ -7909,12 +8088,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternative? =  => ActionFn(237);
-let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
-let ___end = ___start.clone();
-let ___nt = super::___action237::<>(text, &___start, &___end);
-___symbols.push((___start, ___Symbol::Variant42(___nt), ___end));
-(0, 65)
+// Alternative = Annotation+, Symbol+ => ActionFn(463);
+assert!(___symbols.len() >= 2);
+let ___sym1 = ___pop_Variant31(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym1.2.clone();
+let ___nt = super::___action463::<>(text, ___sym0, ___sym1);
+___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
+(2, 64)
 }
 pub(crate) fn ___reduce115<
     'input,
@@ -7925,9 +8107,119 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternatives = Alternative, ";" => ActionFn(38);
-assert!(___symbols.len() >= 2);
-let ___sym1 = ___pop_Variant0(___symbols);
+// Alternative = "if", Cond, Action => ActionFn(464);
+assert!(___symbols.len() >= 3);
+let ___sym2 = ___pop_Variant40(___symbols);
+let ___sym1 = ___pop_Variant7(___symbols);
+let ___sym0 = ___pop_Variant0(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym2.2.clone();
+let ___nt = super::___action464::<>(text, ___sym0, ___sym1, ___sym2);
+___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
+(3, 64)
+}
+pub(crate) fn ___reduce116<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// Alternative = Annotation+, "if", Cond, Action => ActionFn(465);
+assert!(___symbols.len() >= 4);
+let ___sym3 = ___pop_Variant40(___symbols);
+let ___sym2 = ___pop_Variant7(___symbols);
+let ___sym1 = ___pop_Variant0(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym3.2.clone();
+let ___nt = super::___action465::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
+(4, 64)
+}
+pub(crate) fn ___reduce117<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// Alternative = Action => ActionFn(466);
+let ___sym0 = ___pop_Variant40(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym0.2.clone();
+let ___nt = super::___action466::<>(text, ___sym0);
+___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
+(1, 64)
+}
+pub(crate) fn ___reduce118<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// Alternative = Annotation+, Action => ActionFn(467);
+assert!(___symbols.len() >= 2);
+let ___sym1 = ___pop_Variant40(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym1.2.clone();
+let ___nt = super::___action467::<>(text, ___sym0, ___sym1);
+___symbols.push((___start, ___Symbol::Variant10(___nt), ___end));
+(2, 64)
+}
+pub(crate) fn ___reduce119<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// Alternative? = Alternative => ActionFn(239);
+let ___sym0 = ___pop_Variant10(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym0.2.clone();
+let ___nt = super::___action239::<>(text, ___sym0);
+___symbols.push((___start, ___Symbol::Variant42(___nt), ___end));
+(1, 65)
+}
+pub(crate) fn ___reduce120<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// Alternative? =  => ActionFn(240);
+let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+let ___end = ___start.clone();
+let ___nt = super::___action240::<>(text, &___start, &___end);
+___symbols.push((___start, ___Symbol::Variant42(___nt), ___end));
+(0, 65)
+}
+pub(crate) fn ___reduce121<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// Alternatives = Alternative, ";" => ActionFn(38);
+assert!(___symbols.len() >= 2);
+let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant10(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
@@ -7935,7 +8227,7 @@ let ___nt = super::___action38::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant43(___nt), ___end));
 (2, 66)
 }
-pub(crate) fn ___reduce116<
+pub(crate) fn ___reduce122<
     'input,
 >(
 text: &'input str,
@@ -7944,7 +8236,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternatives = "{", Comma<Alternative>, "}", ";" => ActionFn(302);
+// Alternatives = "{", Comma<Alternative>, "}", ";" => ActionFn(305);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -7952,11 +8244,11 @@ let ___sym1 = ___pop_Variant43(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action302::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action305::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant43(___nt), ___end));
 (4, 66)
 }
-pub(crate) fn ___reduce117<
+pub(crate) fn ___reduce123<
     'input,
 >(
 text: &'input str,
@@ -7965,18 +8257,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Alternatives = "{", Comma<Alternative>, "}" => ActionFn(303);
+// Alternatives = "{", Comma<Alternative>, "}" => ActionFn(306);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant43(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action303::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action306::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant43(___nt), ___end));
 (3, 66)
 }
-pub(crate) fn ___reduce118<
+pub(crate) fn ___reduce124<
     'input,
 >(
 text: &'input str,
@@ -7985,7 +8277,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Annotation = "#", "[", Id, AnnotationArg, "]" => ActionFn(458);
+// Annotation = "#", "[", Id, AnnotationArg, "]" => ActionFn(480);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant46(___symbols);
@@ -7994,11 +8286,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action458::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action480::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant44(___nt), ___end));
 (5, 67)
 }
-pub(crate) fn ___reduce119<
+pub(crate) fn ___reduce125<
     'input,
 >(
 text: &'input str,
@@ -8007,7 +8299,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Annotation = "#", "[", Id, "]" => ActionFn(459);
+// Annotation = "#", "[", Id, "]" => ActionFn(481);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant20(___symbols);
@@ -8015,11 +8307,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action459::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action481::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant44(___nt), ___end));
 (4, 67)
 }
-pub(crate) fn ___reduce120<
+pub(crate) fn ___reduce126<
     'input,
 >(
 text: &'input str,
@@ -8028,14 +8320,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Annotation* =  => ActionFn(184);
+// Annotation* =  => ActionFn(187);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action184::<>(text, &___start, &___end);
+let ___nt = super::___action187::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant45(___nt), ___end));
 (0, 68)
 }
-pub(crate) fn ___reduce121<
+pub(crate) fn ___reduce127<
     'input,
 >(
 text: &'input str,
@@ -8044,15 +8336,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Annotation* = Annotation+ => ActionFn(185);
+// Annotation* = Annotation+ => ActionFn(188);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action185::<>(text, ___sym0);
+let ___nt = super::___action188::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant45(___nt), ___end));
 (1, 68)
 }
-pub(crate) fn ___reduce122<
+pub(crate) fn ___reduce128<
     'input,
 >(
 text: &'input str,
@@ -8061,15 +8353,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Annotation+ = Annotation => ActionFn(194);
+// Annotation+ = Annotation => ActionFn(197);
 let ___sym0 = ___pop_Variant44(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action194::<>(text, ___sym0);
+let ___nt = super::___action197::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant45(___nt), ___end));
 (1, 69)
 }
-pub(crate) fn ___reduce123<
+pub(crate) fn ___reduce129<
     'input,
 >(
 text: &'input str,
@@ -8078,17 +8370,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Annotation+ = Annotation+, Annotation => ActionFn(195);
+// Annotation+ = Annotation+, Annotation => ActionFn(198);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant44(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action195::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action198::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant45(___nt), ___end));
 (2, 69)
 }
-pub(crate) fn ___reduce124<
+pub(crate) fn ___reduce130<
     'input,
 >(
 text: &'input str,
@@ -8110,7 +8402,7 @@ let ___nt = super::___action33::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___
 ___symbols.push((___start, ___Symbol::Variant46(___nt), ___end));
 (5, 70)
 }
-pub(crate) fn ___reduce125<
+pub(crate) fn ___reduce131<
     'input,
 >(
 text: &'input str,
@@ -8119,15 +8411,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// AnnotationArg? = AnnotationArg => ActionFn(155);
+// AnnotationArg? = AnnotationArg => ActionFn(158);
 let ___sym0 = ___pop_Variant46(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action155::<>(text, ___sym0);
+let ___nt = super::___action158::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant47(___nt), ___end));
 (1, 71)
 }
-pub(crate) fn ___reduce126<
+pub(crate) fn ___reduce132<
     'input,
 >(
 text: &'input str,
@@ -8136,14 +8428,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// AnnotationArg? =  => ActionFn(156);
+// AnnotationArg? =  => ActionFn(159);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action156::<>(text, &___start, &___end);
+let ___nt = super::___action159::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant47(___nt), ___end));
 (0, 71)
 }
-pub(crate) fn ___reduce127<
+pub(crate) fn ___reduce133<
     'input,
 >(
 text: &'input str,
@@ -8152,7 +8444,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// AssociatedType = "type", Id, "=", TypeRef, ";" => ActionFn(424);
+// AssociatedType = "type", Id, "=", TypeRef, ";" => ActionFn(428);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant3(___symbols);
@@ -8161,11 +8453,11 @@ let ___sym1 = ___pop_Variant20(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action424::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action428::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant48(___nt), ___end));
 (5, 72)
 }
-pub(crate) fn ___reduce128<
+pub(crate) fn ___reduce134<
     'input,
 >(
 text: &'input str,
@@ -8174,14 +8466,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// AssociatedType* =  => ActionFn(129);
+// AssociatedType* =  => ActionFn(132);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action129::<>(text, &___start, &___end);
+let ___nt = super::___action132::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant49(___nt), ___end));
 (0, 73)
 }
-pub(crate) fn ___reduce129<
+pub(crate) fn ___reduce135<
     'input,
 >(
 text: &'input str,
@@ -8190,15 +8482,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// AssociatedType* = AssociatedType+ => ActionFn(130);
+// AssociatedType* = AssociatedType+ => ActionFn(133);
 let ___sym0 = ___pop_Variant49(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action130::<>(text, ___sym0);
+let ___nt = super::___action133::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant49(___nt), ___end));
 (1, 73)
 }
-pub(crate) fn ___reduce130<
+pub(crate) fn ___reduce136<
     'input,
 >(
 text: &'input str,
@@ -8207,15 +8499,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// AssociatedType+ = AssociatedType => ActionFn(253);
+// AssociatedType+ = AssociatedType => ActionFn(256);
 let ___sym0 = ___pop_Variant48(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action253::<>(text, ___sym0);
+let ___nt = super::___action256::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant49(___nt), ___end));
 (1, 74)
 }
-pub(crate) fn ___reduce131<
+pub(crate) fn ___reduce137<
     'input,
 >(
 text: &'input str,
@@ -8224,17 +8516,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// AssociatedType+ = AssociatedType+, AssociatedType => ActionFn(254);
+// AssociatedType+ = AssociatedType+, AssociatedType => ActionFn(257);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant48(___symbols);
 let ___sym0 = ___pop_Variant49(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action254::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action257::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant49(___nt), ___end));
 (2, 74)
 }
-pub(crate) fn ___reduce132<
+pub(crate) fn ___reduce139<
     'input,
 >(
 text: &'input str,
@@ -8243,15 +8535,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Alternative> = Alternative => ActionFn(448);
+// Comma<Alternative> = Alternative => ActionFn(452);
 let ___sym0 = ___pop_Variant10(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action448::<>(text, ___sym0);
+let ___nt = super::___action452::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant43(___nt), ___end));
-(1, 75)
+(1, 76)
 }
-pub(crate) fn ___reduce133<
+pub(crate) fn ___reduce140<
     'input,
 >(
 text: &'input str,
@@ -8260,14 +8552,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Alternative> =  => ActionFn(449);
+// Comma<Alternative> =  => ActionFn(453);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action449::<>(text, &___start, &___end);
+let ___nt = super::___action453::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant43(___nt), ___end));
-(0, 75)
+(0, 76)
 }
-pub(crate) fn ___reduce134<
+pub(crate) fn ___reduce141<
     'input,
 >(
 text: &'input str,
@@ -8276,17 +8568,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Alternative> = (<Alternative> ",")+, Alternative => ActionFn(450);
+// Comma<Alternative> = (<Alternative> ",")+, Alternative => ActionFn(454);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant10(___symbols);
 let ___sym0 = ___pop_Variant11(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action450::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action454::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant43(___nt), ___end));
-(2, 75)
+(2, 76)
 }
-pub(crate) fn ___reduce135<
+pub(crate) fn ___reduce142<
     'input,
 >(
 text: &'input str,
@@ -8295,15 +8587,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Alternative> = (<Alternative> ",")+ => ActionFn(451);
+// Comma<Alternative> = (<Alternative> ",")+ => ActionFn(455);
 let ___sym0 = ___pop_Variant11(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action451::<>(text, ___sym0);
+let ___nt = super::___action455::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant43(___nt), ___end));
-(1, 75)
+(1, 76)
 }
-pub(crate) fn ___reduce136<
+pub(crate) fn ___reduce143<
     'input,
 >(
 text: &'input str,
@@ -8312,15 +8604,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Conversion> = Conversion => ActionFn(466);
+// Comma<Conversion> = Conversion => ActionFn(488);
 let ___sym0 = ___pop_Variant12(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action466::<>(text, ___sym0);
+let ___nt = super::___action488::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant50(___nt), ___end));
-(1, 76)
+(1, 77)
 }
-pub(crate) fn ___reduce137<
+pub(crate) fn ___reduce144<
     'input,
 >(
 text: &'input str,
@@ -8329,14 +8621,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Conversion> =  => ActionFn(467);
+// Comma<Conversion> =  => ActionFn(489);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action467::<>(text, &___start, &___end);
+let ___nt = super::___action489::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant50(___nt), ___end));
-(0, 76)
+(0, 77)
 }
-pub(crate) fn ___reduce138<
+pub(crate) fn ___reduce145<
     'input,
 >(
 text: &'input str,
@@ -8345,17 +8637,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Conversion> = (<Conversion> ",")+, Conversion => ActionFn(468);
+// Comma<Conversion> = (<Conversion> ",")+, Conversion => ActionFn(490);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant12(___symbols);
 let ___sym0 = ___pop_Variant13(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action468::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action490::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant50(___nt), ___end));
-(2, 76)
+(2, 77)
 }
-pub(crate) fn ___reduce139<
+pub(crate) fn ___reduce146<
     'input,
 >(
 text: &'input str,
@@ -8364,15 +8656,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Conversion> = (<Conversion> ",")+ => ActionFn(469);
+// Comma<Conversion> = (<Conversion> ",")+ => ActionFn(491);
 let ___sym0 = ___pop_Variant13(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action469::<>(text, ___sym0);
+let ___nt = super::___action491::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant50(___nt), ___end));
-(1, 76)
+(1, 77)
 }
-pub(crate) fn ___reduce140<
+pub(crate) fn ___reduce147<
     'input,
 >(
 text: &'input str,
@@ -8381,15 +8673,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarParameter> = GrammarParameter => ActionFn(492);
+// Comma<GrammarParameter> = GrammarParameter => ActionFn(514);
 let ___sym0 = ___pop_Variant16(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action492::<>(text, ___sym0);
+let ___nt = super::___action514::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant51(___nt), ___end));
-(1, 77)
+(1, 78)
 }
-pub(crate) fn ___reduce141<
+pub(crate) fn ___reduce148<
     'input,
 >(
 text: &'input str,
@@ -8398,14 +8690,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarParameter> =  => ActionFn(493);
+// Comma<GrammarParameter> =  => ActionFn(515);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action493::<>(text, &___start, &___end);
+let ___nt = super::___action515::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant51(___nt), ___end));
-(0, 77)
+(0, 78)
 }
-pub(crate) fn ___reduce142<
+pub(crate) fn ___reduce149<
     'input,
 >(
 text: &'input str,
@@ -8414,17 +8706,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarParameter> = (<GrammarParameter> ",")+, GrammarParameter => ActionFn(494);
+// Comma<GrammarParameter> = (<GrammarParameter> ",")+, GrammarParameter => ActionFn(516);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant16(___symbols);
 let ___sym0 = ___pop_Variant17(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action494::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action516::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant51(___nt), ___end));
-(2, 77)
+(2, 78)
 }
-pub(crate) fn ___reduce143<
+pub(crate) fn ___reduce150<
     'input,
 >(
 text: &'input str,
@@ -8433,15 +8725,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarParameter> = (<GrammarParameter> ",")+ => ActionFn(495);
+// Comma<GrammarParameter> = (<GrammarParameter> ",")+ => ActionFn(517);
 let ___sym0 = ___pop_Variant17(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action495::<>(text, ___sym0);
+let ___nt = super::___action517::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant51(___nt), ___end));
-(1, 77)
+(1, 78)
 }
-pub(crate) fn ___reduce144<
+pub(crate) fn ___reduce151<
     'input,
 >(
 text: &'input str,
@@ -8450,15 +8742,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarWhereClause> = GrammarWhereClause => ActionFn(520);
+// Comma<GrammarWhereClause> = GrammarWhereClause => ActionFn(542);
 let ___sym0 = ___pop_Variant18(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action520::<>(text, ___sym0);
+let ___nt = super::___action542::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant52(___nt), ___end));
-(1, 78)
+(1, 79)
 }
-pub(crate) fn ___reduce145<
+pub(crate) fn ___reduce152<
     'input,
 >(
 text: &'input str,
@@ -8467,14 +8759,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarWhereClause> =  => ActionFn(521);
+// Comma<GrammarWhereClause> =  => ActionFn(543);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action521::<>(text, &___start, &___end);
+let ___nt = super::___action543::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant52(___nt), ___end));
-(0, 78)
+(0, 79)
 }
-pub(crate) fn ___reduce146<
+pub(crate) fn ___reduce153<
     'input,
 >(
 text: &'input str,
@@ -8483,17 +8775,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarWhereClause> = (<GrammarWhereClause> ",")+, GrammarWhereClause => ActionFn(522);
+// Comma<GrammarWhereClause> = (<GrammarWhereClause> ",")+, GrammarWhereClause => ActionFn(544);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant18(___symbols);
 let ___sym0 = ___pop_Variant19(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action522::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action544::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant52(___nt), ___end));
-(2, 78)
+(2, 79)
 }
-pub(crate) fn ___reduce147<
+pub(crate) fn ___reduce154<
     'input,
 >(
 text: &'input str,
@@ -8502,15 +8794,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<GrammarWhereClause> = (<GrammarWhereClause> ",")+ => ActionFn(523);
+// Comma<GrammarWhereClause> = (<GrammarWhereClause> ",")+ => ActionFn(545);
 let ___sym0 = ___pop_Variant19(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action523::<>(text, ___sym0);
+let ___nt = super::___action545::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant52(___nt), ___end));
-(1, 78)
+(1, 79)
 }
-pub(crate) fn ___reduce148<
+pub(crate) fn ___reduce155<
     'input,
 >(
 text: &'input str,
@@ -8519,15 +8811,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<MatchItem> = MatchItem => ActionFn(564);
+// Comma<MatchItem> = MatchItem => ActionFn(586);
 let ___sym0 = ___pop_Variant24(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action564::<>(text, ___sym0);
+let ___nt = super::___action586::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant53(___nt), ___end));
-(1, 79)
+(1, 80)
 }
-pub(crate) fn ___reduce149<
+pub(crate) fn ___reduce156<
     'input,
 >(
 text: &'input str,
@@ -8536,14 +8828,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<MatchItem> =  => ActionFn(565);
+// Comma<MatchItem> =  => ActionFn(587);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action565::<>(text, &___start, &___end);
+let ___nt = super::___action587::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant53(___nt), ___end));
-(0, 79)
+(0, 80)
 }
-pub(crate) fn ___reduce150<
+pub(crate) fn ___reduce157<
     'input,
 >(
 text: &'input str,
@@ -8552,17 +8844,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<MatchItem> = (<MatchItem> ",")+, MatchItem => ActionFn(566);
+// Comma<MatchItem> = (<MatchItem> ",")+, MatchItem => ActionFn(588);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant24(___symbols);
 let ___sym0 = ___pop_Variant25(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action566::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action588::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant53(___nt), ___end));
-(2, 79)
+(2, 80)
 }
-pub(crate) fn ___reduce151<
+pub(crate) fn ___reduce158<
     'input,
 >(
 text: &'input str,
@@ -8571,15 +8863,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<MatchItem> = (<MatchItem> ",")+ => ActionFn(567);
+// Comma<MatchItem> = (<MatchItem> ",")+ => ActionFn(589);
 let ___sym0 = ___pop_Variant25(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action567::<>(text, ___sym0);
+let ___nt = super::___action589::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant53(___nt), ___end));
-(1, 79)
+(1, 80)
 }
-pub(crate) fn ___reduce152<
+pub(crate) fn ___reduce159<
     'input,
 >(
 text: &'input str,
@@ -8588,15 +8880,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<NotMacroId> = NotMacroId => ActionFn(568);
+// Comma<NotMacroId> = NotMacroId => ActionFn(590);
 let ___sym0 = ___pop_Variant26(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action568::<>(text, ___sym0);
+let ___nt = super::___action590::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant54(___nt), ___end));
-(1, 80)
+(1, 81)
 }
-pub(crate) fn ___reduce153<
+pub(crate) fn ___reduce160<
     'input,
 >(
 text: &'input str,
@@ -8605,14 +8897,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<NotMacroId> =  => ActionFn(569);
+// Comma<NotMacroId> =  => ActionFn(591);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action569::<>(text, &___start, &___end);
+let ___nt = super::___action591::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant54(___nt), ___end));
-(0, 80)
+(0, 81)
 }
-pub(crate) fn ___reduce154<
+pub(crate) fn ___reduce161<
     'input,
 >(
 text: &'input str,
@@ -8621,17 +8913,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<NotMacroId> = (<NotMacroId> ",")+, NotMacroId => ActionFn(570);
+// Comma<NotMacroId> = (<NotMacroId> ",")+, NotMacroId => ActionFn(592);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant26(___symbols);
 let ___sym0 = ___pop_Variant27(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action570::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action592::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant54(___nt), ___end));
-(2, 80)
+(2, 81)
 }
-pub(crate) fn ___reduce155<
+pub(crate) fn ___reduce162<
     'input,
 >(
 text: &'input str,
@@ -8640,15 +8932,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<NotMacroId> = (<NotMacroId> ",")+ => ActionFn(571);
+// Comma<NotMacroId> = (<NotMacroId> ",")+ => ActionFn(593);
 let ___sym0 = ___pop_Variant27(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action571::<>(text, ___sym0);
+let ___nt = super::___action593::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant54(___nt), ___end));
-(1, 80)
+(1, 81)
 }
-pub(crate) fn ___reduce156<
+pub(crate) fn ___reduce163<
     'input,
 >(
 text: &'input str,
@@ -8657,15 +8949,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Pattern> = Pattern => ActionFn(572);
+// Comma<Pattern> = Pattern => ActionFn(594);
 let ___sym0 = ___pop_Variant28(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action572::<>(text, ___sym0);
+let ___nt = super::___action594::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant55(___nt), ___end));
-(1, 81)
+(1, 82)
 }
-pub(crate) fn ___reduce157<
+pub(crate) fn ___reduce164<
     'input,
 >(
 text: &'input str,
@@ -8674,14 +8966,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Pattern> =  => ActionFn(573);
+// Comma<Pattern> =  => ActionFn(595);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action573::<>(text, &___start, &___end);
+let ___nt = super::___action595::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant55(___nt), ___end));
-(0, 81)
+(0, 82)
 }
-pub(crate) fn ___reduce158<
+pub(crate) fn ___reduce165<
     'input,
 >(
 text: &'input str,
@@ -8690,17 +8982,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Pattern> = (<Pattern> ",")+, Pattern => ActionFn(574);
+// Comma<Pattern> = (<Pattern> ",")+, Pattern => ActionFn(596);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant28(___symbols);
 let ___sym0 = ___pop_Variant29(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action574::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action596::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant55(___nt), ___end));
-(2, 81)
+(2, 82)
 }
-pub(crate) fn ___reduce159<
+pub(crate) fn ___reduce166<
     'input,
 >(
 text: &'input str,
@@ -8709,15 +9001,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Pattern> = (<Pattern> ",")+ => ActionFn(575);
+// Comma<Pattern> = (<Pattern> ",")+ => ActionFn(597);
 let ___sym0 = ___pop_Variant29(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action575::<>(text, ___sym0);
+let ___nt = super::___action597::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant55(___nt), ___end));
-(1, 81)
+(1, 82)
 }
-pub(crate) fn ___reduce160<
+pub(crate) fn ___reduce167<
     'input,
 >(
 text: &'input str,
@@ -8726,15 +9018,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Symbol> = Symbol => ActionFn(642);
+// Comma<Symbol> = Symbol => ActionFn(664);
 let ___sym0 = ___pop_Variant30(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action642::<>(text, ___sym0);
+let ___nt = super::___action664::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant56(___nt), ___end));
-(1, 82)
+(1, 83)
 }
-pub(crate) fn ___reduce161<
+pub(crate) fn ___reduce168<
     'input,
 >(
 text: &'input str,
@@ -8743,14 +9035,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Symbol> =  => ActionFn(643);
+// Comma<Symbol> =  => ActionFn(665);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action643::<>(text, &___start, &___end);
+let ___nt = super::___action665::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant56(___nt), ___end));
-(0, 82)
+(0, 83)
 }
-pub(crate) fn ___reduce162<
+pub(crate) fn ___reduce169<
     'input,
 >(
 text: &'input str,
@@ -8759,17 +9051,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Symbol> = (<Symbol> ",")+, Symbol => ActionFn(644);
+// Comma<Symbol> = (<Symbol> ",")+, Symbol => ActionFn(666);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant30(___symbols);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action644::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action666::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant56(___nt), ___end));
-(2, 82)
+(2, 83)
 }
-pub(crate) fn ___reduce163<
+pub(crate) fn ___reduce170<
     'input,
 >(
 text: &'input str,
@@ -8778,15 +9070,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<Symbol> = (<Symbol> ",")+ => ActionFn(645);
+// Comma<Symbol> = (<Symbol> ",")+ => ActionFn(667);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action645::<>(text, ___sym0);
+let ___nt = super::___action667::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant56(___nt), ___end));
-(1, 82)
+(1, 83)
 }
-pub(crate) fn ___reduce164<
+pub(crate) fn ___reduce171<
     'input,
 >(
 text: &'input str,
@@ -8795,15 +9087,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeBoundParameter> = TypeBoundParameter => ActionFn(650);
+// Comma<TypeBoundParameter> = TypeBoundParameter => ActionFn(672);
 let ___sym0 = ___pop_Variant34(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action650::<>(text, ___sym0);
+let ___nt = super::___action672::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant5(___nt), ___end));
-(1, 83)
+(1, 84)
 }
-pub(crate) fn ___reduce165<
+pub(crate) fn ___reduce172<
     'input,
 >(
 text: &'input str,
@@ -8812,14 +9104,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeBoundParameter> =  => ActionFn(651);
+// Comma<TypeBoundParameter> =  => ActionFn(673);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action651::<>(text, &___start, &___end);
+let ___nt = super::___action673::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant5(___nt), ___end));
-(0, 83)
+(0, 84)
 }
-pub(crate) fn ___reduce166<
+pub(crate) fn ___reduce173<
     'input,
 >(
 text: &'input str,
@@ -8828,17 +9120,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeBoundParameter> = (<TypeBoundParameter> ",")+, TypeBoundParameter => ActionFn(652);
+// Comma<TypeBoundParameter> = (<TypeBoundParameter> ",")+, TypeBoundParameter => ActionFn(674);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant34(___symbols);
 let ___sym0 = ___pop_Variant35(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action652::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action674::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant5(___nt), ___end));
-(2, 83)
+(2, 84)
 }
-pub(crate) fn ___reduce167<
+pub(crate) fn ___reduce174<
     'input,
 >(
 text: &'input str,
@@ -8847,15 +9139,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeBoundParameter> = (<TypeBoundParameter> ",")+ => ActionFn(653);
+// Comma<TypeBoundParameter> = (<TypeBoundParameter> ",")+ => ActionFn(675);
 let ___sym0 = ___pop_Variant35(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action653::<>(text, ___sym0);
+let ___nt = super::___action675::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant5(___nt), ___end));
-(1, 83)
+(1, 84)
 }
-pub(crate) fn ___reduce168<
+pub(crate) fn ___reduce175<
     'input,
 >(
 text: &'input str,
@@ -8864,15 +9156,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeParameter> = TypeParameter => ActionFn(654);
+// Comma<TypeParameter> = TypeParameter => ActionFn(676);
 let ___sym0 = ___pop_Variant36(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action654::<>(text, ___sym0);
+let ___nt = super::___action676::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant57(___nt), ___end));
-(1, 84)
+(1, 85)
 }
-pub(crate) fn ___reduce169<
+pub(crate) fn ___reduce176<
     'input,
 >(
 text: &'input str,
@@ -8881,14 +9173,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeParameter> =  => ActionFn(655);
+// Comma<TypeParameter> =  => ActionFn(677);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action655::<>(text, &___start, &___end);
+let ___nt = super::___action677::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant57(___nt), ___end));
-(0, 84)
+(0, 85)
 }
-pub(crate) fn ___reduce170<
+pub(crate) fn ___reduce177<
     'input,
 >(
 text: &'input str,
@@ -8897,17 +9189,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeParameter> = (<TypeParameter> ",")+, TypeParameter => ActionFn(656);
+// Comma<TypeParameter> = (<TypeParameter> ",")+, TypeParameter => ActionFn(678);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant36(___symbols);
 let ___sym0 = ___pop_Variant37(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action656::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action678::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant57(___nt), ___end));
-(2, 84)
+(2, 85)
 }
-pub(crate) fn ___reduce171<
+pub(crate) fn ___reduce178<
     'input,
 >(
 text: &'input str,
@@ -8916,15 +9208,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeParameter> = (<TypeParameter> ",")+ => ActionFn(657);
+// Comma<TypeParameter> = (<TypeParameter> ",")+ => ActionFn(679);
 let ___sym0 = ___pop_Variant37(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action657::<>(text, ___sym0);
+let ___nt = super::___action679::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant57(___nt), ___end));
-(1, 84)
+(1, 85)
 }
-pub(crate) fn ___reduce172<
+pub(crate) fn ___reduce179<
     'input,
 >(
 text: &'input str,
@@ -8933,15 +9225,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRef> = TypeRef => ActionFn(658);
+// Comma<TypeRef> = TypeRef => ActionFn(680);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action658::<>(text, ___sym0);
+let ___nt = super::___action680::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(1, 85)
+(1, 86)
 }
-pub(crate) fn ___reduce173<
+pub(crate) fn ___reduce180<
     'input,
 >(
 text: &'input str,
@@ -8950,14 +9242,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRef> =  => ActionFn(659);
+// Comma<TypeRef> =  => ActionFn(681);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action659::<>(text, &___start, &___end);
+let ___nt = super::___action681::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(0, 85)
+(0, 86)
 }
-pub(crate) fn ___reduce174<
+pub(crate) fn ___reduce181<
     'input,
 >(
 text: &'input str,
@@ -8966,17 +9258,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRef> = (<TypeRef> ",")+, TypeRef => ActionFn(660);
+// Comma<TypeRef> = (<TypeRef> ",")+, TypeRef => ActionFn(682);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action660::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action682::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(2, 85)
+(2, 86)
 }
-pub(crate) fn ___reduce175<
+pub(crate) fn ___reduce182<
     'input,
 >(
 text: &'input str,
@@ -8985,15 +9277,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRef> = (<TypeRef> ",")+ => ActionFn(661);
+// Comma<TypeRef> = (<TypeRef> ",")+ => ActionFn(683);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action661::<>(text, ___sym0);
+let ___nt = super::___action683::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(1, 85)
+(1, 86)
 }
-pub(crate) fn ___reduce176<
+pub(crate) fn ___reduce183<
     'input,
 >(
 text: &'input str,
@@ -9002,15 +9294,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRefOrLifetime> = TypeRefOrLifetime => ActionFn(662);
+// Comma<TypeRefOrLifetime> = TypeRefOrLifetime => ActionFn(684);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action662::<>(text, ___sym0);
+let ___nt = super::___action684::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(1, 86)
+(1, 87)
 }
-pub(crate) fn ___reduce177<
+pub(crate) fn ___reduce184<
     'input,
 >(
 text: &'input str,
@@ -9019,14 +9311,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRefOrLifetime> =  => ActionFn(663);
+// Comma<TypeRefOrLifetime> =  => ActionFn(685);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action663::<>(text, &___start, &___end);
+let ___nt = super::___action685::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(0, 86)
+(0, 87)
 }
-pub(crate) fn ___reduce178<
+pub(crate) fn ___reduce185<
     'input,
 >(
 text: &'input str,
@@ -9035,17 +9327,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRefOrLifetime> = (<TypeRefOrLifetime> ",")+, TypeRefOrLifetime => ActionFn(664);
+// Comma<TypeRefOrLifetime> = (<TypeRefOrLifetime> ",")+, TypeRefOrLifetime => ActionFn(686);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action664::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action686::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(2, 86)
+(2, 87)
 }
-pub(crate) fn ___reduce179<
+pub(crate) fn ___reduce186<
     'input,
 >(
 text: &'input str,
@@ -9054,15 +9346,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Comma<TypeRefOrLifetime> = (<TypeRefOrLifetime> ",")+ => ActionFn(665);
+// Comma<TypeRefOrLifetime> = (<TypeRefOrLifetime> ",")+ => ActionFn(687);
 let ___sym0 = ___pop_Variant38(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action665::<>(text, ___sym0);
+let ___nt = super::___action687::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant58(___nt), ___end));
-(1, 86)
+(1, 87)
 }
-pub(crate) fn ___reduce180<
+pub(crate) fn ___reduce187<
     'input,
 >(
 text: &'input str,
@@ -9071,18 +9363,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Cond = NotMacroId, CondOp, StringLiteral => ActionFn(425);
+// Cond = NotMacroId, CondOp, StringLiteral => ActionFn(429);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant20(___symbols);
 let ___sym1 = ___pop_Variant59(___symbols);
 let ___sym0 = ___pop_Variant26(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action425::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action429::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant7(___nt), ___end));
-(3, 87)
+(3, 88)
 }
-pub(crate) fn ___reduce181<
+pub(crate) fn ___reduce188<
     'input,
 >(
 text: &'input str,
@@ -9097,9 +9389,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action47::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant59(___nt), ___end));
-(1, 88)
+(1, 89)
 }
-pub(crate) fn ___reduce182<
+pub(crate) fn ___reduce189<
     'input,
 >(
 text: &'input str,
@@ -9114,9 +9406,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action48::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant59(___nt), ___end));
-(1, 88)
+(1, 89)
 }
-pub(crate) fn ___reduce183<
+pub(crate) fn ___reduce190<
     'input,
 >(
 text: &'input str,
@@ -9131,9 +9423,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action49::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant59(___nt), ___end));
-(1, 88)
+(1, 89)
 }
-pub(crate) fn ___reduce184<
+pub(crate) fn ___reduce191<
     'input,
 >(
 text: &'input str,
@@ -9148,9 +9440,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action50::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant59(___nt), ___end));
-(1, 88)
+(1, 89)
 }
-pub(crate) fn ___reduce186<
+pub(crate) fn ___reduce194<
     'input,
 >(
 text: &'input str,
@@ -9159,15 +9451,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Conversion? = Conversion => ActionFn(260);
+// Conversion? = Conversion => ActionFn(263);
 let ___sym0 = ___pop_Variant12(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action260::<>(text, ___sym0);
+let ___nt = super::___action263::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant60(___nt), ___end));
-(1, 90)
+(1, 91)
 }
-pub(crate) fn ___reduce187<
+pub(crate) fn ___reduce195<
     'input,
 >(
 text: &'input str,
@@ -9176,14 +9468,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Conversion? =  => ActionFn(261);
+// Conversion? =  => ActionFn(264);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action261::<>(text, &___start, &___end);
+let ___nt = super::___action264::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant60(___nt), ___end));
-(0, 90)
+(0, 91)
 }
-pub(crate) fn ___reduce188<
+pub(crate) fn ___reduce196<
     'input,
 >(
 text: &'input str,
@@ -9192,7 +9484,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// EnumToken = "enum", TypeRef, "{", Comma<Conversion>, "}" => ActionFn(427);
+// EnumToken = "enum", TypeRef, "{", Comma<Conversion>, "}" => ActionFn(431);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant50(___symbols);
@@ -9201,11 +9493,11 @@ let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action427::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action431::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant61(___nt), ___end));
-(5, 91)
+(5, 92)
 }
-pub(crate) fn ___reduce189<
+pub(crate) fn ___reduce197<
     'input,
 >(
 text: &'input str,
@@ -9214,15 +9506,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Escape = "Escape" => ActionFn(111);
+// Escape = "Escape" => ActionFn(112);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action111::<>(text, ___sym0);
+let ___nt = super::___action112::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
-(1, 92)
+(1, 93)
 }
-pub(crate) fn ___reduce190<
+pub(crate) fn ___reduce198<
     'input,
 >(
 text: &'input str,
@@ -9231,14 +9523,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExprSymbol =  => ActionFn(640);
+// ExprSymbol =  => ActionFn(662);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action640::<>(text, &___start, &___end);
+let ___nt = super::___action662::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant62(___nt), ___end));
-(0, 93)
+(0, 94)
 }
-pub(crate) fn ___reduce191<
+pub(crate) fn ___reduce199<
     'input,
 >(
 text: &'input str,
@@ -9247,15 +9539,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExprSymbol = Symbol+ => ActionFn(641);
+// ExprSymbol = Symbol+ => ActionFn(663);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action641::<>(text, ___sym0);
+let ___nt = super::___action663::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant62(___nt), ___end));
-(1, 93)
+(1, 94)
 }
-pub(crate) fn ___reduce192<
+pub(crate) fn ___reduce200<
     'input,
 >(
 text: &'input str,
@@ -9264,7 +9556,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExternToken = "extern", "{", EnumToken, "}" => ActionFn(460);
+// ExternToken = "extern", "{", EnumToken, "}" => ActionFn(482);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant61(___symbols);
@@ -9272,11 +9564,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action460::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action482::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(4, 94)
+(4, 95)
 }
-pub(crate) fn ___reduce193<
+pub(crate) fn ___reduce201<
     'input,
 >(
 text: &'input str,
@@ -9285,7 +9577,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExternToken = "extern", "{", EnumToken, AssociatedType+, "}" => ActionFn(461);
+// ExternToken = "extern", "{", EnumToken, AssociatedType+, "}" => ActionFn(483);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant49(___symbols);
@@ -9294,11 +9586,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action461::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action483::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(5, 94)
+(5, 95)
 }
-pub(crate) fn ___reduce194<
+pub(crate) fn ___reduce202<
     'input,
 >(
 text: &'input str,
@@ -9307,7 +9599,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExternToken = "extern", "{", AssociatedType+, EnumToken, "}" => ActionFn(462);
+// ExternToken = "extern", "{", AssociatedType+, EnumToken, "}" => ActionFn(484);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant61(___symbols);
@@ -9316,11 +9608,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action462::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action484::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(5, 94)
+(5, 95)
 }
-pub(crate) fn ___reduce195<
+pub(crate) fn ___reduce203<
     'input,
 >(
 text: &'input str,
@@ -9329,7 +9621,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExternToken = "extern", "{", AssociatedType+, EnumToken, AssociatedType+, "}" => ActionFn(463);
+// ExternToken = "extern", "{", AssociatedType+, EnumToken, AssociatedType+, "}" => ActionFn(485);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant49(___symbols);
@@ -9339,11 +9631,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action463::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action485::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(6, 94)
+(6, 95)
 }
-pub(crate) fn ___reduce196<
+pub(crate) fn ___reduce204<
     'input,
 >(
 text: &'input str,
@@ -9352,18 +9644,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExternToken = "extern", "{", "}" => ActionFn(464);
+// ExternToken = "extern", "{", "}" => ActionFn(486);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action464::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action486::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(3, 94)
+(3, 95)
 }
-pub(crate) fn ___reduce197<
+pub(crate) fn ___reduce205<
     'input,
 >(
 text: &'input str,
@@ -9372,7 +9664,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ExternToken = "extern", "{", AssociatedType+, "}" => ActionFn(465);
+// ExternToken = "extern", "{", AssociatedType+, "}" => ActionFn(487);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant49(___symbols);
@@ -9380,11 +9672,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action465::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action487::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(4, 94)
+(4, 95)
 }
-pub(crate) fn ___reduce198<
+pub(crate) fn ___reduce206<
     'input,
 >(
 text: &'input str,
@@ -9393,18 +9685,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// FieldPattern = Id, ":", Pattern => ActionFn(430);
+// FieldPattern = Id, ":", Pattern => ActionFn(434);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant28(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant20(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action430::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action434::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant14(___nt), ___end));
-(3, 95)
+(3, 96)
 }
-pub(crate) fn ___reduce199<
+pub(crate) fn ___reduce207<
     'input,
 >(
 text: &'input str,
@@ -9413,15 +9705,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// FieldPattern? = FieldPattern => ActionFn(121);
+// FieldPattern? = FieldPattern => ActionFn(124);
 let ___sym0 = ___pop_Variant14(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action121::<>(text, ___sym0);
+let ___nt = super::___action124::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant64(___nt), ___end));
-(1, 96)
+(1, 97)
 }
-pub(crate) fn ___reduce200<
+pub(crate) fn ___reduce208<
     'input,
 >(
 text: &'input str,
@@ -9430,14 +9722,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// FieldPattern? =  => ActionFn(122);
+// FieldPattern? =  => ActionFn(125);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action122::<>(text, &___start, &___end);
+let ___nt = super::___action125::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant64(___nt), ___end));
-(0, 96)
+(0, 97)
 }
-pub(crate) fn ___reduce201<
+pub(crate) fn ___reduce209<
     'input,
 >(
 text: &'input str,
@@ -9456,9 +9748,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
 let ___nt = super::___action13::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant57(___nt), ___end));
-(4, 97)
+(4, 98)
 }
-pub(crate) fn ___reduce202<
+pub(crate) fn ___reduce210<
     'input,
 >(
 text: &'input str,
@@ -9467,14 +9759,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ForAll =  => ActionFn(324);
+// ForAll =  => ActionFn(327);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action324::<>(text, &___start, &___end);
+let ___nt = super::___action327::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant57(___nt), ___end));
-(0, 97)
+(0, 98)
 }
-pub(crate) fn ___reduce203<
+pub(crate) fn ___reduce211<
     'input,
 >(
 text: &'input str,
@@ -9483,7 +9775,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(666);
+// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(688);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -9492,11 +9784,11 @@ let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action666::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action688::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce204<
+pub(crate) fn ___reduce212<
     'input,
 >(
 text: &'input str,
@@ -9505,7 +9797,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(667);
+// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(689);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -9515,11 +9807,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action667::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action689::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce205<
+pub(crate) fn ___reduce213<
     'input,
 >(
 text: &'input str,
@@ -9528,7 +9820,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(668);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(690);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -9538,11 +9830,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action668::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action690::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce206<
+pub(crate) fn ___reduce214<
     'input,
 >(
 text: &'input str,
@@ -9551,7 +9843,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(669);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(691);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant0(___symbols);
 let ___sym5 = ___pop_Variant52(___symbols);
@@ -9562,11 +9854,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action669::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action691::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce207<
+pub(crate) fn ___reduce215<
     'input,
 >(
 text: &'input str,
@@ -9575,7 +9867,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(670);
+// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(692);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant51(___symbols);
@@ -9583,11 +9875,11 @@ let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action670::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action692::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce208<
+pub(crate) fn ___reduce216<
     'input,
 >(
 text: &'input str,
@@ -9596,7 +9888,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(671);
+// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(693);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant51(___symbols);
@@ -9605,11 +9897,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action671::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action693::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce209<
+pub(crate) fn ___reduce217<
     'input,
 >(
 text: &'input str,
@@ -9618,7 +9910,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(672);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(694);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant51(___symbols);
@@ -9627,11 +9919,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action672::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action694::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce210<
+pub(crate) fn ___reduce218<
     'input,
 >(
 text: &'input str,
@@ -9640,7 +9932,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(673);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(695);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant51(___symbols);
@@ -9650,11 +9942,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action673::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action695::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce211<
+pub(crate) fn ___reduce219<
     'input,
 >(
 text: &'input str,
@@ -9663,7 +9955,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(674);
+// Grammar = "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(696);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant52(___symbols);
@@ -9671,11 +9963,11 @@ let ___sym1 = ___pop_Variant51(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action674::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action696::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce212<
+pub(crate) fn ___reduce220<
     'input,
 >(
 text: &'input str,
@@ -9684,7 +9976,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(675);
+// Grammar = Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(697);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -9693,11 +9985,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action675::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action697::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce213<
+pub(crate) fn ___reduce221<
     'input,
 >(
 text: &'input str,
@@ -9706,7 +9998,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(676);
+// Grammar = ShebangAttribute+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(698);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -9715,11 +10007,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action676::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action698::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce214<
+pub(crate) fn ___reduce222<
     'input,
 >(
 text: &'input str,
@@ -9728,7 +10020,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(677);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(699);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -9738,11 +10030,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action677::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action699::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce215<
+pub(crate) fn ___reduce223<
     'input,
 >(
 text: &'input str,
@@ -9751,18 +10043,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarParameters, ";" => ActionFn(678);
+// Grammar = "grammar", GrammarParameters, ";" => ActionFn(700);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant51(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action678::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action700::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(3, 98)
+(3, 99)
 }
-pub(crate) fn ___reduce216<
+pub(crate) fn ___reduce224<
     'input,
 >(
 text: &'input str,
@@ -9771,7 +10063,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarParameters, ";" => ActionFn(679);
+// Grammar = Use+, "grammar", GrammarParameters, ";" => ActionFn(701);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant51(___symbols);
@@ -9779,11 +10071,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action679::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action701::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce217<
+pub(crate) fn ___reduce225<
     'input,
 >(
 text: &'input str,
@@ -9792,7 +10084,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarParameters, ";" => ActionFn(680);
+// Grammar = ShebangAttribute+, "grammar", GrammarParameters, ";" => ActionFn(702);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant51(___symbols);
@@ -9800,11 +10092,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action680::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action702::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce218<
+pub(crate) fn ___reduce226<
     'input,
 >(
 text: &'input str,
@@ -9813,7 +10105,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, ";" => ActionFn(681);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, ";" => ActionFn(703);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant51(___symbols);
@@ -9822,11 +10114,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action681::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action703::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce219<
+pub(crate) fn ___reduce227<
     'input,
 >(
 text: &'input str,
@@ -9835,7 +10127,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(682);
+// Grammar = "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(704);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant52(___symbols);
@@ -9843,11 +10135,11 @@ let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action682::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action704::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce220<
+pub(crate) fn ___reduce228<
     'input,
 >(
 text: &'input str,
@@ -9856,7 +10148,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(683);
+// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(705);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -9865,11 +10157,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action683::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action705::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce221<
+pub(crate) fn ___reduce229<
     'input,
 >(
 text: &'input str,
@@ -9878,7 +10170,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(684);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(706);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -9887,11 +10179,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action684::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action706::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce222<
+pub(crate) fn ___reduce230<
     'input,
 >(
 text: &'input str,
@@ -9900,7 +10192,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(685);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(707);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -9910,11 +10202,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action685::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action707::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce223<
+pub(crate) fn ___reduce231<
     'input,
 >(
 text: &'input str,
@@ -9923,18 +10215,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, ";" => ActionFn(686);
+// Grammar = "grammar", GrammarTypeParameters, ";" => ActionFn(708);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action686::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action708::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(3, 98)
+(3, 99)
 }
-pub(crate) fn ___reduce224<
+pub(crate) fn ___reduce232<
     'input,
 >(
 text: &'input str,
@@ -9943,7 +10235,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, ";" => ActionFn(687);
+// Grammar = Use+, "grammar", GrammarTypeParameters, ";" => ActionFn(709);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant57(___symbols);
@@ -9951,11 +10243,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action687::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action709::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce225<
+pub(crate) fn ___reduce233<
     'input,
 >(
 text: &'input str,
@@ -9964,7 +10256,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, ";" => ActionFn(688);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, ";" => ActionFn(710);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant57(___symbols);
@@ -9972,11 +10264,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action688::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action710::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce226<
+pub(crate) fn ___reduce234<
     'input,
 >(
 text: &'input str,
@@ -9985,7 +10277,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, ";" => ActionFn(689);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, ";" => ActionFn(711);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant57(___symbols);
@@ -9994,11 +10286,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action689::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action711::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce227<
+pub(crate) fn ___reduce235<
     'input,
 >(
 text: &'input str,
@@ -10007,18 +10299,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarWhereClauses, ";" => ActionFn(690);
+// Grammar = "grammar", GrammarWhereClauses, ";" => ActionFn(712);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant52(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action690::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action712::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(3, 98)
+(3, 99)
 }
-pub(crate) fn ___reduce228<
+pub(crate) fn ___reduce236<
     'input,
 >(
 text: &'input str,
@@ -10027,7 +10319,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarWhereClauses, ";" => ActionFn(691);
+// Grammar = Use+, "grammar", GrammarWhereClauses, ";" => ActionFn(713);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant52(___symbols);
@@ -10035,11 +10327,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action691::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action713::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce229<
+pub(crate) fn ___reduce237<
     'input,
 >(
 text: &'input str,
@@ -10048,7 +10340,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarWhereClauses, ";" => ActionFn(692);
+// Grammar = ShebangAttribute+, "grammar", GrammarWhereClauses, ";" => ActionFn(714);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant52(___symbols);
@@ -10056,11 +10348,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action692::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action714::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce230<
+pub(crate) fn ___reduce238<
     'input,
 >(
 text: &'input str,
@@ -10069,7 +10361,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarWhereClauses, ";" => ActionFn(693);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarWhereClauses, ";" => ActionFn(715);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -10078,11 +10370,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action693::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action715::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce231<
+pub(crate) fn ___reduce239<
     'input,
 >(
 text: &'input str,
@@ -10091,17 +10383,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", ";" => ActionFn(694);
+// Grammar = "grammar", ";" => ActionFn(716);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action694::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action716::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(2, 98)
+(2, 99)
 }
-pub(crate) fn ___reduce232<
+pub(crate) fn ___reduce240<
     'input,
 >(
 text: &'input str,
@@ -10110,18 +10402,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", ";" => ActionFn(695);
+// Grammar = Use+, "grammar", ";" => ActionFn(717);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action695::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action717::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(3, 98)
+(3, 99)
 }
-pub(crate) fn ___reduce233<
+pub(crate) fn ___reduce241<
     'input,
 >(
 text: &'input str,
@@ -10130,18 +10422,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", ";" => ActionFn(696);
+// Grammar = ShebangAttribute+, "grammar", ";" => ActionFn(718);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action696::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action718::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(3, 98)
+(3, 99)
 }
-pub(crate) fn ___reduce234<
+pub(crate) fn ___reduce242<
     'input,
 >(
 text: &'input str,
@@ -10150,7 +10442,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", ";" => ActionFn(697);
+// Grammar = ShebangAttribute+, Use+, "grammar", ";" => ActionFn(719);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -10158,11 +10450,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action697::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action719::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce235<
+pub(crate) fn ___reduce243<
     'input,
 >(
 text: &'input str,
@@ -10171,7 +10463,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(698);
+// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(720);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10181,11 +10473,11 @@ let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action698::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action720::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce236<
+pub(crate) fn ___reduce244<
     'input,
 >(
 text: &'input str,
@@ -10194,7 +10486,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(699);
+// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(721);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -10205,11 +10497,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action699::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action721::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce237<
+pub(crate) fn ___reduce245<
     'input,
 >(
 text: &'input str,
@@ -10218,7 +10510,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(700);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(722);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -10229,11 +10521,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action700::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action722::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce238<
+pub(crate) fn ___reduce246<
     'input,
 >(
 text: &'input str,
@@ -10242,7 +10534,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(701);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(723);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant66(___symbols);
 let ___sym6 = ___pop_Variant0(___symbols);
@@ -10254,11 +10546,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action701::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action723::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(8, 98)
+(8, 99)
 }
-pub(crate) fn ___reduce239<
+pub(crate) fn ___reduce247<
     'input,
 >(
 text: &'input str,
@@ -10267,7 +10559,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(702);
+// Grammar = "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(724);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10276,11 +10568,11 @@ let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action702::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action724::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce240<
+pub(crate) fn ___reduce248<
     'input,
 >(
 text: &'input str,
@@ -10289,7 +10581,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(703);
+// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(725);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10299,11 +10591,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action703::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action725::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce241<
+pub(crate) fn ___reduce249<
     'input,
 >(
 text: &'input str,
@@ -10312,7 +10604,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(704);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(726);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10322,11 +10614,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action704::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action726::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce242<
+pub(crate) fn ___reduce250<
     'input,
 >(
 text: &'input str,
@@ -10335,7 +10627,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(705);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(727);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -10346,11 +10638,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action705::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action727::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce243<
+pub(crate) fn ___reduce251<
     'input,
 >(
 text: &'input str,
@@ -10359,7 +10651,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(706);
+// Grammar = "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(728);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10368,11 +10660,11 @@ let ___sym1 = ___pop_Variant51(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action706::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action728::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce244<
+pub(crate) fn ___reduce252<
     'input,
 >(
 text: &'input str,
@@ -10381,7 +10673,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(707);
+// Grammar = Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(729);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10391,11 +10683,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action707::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action729::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce245<
+pub(crate) fn ___reduce253<
     'input,
 >(
 text: &'input str,
@@ -10404,7 +10696,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(708);
+// Grammar = ShebangAttribute+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(730);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10414,11 +10706,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action708::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action730::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce246<
+pub(crate) fn ___reduce254<
     'input,
 >(
 text: &'input str,
@@ -10427,7 +10719,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(709);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(731);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -10438,11 +10730,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action709::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action731::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce247<
+pub(crate) fn ___reduce255<
     'input,
 >(
 text: &'input str,
@@ -10451,7 +10743,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(710);
+// Grammar = "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(732);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant66(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -10459,11 +10751,11 @@ let ___sym1 = ___pop_Variant51(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action710::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action732::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce248<
+pub(crate) fn ___reduce256<
     'input,
 >(
 text: &'input str,
@@ -10472,7 +10764,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(711);
+// Grammar = Use+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(733);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10481,11 +10773,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action711::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action733::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce249<
+pub(crate) fn ___reduce257<
     'input,
 >(
 text: &'input str,
@@ -10494,7 +10786,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(712);
+// Grammar = ShebangAttribute+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(734);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10503,11 +10795,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action712::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action734::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce250<
+pub(crate) fn ___reduce258<
     'input,
 >(
 text: &'input str,
@@ -10516,7 +10808,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(713);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(735);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10526,11 +10818,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action713::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action735::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce251<
+pub(crate) fn ___reduce259<
     'input,
 >(
 text: &'input str,
@@ -10539,7 +10831,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(714);
+// Grammar = "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(736);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10548,11 +10840,11 @@ let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action714::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action736::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce252<
+pub(crate) fn ___reduce260<
     'input,
 >(
 text: &'input str,
@@ -10561,7 +10853,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(715);
+// Grammar = Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(737);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10571,11 +10863,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action715::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action737::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce253<
+pub(crate) fn ___reduce261<
     'input,
 >(
 text: &'input str,
@@ -10584,7 +10876,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(716);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(738);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10594,11 +10886,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action716::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action738::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce254<
+pub(crate) fn ___reduce262<
     'input,
 >(
 text: &'input str,
@@ -10607,7 +10899,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(717);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(739);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -10618,11 +10910,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action717::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action739::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce255<
+pub(crate) fn ___reduce263<
     'input,
 >(
 text: &'input str,
@@ -10631,7 +10923,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(718);
+// Grammar = "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(740);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant66(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -10639,11 +10931,11 @@ let ___sym1 = ___pop_Variant57(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action718::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action740::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce256<
+pub(crate) fn ___reduce264<
     'input,
 >(
 text: &'input str,
@@ -10652,7 +10944,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(719);
+// Grammar = Use+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(741);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10661,11 +10953,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action719::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action741::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce257<
+pub(crate) fn ___reduce265<
     'input,
 >(
 text: &'input str,
@@ -10674,7 +10966,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(720);
+// Grammar = ShebangAttribute+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(742);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10683,11 +10975,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action720::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action742::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce258<
+pub(crate) fn ___reduce266<
     'input,
 >(
 text: &'input str,
@@ -10696,7 +10988,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(721);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(743);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10706,11 +10998,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action721::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action743::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce259<
+pub(crate) fn ___reduce267<
     'input,
 >(
 text: &'input str,
@@ -10719,7 +11011,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(722);
+// Grammar = "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(744);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant66(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -10727,11 +11019,11 @@ let ___sym1 = ___pop_Variant52(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action722::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action744::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce260<
+pub(crate) fn ___reduce268<
     'input,
 >(
 text: &'input str,
@@ -10740,7 +11032,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(723);
+// Grammar = Use+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(745);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10749,11 +11041,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action723::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action745::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce261<
+pub(crate) fn ___reduce269<
     'input,
 >(
 text: &'input str,
@@ -10762,7 +11054,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(724);
+// Grammar = ShebangAttribute+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(746);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10771,11 +11063,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action724::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action746::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce262<
+pub(crate) fn ___reduce270<
     'input,
 >(
 text: &'input str,
@@ -10784,7 +11076,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(725);
+// Grammar = ShebangAttribute+, Use+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(747);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -10794,11 +11086,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action725::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action747::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce263<
+pub(crate) fn ___reduce271<
     'input,
 >(
 text: &'input str,
@@ -10807,18 +11099,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = "grammar", ";", GrammarItem+ => ActionFn(726);
+// Grammar = "grammar", ";", GrammarItem+ => ActionFn(748);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant66(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action726::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action748::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(3, 98)
+(3, 99)
 }
-pub(crate) fn ___reduce264<
+pub(crate) fn ___reduce272<
     'input,
 >(
 text: &'input str,
@@ -10827,7 +11119,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, "grammar", ";", GrammarItem+ => ActionFn(727);
+// Grammar = Use+, "grammar", ";", GrammarItem+ => ActionFn(749);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant66(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -10835,11 +11127,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action727::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action749::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce265<
+pub(crate) fn ___reduce273<
     'input,
 >(
 text: &'input str,
@@ -10848,7 +11140,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, "grammar", ";", GrammarItem+ => ActionFn(728);
+// Grammar = ShebangAttribute+, "grammar", ";", GrammarItem+ => ActionFn(750);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant66(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -10856,11 +11148,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action728::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action750::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce266<
+pub(crate) fn ___reduce274<
     'input,
 >(
 text: &'input str,
@@ -10869,7 +11161,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, "grammar", ";", GrammarItem+ => ActionFn(729);
+// Grammar = ShebangAttribute+, Use+, "grammar", ";", GrammarItem+ => ActionFn(751);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -10878,11 +11170,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action729::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action751::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce267<
+pub(crate) fn ___reduce275<
     'input,
 >(
 text: &'input str,
@@ -10891,7 +11183,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(730);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(752);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -10901,11 +11193,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action730::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action752::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce268<
+pub(crate) fn ___reduce276<
     'input,
 >(
 text: &'input str,
@@ -10914,7 +11206,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(731);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(753);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant0(___symbols);
 let ___sym5 = ___pop_Variant52(___symbols);
@@ -10925,11 +11217,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action731::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action753::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce269<
+pub(crate) fn ___reduce277<
     'input,
 >(
 text: &'input str,
@@ -10938,7 +11230,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(732);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(754);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant0(___symbols);
 let ___sym5 = ___pop_Variant52(___symbols);
@@ -10949,11 +11241,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action732::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action754::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce270<
+pub(crate) fn ___reduce278<
     'input,
 >(
 text: &'input str,
@@ -10962,7 +11254,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(733);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";" => ActionFn(755);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant0(___symbols);
 let ___sym6 = ___pop_Variant52(___symbols);
@@ -10974,11 +11266,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action733::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action755::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(8, 98)
+(8, 99)
 }
-pub(crate) fn ___reduce271<
+pub(crate) fn ___reduce279<
     'input,
 >(
 text: &'input str,
@@ -10987,7 +11279,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(734);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(756);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant51(___symbols);
@@ -10996,11 +11288,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action734::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action756::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce272<
+pub(crate) fn ___reduce280<
     'input,
 >(
 text: &'input str,
@@ -11009,7 +11301,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(735);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(757);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant51(___symbols);
@@ -11019,11 +11311,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action735::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action757::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce273<
+pub(crate) fn ___reduce281<
     'input,
 >(
 text: &'input str,
@@ -11032,7 +11324,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(736);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(758);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant51(___symbols);
@@ -11042,11 +11334,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action736::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action758::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce274<
+pub(crate) fn ___reduce282<
     'input,
 >(
 text: &'input str,
@@ -11055,7 +11347,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(737);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";" => ActionFn(759);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant0(___symbols);
 let ___sym5 = ___pop_Variant51(___symbols);
@@ -11066,11 +11358,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action737::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action759::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce275<
+pub(crate) fn ___reduce283<
     'input,
 >(
 text: &'input str,
@@ -11079,7 +11371,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(738);
+// Grammar = Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(760);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -11088,11 +11380,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action738::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action760::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce276<
+pub(crate) fn ___reduce284<
     'input,
 >(
 text: &'input str,
@@ -11101,7 +11393,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(739);
+// Grammar = Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(761);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -11111,11 +11403,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action739::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action761::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce277<
+pub(crate) fn ___reduce285<
     'input,
 >(
 text: &'input str,
@@ -11124,7 +11416,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(740);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(762);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -11134,11 +11426,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action740::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action762::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce278<
+pub(crate) fn ___reduce286<
     'input,
 >(
 text: &'input str,
@@ -11147,7 +11439,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(741);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";" => ActionFn(763);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant0(___symbols);
 let ___sym5 = ___pop_Variant52(___symbols);
@@ -11158,11 +11450,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action741::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action763::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce279<
+pub(crate) fn ___reduce287<
     'input,
 >(
 text: &'input str,
@@ -11171,7 +11463,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarParameters, ";" => ActionFn(742);
+// Grammar = Annotation+, "grammar", GrammarParameters, ";" => ActionFn(764);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant51(___symbols);
@@ -11179,11 +11471,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action742::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action764::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce280<
+pub(crate) fn ___reduce288<
     'input,
 >(
 text: &'input str,
@@ -11192,7 +11484,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarParameters, ";" => ActionFn(743);
+// Grammar = Use+, Annotation+, "grammar", GrammarParameters, ";" => ActionFn(765);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant51(___symbols);
@@ -11201,11 +11493,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action743::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action765::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce281<
+pub(crate) fn ___reduce289<
     'input,
 >(
 text: &'input str,
@@ -11214,7 +11506,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, ";" => ActionFn(744);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, ";" => ActionFn(766);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant51(___symbols);
@@ -11223,11 +11515,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action744::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action766::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce282<
+pub(crate) fn ___reduce290<
     'input,
 >(
 text: &'input str,
@@ -11236,7 +11528,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, ";" => ActionFn(745);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, ";" => ActionFn(767);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant51(___symbols);
@@ -11246,11 +11538,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action745::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action767::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce283<
+pub(crate) fn ___reduce291<
     'input,
 >(
 text: &'input str,
@@ -11259,7 +11551,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(746);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(768);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -11268,11 +11560,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action746::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action768::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce284<
+pub(crate) fn ___reduce292<
     'input,
 >(
 text: &'input str,
@@ -11281,7 +11573,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(747);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(769);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -11291,11 +11583,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action747::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action769::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce285<
+pub(crate) fn ___reduce293<
     'input,
 >(
 text: &'input str,
@@ -11304,7 +11596,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(748);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(770);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -11314,11 +11606,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action748::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action770::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce286<
+pub(crate) fn ___reduce294<
     'input,
 >(
 text: &'input str,
@@ -11327,7 +11619,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(749);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";" => ActionFn(771);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant0(___symbols);
 let ___sym5 = ___pop_Variant52(___symbols);
@@ -11338,11 +11630,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action749::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action771::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce287<
+pub(crate) fn ___reduce295<
     'input,
 >(
 text: &'input str,
@@ -11351,7 +11643,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(750);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(772);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant57(___symbols);
@@ -11359,11 +11651,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action750::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action772::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce288<
+pub(crate) fn ___reduce296<
     'input,
 >(
 text: &'input str,
@@ -11372,7 +11664,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(751);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(773);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant57(___symbols);
@@ -11381,11 +11673,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action751::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action773::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce289<
+pub(crate) fn ___reduce297<
     'input,
 >(
 text: &'input str,
@@ -11394,7 +11686,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(752);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(774);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant57(___symbols);
@@ -11403,11 +11695,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action752::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action774::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce290<
+pub(crate) fn ___reduce298<
     'input,
 >(
 text: &'input str,
@@ -11416,7 +11708,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(753);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, ";" => ActionFn(775);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant57(___symbols);
@@ -11426,11 +11718,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action753::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action775::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce291<
+pub(crate) fn ___reduce299<
     'input,
 >(
 text: &'input str,
@@ -11439,7 +11731,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(754);
+// Grammar = Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(776);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant52(___symbols);
@@ -11447,11 +11739,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action754::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action776::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce292<
+pub(crate) fn ___reduce300<
     'input,
 >(
 text: &'input str,
@@ -11460,7 +11752,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(755);
+// Grammar = Use+, Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(777);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -11469,11 +11761,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action755::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action777::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce293<
+pub(crate) fn ___reduce301<
     'input,
 >(
 text: &'input str,
@@ -11482,7 +11774,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(756);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(778);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant52(___symbols);
@@ -11491,11 +11783,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action756::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action778::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce294<
+pub(crate) fn ___reduce302<
     'input,
 >(
 text: &'input str,
@@ -11504,7 +11796,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(757);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarWhereClauses, ";" => ActionFn(779);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant52(___symbols);
@@ -11514,11 +11806,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action757::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action779::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce295<
+pub(crate) fn ___reduce303<
     'input,
 >(
 text: &'input str,
@@ -11527,18 +11819,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", ";" => ActionFn(758);
+// Grammar = Annotation+, "grammar", ";" => ActionFn(780);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action758::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action780::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(3, 98)
+(3, 99)
 }
-pub(crate) fn ___reduce296<
+pub(crate) fn ___reduce304<
     'input,
 >(
 text: &'input str,
@@ -11547,7 +11839,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", ";" => ActionFn(759);
+// Grammar = Use+, Annotation+, "grammar", ";" => ActionFn(781);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -11555,11 +11847,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action759::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action781::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce297<
+pub(crate) fn ___reduce305<
     'input,
 >(
 text: &'input str,
@@ -11568,7 +11860,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", ";" => ActionFn(760);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", ";" => ActionFn(782);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -11576,11 +11868,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action760::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action782::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce298<
+pub(crate) fn ___reduce306<
     'input,
 >(
 text: &'input str,
@@ -11589,7 +11881,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", ";" => ActionFn(761);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", ";" => ActionFn(783);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -11598,11 +11890,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action761::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action783::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce299<
+pub(crate) fn ___reduce307<
     'input,
 >(
 text: &'input str,
@@ -11611,7 +11903,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(762);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(784);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -11622,11 +11914,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action762::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action784::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce300<
+pub(crate) fn ___reduce308<
     'input,
 >(
 text: &'input str,
@@ -11635,7 +11927,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(763);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(785);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant66(___symbols);
 let ___sym6 = ___pop_Variant0(___symbols);
@@ -11647,11 +11939,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action763::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action785::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(8, 98)
+(8, 99)
 }
-pub(crate) fn ___reduce301<
+pub(crate) fn ___reduce309<
     'input,
 >(
 text: &'input str,
@@ -11660,7 +11952,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(764);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(786);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant66(___symbols);
 let ___sym6 = ___pop_Variant0(___symbols);
@@ -11672,11 +11964,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action764::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action786::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(8, 98)
+(8, 99)
 }
-pub(crate) fn ___reduce302<
+pub(crate) fn ___reduce310<
     'input,
 >(
 text: &'input str,
@@ -11685,7 +11977,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(765);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(787);
 assert!(___symbols.len() >= 9);
 let ___sym8 = ___pop_Variant66(___symbols);
 let ___sym7 = ___pop_Variant0(___symbols);
@@ -11698,11 +11990,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym8.2.clone();
-let ___nt = super::___action765::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8);
+let ___nt = super::___action787::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(9, 98)
+(9, 99)
 }
-pub(crate) fn ___reduce303<
+pub(crate) fn ___reduce311<
     'input,
 >(
 text: &'input str,
@@ -11711,7 +12003,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(766);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(788);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -11721,11 +12013,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action766::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action788::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce304<
+pub(crate) fn ___reduce312<
     'input,
 >(
 text: &'input str,
@@ -11734,7 +12026,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(767);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(789);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -11745,11 +12037,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action767::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action789::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce305<
+pub(crate) fn ___reduce313<
     'input,
 >(
 text: &'input str,
@@ -11758,7 +12050,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(768);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(790);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -11769,11 +12061,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action768::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action790::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce306<
+pub(crate) fn ___reduce314<
     'input,
 >(
 text: &'input str,
@@ -11782,7 +12074,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(769);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarParameters, ";", GrammarItem+ => ActionFn(791);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant66(___symbols);
 let ___sym6 = ___pop_Variant0(___symbols);
@@ -11794,11 +12086,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action769::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action791::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(8, 98)
+(8, 99)
 }
-pub(crate) fn ___reduce307<
+pub(crate) fn ___reduce315<
     'input,
 >(
 text: &'input str,
@@ -11807,7 +12099,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(770);
+// Grammar = Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(792);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -11817,11 +12109,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action770::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action792::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce308<
+pub(crate) fn ___reduce316<
     'input,
 >(
 text: &'input str,
@@ -11830,7 +12122,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(771);
+// Grammar = Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(793);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -11841,11 +12133,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action771::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action793::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce309<
+pub(crate) fn ___reduce317<
     'input,
 >(
 text: &'input str,
@@ -11854,7 +12146,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(772);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(794);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -11865,11 +12157,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action772::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action794::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce310<
+pub(crate) fn ___reduce318<
     'input,
 >(
 text: &'input str,
@@ -11878,7 +12170,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(773);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(795);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant66(___symbols);
 let ___sym6 = ___pop_Variant0(___symbols);
@@ -11890,11 +12182,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action773::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action795::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(8, 98)
+(8, 99)
 }
-pub(crate) fn ___reduce311<
+pub(crate) fn ___reduce319<
     'input,
 >(
 text: &'input str,
@@ -11903,7 +12195,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(774);
+// Grammar = Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(796);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -11912,11 +12204,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action774::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action796::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce312<
+pub(crate) fn ___reduce320<
     'input,
 >(
 text: &'input str,
@@ -11925,7 +12217,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(775);
+// Grammar = Use+, Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(797);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -11935,11 +12227,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action775::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action797::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce313<
+pub(crate) fn ___reduce321<
     'input,
 >(
 text: &'input str,
@@ -11948,7 +12240,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(776);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(798);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -11958,11 +12250,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action776::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action798::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce314<
+pub(crate) fn ___reduce322<
     'input,
 >(
 text: &'input str,
@@ -11971,7 +12263,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(777);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarParameters, ";", GrammarItem+ => ActionFn(799);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -11982,11 +12274,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action777::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action799::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce315<
+pub(crate) fn ___reduce323<
     'input,
 >(
 text: &'input str,
@@ -11995,7 +12287,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(778);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(800);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -12005,11 +12297,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action778::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action800::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce316<
+pub(crate) fn ___reduce324<
     'input,
 >(
 text: &'input str,
@@ -12018,7 +12310,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(779);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(801);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -12029,11 +12321,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action779::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action801::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce317<
+pub(crate) fn ___reduce325<
     'input,
 >(
 text: &'input str,
@@ -12042,7 +12334,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(780);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(802);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -12053,11 +12345,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action780::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action802::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce318<
+pub(crate) fn ___reduce326<
     'input,
 >(
 text: &'input str,
@@ -12066,7 +12358,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(781);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, GrammarWhereClauses, ";", GrammarItem+ => ActionFn(803);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant66(___symbols);
 let ___sym6 = ___pop_Variant0(___symbols);
@@ -12078,11 +12370,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action781::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action803::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(8, 98)
+(8, 99)
 }
-pub(crate) fn ___reduce319<
+pub(crate) fn ___reduce327<
     'input,
 >(
 text: &'input str,
@@ -12091,7 +12383,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(782);
+// Grammar = Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(804);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -12100,11 +12392,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action782::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action804::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce320<
+pub(crate) fn ___reduce328<
     'input,
 >(
 text: &'input str,
@@ -12113,7 +12405,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(783);
+// Grammar = Use+, Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(805);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -12123,11 +12415,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action783::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action805::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce321<
+pub(crate) fn ___reduce329<
     'input,
 >(
 text: &'input str,
@@ -12136,7 +12428,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(784);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(806);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -12146,11 +12438,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action784::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action806::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce322<
+pub(crate) fn ___reduce330<
     'input,
 >(
 text: &'input str,
@@ -12159,7 +12451,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(785);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarTypeParameters, ";", GrammarItem+ => ActionFn(807);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -12170,11 +12462,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action785::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action807::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce323<
+pub(crate) fn ___reduce331<
     'input,
 >(
 text: &'input str,
@@ -12183,7 +12475,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(786);
+// Grammar = Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(808);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -12192,11 +12484,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action786::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action808::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce324<
+pub(crate) fn ___reduce332<
     'input,
 >(
 text: &'input str,
@@ -12205,7 +12497,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(787);
+// Grammar = Use+, Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(809);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -12215,11 +12507,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action787::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action809::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce325<
+pub(crate) fn ___reduce333<
     'input,
 >(
 text: &'input str,
@@ -12228,7 +12520,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(788);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(810);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -12238,11 +12530,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action788::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action810::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce326<
+pub(crate) fn ___reduce334<
     'input,
 >(
 text: &'input str,
@@ -12251,7 +12543,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(789);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", GrammarWhereClauses, ";", GrammarItem+ => ActionFn(811);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant66(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -12262,11 +12554,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action789::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action811::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(7, 98)
+(7, 99)
 }
-pub(crate) fn ___reduce327<
+pub(crate) fn ___reduce335<
     'input,
 >(
 text: &'input str,
@@ -12275,7 +12567,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Annotation+, "grammar", ";", GrammarItem+ => ActionFn(790);
+// Grammar = Annotation+, "grammar", ";", GrammarItem+ => ActionFn(812);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant66(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -12283,11 +12575,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action790::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action812::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(4, 98)
+(4, 99)
 }
-pub(crate) fn ___reduce328<
+pub(crate) fn ___reduce336<
     'input,
 >(
 text: &'input str,
@@ -12296,7 +12588,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = Use+, Annotation+, "grammar", ";", GrammarItem+ => ActionFn(791);
+// Grammar = Use+, Annotation+, "grammar", ";", GrammarItem+ => ActionFn(813);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -12305,11 +12597,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action791::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action813::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce329<
+pub(crate) fn ___reduce337<
     'input,
 >(
 text: &'input str,
@@ -12318,7 +12610,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Annotation+, "grammar", ";", GrammarItem+ => ActionFn(792);
+// Grammar = ShebangAttribute+, Annotation+, "grammar", ";", GrammarItem+ => ActionFn(814);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant66(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -12327,11 +12619,11 @@ let ___sym1 = ___pop_Variant45(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action792::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action814::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(5, 98)
+(5, 99)
 }
-pub(crate) fn ___reduce330<
+pub(crate) fn ___reduce338<
     'input,
 >(
 text: &'input str,
@@ -12340,7 +12632,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", ";", GrammarItem+ => ActionFn(793);
+// Grammar = ShebangAttribute+, Use+, Annotation+, "grammar", ";", GrammarItem+ => ActionFn(815);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant66(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -12350,11 +12642,11 @@ let ___sym1 = ___pop_Variant66(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action793::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action815::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant65(___nt), ___end));
-(6, 98)
+(6, 99)
 }
-pub(crate) fn ___reduce331<
+pub(crate) fn ___reduce339<
     'input,
 >(
 text: &'input str,
@@ -12369,9 +12661,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action24::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(1, 99)
+(1, 100)
 }
-pub(crate) fn ___reduce332<
+pub(crate) fn ___reduce340<
     'input,
 >(
 text: &'input str,
@@ -12386,9 +12678,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action25::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(1, 99)
+(1, 100)
 }
-pub(crate) fn ___reduce333<
+pub(crate) fn ___reduce341<
     'input,
 >(
 text: &'input str,
@@ -12403,9 +12695,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action26::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(1, 99)
+(1, 100)
 }
-pub(crate) fn ___reduce334<
+pub(crate) fn ___reduce342<
     'input,
 >(
 text: &'input str,
@@ -12420,9 +12712,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action27::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(1, 99)
+(1, 100)
 }
-pub(crate) fn ___reduce335<
+pub(crate) fn ___reduce343<
     'input,
 >(
 text: &'input str,
@@ -12431,14 +12723,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarItem* =  => ActionFn(174);
+// GrammarItem* =  => ActionFn(177);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action174::<>(text, &___start, &___end);
+let ___nt = super::___action177::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(0, 100)
+(0, 101)
 }
-pub(crate) fn ___reduce336<
+pub(crate) fn ___reduce344<
     'input,
 >(
 text: &'input str,
@@ -12447,15 +12739,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarItem* = GrammarItem+ => ActionFn(175);
+// GrammarItem* = GrammarItem+ => ActionFn(178);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action175::<>(text, ___sym0);
+let ___nt = super::___action178::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(1, 100)
+(1, 101)
 }
-pub(crate) fn ___reduce337<
+pub(crate) fn ___reduce345<
     'input,
 >(
 text: &'input str,
@@ -12464,15 +12756,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarItem+ = GrammarItem => ActionFn(196);
+// GrammarItem+ = GrammarItem => ActionFn(199);
 let ___sym0 = ___pop_Variant63(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action196::<>(text, ___sym0);
+let ___nt = super::___action199::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(1, 101)
+(1, 102)
 }
-pub(crate) fn ___reduce338<
+pub(crate) fn ___reduce346<
     'input,
 >(
 text: &'input str,
@@ -12481,17 +12773,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarItem+ = GrammarItem+, GrammarItem => ActionFn(197);
+// GrammarItem+ = GrammarItem+, GrammarItem => ActionFn(200);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant63(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action197::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action200::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(2, 101)
+(2, 102)
 }
-pub(crate) fn ___reduce339<
+pub(crate) fn ___reduce347<
     'input,
 >(
 text: &'input str,
@@ -12509,9 +12801,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action23::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant16(___nt), ___end));
-(3, 102)
+(3, 103)
 }
-pub(crate) fn ___reduce340<
+pub(crate) fn ___reduce348<
     'input,
 >(
 text: &'input str,
@@ -12520,15 +12812,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarParameter? = GrammarParameter => ActionFn(226);
+// GrammarParameter? = GrammarParameter => ActionFn(229);
 let ___sym0 = ___pop_Variant16(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action226::<>(text, ___sym0);
+let ___nt = super::___action229::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant67(___nt), ___end));
-(1, 103)
+(1, 104)
 }
-pub(crate) fn ___reduce341<
+pub(crate) fn ___reduce349<
     'input,
 >(
 text: &'input str,
@@ -12537,14 +12829,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarParameter? =  => ActionFn(227);
+// GrammarParameter? =  => ActionFn(230);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action227::<>(text, &___start, &___end);
+let ___nt = super::___action230::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant67(___nt), ___end));
-(0, 103)
+(0, 104)
 }
-pub(crate) fn ___reduce342<
+pub(crate) fn ___reduce350<
     'input,
 >(
 text: &'input str,
@@ -12562,9 +12854,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action22::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant51(___nt), ___end));
-(3, 104)
+(3, 105)
 }
-pub(crate) fn ___reduce343<
+pub(crate) fn ___reduce351<
     'input,
 >(
 text: &'input str,
@@ -12573,15 +12865,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarParameters? = GrammarParameters => ActionFn(178);
+// GrammarParameters? = GrammarParameters => ActionFn(181);
 let ___sym0 = ___pop_Variant51(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action178::<>(text, ___sym0);
+let ___nt = super::___action181::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant68(___nt), ___end));
-(1, 105)
+(1, 106)
 }
-pub(crate) fn ___reduce344<
+pub(crate) fn ___reduce352<
     'input,
 >(
 text: &'input str,
@@ -12590,14 +12882,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarParameters? =  => ActionFn(179);
+// GrammarParameters? =  => ActionFn(182);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action179::<>(text, &___start, &___end);
+let ___nt = super::___action182::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant68(___nt), ___end));
-(0, 105)
+(0, 106)
 }
-pub(crate) fn ___reduce345<
+pub(crate) fn ___reduce353<
     'input,
 >(
 text: &'input str,
@@ -12615,9 +12907,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action7::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant57(___nt), ___end));
-(3, 106)
+(3, 107)
 }
-pub(crate) fn ___reduce346<
+pub(crate) fn ___reduce354<
     'input,
 >(
 text: &'input str,
@@ -12626,15 +12918,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarTypeParameters? = GrammarTypeParameters => ActionFn(180);
+// GrammarTypeParameters? = GrammarTypeParameters => ActionFn(183);
 let ___sym0 = ___pop_Variant57(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action180::<>(text, ___sym0);
+let ___nt = super::___action183::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant69(___nt), ___end));
-(1, 107)
+(1, 108)
 }
-pub(crate) fn ___reduce347<
+pub(crate) fn ___reduce355<
     'input,
 >(
 text: &'input str,
@@ -12643,14 +12935,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarTypeParameters? =  => ActionFn(181);
+// GrammarTypeParameters? =  => ActionFn(184);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action181::<>(text, &___start, &___end);
+let ___nt = super::___action184::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant69(___nt), ___end));
-(0, 107)
+(0, 108)
 }
-pub(crate) fn ___reduce348<
+pub(crate) fn ___reduce356<
     'input,
 >(
 text: &'input str,
@@ -12668,9 +12960,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action11::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant18(___nt), ___end));
-(3, 108)
+(3, 109)
 }
-pub(crate) fn ___reduce349<
+pub(crate) fn ___reduce357<
     'input,
 >(
 text: &'input str,
@@ -12679,7 +12971,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarWhereClause = "for", "<", Comma<TypeParameter>, ">", TypeRef, ":", TypeBounds => ActionFn(474);
+// GrammarWhereClause = "for", "<", Comma<TypeParameter>, ">", TypeRef, ":", TypeBounds => ActionFn(496);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant84(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -12690,11 +12982,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action474::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action496::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant18(___nt), ___end));
-(7, 108)
+(7, 109)
 }
-pub(crate) fn ___reduce350<
+pub(crate) fn ___reduce358<
     'input,
 >(
 text: &'input str,
@@ -12703,18 +12995,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarWhereClause = TypeRef, ":", TypeBounds => ActionFn(475);
+// GrammarWhereClause = TypeRef, ":", TypeBounds => ActionFn(497);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant84(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action475::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action497::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant18(___nt), ___end));
-(3, 108)
+(3, 109)
 }
-pub(crate) fn ___reduce351<
+pub(crate) fn ___reduce359<
     'input,
 >(
 text: &'input str,
@@ -12723,15 +13015,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarWhereClause? = GrammarWhereClause => ActionFn(203);
+// GrammarWhereClause? = GrammarWhereClause => ActionFn(206);
 let ___sym0 = ___pop_Variant18(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action203::<>(text, ___sym0);
+let ___nt = super::___action206::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant70(___nt), ___end));
-(1, 109)
+(1, 110)
 }
-pub(crate) fn ___reduce352<
+pub(crate) fn ___reduce360<
     'input,
 >(
 text: &'input str,
@@ -12740,14 +13032,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarWhereClause? =  => ActionFn(204);
+// GrammarWhereClause? =  => ActionFn(207);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action204::<>(text, &___start, &___end);
+let ___nt = super::___action207::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant70(___nt), ___end));
-(0, 109)
+(0, 110)
 }
-pub(crate) fn ___reduce353<
+pub(crate) fn ___reduce361<
     'input,
 >(
 text: &'input str,
@@ -12764,9 +13056,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action10::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant52(___nt), ___end));
-(2, 110)
+(2, 111)
 }
-pub(crate) fn ___reduce354<
+pub(crate) fn ___reduce362<
     'input,
 >(
 text: &'input str,
@@ -12775,15 +13067,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarWhereClauses? = GrammarWhereClauses => ActionFn(176);
+// GrammarWhereClauses? = GrammarWhereClauses => ActionFn(179);
 let ___sym0 = ___pop_Variant52(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action176::<>(text, ___sym0);
+let ___nt = super::___action179::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant71(___nt), ___end));
-(1, 111)
+(1, 112)
 }
-pub(crate) fn ___reduce355<
+pub(crate) fn ___reduce363<
     'input,
 >(
 text: &'input str,
@@ -12792,14 +13084,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// GrammarWhereClauses? =  => ActionFn(177);
+// GrammarWhereClauses? =  => ActionFn(180);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action177::<>(text, &___start, &___end);
+let ___nt = super::___action180::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant71(___nt), ___end));
-(0, 111)
+(0, 112)
 }
-pub(crate) fn ___reduce356<
+pub(crate) fn ___reduce364<
     'input,
 >(
 text: &'input str,
@@ -12808,15 +13100,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Id = "Id" => ActionFn(109);
+// Id = "Id" => ActionFn(110);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action109::<>(text, ___sym0);
+let ___nt = super::___action110::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
-(1, 112)
+(1, 113)
 }
-pub(crate) fn ___reduce357<
+pub(crate) fn ___reduce365<
     'input,
 >(
 text: &'input str,
@@ -12825,15 +13117,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Id = "MacroId" => ActionFn(110);
+// Id = "MacroId" => ActionFn(111);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action110::<>(text, ___sym0);
+let ___nt = super::___action111::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
-(1, 112)
+(1, 113)
 }
-pub(crate) fn ___reduce358<
+pub(crate) fn ___reduce366<
     'input,
 >(
 text: &'input str,
@@ -12842,15 +13134,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Lifetime = "Lifetime" => ActionFn(112);
+// Lifetime = "Lifetime" => ActionFn(113);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action112::<>(text, ___sym0);
+let ___nt = super::___action113::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant22(___nt), ___end));
-(1, 113)
+(1, 114)
 }
-pub(crate) fn ___reduce359<
+pub(crate) fn ___reduce367<
     'input,
 >(
 text: &'input str,
@@ -12859,15 +13151,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Lifetime? = Lifetime => ActionFn(137);
+// Lifetime? = Lifetime => ActionFn(140);
 let ___sym0 = ___pop_Variant22(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action137::<>(text, ___sym0);
+let ___nt = super::___action140::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant72(___nt), ___end));
-(1, 114)
+(1, 115)
 }
-pub(crate) fn ___reduce360<
+pub(crate) fn ___reduce368<
     'input,
 >(
 text: &'input str,
@@ -12876,14 +13168,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Lifetime? =  => ActionFn(138);
+// Lifetime? =  => ActionFn(141);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action138::<>(text, &___start, &___end);
+let ___nt = super::___action141::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant72(___nt), ___end));
-(0, 114)
+(0, 115)
 }
-pub(crate) fn ___reduce361<
+pub(crate) fn ___reduce369<
     'input,
 >(
 text: &'input str,
@@ -12892,15 +13184,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// MacroId = "MacroId" => ActionFn(107);
+// MacroId = "MacroId" => ActionFn(108);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action107::<>(text, ___sym0);
+let ___nt = super::___action108::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant26(___nt), ___end));
-(1, 115)
+(1, 116)
 }
-pub(crate) fn ___reduce362<
+pub(crate) fn ___reduce370<
     'input,
 >(
 text: &'input str,
@@ -12915,9 +13207,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action86::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant73(___nt), ___end));
-(1, 116)
+(1, 117)
 }
-pub(crate) fn ___reduce363<
+pub(crate) fn ___reduce371<
     'input,
 >(
 text: &'input str,
@@ -12926,15 +13218,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// MatchItem = "_" => ActionFn(432);
+// MatchItem = "_" => ActionFn(436);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action432::<>(text, ___sym0);
+let ___nt = super::___action436::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant24(___nt), ___end));
-(1, 117)
+(1, 118)
 }
-pub(crate) fn ___reduce364<
+pub(crate) fn ___reduce372<
     'input,
 >(
 text: &'input str,
@@ -12943,15 +13235,34 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// MatchItem = MatchSymbol => ActionFn(433);
+// MatchItem = MatchSymbol => ActionFn(472);
 let ___sym0 = ___pop_Variant76(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action433::<>(text, ___sym0);
+let ___nt = super::___action472::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant24(___nt), ___end));
-(1, 117)
+(1, 118)
 }
-pub(crate) fn ___reduce366<
+pub(crate) fn ___reduce373<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// MatchItem = Annotation+, MatchSymbol => ActionFn(473);
+assert!(___symbols.len() >= 2);
+let ___sym1 = ___pop_Variant76(___symbols);
+let ___sym0 = ___pop_Variant45(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym1.2.clone();
+let ___nt = super::___action473::<>(text, ___sym0, ___sym1);
+___symbols.push((___start, ___Symbol::Variant24(___nt), ___end));
+(2, 118)
+}
+pub(crate) fn ___reduce376<
     'input,
 >(
 text: &'input str,
@@ -12960,15 +13271,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// MatchItem? = MatchItem => ActionFn(255);
+// MatchItem? = MatchItem => ActionFn(258);
 let ___sym0 = ___pop_Variant24(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action255::<>(text, ___sym0);
+let ___nt = super::___action258::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant74(___nt), ___end));
-(1, 118)
+(1, 119)
 }
-pub(crate) fn ___reduce367<
+pub(crate) fn ___reduce377<
     'input,
 >(
 text: &'input str,
@@ -12977,14 +13288,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// MatchItem? =  => ActionFn(256);
+// MatchItem? =  => ActionFn(259);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action256::<>(text, &___start, &___end);
+let ___nt = super::___action259::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant74(___nt), ___end));
-(0, 118)
+(0, 119)
 }
-pub(crate) fn ___reduce368<
+pub(crate) fn ___reduce378<
     'input,
 >(
 text: &'input str,
@@ -12999,9 +13310,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action91::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant75(___nt), ___end));
-(1, 119)
+(1, 120)
 }
-pub(crate) fn ___reduce369<
+pub(crate) fn ___reduce379<
     'input,
 >(
 text: &'input str,
@@ -13018,9 +13329,29 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action92::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant75(___nt), ___end));
-(2, 119)
+(2, 120)
 }
-pub(crate) fn ___reduce370<
+pub(crate) fn ___reduce380<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// MatchMapping = "{", StringLiteral, "}" => ActionFn(93);
+assert!(___symbols.len() >= 3);
+let ___sym2 = ___pop_Variant0(___symbols);
+let ___sym1 = ___pop_Variant20(___symbols);
+let ___sym0 = ___pop_Variant0(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym2.2.clone();
+let ___nt = super::___action93::<>(text, ___sym0, ___sym1, ___sym2);
+___symbols.push((___start, ___Symbol::Variant75(___nt), ___end));
+(3, 120)
+}
+pub(crate) fn ___reduce381<
     'input,
 >(
 text: &'input str,
@@ -13035,9 +13366,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action90::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant76(___nt), ___end));
-(1, 120)
+(1, 121)
 }
-pub(crate) fn ___reduce371<
+pub(crate) fn ___reduce382<
     'input,
 >(
 text: &'input str,
@@ -13052,9 +13383,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action83::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(1, 121)
+(1, 122)
 }
-pub(crate) fn ___reduce372<
+pub(crate) fn ___reduce383<
     'input,
 >(
 text: &'input str,
@@ -13074,9 +13405,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
 let ___nt = super::___action84::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant77(___nt), ___end));
-(5, 122)
+(5, 123)
 }
-pub(crate) fn ___reduce373<
+pub(crate) fn ___reduce384<
     'input,
 >(
 text: &'input str,
@@ -13085,7 +13416,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// MatchTokenInt = "match", "{", MatchContents, "}" => ActionFn(435);
+// MatchTokenInt = "match", "{", MatchContents, "}" => ActionFn(439);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant73(___symbols);
@@ -13093,11 +13424,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action435::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action439::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant77(___nt), ___end));
-(4, 122)
+(4, 123)
 }
-pub(crate) fn ___reduce374<
+pub(crate) fn ___reduce385<
     'input,
 >(
 text: &'input str,
@@ -13106,7 +13437,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Nonterminal = Visibility, NonterminalName, ":", TypeRef, "=", Alternatives => ActionFn(454);
+// Nonterminal = Visibility, NonterminalName, ":", TypeRef, "=", Alternatives => ActionFn(476);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant43(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -13116,11 +13447,11 @@ let ___sym1 = ___pop_Variant78(___symbols);
 let ___sym0 = ___pop_Variant95(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action454::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action476::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(6, 123)
+(6, 124)
 }
-pub(crate) fn ___reduce375<
+pub(crate) fn ___reduce386<
     'input,
 >(
 text: &'input str,
@@ -13129,7 +13460,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Nonterminal = Annotation+, Visibility, NonterminalName, ":", TypeRef, "=", Alternatives => ActionFn(455);
+// Nonterminal = Annotation+, Visibility, NonterminalName, ":", TypeRef, "=", Alternatives => ActionFn(477);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant43(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -13140,11 +13471,11 @@ let ___sym1 = ___pop_Variant95(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action455::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action477::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(7, 123)
+(7, 124)
 }
-pub(crate) fn ___reduce376<
+pub(crate) fn ___reduce387<
     'input,
 >(
 text: &'input str,
@@ -13153,7 +13484,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Nonterminal = Visibility, NonterminalName, "=", Alternatives => ActionFn(456);
+// Nonterminal = Visibility, NonterminalName, "=", Alternatives => ActionFn(478);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant43(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -13161,11 +13492,11 @@ let ___sym1 = ___pop_Variant78(___symbols);
 let ___sym0 = ___pop_Variant95(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action456::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action478::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(4, 123)
+(4, 124)
 }
-pub(crate) fn ___reduce377<
+pub(crate) fn ___reduce388<
     'input,
 >(
 text: &'input str,
@@ -13174,7 +13505,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Nonterminal = Annotation+, Visibility, NonterminalName, "=", Alternatives => ActionFn(457);
+// Nonterminal = Annotation+, Visibility, NonterminalName, "=", Alternatives => ActionFn(479);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant43(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -13183,11 +13514,11 @@ let ___sym1 = ___pop_Variant95(___symbols);
 let ___sym0 = ___pop_Variant45(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action457::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action479::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(5, 123)
+(5, 124)
 }
-pub(crate) fn ___reduce378<
+pub(crate) fn ___reduce389<
     'input,
 >(
 text: &'input str,
@@ -13206,9 +13537,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
 let ___nt = super::___action35::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant78(___nt), ___end));
-(4, 124)
+(4, 125)
 }
-pub(crate) fn ___reduce379<
+pub(crate) fn ___reduce390<
     'input,
 >(
 text: &'input str,
@@ -13223,9 +13554,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action36::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant78(___nt), ___end));
-(1, 124)
+(1, 125)
 }
-pub(crate) fn ___reduce380<
+pub(crate) fn ___reduce391<
     'input,
 >(
 text: &'input str,
@@ -13240,9 +13571,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action37::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant78(___nt), ___end));
-(1, 124)
+(1, 125)
 }
-pub(crate) fn ___reduce381<
+pub(crate) fn ___reduce392<
     'input,
 >(
 text: &'input str,
@@ -13251,15 +13582,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// NotMacroId = "Id" => ActionFn(108);
+// NotMacroId = "Id" => ActionFn(109);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action108::<>(text, ___sym0);
+let ___nt = super::___action109::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant26(___nt), ___end));
-(1, 125)
+(1, 126)
 }
-pub(crate) fn ___reduce382<
+pub(crate) fn ___reduce393<
     'input,
 >(
 text: &'input str,
@@ -13268,15 +13599,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// NotMacroId? = NotMacroId => ActionFn(231);
+// NotMacroId? = NotMacroId => ActionFn(234);
 let ___sym0 = ___pop_Variant26(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action231::<>(text, ___sym0);
+let ___nt = super::___action234::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant79(___nt), ___end));
-(1, 126)
+(1, 127)
 }
-pub(crate) fn ___reduce383<
+pub(crate) fn ___reduce394<
     'input,
 >(
 text: &'input str,
@@ -13285,14 +13616,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// NotMacroId? =  => ActionFn(232);
+// NotMacroId? =  => ActionFn(235);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action232::<>(text, &___start, &___end);
+let ___nt = super::___action235::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant79(___nt), ___end));
-(0, 126)
+(0, 127)
 }
-pub(crate) fn ___reduce384<
+pub(crate) fn ___reduce395<
     'input,
 >(
 text: &'input str,
@@ -13301,17 +13632,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Path = "::", Id => ActionFn(350);
+// Path = "::", Id => ActionFn(353);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant20(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action350::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action353::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant80(___nt), ___end));
-(2, 127)
+(2, 128)
 }
-pub(crate) fn ___reduce385<
+pub(crate) fn ___reduce396<
     'input,
 >(
 text: &'input str,
@@ -13320,18 +13651,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Path = "::", (<Id> "::")+, Id => ActionFn(351);
+// Path = "::", (<Id> "::")+, Id => ActionFn(354);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant20(___symbols);
 let ___sym1 = ___pop_Variant21(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action351::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action354::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant80(___nt), ___end));
-(3, 127)
+(3, 128)
 }
-pub(crate) fn ___reduce386<
+pub(crate) fn ___reduce397<
     'input,
 >(
 text: &'input str,
@@ -13340,15 +13671,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Path = Id => ActionFn(352);
+// Path = Id => ActionFn(355);
 let ___sym0 = ___pop_Variant20(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action352::<>(text, ___sym0);
+let ___nt = super::___action355::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant80(___nt), ___end));
-(1, 127)
+(1, 128)
 }
-pub(crate) fn ___reduce387<
+pub(crate) fn ___reduce398<
     'input,
 >(
 text: &'input str,
@@ -13357,17 +13688,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Path = (<Id> "::")+, Id => ActionFn(353);
+// Path = (<Id> "::")+, Id => ActionFn(356);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant20(___symbols);
 let ___sym0 = ___pop_Variant21(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action353::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action356::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant80(___nt), ___end));
-(2, 127)
+(2, 128)
 }
-pub(crate) fn ___reduce388<
+pub(crate) fn ___reduce399<
     'input,
 >(
 text: &'input str,
@@ -13376,15 +13707,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Pattern = PatternKind => ActionFn(438);
+// Pattern = PatternKind => ActionFn(442);
 let ___sym0 = ___pop_Variant82(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action438::<>(text, ___sym0);
+let ___nt = super::___action442::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant28(___nt), ___end));
-(1, 128)
+(1, 129)
 }
-pub(crate) fn ___reduce389<
+pub(crate) fn ___reduce400<
     'input,
 >(
 text: &'input str,
@@ -13393,15 +13724,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Pattern? = Pattern => ActionFn(265);
+// Pattern? = Pattern => ActionFn(268);
 let ___sym0 = ___pop_Variant28(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action265::<>(text, ___sym0);
+let ___nt = super::___action268::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant81(___nt), ___end));
-(1, 129)
+(1, 130)
 }
-pub(crate) fn ___reduce390<
+pub(crate) fn ___reduce401<
     'input,
 >(
 text: &'input str,
@@ -13410,14 +13741,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Pattern? =  => ActionFn(266);
+// Pattern? =  => ActionFn(269);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action266::<>(text, &___start, &___end);
+let ___nt = super::___action269::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant81(___nt), ___end));
-(0, 129)
+(0, 130)
 }
-pub(crate) fn ___reduce391<
+pub(crate) fn ___reduce402<
     'input,
 >(
 text: &'input str,
@@ -13426,7 +13757,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path, "(", Comma<Pattern>, ")" => ActionFn(97);
+// PatternKind = Path, "(", Comma<Pattern>, ")" => ActionFn(98);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant55(___symbols);
@@ -13434,11 +13765,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action97::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action98::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(4, 130)
+(4, 131)
 }
-pub(crate) fn ___reduce392<
+pub(crate) fn ___reduce403<
     'input,
 >(
 text: &'input str,
@@ -13447,7 +13778,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path, "{", FieldPattern, "}" => ActionFn(470);
+// PatternKind = Path, "{", FieldPattern, "}" => ActionFn(492);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant14(___symbols);
@@ -13455,11 +13786,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action470::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action492::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(4, 130)
+(4, 131)
 }
-pub(crate) fn ___reduce393<
+pub(crate) fn ___reduce404<
     'input,
 >(
 text: &'input str,
@@ -13468,18 +13799,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path, "{", "}" => ActionFn(471);
+// PatternKind = Path, "{", "}" => ActionFn(493);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action471::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action493::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(3, 130)
+(3, 131)
 }
-pub(crate) fn ___reduce394<
+pub(crate) fn ___reduce405<
     'input,
 >(
 text: &'input str,
@@ -13488,7 +13819,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path, "{", (<FieldPattern> ",")+, FieldPattern, "}" => ActionFn(472);
+// PatternKind = Path, "{", (<FieldPattern> ",")+, FieldPattern, "}" => ActionFn(494);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant14(___symbols);
@@ -13497,11 +13828,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action472::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action494::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(5, 130)
+(5, 131)
 }
-pub(crate) fn ___reduce395<
+pub(crate) fn ___reduce406<
     'input,
 >(
 text: &'input str,
@@ -13510,7 +13841,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path, "{", (<FieldPattern> ",")+, "}" => ActionFn(473);
+// PatternKind = Path, "{", (<FieldPattern> ",")+, "}" => ActionFn(495);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant15(___symbols);
@@ -13518,11 +13849,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action473::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action495::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(4, 130)
+(4, 131)
 }
-pub(crate) fn ___reduce396<
+pub(crate) fn ___reduce407<
     'input,
 >(
 text: &'input str,
@@ -13531,7 +13862,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path, "{", "..", "}" => ActionFn(338);
+// PatternKind = Path, "{", "..", "}" => ActionFn(341);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -13539,11 +13870,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action338::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action341::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(4, 130)
+(4, 131)
 }
-pub(crate) fn ___reduce397<
+pub(crate) fn ___reduce408<
     'input,
 >(
 text: &'input str,
@@ -13552,7 +13883,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path, "{", (<FieldPattern> ",")+, "..", "}" => ActionFn(339);
+// PatternKind = Path, "{", (<FieldPattern> ",")+, "..", "}" => ActionFn(342);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -13561,11 +13892,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action339::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action342::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(5, 130)
+(5, 131)
 }
-pub(crate) fn ___reduce398<
+pub(crate) fn ___reduce409<
     'input,
 >(
 text: &'input str,
@@ -13574,15 +13905,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = "_" => ActionFn(100);
+// PatternKind = "_" => ActionFn(101);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action100::<>(text, ___sym0);
+let ___nt = super::___action101::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(1, 130)
+(1, 131)
 }
-pub(crate) fn ___reduce399<
+pub(crate) fn ___reduce410<
     'input,
 >(
 text: &'input str,
@@ -13591,15 +13922,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = ".." => ActionFn(101);
+// PatternKind = ".." => ActionFn(102);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action101::<>(text, ___sym0);
+let ___nt = super::___action102::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(1, 130)
+(1, 131)
 }
-pub(crate) fn ___reduce400<
+pub(crate) fn ___reduce411<
     'input,
 >(
 text: &'input str,
@@ -13608,18 +13939,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = "<", TypeRef, ">" => ActionFn(102);
+// PatternKind = "<", TypeRef, ">" => ActionFn(103);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action102::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action103::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(3, 130)
+(3, 131)
 }
-pub(crate) fn ___reduce401<
+pub(crate) fn ___reduce412<
     'input,
 >(
 text: &'input str,
@@ -13628,18 +13959,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = "(", Comma<Pattern>, ")" => ActionFn(103);
+// PatternKind = "(", Comma<Pattern>, ")" => ActionFn(104);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant55(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action103::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action104::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(3, 130)
+(3, 131)
 }
-pub(crate) fn ___reduce402<
+pub(crate) fn ___reduce413<
     'input,
 >(
 text: &'input str,
@@ -13648,15 +13979,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = "CharLiteral" => ActionFn(104);
+// PatternKind = "CharLiteral" => ActionFn(105);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action104::<>(text, ___sym0);
+let ___nt = super::___action105::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(1, 130)
+(1, 131)
 }
-pub(crate) fn ___reduce403<
+pub(crate) fn ___reduce414<
     'input,
 >(
 text: &'input str,
@@ -13665,15 +13996,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// PatternKind = Path => ActionFn(105);
+// PatternKind = Path => ActionFn(106);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action105::<>(text, ___sym0);
+let ___nt = super::___action106::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant82(___nt), ___end));
-(1, 130)
+(1, 131)
 }
-pub(crate) fn ___reduce404<
+pub(crate) fn ___reduce415<
     'input,
 >(
 text: &'input str,
@@ -13682,15 +14013,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<Lifetime> = Lifetime => ActionFn(556);
+// Plus<Lifetime> = Lifetime => ActionFn(578);
 let ___sym0 = ___pop_Variant22(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action556::<>(text, ___sym0);
+let ___nt = super::___action578::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant83(___nt), ___end));
-(1, 131)
+(1, 132)
 }
-pub(crate) fn ___reduce405<
+pub(crate) fn ___reduce416<
     'input,
 >(
 text: &'input str,
@@ -13699,14 +14030,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<Lifetime> =  => ActionFn(557);
+// Plus<Lifetime> =  => ActionFn(579);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action557::<>(text, &___start, &___end);
+let ___nt = super::___action579::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant83(___nt), ___end));
-(0, 131)
+(0, 132)
 }
-pub(crate) fn ___reduce406<
+pub(crate) fn ___reduce417<
     'input,
 >(
 text: &'input str,
@@ -13715,17 +14046,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<Lifetime> = (<Lifetime> "+")+, Lifetime => ActionFn(558);
+// Plus<Lifetime> = (<Lifetime> "+")+, Lifetime => ActionFn(580);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant22(___symbols);
 let ___sym0 = ___pop_Variant23(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action558::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action580::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant83(___nt), ___end));
-(2, 131)
+(2, 132)
 }
-pub(crate) fn ___reduce407<
+pub(crate) fn ___reduce418<
     'input,
 >(
 text: &'input str,
@@ -13734,15 +14065,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<Lifetime> = (<Lifetime> "+")+ => ActionFn(559);
+// Plus<Lifetime> = (<Lifetime> "+")+ => ActionFn(581);
 let ___sym0 = ___pop_Variant23(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action559::<>(text, ___sym0);
+let ___nt = super::___action581::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant83(___nt), ___end));
-(1, 131)
+(1, 132)
 }
-pub(crate) fn ___reduce408<
+pub(crate) fn ___reduce419<
     'input,
 >(
 text: &'input str,
@@ -13751,15 +14082,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<TypeBound> = TypeBound => ActionFn(646);
+// Plus<TypeBound> = TypeBound => ActionFn(668);
 let ___sym0 = ___pop_Variant32(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action646::<>(text, ___sym0);
+let ___nt = super::___action668::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant84(___nt), ___end));
-(1, 132)
+(1, 133)
 }
-pub(crate) fn ___reduce409<
+pub(crate) fn ___reduce420<
     'input,
 >(
 text: &'input str,
@@ -13768,14 +14099,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<TypeBound> =  => ActionFn(647);
+// Plus<TypeBound> =  => ActionFn(669);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action647::<>(text, &___start, &___end);
+let ___nt = super::___action669::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant84(___nt), ___end));
-(0, 132)
+(0, 133)
 }
-pub(crate) fn ___reduce410<
+pub(crate) fn ___reduce421<
     'input,
 >(
 text: &'input str,
@@ -13784,17 +14115,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<TypeBound> = (<TypeBound> "+")+, TypeBound => ActionFn(648);
+// Plus<TypeBound> = (<TypeBound> "+")+, TypeBound => ActionFn(670);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant32(___symbols);
 let ___sym0 = ___pop_Variant33(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action648::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action670::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant84(___nt), ___end));
-(2, 132)
+(2, 133)
 }
-pub(crate) fn ___reduce411<
+pub(crate) fn ___reduce422<
     'input,
 >(
 text: &'input str,
@@ -13803,15 +14134,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Plus<TypeBound> = (<TypeBound> "+")+ => ActionFn(649);
+// Plus<TypeBound> = (<TypeBound> "+")+ => ActionFn(671);
 let ___sym0 = ___pop_Variant33(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action649::<>(text, ___sym0);
+let ___nt = super::___action671::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant84(___nt), ___end));
-(1, 132)
+(1, 133)
 }
-pub(crate) fn ___reduce412<
+pub(crate) fn ___reduce423<
     'input,
 >(
 text: &'input str,
@@ -13820,15 +14151,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// QuotedLiteral = StringLiteral => ActionFn(116);
+// QuotedLiteral = StringLiteral => ActionFn(117);
 let ___sym0 = ___pop_Variant20(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action116::<>(text, ___sym0);
+let ___nt = super::___action117::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant76(___nt), ___end));
-(1, 133)
+(1, 134)
 }
-pub(crate) fn ___reduce413<
+pub(crate) fn ___reduce424<
     'input,
 >(
 text: &'input str,
@@ -13837,15 +14168,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// QuotedLiteral = RegexLiteral => ActionFn(117);
+// QuotedLiteral = RegexLiteral => ActionFn(118);
 let ___sym0 = ___pop_Variant20(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action117::<>(text, ___sym0);
+let ___nt = super::___action118::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant76(___nt), ___end));
-(1, 133)
+(1, 134)
 }
-pub(crate) fn ___reduce414<
+pub(crate) fn ___reduce425<
     'input,
 >(
 text: &'input str,
@@ -13854,15 +14185,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// QuotedTerminal = QuotedLiteral => ActionFn(115);
-let ___sym0 = ___pop_Variant76(___symbols);
+// QuotedLiteral = CaseInsensitiveLiteral => ActionFn(119);
+let ___sym0 = ___pop_Variant20(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action115::<>(text, ___sym0);
-___symbols.push((___start, ___Symbol::Variant85(___nt), ___end));
+let ___nt = super::___action119::<>(text, ___sym0);
+___symbols.push((___start, ___Symbol::Variant76(___nt), ___end));
 (1, 134)
 }
-pub(crate) fn ___reduce415<
+pub(crate) fn ___reduce426<
     'input,
 >(
 text: &'input str,
@@ -13871,15 +14202,32 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// RegexLiteral = "RegexLiteral" => ActionFn(119);
-let ___sym0 = ___pop_Variant1(___symbols);
+// QuotedTerminal = QuotedLiteral => ActionFn(116);
+let ___sym0 = ___pop_Variant76(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action119::<>(text, ___sym0);
-___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
+let ___nt = super::___action116::<>(text, ___sym0);
+___symbols.push((___start, ___Symbol::Variant85(___nt), ___end));
 (1, 135)
 }
-pub(crate) fn ___reduce416<
+pub(crate) fn ___reduce427<
+    'input,
+>(
+text: &'input str,
+___lookahead_start: Option<&usize>,
+___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
+_: ::std::marker::PhantomData<(&'input ())>,
+) -> (usize, usize)
+{
+// RegexLiteral = "RegexLiteral" => ActionFn(121);
+let ___sym0 = ___pop_Variant1(___symbols);
+let ___start = ___sym0.0.clone();
+let ___end = ___sym0.2.clone();
+let ___nt = super::___action121::<>(text, ___sym0);
+___symbols.push((___start, ___Symbol::Variant20(___nt), ___end));
+(1, 136)
+}
+pub(crate) fn ___reduce428<
     'input,
 >(
 text: &'input str,
@@ -13894,9 +14242,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action57::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant86(___nt), ___end));
-(1, 136)
+(1, 137)
 }
-pub(crate) fn ___reduce417<
+pub(crate) fn ___reduce429<
     'input,
 >(
 text: &'input str,
@@ -13911,9 +14259,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action58::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant86(___nt), ___end));
-(1, 136)
+(1, 137)
 }
-pub(crate) fn ___reduce418<
+pub(crate) fn ___reduce430<
     'input,
 >(
 text: &'input str,
@@ -13928,9 +14276,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action59::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant86(___nt), ___end));
-(1, 136)
+(1, 137)
 }
-pub(crate) fn ___reduce419<
+pub(crate) fn ___reduce431<
     'input,
 >(
 text: &'input str,
@@ -13939,15 +14287,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ShebangAttribute = "#![...]" => ActionFn(120);
+// ShebangAttribute = "#![...]" => ActionFn(123);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action120::<>(text, ___sym0);
+let ___nt = super::___action123::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant87(___nt), ___end));
-(1, 137)
+(1, 138)
 }
-pub(crate) fn ___reduce420<
+pub(crate) fn ___reduce432<
     'input,
 >(
 text: &'input str,
@@ -13956,14 +14304,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ShebangAttribute* =  => ActionFn(188);
+// ShebangAttribute* =  => ActionFn(191);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action188::<>(text, &___start, &___end);
+let ___nt = super::___action191::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant88(___nt), ___end));
-(0, 138)
+(0, 139)
 }
-pub(crate) fn ___reduce421<
+pub(crate) fn ___reduce433<
     'input,
 >(
 text: &'input str,
@@ -13972,15 +14320,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ShebangAttribute* = ShebangAttribute+ => ActionFn(189);
+// ShebangAttribute* = ShebangAttribute+ => ActionFn(192);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action189::<>(text, ___sym0);
+let ___nt = super::___action192::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant88(___nt), ___end));
-(1, 138)
+(1, 139)
 }
-pub(crate) fn ___reduce422<
+pub(crate) fn ___reduce434<
     'input,
 >(
 text: &'input str,
@@ -13989,15 +14337,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ShebangAttribute+ = ShebangAttribute => ActionFn(190);
+// ShebangAttribute+ = ShebangAttribute => ActionFn(193);
 let ___sym0 = ___pop_Variant87(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action190::<>(text, ___sym0);
+let ___nt = super::___action193::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant88(___nt), ___end));
-(1, 139)
+(1, 140)
 }
-pub(crate) fn ___reduce423<
+pub(crate) fn ___reduce435<
     'input,
 >(
 text: &'input str,
@@ -14006,17 +14354,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// ShebangAttribute+ = ShebangAttribute+, ShebangAttribute => ActionFn(191);
+// ShebangAttribute+ = ShebangAttribute+, ShebangAttribute => ActionFn(194);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant87(___symbols);
 let ___sym0 = ___pop_Variant88(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action191::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action194::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant88(___nt), ___end));
-(2, 139)
+(2, 140)
 }
-pub(crate) fn ___reduce425<
+pub(crate) fn ___reduce437<
     'input,
 >(
 text: &'input str,
@@ -14025,7 +14373,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol = "<", "mut", Id, ":", Symbol0, ">" => ActionFn(439);
+// Symbol = "<", "mut", Id, ":", Symbol0, ">" => ActionFn(443);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant0(___symbols);
 let ___sym4 = ___pop_Variant30(___symbols);
@@ -14035,11 +14383,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action439::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action443::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
-(6, 141)
+(6, 142)
 }
-pub(crate) fn ___reduce426<
+pub(crate) fn ___reduce438<
     'input,
 >(
 text: &'input str,
@@ -14048,7 +14396,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol = "<", Id, ":", Symbol0, ">" => ActionFn(440);
+// Symbol = "<", Id, ":", Symbol0, ">" => ActionFn(444);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant30(___symbols);
@@ -14057,11 +14405,11 @@ let ___sym1 = ___pop_Variant20(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action440::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action444::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
-(5, 141)
+(5, 142)
 }
-pub(crate) fn ___reduce427<
+pub(crate) fn ___reduce439<
     'input,
 >(
 text: &'input str,
@@ -14070,18 +14418,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol = "<", Symbol0, ">" => ActionFn(441);
+// Symbol = "<", Symbol0, ">" => ActionFn(445);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant0(___symbols);
 let ___sym1 = ___pop_Variant30(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action441::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action445::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
-(3, 141)
+(3, 142)
 }
-pub(crate) fn ___reduce428<
+pub(crate) fn ___reduce440<
     'input,
 >(
 text: &'input str,
@@ -14096,9 +14444,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action54::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
-(1, 141)
+(1, 142)
 }
-pub(crate) fn ___reduce429<
+pub(crate) fn ___reduce441<
     'input,
 >(
 text: &'input str,
@@ -14107,14 +14455,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol* =  => ActionFn(142);
+// Symbol* =  => ActionFn(145);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action142::<>(text, &___start, &___end);
+let ___nt = super::___action145::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
-(0, 142)
+(0, 143)
 }
-pub(crate) fn ___reduce430<
+pub(crate) fn ___reduce442<
     'input,
 >(
 text: &'input str,
@@ -14123,15 +14471,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol* = Symbol+ => ActionFn(143);
+// Symbol* = Symbol+ => ActionFn(146);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action143::<>(text, ___sym0);
+let ___nt = super::___action146::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
-(1, 142)
+(1, 143)
 }
-pub(crate) fn ___reduce431<
+pub(crate) fn ___reduce443<
     'input,
 >(
 text: &'input str,
@@ -14140,15 +14488,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol+ = Symbol => ActionFn(149);
+// Symbol+ = Symbol => ActionFn(152);
 let ___sym0 = ___pop_Variant30(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action149::<>(text, ___sym0);
+let ___nt = super::___action152::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
-(1, 143)
+(1, 144)
 }
-pub(crate) fn ___reduce432<
+pub(crate) fn ___reduce444<
     'input,
 >(
 text: &'input str,
@@ -14157,17 +14505,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol+ = Symbol+, Symbol => ActionFn(150);
+// Symbol+ = Symbol+, Symbol => ActionFn(153);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant30(___symbols);
 let ___sym0 = ___pop_Variant31(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action150::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action153::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant31(___nt), ___end));
-(2, 143)
+(2, 144)
 }
-pub(crate) fn ___reduce433<
+pub(crate) fn ___reduce445<
     'input,
 >(
 text: &'input str,
@@ -14182,9 +14530,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action55::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
-(1, 144)
+(1, 145)
 }
-pub(crate) fn ___reduce434<
+pub(crate) fn ___reduce446<
     'input,
 >(
 text: &'input str,
@@ -14193,17 +14541,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol0 = Symbol0, RepeatOp => ActionFn(442);
+// Symbol0 = Symbol0, RepeatOp => ActionFn(446);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant86(___symbols);
 let ___sym0 = ___pop_Variant30(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action442::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action446::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
-(2, 144)
+(2, 145)
 }
-pub(crate) fn ___reduce435<
+pub(crate) fn ___reduce447<
     'input,
 >(
 text: &'input str,
@@ -14212,15 +14560,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol1 = SymbolKind1 => ActionFn(443);
+// Symbol1 = SymbolKind1 => ActionFn(447);
 let ___sym0 = ___pop_Variant90(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action443::<>(text, ___sym0);
+let ___nt = super::___action447::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant30(___nt), ___end));
-(1, 145)
+(1, 146)
 }
-pub(crate) fn ___reduce436<
+pub(crate) fn ___reduce448<
     'input,
 >(
 text: &'input str,
@@ -14229,15 +14577,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol? = Symbol => ActionFn(241);
+// Symbol? = Symbol => ActionFn(244);
 let ___sym0 = ___pop_Variant30(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action241::<>(text, ___sym0);
+let ___nt = super::___action244::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant89(___nt), ___end));
-(1, 146)
+(1, 147)
 }
-pub(crate) fn ___reduce437<
+pub(crate) fn ___reduce449<
     'input,
 >(
 text: &'input str,
@@ -14246,14 +14594,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Symbol? =  => ActionFn(242);
+// Symbol? =  => ActionFn(245);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action242::<>(text, &___start, &___end);
+let ___nt = super::___action245::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant89(___nt), ___end));
-(0, 146)
+(0, 147)
 }
-pub(crate) fn ___reduce438<
+pub(crate) fn ___reduce450<
     'input,
 >(
 text: &'input str,
@@ -14272,9 +14620,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
 let ___nt = super::___action61::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(4, 147)
+(4, 148)
 }
-pub(crate) fn ___reduce439<
+pub(crate) fn ___reduce451<
     'input,
 >(
 text: &'input str,
@@ -14289,9 +14637,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action62::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(1, 147)
+(1, 148)
 }
-pub(crate) fn ___reduce440<
+pub(crate) fn ___reduce452<
     'input,
 >(
 text: &'input str,
@@ -14306,9 +14654,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action63::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(1, 147)
+(1, 148)
 }
-pub(crate) fn ___reduce441<
+pub(crate) fn ___reduce453<
     'input,
 >(
 text: &'input str,
@@ -14323,9 +14671,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action64::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(1, 147)
+(1, 148)
 }
-pub(crate) fn ___reduce442<
+pub(crate) fn ___reduce454<
     'input,
 >(
 text: &'input str,
@@ -14343,9 +14691,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action65::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(3, 147)
+(3, 148)
 }
-pub(crate) fn ___reduce443<
+pub(crate) fn ___reduce455<
     'input,
 >(
 text: &'input str,
@@ -14360,9 +14708,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action66::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(1, 147)
+(1, 148)
 }
-pub(crate) fn ___reduce444<
+pub(crate) fn ___reduce456<
     'input,
 >(
 text: &'input str,
@@ -14377,9 +14725,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action67::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(1, 147)
+(1, 148)
 }
-pub(crate) fn ___reduce445<
+pub(crate) fn ___reduce457<
     'input,
 >(
 text: &'input str,
@@ -14394,9 +14742,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action68::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant90(___nt), ___end));
-(1, 147)
+(1, 148)
 }
-pub(crate) fn ___reduce446<
+pub(crate) fn ___reduce458<
     'input,
 >(
 text: &'input str,
@@ -14405,15 +14753,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Terminal = QuotedTerminal => ActionFn(113);
+// Terminal = QuotedTerminal => ActionFn(114);
 let ___sym0 = ___pop_Variant85(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action113::<>(text, ___sym0);
+let ___nt = super::___action114::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant85(___nt), ___end));
-(1, 148)
+(1, 149)
 }
-pub(crate) fn ___reduce447<
+pub(crate) fn ___reduce459<
     'input,
 >(
 text: &'input str,
@@ -14422,15 +14770,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Terminal = "Id" => ActionFn(114);
+// Terminal = "Id" => ActionFn(115);
 let ___sym0 = ___pop_Variant1(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action114::<>(text, ___sym0);
+let ___nt = super::___action115::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant85(___nt), ___end));
-(1, 148)
+(1, 149)
 }
-pub(crate) fn ___reduce448<
+pub(crate) fn ___reduce460<
     'input,
 >(
 text: &'input str,
@@ -14447,9 +14795,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action1::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant91(___nt), ___end));
-(2, 149)
+(2, 150)
 }
-pub(crate) fn ___reduce449<
+pub(crate) fn ___reduce461<
     'input,
 >(
 text: &'input str,
@@ -14466,9 +14814,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action2::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant91(___nt), ___end));
-(2, 149)
+(2, 150)
 }
-pub(crate) fn ___reduce450<
+pub(crate) fn ___reduce462<
     'input,
 >(
 text: &'input str,
@@ -14485,9 +14833,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action3::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant91(___nt), ___end));
-(2, 149)
+(2, 150)
 }
-pub(crate) fn ___reduce451<
+pub(crate) fn ___reduce463<
     'input,
 >(
 text: &'input str,
@@ -14504,9 +14852,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action4::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant91(___nt), ___end));
-(2, 149)
+(2, 150)
 }
-pub(crate) fn ___reduce452<
+pub(crate) fn ___reduce464<
     'input,
 >(
 text: &'input str,
@@ -14523,9 +14871,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action5::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant91(___nt), ___end));
-(2, 149)
+(2, 150)
 }
-pub(crate) fn ___reduce453<
+pub(crate) fn ___reduce465<
     'input,
 >(
 text: &'input str,
@@ -14540,9 +14888,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action16::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(1, 150)
+(1, 151)
 }
-pub(crate) fn ___reduce454<
+pub(crate) fn ___reduce466<
     'input,
 >(
 text: &'input str,
@@ -14551,7 +14899,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(476);
+// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(498);
 assert!(___symbols.len() >= 10);
 let ___sym9 = ___pop_Variant3(___symbols);
 let ___sym8 = ___pop_Variant0(___symbols);
@@ -14565,11 +14913,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym9.2.clone();
-let ___nt = super::___action476::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8, ___sym9);
+let ___nt = super::___action498::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8, ___sym9);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(10, 150)
+(10, 151)
 }
-pub(crate) fn ___reduce455<
+pub(crate) fn ___reduce467<
     'input,
 >(
 text: &'input str,
@@ -14578,7 +14926,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(477);
+// TypeBound = Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(499);
 assert!(___symbols.len() >= 6);
 let ___sym5 = ___pop_Variant3(___symbols);
 let ___sym4 = ___pop_Variant0(___symbols);
@@ -14588,11 +14936,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym5.2.clone();
-let ___nt = super::___action477::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
+let ___nt = super::___action499::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(6, 150)
+(6, 151)
 }
-pub(crate) fn ___reduce456<
+pub(crate) fn ___reduce468<
     'input,
 >(
 text: &'input str,
@@ -14601,7 +14949,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")" => ActionFn(478);
+// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")" => ActionFn(500);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant0(___symbols);
 let ___sym6 = ___pop_Variant58(___symbols);
@@ -14613,11 +14961,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action478::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action500::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(8, 150)
+(8, 151)
 }
-pub(crate) fn ___reduce457<
+pub(crate) fn ___reduce469<
     'input,
 >(
 text: &'input str,
@@ -14626,7 +14974,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = Path, "(", Comma<TypeRef>, ")" => ActionFn(479);
+// TypeBound = Path, "(", Comma<TypeRef>, ")" => ActionFn(501);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant58(___symbols);
@@ -14634,11 +14982,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action479::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action501::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(4, 150)
+(4, 151)
 }
-pub(crate) fn ___reduce458<
+pub(crate) fn ___reduce470<
     'input,
 >(
 text: &'input str,
@@ -14647,7 +14995,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path, "<", Comma<TypeBoundParameter>, ">" => ActionFn(480);
+// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path, "<", Comma<TypeBoundParameter>, ">" => ActionFn(502);
 assert!(___symbols.len() >= 8);
 let ___sym7 = ___pop_Variant0(___symbols);
 let ___sym6 = ___pop_Variant5(___symbols);
@@ -14659,11 +15007,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym7.2.clone();
-let ___nt = super::___action480::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
+let ___nt = super::___action502::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(8, 150)
+(8, 151)
 }
-pub(crate) fn ___reduce459<
+pub(crate) fn ___reduce471<
     'input,
 >(
 text: &'input str,
@@ -14672,7 +15020,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = Path, "<", Comma<TypeBoundParameter>, ">" => ActionFn(481);
+// TypeBound = Path, "<", Comma<TypeBoundParameter>, ">" => ActionFn(503);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant0(___symbols);
 let ___sym2 = ___pop_Variant5(___symbols);
@@ -14680,11 +15028,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action481::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action503::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(4, 150)
+(4, 151)
 }
-pub(crate) fn ___reduce460<
+pub(crate) fn ___reduce472<
     'input,
 >(
 text: &'input str,
@@ -14693,7 +15041,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path => ActionFn(482);
+// TypeBound = "for", "<", Comma<TypeParameter>, ">", Path => ActionFn(504);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant80(___symbols);
 let ___sym3 = ___pop_Variant0(___symbols);
@@ -14702,11 +15050,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action482::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action504::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(5, 150)
+(5, 151)
 }
-pub(crate) fn ___reduce461<
+pub(crate) fn ___reduce473<
     'input,
 >(
 text: &'input str,
@@ -14715,15 +15063,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound = Path => ActionFn(483);
+// TypeBound = Path => ActionFn(505);
 let ___sym0 = ___pop_Variant80(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action483::<>(text, ___sym0);
+let ___nt = super::___action505::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant32(___nt), ___end));
-(1, 150)
+(1, 151)
 }
-pub(crate) fn ___reduce462<
+pub(crate) fn ___reduce474<
     'input,
 >(
 text: &'input str,
@@ -14732,15 +15080,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound? = TypeBound => ActionFn(211);
+// TypeBound? = TypeBound => ActionFn(214);
 let ___sym0 = ___pop_Variant32(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action211::<>(text, ___sym0);
+let ___nt = super::___action214::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant92(___nt), ___end));
-(1, 151)
+(1, 152)
 }
-pub(crate) fn ___reduce463<
+pub(crate) fn ___reduce475<
     'input,
 >(
 text: &'input str,
@@ -14749,14 +15097,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBound? =  => ActionFn(212);
+// TypeBound? =  => ActionFn(215);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action212::<>(text, &___start, &___end);
+let ___nt = super::___action215::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant92(___nt), ___end));
-(0, 151)
+(0, 152)
 }
-pub(crate) fn ___reduce464<
+pub(crate) fn ___reduce476<
     'input,
 >(
 text: &'input str,
@@ -14771,9 +15119,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action19::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant34(___nt), ___end));
-(1, 152)
+(1, 153)
 }
-pub(crate) fn ___reduce465<
+pub(crate) fn ___reduce477<
     'input,
 >(
 text: &'input str,
@@ -14788,9 +15136,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action20::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant34(___nt), ___end));
-(1, 152)
+(1, 153)
 }
-pub(crate) fn ___reduce466<
+pub(crate) fn ___reduce478<
     'input,
 >(
 text: &'input str,
@@ -14808,9 +15156,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action21::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant34(___nt), ___end));
-(3, 152)
+(3, 153)
 }
-pub(crate) fn ___reduce467<
+pub(crate) fn ___reduce479<
     'input,
 >(
 text: &'input str,
@@ -14819,15 +15167,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBoundParameter? = TypeBoundParameter => ActionFn(221);
+// TypeBoundParameter? = TypeBoundParameter => ActionFn(224);
 let ___sym0 = ___pop_Variant34(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action221::<>(text, ___sym0);
+let ___nt = super::___action224::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant93(___nt), ___end));
-(1, 153)
+(1, 154)
 }
-pub(crate) fn ___reduce468<
+pub(crate) fn ___reduce480<
     'input,
 >(
 text: &'input str,
@@ -14836,14 +15184,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeBoundParameter? =  => ActionFn(222);
+// TypeBoundParameter? =  => ActionFn(225);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action222::<>(text, &___start, &___end);
+let ___nt = super::___action225::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant93(___nt), ___end));
-(0, 153)
+(0, 154)
 }
-pub(crate) fn ___reduce469<
+pub(crate) fn ___reduce481<
     'input,
 >(
 text: &'input str,
@@ -14858,9 +15206,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action15::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant84(___nt), ___end));
-(1, 154)
+(1, 155)
 }
-pub(crate) fn ___reduce470<
+pub(crate) fn ___reduce482<
     'input,
 >(
 text: &'input str,
@@ -14875,9 +15223,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action8::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant36(___nt), ___end));
-(1, 155)
+(1, 156)
 }
-pub(crate) fn ___reduce471<
+pub(crate) fn ___reduce483<
     'input,
 >(
 text: &'input str,
@@ -14892,9 +15240,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action9::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant36(___nt), ___end));
-(1, 155)
+(1, 156)
 }
-pub(crate) fn ___reduce472<
+pub(crate) fn ___reduce484<
     'input,
 >(
 text: &'input str,
@@ -14903,15 +15251,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeParameter? = TypeParameter => ActionFn(198);
+// TypeParameter? = TypeParameter => ActionFn(201);
 let ___sym0 = ___pop_Variant36(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action198::<>(text, ___sym0);
+let ___nt = super::___action201::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant94(___nt), ___end));
-(1, 156)
+(1, 157)
 }
-pub(crate) fn ___reduce473<
+pub(crate) fn ___reduce485<
     'input,
 >(
 text: &'input str,
@@ -14920,14 +15268,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeParameter? =  => ActionFn(199);
+// TypeParameter? =  => ActionFn(202);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action199::<>(text, &___start, &___end);
+let ___nt = super::___action202::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant94(___nt), ___end));
-(0, 156)
+(0, 157)
 }
-pub(crate) fn ___reduce474<
+pub(crate) fn ___reduce486<
     'input,
 >(
 text: &'input str,
@@ -14945,9 +15293,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action69::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(3, 157)
+(3, 158)
 }
-pub(crate) fn ___reduce475<
+pub(crate) fn ___reduce487<
     'input,
 >(
 text: &'input str,
@@ -14965,9 +15313,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action70::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(3, 157)
+(3, 158)
 }
-pub(crate) fn ___reduce476<
+pub(crate) fn ___reduce488<
     'input,
 >(
 text: &'input str,
@@ -14985,9 +15333,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
 let ___nt = super::___action71::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(3, 157)
+(3, 158)
 }
-pub(crate) fn ___reduce477<
+pub(crate) fn ___reduce489<
     'input,
 >(
 text: &'input str,
@@ -14996,7 +15344,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "&", Lifetime, "mut", TypeRef => ActionFn(560);
+// TypeRef = "&", Lifetime, "mut", TypeRef => ActionFn(582);
 assert!(___symbols.len() >= 4);
 let ___sym3 = ___pop_Variant3(___symbols);
 let ___sym2 = ___pop_Variant0(___symbols);
@@ -15004,11 +15352,11 @@ let ___sym1 = ___pop_Variant22(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
-let ___nt = super::___action560::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
+let ___nt = super::___action582::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(4, 157)
+(4, 158)
 }
-pub(crate) fn ___reduce478<
+pub(crate) fn ___reduce490<
     'input,
 >(
 text: &'input str,
@@ -15017,18 +15365,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "&", "mut", TypeRef => ActionFn(561);
+// TypeRef = "&", "mut", TypeRef => ActionFn(583);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant3(___symbols);
 let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action561::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action583::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(3, 157)
+(3, 158)
 }
-pub(crate) fn ___reduce479<
+pub(crate) fn ___reduce491<
     'input,
 >(
 text: &'input str,
@@ -15037,18 +15385,18 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "&", Lifetime, TypeRef => ActionFn(562);
+// TypeRef = "&", Lifetime, TypeRef => ActionFn(584);
 assert!(___symbols.len() >= 3);
 let ___sym2 = ___pop_Variant3(___symbols);
 let ___sym1 = ___pop_Variant22(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym2.2.clone();
-let ___nt = super::___action562::<>(text, ___sym0, ___sym1, ___sym2);
+let ___nt = super::___action584::<>(text, ___sym0, ___sym1, ___sym2);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(3, 157)
+(3, 158)
 }
-pub(crate) fn ___reduce480<
+pub(crate) fn ___reduce492<
     'input,
 >(
 text: &'input str,
@@ -15057,17 +15405,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "&", TypeRef => ActionFn(563);
+// TypeRef = "&", TypeRef => ActionFn(585);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant3(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action563::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action585::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(2, 157)
+(2, 158)
 }
-pub(crate) fn ___reduce481<
+pub(crate) fn ___reduce493<
     'input,
 >(
 text: &'input str,
@@ -15086,9 +15434,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
 let ___nt = super::___action73::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(4, 157)
+(4, 158)
 }
-pub(crate) fn ___reduce482<
+pub(crate) fn ___reduce494<
     'input,
 >(
 text: &'input str,
@@ -15103,9 +15451,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action74::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(1, 157)
+(1, 158)
 }
-pub(crate) fn ___reduce483<
+pub(crate) fn ___reduce495<
     'input,
 >(
 text: &'input str,
@@ -15125,9 +15473,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
 let ___nt = super::___action75::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(5, 157)
+(5, 158)
 }
-pub(crate) fn ___reduce484<
+pub(crate) fn ___reduce496<
     'input,
 >(
 text: &'input str,
@@ -15144,9 +15492,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action76::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(2, 157)
+(2, 158)
 }
-pub(crate) fn ___reduce485<
+pub(crate) fn ___reduce497<
     'input,
 >(
 text: &'input str,
@@ -15155,7 +15503,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "dyn", "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(484);
+// TypeRef = "dyn", "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(506);
 assert!(___symbols.len() >= 11);
 let ___sym10 = ___pop_Variant3(___symbols);
 let ___sym9 = ___pop_Variant0(___symbols);
@@ -15170,11 +15518,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym10.2.clone();
-let ___nt = super::___action484::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8, ___sym9, ___sym10);
+let ___nt = super::___action506::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8, ___sym9, ___sym10);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(11, 157)
+(11, 158)
 }
-pub(crate) fn ___reduce486<
+pub(crate) fn ___reduce498<
     'input,
 >(
 text: &'input str,
@@ -15183,7 +15531,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "dyn", Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(485);
+// TypeRef = "dyn", Path, "(", Comma<TypeRef>, ")", "->", TypeRef => ActionFn(507);
 assert!(___symbols.len() >= 7);
 let ___sym6 = ___pop_Variant3(___symbols);
 let ___sym5 = ___pop_Variant0(___symbols);
@@ -15194,11 +15542,11 @@ let ___sym1 = ___pop_Variant80(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym6.2.clone();
-let ___nt = super::___action485::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
+let ___nt = super::___action507::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(7, 157)
+(7, 158)
 }
-pub(crate) fn ___reduce487<
+pub(crate) fn ___reduce499<
     'input,
 >(
 text: &'input str,
@@ -15207,7 +15555,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "dyn", "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")" => ActionFn(486);
+// TypeRef = "dyn", "for", "<", Comma<TypeParameter>, ">", Path, "(", Comma<TypeRef>, ")" => ActionFn(508);
 assert!(___symbols.len() >= 9);
 let ___sym8 = ___pop_Variant0(___symbols);
 let ___sym7 = ___pop_Variant58(___symbols);
@@ -15220,11 +15568,11 @@ let ___sym1 = ___pop_Variant0(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym8.2.clone();
-let ___nt = super::___action486::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8);
+let ___nt = super::___action508::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4, ___sym5, ___sym6, ___sym7, ___sym8);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(9, 157)
+(9, 158)
 }
-pub(crate) fn ___reduce488<
+pub(crate) fn ___reduce500<
     'input,
 >(
 text: &'input str,
@@ -15233,7 +15581,7 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef = "dyn", Path, "(", Comma<TypeRef>, ")" => ActionFn(487);
+// TypeRef = "dyn", Path, "(", Comma<TypeRef>, ")" => ActionFn(509);
 assert!(___symbols.len() >= 5);
 let ___sym4 = ___pop_Variant0(___symbols);
 let ___sym3 = ___pop_Variant58(___symbols);
@@ -15242,11 +15590,11 @@ let ___sym1 = ___pop_Variant80(___symbols);
 let ___sym0 = ___pop_Variant0(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym4.2.clone();
-let ___nt = super::___action487::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
+let ___nt = super::___action509::<>(text, ___sym0, ___sym1, ___sym2, ___sym3, ___sym4);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(5, 157)
+(5, 158)
 }
-pub(crate) fn ___reduce489<
+pub(crate) fn ___reduce501<
     'input,
 >(
 text: &'input str,
@@ -15255,15 +15603,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef? = TypeRef => ActionFn(216);
+// TypeRef? = TypeRef => ActionFn(219);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action216::<>(text, ___sym0);
+let ___nt = super::___action219::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
-(1, 158)
+(1, 159)
 }
-pub(crate) fn ___reduce490<
+pub(crate) fn ___reduce502<
     'input,
 >(
 text: &'input str,
@@ -15272,14 +15620,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRef? =  => ActionFn(217);
+// TypeRef? =  => ActionFn(220);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action217::<>(text, &___start, &___end);
+let ___nt = super::___action220::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
-(0, 158)
+(0, 159)
 }
-pub(crate) fn ___reduce491<
+pub(crate) fn ___reduce503<
     'input,
 >(
 text: &'input str,
@@ -15294,9 +15642,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action78::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(1, 159)
+(1, 160)
 }
-pub(crate) fn ___reduce492<
+pub(crate) fn ___reduce504<
     'input,
 >(
 text: &'input str,
@@ -15311,9 +15659,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action79::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant3(___nt), ___end));
-(1, 159)
+(1, 160)
 }
-pub(crate) fn ___reduce493<
+pub(crate) fn ___reduce505<
     'input,
 >(
 text: &'input str,
@@ -15322,15 +15670,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRefOrLifetime? = TypeRefOrLifetime => ActionFn(246);
+// TypeRefOrLifetime? = TypeRefOrLifetime => ActionFn(249);
 let ___sym0 = ___pop_Variant3(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action246::<>(text, ___sym0);
+let ___nt = super::___action249::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
-(1, 160)
+(1, 161)
 }
-pub(crate) fn ___reduce494<
+pub(crate) fn ___reduce506<
     'input,
 >(
 text: &'input str,
@@ -15339,14 +15687,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// TypeRefOrLifetime? =  => ActionFn(247);
+// TypeRefOrLifetime? =  => ActionFn(250);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action247::<>(text, &___start, &___end);
+let ___nt = super::___action250::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant4(___nt), ___end));
-(0, 160)
+(0, 161)
 }
-pub(crate) fn ___reduce495<
+pub(crate) fn ___reduce507<
     'input,
 >(
 text: &'input str,
@@ -15363,9 +15711,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
 let ___nt = super::___action28::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant63(___nt), ___end));
-(2, 161)
+(2, 162)
 }
-pub(crate) fn ___reduce496<
+pub(crate) fn ___reduce508<
     'input,
 >(
 text: &'input str,
@@ -15374,14 +15722,14 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Use* =  => ActionFn(186);
+// Use* =  => ActionFn(189);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action186::<>(text, &___start, &___end);
+let ___nt = super::___action189::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(0, 162)
+(0, 163)
 }
-pub(crate) fn ___reduce497<
+pub(crate) fn ___reduce509<
     'input,
 >(
 text: &'input str,
@@ -15390,15 +15738,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Use* = Use+ => ActionFn(187);
+// Use* = Use+ => ActionFn(190);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action187::<>(text, ___sym0);
+let ___nt = super::___action190::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(1, 162)
+(1, 163)
 }
-pub(crate) fn ___reduce498<
+pub(crate) fn ___reduce510<
     'input,
 >(
 text: &'input str,
@@ -15407,15 +15755,15 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Use+ = Use => ActionFn(192);
+// Use+ = Use => ActionFn(195);
 let ___sym0 = ___pop_Variant63(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
-let ___nt = super::___action192::<>(text, ___sym0);
+let ___nt = super::___action195::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(1, 163)
+(1, 164)
 }
-pub(crate) fn ___reduce499<
+pub(crate) fn ___reduce511<
     'input,
 >(
 text: &'input str,
@@ -15424,17 +15772,17 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Use+ = Use+, Use => ActionFn(193);
+// Use+ = Use+, Use => ActionFn(196);
 assert!(___symbols.len() >= 2);
 let ___sym1 = ___pop_Variant63(___symbols);
 let ___sym0 = ___pop_Variant66(___symbols);
 let ___start = ___sym0.0.clone();
 let ___end = ___sym1.2.clone();
-let ___nt = super::___action193::<>(text, ___sym0, ___sym1);
+let ___nt = super::___action196::<>(text, ___sym0, ___sym1);
 ___symbols.push((___start, ___Symbol::Variant66(___nt), ___end));
-(2, 163)
+(2, 164)
 }
-pub(crate) fn ___reduce500<
+pub(crate) fn ___reduce512<
     'input,
 >(
 text: &'input str,
@@ -15453,9 +15801,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym3.2.clone();
 let ___nt = super::___action29::<>(text, ___sym0, ___sym1, ___sym2, ___sym3);
 ___symbols.push((___start, ___Symbol::Variant95(___nt), ___end));
-(4, 164)
+(4, 165)
 }
-pub(crate) fn ___reduce501<
+pub(crate) fn ___reduce513<
     'input,
 >(
 text: &'input str,
@@ -15470,9 +15818,9 @@ let ___start = ___sym0.0.clone();
 let ___end = ___sym0.2.clone();
 let ___nt = super::___action30::<>(text, ___sym0);
 ___symbols.push((___start, ___Symbol::Variant95(___nt), ___end));
-(1, 164)
+(1, 165)
 }
-pub(crate) fn ___reduce502<
+pub(crate) fn ___reduce514<
     'input,
 >(
 text: &'input str,
@@ -15481,12 +15829,12 @@ ___symbols: &mut ::std::vec::Vec<(usize,___Symbol<'input>,usize)>,
 _: ::std::marker::PhantomData<(&'input ())>,
 ) -> (usize, usize)
 {
-// Visibility =  => ActionFn(325);
+// Visibility =  => ActionFn(328);
 let ___start = ___lookahead_start.cloned().or_else(|| ___symbols.last().map(|s| s.2.clone())).unwrap_or_default();
 let ___end = ___start.clone();
-let ___nt = super::___action325::<>(text, &___start, &___end);
+let ___nt = super::___action328::<>(text, &___start, &___end);
 ___symbols.push((___start, ___Symbol::Variant95(___nt), ___end));
-(0, 164)
+(0, 165)
 }
 }
 pub use self::___parse___Top::TopParser;
@@ -16022,6 +16370,7 @@ fn ___action40<
     'input,
 >(
 text: &'input str,
+(_, annotations, _): (usize, ::std::vec::Vec<Annotation>, usize),
 (_, lo, _): (usize, usize, usize),
 (_, s, _): (usize, ::std::vec::Vec<Symbol>, usize),
 (_, c, _): (usize, ::std::option::Option<Condition>, usize),
@@ -16032,6 +16381,7 @@ text: &'input str,
 {
         Alternative {
             span: Span(lo, hi),
+            annotations,
             expr: ExprSymbol { symbols: s },
             condition: c,
             action: a
@@ -16044,6 +16394,7 @@ fn ___action41<
     'input,
 >(
 text: &'input str,
+(_, annotations, _): (usize, ::std::vec::Vec<Annotation>, usize),
 (_, lo, _): (usize, usize, usize),
 (_, c, _): (usize, ::std::option::Option<Condition>, usize),
 (_, a, _): (usize, ActionKind, usize),
@@ -16053,6 +16404,7 @@ text: &'input str,
 {
         Alternative {
             span: Span(lo, hi),
+            annotations,
             expr: ExprSymbol { symbols: vec![] },
             condition: c,
             action: Some(a)
@@ -16669,12 +17021,13 @@ fn ___action88<
     'input,
 >(
 text: &'input str,
+(_, a, _): (usize, ::std::vec::Vec<Annotation>, usize),
 (_, lo, _): (usize, usize, usize),
 (_, s, _): (usize, TerminalLiteral, usize),
 (_, hi, _): (usize, usize, usize),
 ) -> MatchItem
 {
-MatchItem::Unmapped(s, Span(lo, hi))
+MatchItem::Unmapped(s, a, Span(lo, hi))
 }
 
 #[allow(unused_variables)]
@@ -16682,6 +17035,7 @@ fn ___action89<
     'input,
 >(
 text: &'input str,
+(_, a, _): (usize, ::std::vec::Vec<Annotation>, usize),
 (_, lo, _): (usize, usize, usize),
 (_, from, _): (usize, TerminalLiteral, usize),
 (_, start, _): (usize, usize, usize),
@@ -16691,7 +17045,7 @@ text: &'input str,
 {
 {
         let to = super::parse_match_mapping(p, start + 2)?;
-        Ok(MatchItem::Mapped(from, to, Span(lo, hi)))
+        Ok(MatchItem::Mapped(from, to, a, Span(lo, hi)))
     }
 }
 
@@ -16735,6 +17089,19 @@ fn ___action93<
 >(
 text: &'input str,
 (_, _, _): (usize, Tok<'input>, usize),
+(_, ___0, _): (usize, Atom, usize),
+(_, _, _): (usize, Tok<'input>, usize),
+) -> MatchMapping
+{
+MatchMapping::SkipNested(___0)
+}
+
+#[allow(unused_variables)]
+fn ___action94<
+    'input,
+>(
+text: &'input str,
+(_, _, _): (usize, Tok<'input>, usize),
 (_, lo, _): (usize, usize, usize),
 (_, t, _): (usize, TypeRef, usize),
 (_, hi, _): (usize, usize, usize),
@@ -16753,7 +17120,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action94<
+fn ___action95<
     'input,
 >(
 text: &'input str,
@@ -16774,10 +17141,11 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action95<
+fn ___action96<
     'input,
 >(
 text: &'input str,
+(_, annotations, _): (usize, ::std::vec::Vec<Annotation>, usize),
 (_, lo, _): (usize, usize, usize),
 (_, from, _): (usize, TerminalString, usize),
 (_, start, _): (usize, usize, usize),
@@ -16788,13 +17156,14 @@ text: &'input str,
 {
         let pattern = super::parse_pattern(p, start + 2)?;
         Ok(Conversion { span: Span(lo, hi),
+                        annotations,
                         from,
                         to: pattern })
     }
 }
 
 #[allow(unused_variables)]
-fn ___action96<
+fn ___action97<
     'input,
 >(
 text: &'input str,
@@ -16807,7 +17176,7 @@ Pattern { span: Span(lo, hi), kind: k }
 }
 
 #[allow(unused_variables)]
-fn ___action97<
+fn ___action98<
     'input,
 >(
 text: &'input str,
@@ -16821,7 +17190,7 @@ PatternKind::Enum(___0, ___1)
 }
 
 #[allow(unused_variables)]
-fn ___action98<
+fn ___action99<
     'input,
 >(
 text: &'input str,
@@ -16836,7 +17205,7 @@ PatternKind::Struct(p, a0.into_iter().chain(a1).collect(), false)
 }
 
 #[allow(unused_variables)]
-fn ___action99<
+fn ___action100<
     'input,
 >(
 text: &'input str,
@@ -16851,7 +17220,7 @@ PatternKind::Struct(p, a0, true)
 }
 
 #[allow(unused_variables)]
-fn ___action100<
+fn ___action101<
     'input,
 >(
 text: &'input str,
@@ -16862,7 +17231,7 @@ PatternKind::Underscore
 }
 
 #[allow(unused_variables)]
-fn ___action101<
+fn ___action102<
     'input,
 >(
 text: &'input str,
@@ -16873,7 +17242,7 @@ PatternKind::DotDot
 }
 
 #[allow(unused_variables)]
-fn ___action102<
+fn ___action103<
     'input,
 >(
 text: &'input str,
@@ -16886,7 +17255,7 @@ PatternKind::Choose(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action103<
+fn ___action104<
     'input,
 >(
 text: &'input str,
@@ -16899,7 +17268,7 @@ PatternKind::Tuple(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action104<
+fn ___action105<
     'input,
 >(
 text: &'input str,
@@ -16910,7 +17279,7 @@ PatternKind::CharLiteral(Atom::from(c))
 }
 
 #[allow(unused_variables)]
-fn ___action105<
+fn ___action106<
     'input,
 >(
 text: &'input str,
@@ -16921,7 +17290,7 @@ PatternKind::Path(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action106<
+fn ___action107<
     'input,
 >(
 text: &'input str,
@@ -16940,7 +17309,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action107<
+fn ___action108<
     'input,
 >(
 text: &'input str,
@@ -16951,7 +17320,7 @@ NonterminalString(Atom::from(i))
 }
 
 #[allow(unused_variables)]
-fn ___action108<
+fn ___action109<
     'input,
 >(
 text: &'input str,
@@ -16962,7 +17331,7 @@ NonterminalString(Atom::from(i))
 }
 
 #[allow(unused_variables)]
-fn ___action109<
+fn ___action110<
     'input,
 >(
 text: &'input str,
@@ -16973,7 +17342,7 @@ Atom::from(i)
 }
 
 #[allow(unused_variables)]
-fn ___action110<
+fn ___action111<
     'input,
 >(
 text: &'input str,
@@ -16984,7 +17353,7 @@ Atom::from(i)
 }
 
 #[allow(unused_variables)]
-fn ___action111<
+fn ___action112<
     'input,
 >(
 text: &'input str,
@@ -16995,7 +17364,7 @@ Atom::from(i)
 }
 
 #[allow(unused_variables)]
-fn ___action112<
+fn ___action113<
     'input,
 >(
 text: &'input str,
@@ -17006,7 +17375,7 @@ Lifetime(Atom::from(i))
 }
 
 #[allow(unused_variables)]
-fn ___action113<
+fn ___action114<
     'input,
 >(
 text: &'input str,
@@ -17017,7 +17386,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action114<
+fn ___action115<
     'input,
 >(
 text: &'input str,
@@ -17028,7 +17397,7 @@ TerminalString::Bare(Atom::from(i))
 }
 
 #[allow(unused_variables)]
-fn ___action115<
+fn ___action116<
     'input,
 >(
 text: &'input str,
@@ -17039,7 +17408,7 @@ TerminalString::Literal(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action116<
+fn ___action117<
     'input,
 >(
 text: &'input str,
@@ -17050,7 +17419,7 @@ TerminalLiteral::Quoted(s)
 }
 
 #[allow(unused_variables)]
-fn ___action117<
+fn ___action118<
     'input,
 >(
 text: &'input str,
@@ -17061,7 +17430,18 @@ TerminalLiteral::Regex(s)
 }
 
 #[allow(unused_variables)]
-fn ___action118<
+fn ___action119<
+    'input,
+>(
+text: &'input str,
+(_, s, _): (usize, Atom, usize),
+) -> TerminalLiteral
+{
+TerminalLiteral::CaseInsensitive(s)
+}
+
+#[allow(unused_variables)]
+fn ___action120<
     'input,
 >(
 text: &'input str,
@@ -17077,7 +17457,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action119<
+fn ___action121<
     'input,
 >(
 text: &'input str,
@@ -17088,7 +17468,23 @@ Atom::from(s)
 }
 
 #[allow(unused_variables)]
-fn ___action120<
+fn ___action122<
+    'input,
+>(
+text: &'input str,
+(_, lo, _): (usize, usize, usize),
+(_, s, _): (usize, &'input str, usize),
+) -> Result<Atom,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
+{
+{
+        let text = tok::apply_string_escapes(s, lo + 2)
+            .map_err(|e| ParseError::User { error: e })?;
+        Ok(Atom::from(text))
+    }
+}
+
+#[allow(unused_variables)]
+fn ___action123<
     'input,
 >(
 text: &'input str,
@@ -17099,7 +17495,7 @@ s.to_string()
 }
 
 #[allow(unused_variables)]
-fn ___action121<
+fn ___action124<
     'input,
 >(
 text: &'input str,
@@ -17110,7 +17506,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action122<
+fn ___action125<
     'input,
 >(
 text: &'input str,
@@ -17122,7 +17518,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action123<
+fn ___action126<
     'input,
 >(
 text: &'input str,
@@ -17134,7 +17530,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action124<
+fn ___action127<
     'input,
 >(
 text: &'input str,
@@ -17145,7 +17541,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action125<
+fn ___action128<
     'input,
 >(
 text: &'input str,
@@ -17157,7 +17553,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action126<
+fn ___action129<
     'input,
 >(
 text: &'input str,
@@ -17169,7 +17565,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action127<
+fn ___action130<
     'input,
 >(
 text: &'input str,
@@ -17181,7 +17577,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action128<
+fn ___action131<
     'input,
 >(
 text: &'input str,
@@ -17193,7 +17589,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action129<
+fn ___action132<
     'input,
 >(
 text: &'input str,
@@ -17205,7 +17601,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action130<
+fn ___action133<
     'input,
 >(
 text: &'input str,
@@ -17216,7 +17612,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action131<
+fn ___action134<
     'input,
 >(
 text: &'input str,
@@ -17228,7 +17624,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action132<
+fn ___action135<
     'input,
 >(
 text: &'input str,
@@ -17239,7 +17635,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action133<
+fn ___action136<
     'input,
 >(
 text: &'input str,
@@ -17251,7 +17647,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action134<
+fn ___action137<
     'input,
 >(
 text: &'input str,
@@ -17262,7 +17658,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action135<
+fn ___action138<
     'input,
 >(
 text: &'input str,
@@ -17274,7 +17670,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action136<
+fn ___action139<
     'input,
 >(
 text: &'input str,
@@ -17286,7 +17682,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action137<
+fn ___action140<
     'input,
 >(
 text: &'input str,
@@ -17297,7 +17693,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action138<
+fn ___action141<
     'input,
 >(
 text: &'input str,
@@ -17309,7 +17705,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action139<
+fn ___action142<
     'input,
 >(
 text: &'input str,
@@ -17321,7 +17717,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action140<
+fn ___action143<
     'input,
 >(
 text: &'input str,
@@ -17332,7 +17728,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action141<
+fn ___action144<
     'input,
 >(
 text: &'input str,
@@ -17344,7 +17740,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action142<
+fn ___action145<
     'input,
 >(
 text: &'input str,
@@ -17356,7 +17752,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action143<
+fn ___action146<
     'input,
 >(
 text: &'input str,
@@ -17367,7 +17763,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action144<
+fn ___action147<
     'input,
 >(
 text: &'input str,
@@ -17378,7 +17774,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action145<
+fn ___action148<
     'input,
 >(
 text: &'input str,
@@ -17390,7 +17786,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action146<
+fn ___action149<
     'input,
 >(
 text: &'input str,
@@ -17401,7 +17797,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action147<
+fn ___action150<
     'input,
 >(
 text: &'input str,
@@ -17413,7 +17809,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action148<
+fn ___action151<
     'input,
 >(
 text: &'input str,
@@ -17425,7 +17821,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action149<
+fn ___action152<
     'input,
 >(
 text: &'input str,
@@ -17436,7 +17832,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action150<
+fn ___action153<
     'input,
 >(
 text: &'input str,
@@ -17448,7 +17844,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action151<
+fn ___action154<
     'input,
 >(
 text: &'input str,
@@ -17459,7 +17855,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action152<
+fn ___action155<
     'input,
 >(
 text: &'input str,
@@ -17471,7 +17867,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action153<
+fn ___action156<
     'input,
 >(
 text: &'input str,
@@ -17483,7 +17879,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action154<
+fn ___action157<
     'input,
 >(
 text: &'input str,
@@ -17495,7 +17891,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action155<
+fn ___action158<
     'input,
 >(
 text: &'input str,
@@ -17506,7 +17902,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action156<
+fn ___action159<
     'input,
 >(
 text: &'input str,
@@ -17518,7 +17914,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action157<
+fn ___action160<
     'input,
 >(
 text: &'input str,
@@ -17529,7 +17925,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action158<
+fn ___action161<
     'input,
 >(
 text: &'input str,
@@ -17541,7 +17937,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action159<
+fn ___action162<
     'input,
 >(
 text: &'input str,
@@ -17553,7 +17949,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action160<
+fn ___action163<
     'input,
 >(
 text: &'input str,
@@ -17565,7 +17961,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action161<
+fn ___action164<
     'input,
 >(
 text: &'input str,
@@ -17576,7 +17972,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action162<
+fn ___action165<
     'input,
 >(
 text: &'input str,
@@ -17588,7 +17984,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action163<
+fn ___action166<
     'input,
 >(
 text: &'input str,
@@ -17601,7 +17997,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action164<
+fn ___action167<
     'input,
 >(
 text: &'input str,
@@ -17613,7 +18009,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action165<
+fn ___action168<
     'input,
 >(
 text: &'input str,
@@ -17624,7 +18020,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action166<
+fn ___action169<
     'input,
 >(
 text: &'input str,
@@ -17636,7 +18032,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action167<
+fn ___action170<
     'input,
 >(
 text: &'input str,
@@ -17648,7 +18044,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action168<
+fn ___action171<
     'input,
 >(
 text: &'input str,
@@ -17660,7 +18056,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action169<
+fn ___action172<
     'input,
 >(
 text: &'input str,
@@ -17675,7 +18071,7 @@ match e {
 }
 
 #[allow(unused_variables)]
-fn ___action170<
+fn ___action173<
     'input,
 >(
 text: &'input str,
@@ -17687,7 +18083,7 @@ ___lookahead: &usize,
 }
 
 #[allow(unused_variables)]
-fn ___action171<
+fn ___action174<
     'input,
 >(
 text: &'input str,
@@ -17702,7 +18098,7 @@ match e {
 }
 
 #[allow(unused_variables)]
-fn ___action172<
+fn ___action175<
     'input,
 >(
 text: &'input str,
@@ -17714,7 +18110,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action173<
+fn ___action176<
     'input,
 >(
 text: &'input str,
@@ -17726,7 +18122,7 @@ v0.into_iter().chain(e1).collect()
 }
 
 #[allow(unused_variables)]
-fn ___action174<
+fn ___action177<
     'input,
 >(
 text: &'input str,
@@ -17738,7 +18134,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action175<
+fn ___action178<
     'input,
 >(
 text: &'input str,
@@ -17749,7 +18145,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action176<
+fn ___action179<
     'input,
 >(
 text: &'input str,
@@ -17760,7 +18156,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action177<
+fn ___action180<
     'input,
 >(
 text: &'input str,
@@ -17772,7 +18168,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action178<
+fn ___action181<
     'input,
 >(
 text: &'input str,
@@ -17783,7 +18179,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action179<
+fn ___action182<
     'input,
 >(
 text: &'input str,
@@ -17795,7 +18191,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action180<
+fn ___action183<
     'input,
 >(
 text: &'input str,
@@ -17806,7 +18202,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action181<
+fn ___action184<
     'input,
 >(
 text: &'input str,
@@ -17818,7 +18214,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action182<
+fn ___action185<
     'input,
 >(
 text: &'input str,
@@ -17830,7 +18226,7 @@ ___lookbehind.clone()
 }
 
 #[allow(unused_variables)]
-fn ___action183<
+fn ___action186<
     'input,
 >(
 text: &'input str,
@@ -17842,7 +18238,7 @@ ___lookahead.clone()
 }
 
 #[allow(unused_variables)]
-fn ___action184<
+fn ___action187<
     'input,
 >(
 text: &'input str,
@@ -17854,7 +18250,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action185<
+fn ___action188<
     'input,
 >(
 text: &'input str,
@@ -17865,7 +18261,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action186<
+fn ___action189<
     'input,
 >(
 text: &'input str,
@@ -17877,7 +18273,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action187<
+fn ___action190<
     'input,
 >(
 text: &'input str,
@@ -17888,7 +18284,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action188<
+fn ___action191<
     'input,
 >(
 text: &'input str,
@@ -17900,7 +18296,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action189<
+fn ___action192<
     'input,
 >(
 text: &'input str,
@@ -17911,7 +18307,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action190<
+fn ___action193<
     'input,
 >(
 text: &'input str,
@@ -17922,7 +18318,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action191<
+fn ___action194<
     'input,
 >(
 text: &'input str,
@@ -17934,7 +18330,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action192<
+fn ___action195<
     'input,
 >(
 text: &'input str,
@@ -17945,7 +18341,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action193<
+fn ___action196<
     'input,
 >(
 text: &'input str,
@@ -17957,7 +18353,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action194<
+fn ___action197<
     'input,
 >(
 text: &'input str,
@@ -17968,7 +18364,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action195<
+fn ___action198<
     'input,
 >(
 text: &'input str,
@@ -17980,7 +18376,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action196<
+fn ___action199<
     'input,
 >(
 text: &'input str,
@@ -17991,7 +18387,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action197<
+fn ___action200<
     'input,
 >(
 text: &'input str,
@@ -18003,7 +18399,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action198<
+fn ___action201<
     'input,
 >(
 text: &'input str,
@@ -18014,7 +18410,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action199<
+fn ___action202<
     'input,
 >(
 text: &'input str,
@@ -18026,7 +18422,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action200<
+fn ___action203<
     'input,
 >(
 text: &'input str,
@@ -18038,7 +18434,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action201<
+fn ___action204<
     'input,
 >(
 text: &'input str,
@@ -18049,7 +18445,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action202<
+fn ___action205<
     'input,
 >(
 text: &'input str,
@@ -18061,7 +18457,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action203<
+fn ___action206<
     'input,
 >(
 text: &'input str,
@@ -18072,7 +18468,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action204<
+fn ___action207<
     'input,
 >(
 text: &'input str,
@@ -18084,7 +18480,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action205<
+fn ___action208<
     'input,
 >(
 text: &'input str,
@@ -18096,7 +18492,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action206<
+fn ___action209<
     'input,
 >(
 text: &'input str,
@@ -18107,7 +18503,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action207<
+fn ___action210<
     'input,
 >(
 text: &'input str,
@@ -18119,7 +18515,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action208<
+fn ___action211<
     'input,
 >(
 text: &'input str,
@@ -18131,7 +18527,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action209<
+fn ___action212<
     'input,
 >(
 text: &'input str,
@@ -18142,7 +18538,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action210<
+fn ___action213<
     'input,
 >(
 text: &'input str,
@@ -18154,7 +18550,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action211<
+fn ___action214<
     'input,
 >(
 text: &'input str,
@@ -18165,7 +18561,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action212<
+fn ___action215<
     'input,
 >(
 text: &'input str,
@@ -18177,7 +18573,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action213<
+fn ___action216<
     'input,
 >(
 text: &'input str,
@@ -18189,7 +18585,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action214<
+fn ___action217<
     'input,
 >(
 text: &'input str,
@@ -18200,7 +18596,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action215<
+fn ___action218<
     'input,
 >(
 text: &'input str,
@@ -18212,7 +18608,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action216<
+fn ___action219<
     'input,
 >(
 text: &'input str,
@@ -18223,7 +18619,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action217<
+fn ___action220<
     'input,
 >(
 text: &'input str,
@@ -18235,7 +18631,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action218<
+fn ___action221<
     'input,
 >(
 text: &'input str,
@@ -18247,7 +18643,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action219<
+fn ___action222<
     'input,
 >(
 text: &'input str,
@@ -18258,7 +18654,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action220<
+fn ___action223<
     'input,
 >(
 text: &'input str,
@@ -18270,7 +18666,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action221<
+fn ___action224<
     'input,
 >(
 text: &'input str,
@@ -18281,7 +18677,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action222<
+fn ___action225<
     'input,
 >(
 text: &'input str,
@@ -18293,7 +18689,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action223<
+fn ___action226<
     'input,
 >(
 text: &'input str,
@@ -18305,7 +18701,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action224<
+fn ___action227<
     'input,
 >(
 text: &'input str,
@@ -18316,7 +18712,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action225<
+fn ___action228<
     'input,
 >(
 text: &'input str,
@@ -18328,7 +18724,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action226<
+fn ___action229<
     'input,
 >(
 text: &'input str,
@@ -18339,7 +18735,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action227<
+fn ___action230<
     'input,
 >(
 text: &'input str,
@@ -18351,7 +18747,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action228<
+fn ___action231<
     'input,
 >(
 text: &'input str,
@@ -18363,7 +18759,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action229<
+fn ___action232<
     'input,
 >(
 text: &'input str,
@@ -18374,7 +18770,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action230<
+fn ___action233<
     'input,
 >(
 text: &'input str,
@@ -18386,7 +18782,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action231<
+fn ___action234<
     'input,
 >(
 text: &'input str,
@@ -18397,7 +18793,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action232<
+fn ___action235<
     'input,
 >(
 text: &'input str,
@@ -18409,7 +18805,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action233<
+fn ___action236<
     'input,
 >(
 text: &'input str,
@@ -18421,7 +18817,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action234<
+fn ___action237<
     'input,
 >(
 text: &'input str,
@@ -18432,7 +18828,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action235<
+fn ___action238<
     'input,
 >(
 text: &'input str,
@@ -18444,7 +18840,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action236<
+fn ___action239<
     'input,
 >(
 text: &'input str,
@@ -18455,7 +18851,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action237<
+fn ___action240<
     'input,
 >(
 text: &'input str,
@@ -18467,7 +18863,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action238<
+fn ___action241<
     'input,
 >(
 text: &'input str,
@@ -18479,7 +18875,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action239<
+fn ___action242<
     'input,
 >(
 text: &'input str,
@@ -18490,7 +18886,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action240<
+fn ___action243<
     'input,
 >(
 text: &'input str,
@@ -18502,7 +18898,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action241<
+fn ___action244<
     'input,
 >(
 text: &'input str,
@@ -18513,7 +18909,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action242<
+fn ___action245<
     'input,
 >(
 text: &'input str,
@@ -18525,7 +18921,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action243<
+fn ___action246<
     'input,
 >(
 text: &'input str,
@@ -18537,7 +18933,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action244<
+fn ___action247<
     'input,
 >(
 text: &'input str,
@@ -18548,7 +18944,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action245<
+fn ___action248<
     'input,
 >(
 text: &'input str,
@@ -18560,7 +18956,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action246<
+fn ___action249<
     'input,
 >(
 text: &'input str,
@@ -18571,7 +18967,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action247<
+fn ___action250<
     'input,
 >(
 text: &'input str,
@@ -18583,7 +18979,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action248<
+fn ___action251<
     'input,
 >(
 text: &'input str,
@@ -18595,7 +18991,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action249<
+fn ___action252<
     'input,
 >(
 text: &'input str,
@@ -18606,7 +19002,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action250<
+fn ___action253<
     'input,
 >(
 text: &'input str,
@@ -18618,7 +19014,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action251<
+fn ___action254<
     'input,
 >(
 text: &'input str,
@@ -18629,7 +19025,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action252<
+fn ___action255<
     'input,
 >(
 text: &'input str,
@@ -18641,7 +19037,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action253<
+fn ___action256<
     'input,
 >(
 text: &'input str,
@@ -18652,7 +19048,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action254<
+fn ___action257<
     'input,
 >(
 text: &'input str,
@@ -18664,7 +19060,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action255<
+fn ___action258<
     'input,
 >(
 text: &'input str,
@@ -18675,7 +19071,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action256<
+fn ___action259<
     'input,
 >(
 text: &'input str,
@@ -18687,7 +19083,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action257<
+fn ___action260<
     'input,
 >(
 text: &'input str,
@@ -18699,7 +19095,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action258<
+fn ___action261<
     'input,
 >(
 text: &'input str,
@@ -18710,7 +19106,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action259<
+fn ___action262<
     'input,
 >(
 text: &'input str,
@@ -18722,7 +19118,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action260<
+fn ___action263<
     'input,
 >(
 text: &'input str,
@@ -18733,7 +19129,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action261<
+fn ___action264<
     'input,
 >(
 text: &'input str,
@@ -18745,7 +19141,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action262<
+fn ___action265<
     'input,
 >(
 text: &'input str,
@@ -18757,7 +19153,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action263<
+fn ___action266<
     'input,
 >(
 text: &'input str,
@@ -18768,7 +19164,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action264<
+fn ___action267<
     'input,
 >(
 text: &'input str,
@@ -18780,7 +19176,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action265<
+fn ___action268<
     'input,
 >(
 text: &'input str,
@@ -18791,7 +19187,7 @@ Some(___0)
 }
 
 #[allow(unused_variables)]
-fn ___action266<
+fn ___action269<
     'input,
 >(
 text: &'input str,
@@ -18803,7 +19199,7 @@ None
 }
 
 #[allow(unused_variables)]
-fn ___action267<
+fn ___action270<
     'input,
 >(
 text: &'input str,
@@ -18815,7 +19211,7 @@ vec![]
 }
 
 #[allow(unused_variables)]
-fn ___action268<
+fn ___action271<
     'input,
 >(
 text: &'input str,
@@ -18826,7 +19222,7 @@ v
 }
 
 #[allow(unused_variables)]
-fn ___action269<
+fn ___action272<
     'input,
 >(
 text: &'input str,
@@ -18838,7 +19234,7 @@ ___0
 }
 
 #[allow(unused_variables)]
-fn ___action270<
+fn ___action273<
     'input,
 >(
 text: &'input str,
@@ -18849,7 +19245,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action271<
+fn ___action274<
     'input,
 >(
 text: &'input str,
@@ -18861,7 +19257,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action272<
+fn ___action275<
     'input,
 >(
 text: &'input str,
@@ -18872,7 +19268,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action273<
+fn ___action276<
     'input,
 >(
 text: &'input str,
@@ -18884,7 +19280,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action274<
+fn ___action277<
     'input,
 >(
 text: &'input str,
@@ -18895,7 +19291,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action275<
+fn ___action278<
     'input,
 >(
 text: &'input str,
@@ -18907,7 +19303,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action276<
+fn ___action279<
     'input,
 >(
 text: &'input str,
@@ -18918,7 +19314,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action277<
+fn ___action280<
     'input,
 >(
 text: &'input str,
@@ -18930,7 +19326,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action278<
+fn ___action281<
     'input,
 >(
 text: &'input str,
@@ -18941,7 +19337,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action279<
+fn ___action282<
     'input,
 >(
 text: &'input str,
@@ -18953,7 +19349,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action280<
+fn ___action283<
     'input,
 >(
 text: &'input str,
@@ -18964,7 +19360,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action281<
+fn ___action284<
     'input,
 >(
 text: &'input str,
@@ -18976,7 +19372,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action282<
+fn ___action285<
     'input,
 >(
 text: &'input str,
@@ -18987,7 +19383,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action283<
+fn ___action286<
     'input,
 >(
 text: &'input str,
@@ -18999,7 +19395,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action284<
+fn ___action287<
     'input,
 >(
 text: &'input str,
@@ -19010,7 +19406,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action285<
+fn ___action288<
     'input,
 >(
 text: &'input str,
@@ -19022,7 +19418,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action286<
+fn ___action289<
     'input,
 >(
 text: &'input str,
@@ -19033,7 +19429,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action287<
+fn ___action290<
     'input,
 >(
 text: &'input str,
@@ -19045,7 +19441,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action288<
+fn ___action291<
     'input,
 >(
 text: &'input str,
@@ -19056,7 +19452,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action289<
+fn ___action292<
     'input,
 >(
 text: &'input str,
@@ -19068,7 +19464,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action290<
+fn ___action293<
     'input,
 >(
 text: &'input str,
@@ -19079,7 +19475,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action291<
+fn ___action294<
     'input,
 >(
 text: &'input str,
@@ -19091,7 +19487,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action292<
+fn ___action295<
     'input,
 >(
 text: &'input str,
@@ -19102,7 +19498,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action293<
+fn ___action296<
     'input,
 >(
 text: &'input str,
@@ -19114,7 +19510,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action294<
+fn ___action297<
     'input,
 >(
 text: &'input str,
@@ -19125,7 +19521,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action295<
+fn ___action298<
     'input,
 >(
 text: &'input str,
@@ -19137,7 +19533,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action296<
+fn ___action299<
     'input,
 >(
 text: &'input str,
@@ -19148,7 +19544,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action297<
+fn ___action300<
     'input,
 >(
 text: &'input str,
@@ -19160,7 +19556,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action298<
+fn ___action301<
     'input,
 >(
 text: &'input str,
@@ -19171,7 +19567,7 @@ vec![___0]
 }
 
 #[allow(unused_variables)]
-fn ___action299<
+fn ___action302<
     'input,
 >(
 text: &'input str,
@@ -19183,7 +19579,7 @@ text: &'input str,
 }
 
 #[allow(unused_variables)]
-fn ___action300<
+fn ___action303<
     'input,
 >(
 text: &'input str,
@@ -19194,7 +19590,7 @@ ___2: (usize, Atom, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action134(
+let ___temp0 = ___action137(
 text,
 ___0,
 );
@@ -19208,7 +19604,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action301<
+fn ___action304<
     'input,
 >(
 text: &'input str,
@@ -19218,7 +19614,7 @@ ___1: (usize, Atom, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action135(
+let ___temp0 = ___action138(
 text,
 &___start0,
 &___end0,
@@ -19233,7 +19629,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action302<
+fn ___action305<
     'input,
 >(
 text: &'input str,
@@ -19245,7 +19641,7 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action151(
+let ___temp0 = ___action154(
 text,
 ___3,
 );
@@ -19260,7 +19656,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action303<
+fn ___action306<
     'input,
 >(
 text: &'input str,
@@ -19271,7 +19667,7 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action152(
+let ___temp0 = ___action155(
 text,
 &___start0,
 &___end0,
@@ -19287,7 +19683,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action304<
+fn ___action307<
     'input,
 >(
 text: &'input str,
@@ -19304,7 +19700,7 @@ ___8: (usize, usize, usize),
 {
 let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action140(
+let ___temp0 = ___action143(
 text,
 ___2,
 );
@@ -19324,7 +19720,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action305<
+fn ___action308<
     'input,
 >(
 text: &'input str,
@@ -19340,7 +19736,7 @@ ___7: (usize, usize, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action141(
+let ___temp0 = ___action144(
 text,
 &___start0,
 &___end0,
@@ -19361,7 +19757,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action306<
+fn ___action309<
     'input,
 >(
 text: &'input str,
@@ -19373,7 +19769,7 @@ ___3: (usize, TypeRef, usize),
 {
 let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action140(
+let ___temp0 = ___action143(
 text,
 ___2,
 );
@@ -19388,7 +19784,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action307<
+fn ___action310<
     'input,
 >(
 text: &'input str,
@@ -19399,7 +19795,7 @@ ___2: (usize, TypeRef, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action141(
+let ___temp0 = ___action144(
 text,
 &___start0,
 &___end0,
@@ -19415,7 +19811,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action308<
+fn ___action311<
     'input,
 >(
 text: &'input str,
@@ -19425,20 +19821,20 @@ ___1: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action167(
+let ___temp0 = ___action170(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action165(
+___action168(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action309<
+fn ___action312<
     'input,
 >(
 text: &'input str,
@@ -19453,7 +19849,7 @@ ___6: (usize, TypeRef, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___6.2.clone();
-let ___temp0 = ___action308(
+let ___temp0 = ___action311(
 text,
 ___5,
 ___6,
@@ -19471,7 +19867,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action310<
+fn ___action313<
     'input,
 >(
 text: &'input str,
@@ -19484,7 +19880,7 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action166(
+let ___temp0 = ___action169(
 text,
 &___start0,
 &___end0,
@@ -19502,7 +19898,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action311<
+fn ___action314<
     'input,
 >(
 text: &'input str,
@@ -19518,7 +19914,7 @@ ___7: (usize, TypeRef, usize),
 {
 let ___start0 = ___6.0.clone();
 let ___end0 = ___7.2.clone();
-let ___temp0 = ___action308(
+let ___temp0 = ___action311(
 text,
 ___6,
 ___7,
@@ -19537,7 +19933,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action312<
+fn ___action315<
     'input,
 >(
 text: &'input str,
@@ -19551,7 +19947,7 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___5.2.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action166(
+let ___temp0 = ___action169(
 text,
 &___start0,
 &___end0,
@@ -19570,7 +19966,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action313<
+fn ___action316<
     'input,
 >(
 text: &'input str,
@@ -19580,20 +19976,20 @@ ___1: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action159(
+let ___temp0 = ___action162(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action157(
+___action160(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action314<
+fn ___action317<
     'input,
 >(
 text: &'input str,
@@ -19610,7 +20006,7 @@ ___8: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___6.2.clone();
-let ___temp0 = ___action313(
+let ___temp0 = ___action316(
 text,
 ___5,
 ___6,
@@ -19630,7 +20026,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action315<
+fn ___action318<
     'input,
 >(
 text: &'input str,
@@ -19645,7 +20041,7 @@ ___6: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action158(
+let ___temp0 = ___action161(
 text,
 &___start0,
 &___end0,
@@ -19665,7 +20061,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action316<
+fn ___action319<
     'input,
 >(
 text: &'input str,
@@ -19676,21 +20072,21 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action163(
+let ___temp0 = ___action166(
 text,
 ___0,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action161(
+___action164(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action317<
+fn ___action320<
     'input,
 >(
 text: &'input str,
@@ -19703,7 +20099,7 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action316(
+let ___temp0 = ___action319(
 text,
 ___2,
 ___3,
@@ -19719,7 +20115,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action318<
+fn ___action321<
     'input,
 >(
 text: &'input str,
@@ -19729,7 +20125,7 @@ ___1: (usize, Path, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action162(
+let ___temp0 = ___action165(
 text,
 &___start0,
 &___end0,
@@ -19744,7 +20140,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action319<
+fn ___action322<
     'input,
 >(
 text: &'input str,
@@ -19754,63 +20150,66 @@ ___1: (usize, Condition, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action148(
+let ___temp0 = ___action151(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action146(
+___action149(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action320<
+fn ___action323<
     'input,
 >(
 text: &'input str,
-___0: (usize, usize, usize),
-___1: (usize, ::std::vec::Vec<Symbol>, usize),
-___2: (usize, Tok<'input>, usize),
-___3: (usize, Condition, usize),
-___4: (usize, ::std::option::Option<ActionKind>, usize),
-___5: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, usize, usize),
+___2: (usize, ::std::vec::Vec<Symbol>, usize),
+___3: (usize, Tok<'input>, usize),
+___4: (usize, Condition, usize),
+___5: (usize, ::std::option::Option<ActionKind>, usize),
+___6: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___2.0.clone();
-let ___end0 = ___3.2.clone();
-let ___temp0 = ___action319(
+let ___start0 = ___3.0.clone();
+let ___end0 = ___4.2.clone();
+let ___temp0 = ___action322(
 text,
-___2,
 ___3,
+___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
 ___action40(
 text,
 ___0,
 ___1,
+___2,
 ___temp0,
-___4,
 ___5,
+___6,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action321<
+fn ___action324<
     'input,
 >(
 text: &'input str,
-___0: (usize, usize, usize),
-___1: (usize, ::std::vec::Vec<Symbol>, usize),
-___2: (usize, ::std::option::Option<ActionKind>, usize),
-___3: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, usize, usize),
+___2: (usize, ::std::vec::Vec<Symbol>, usize),
+___3: (usize, ::std::option::Option<ActionKind>, usize),
+___4: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___1.2.clone();
-let ___end0 = ___2.0.clone();
-let ___temp0 = ___action147(
+let ___start0 = ___2.2.clone();
+let ___end0 = ___3.0.clone();
+let ___temp0 = ___action150(
 text,
 &___start0,
 &___end0,
@@ -19820,54 +20219,58 @@ ___action40(
 text,
 ___0,
 ___1,
-___temp0,
 ___2,
+___temp0,
 ___3,
+___4,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action322<
+fn ___action325<
     'input,
 >(
 text: &'input str,
-___0: (usize, usize, usize),
-___1: (usize, Tok<'input>, usize),
-___2: (usize, Condition, usize),
-___3: (usize, ActionKind, usize),
-___4: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, usize, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, Condition, usize),
+___4: (usize, ActionKind, usize),
+___5: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___1.0.clone();
-let ___end0 = ___2.2.clone();
-let ___temp0 = ___action319(
+let ___start0 = ___2.0.clone();
+let ___end0 = ___3.2.clone();
+let ___temp0 = ___action322(
 text,
-___1,
 ___2,
+___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
 ___action41(
 text,
 ___0,
+___1,
 ___temp0,
-___3,
 ___4,
+___5,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action323<
+fn ___action326<
     'input,
 >(
 text: &'input str,
-___0: (usize, usize, usize),
-___1: (usize, ActionKind, usize),
-___2: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, usize, usize),
+___2: (usize, ActionKind, usize),
+___3: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___0.2.clone();
-let ___end0 = ___1.0.clone();
-let ___temp0 = ___action147(
+let ___start0 = ___1.2.clone();
+let ___end0 = ___2.0.clone();
+let ___temp0 = ___action150(
 text,
 &___start0,
 &___end0,
@@ -19876,14 +20279,15 @@ let ___temp0 = (___start0, ___temp0, ___end0);
 ___action41(
 text,
 ___0,
-___temp0,
 ___1,
+___temp0,
 ___2,
+___3,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action324<
+fn ___action327<
     'input,
 >(
 text: &'input str,
@@ -19893,7 +20297,7 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action170(
+let ___temp0 = ___action173(
 text,
 &___start0,
 &___end0,
@@ -19906,7 +20310,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action325<
+fn ___action328<
     'input,
 >(
 text: &'input str,
@@ -19916,7 +20320,7 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action170(
+let ___temp0 = ___action173(
 text,
 &___start0,
 &___end0,
@@ -19929,7 +20333,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action326<
+fn ___action329<
     'input,
 >(
 text: &'input str,
@@ -19939,20 +20343,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action240(
+let ___temp0 = ___action243(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action282(
+___action285(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action327<
+fn ___action330<
     'input,
 >(
 text: &'input str,
@@ -19963,13 +20367,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action240(
+let ___temp0 = ___action243(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action283(
+___action286(
 text,
 ___0,
 ___temp0,
@@ -19977,7 +20381,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action328<
+fn ___action331<
     'input,
 >(
 text: &'input str,
@@ -19986,13 +20390,13 @@ ___0: (usize, ::std::option::Option<Alternative>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action238(
+let ___temp0 = ___action241(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action153(
+___action156(
 text,
 ___temp0,
 ___0,
@@ -20000,7 +20404,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action329<
+fn ___action332<
     'input,
 >(
 text: &'input str,
@@ -20010,12 +20414,12 @@ ___1: (usize, ::std::option::Option<Alternative>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action239(
+let ___temp0 = ___action242(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action153(
+___action156(
 text,
 ___temp0,
 ___1,
@@ -20023,7 +20427,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action330<
+fn ___action333<
     'input,
 >(
 text: &'input str,
@@ -20033,20 +20437,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action264(
+let ___temp0 = ___action267(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action274(
+___action277(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action331<
+fn ___action334<
     'input,
 >(
 text: &'input str,
@@ -20057,13 +20461,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action264(
+let ___temp0 = ___action267(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action275(
+___action278(
 text,
 ___0,
 ___temp0,
@@ -20071,7 +20475,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action332<
+fn ___action335<
     'input,
 >(
 text: &'input str,
@@ -20080,13 +20484,13 @@ ___0: (usize, ::std::option::Option<Conversion>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action262(
+let ___temp0 = ___action265(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action127(
+___action130(
 text,
 ___temp0,
 ___0,
@@ -20094,7 +20498,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action333<
+fn ___action336<
     'input,
 >(
 text: &'input str,
@@ -20104,12 +20508,12 @@ ___1: (usize, ::std::option::Option<Conversion>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action263(
+let ___temp0 = ___action266(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action127(
+___action130(
 text,
 ___temp0,
 ___1,
@@ -20117,7 +20521,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action334<
+fn ___action337<
     'input,
 >(
 text: &'input str,
@@ -20127,20 +20531,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action125(
+let ___temp0 = ___action128(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action270(
+___action273(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action335<
+fn ___action338<
     'input,
 >(
 text: &'input str,
@@ -20151,13 +20555,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action125(
+let ___temp0 = ___action128(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action271(
+___action274(
 text,
 ___0,
 ___temp0,
@@ -20165,7 +20569,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action336<
+fn ___action339<
     'input,
 >(
 text: &'input str,
@@ -20177,13 +20581,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action123(
+let ___temp0 = ___action126(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action98(
+___action99(
 text,
 ___0,
 ___1,
@@ -20194,7 +20598,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action337<
+fn ___action340<
     'input,
 >(
 text: &'input str,
@@ -20207,12 +20611,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action124(
+let ___temp0 = ___action127(
 text,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action98(
+___action99(
 text,
 ___0,
 ___1,
@@ -20223,7 +20627,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action338<
+fn ___action341<
     'input,
 >(
 text: &'input str,
@@ -20235,13 +20639,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action123(
+let ___temp0 = ___action126(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action99(
+___action100(
 text,
 ___0,
 ___1,
@@ -20252,7 +20656,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action339<
+fn ___action342<
     'input,
 >(
 text: &'input str,
@@ -20265,12 +20669,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action124(
+let ___temp0 = ___action127(
 text,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action99(
+___action100(
 text,
 ___0,
 ___1,
@@ -20281,7 +20685,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action340<
+fn ___action343<
     'input,
 >(
 text: &'input str,
@@ -20291,20 +20695,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action230(
+let ___temp0 = ___action233(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action286(
+___action289(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action341<
+fn ___action344<
     'input,
 >(
 text: &'input str,
@@ -20315,13 +20719,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action230(
+let ___temp0 = ___action233(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action287(
+___action290(
 text,
 ___0,
 ___temp0,
@@ -20329,7 +20733,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action342<
+fn ___action345<
     'input,
 >(
 text: &'input str,
@@ -20338,13 +20742,13 @@ ___0: (usize, ::std::option::Option<Parameter>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action228(
+let ___temp0 = ___action231(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action160(
+___action163(
 text,
 ___temp0,
 ___0,
@@ -20352,7 +20756,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action343<
+fn ___action346<
     'input,
 >(
 text: &'input str,
@@ -20362,12 +20766,12 @@ ___1: (usize, ::std::option::Option<Parameter>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action229(
+let ___temp0 = ___action232(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action160(
+___action163(
 text,
 ___temp0,
 ___1,
@@ -20375,7 +20779,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action344<
+fn ___action347<
     'input,
 >(
 text: &'input str,
@@ -20385,20 +20789,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action207(
+let ___temp0 = ___action210(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action296(
+___action299(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action345<
+fn ___action348<
     'input,
 >(
 text: &'input str,
@@ -20409,13 +20813,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action207(
+let ___temp0 = ___action210(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action297(
+___action300(
 text,
 ___0,
 ___temp0,
@@ -20423,7 +20827,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action346<
+fn ___action349<
     'input,
 >(
 text: &'input str,
@@ -20432,13 +20836,13 @@ ___0: (usize, ::std::option::Option<WhereClause<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action205(
+let ___temp0 = ___action208(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action172(
+___action175(
 text,
 ___temp0,
 ___0,
@@ -20446,7 +20850,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action347<
+fn ___action350<
     'input,
 >(
 text: &'input str,
@@ -20456,12 +20860,12 @@ ___1: (usize, ::std::option::Option<WhereClause<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action206(
+let ___temp0 = ___action209(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action172(
+___action175(
 text,
 ___temp0,
 ___1,
@@ -20469,7 +20873,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action348<
+fn ___action351<
     'input,
 >(
 text: &'input str,
@@ -20479,20 +20883,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action133(
+let ___temp0 = ___action136(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action251(
+___action254(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action349<
+fn ___action352<
     'input,
 >(
 text: &'input str,
@@ -20503,13 +20907,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action133(
+let ___temp0 = ___action136(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action252(
+___action255(
 text,
 ___0,
 ___temp0,
@@ -20517,7 +20921,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action350<
+fn ___action353<
     'input,
 >(
 text: &'input str,
@@ -20527,13 +20931,13 @@ ___1: (usize, Atom, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action131(
+let ___temp0 = ___action134(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action300(
+___action303(
 text,
 ___0,
 ___temp0,
@@ -20542,7 +20946,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action351<
+fn ___action354<
     'input,
 >(
 text: &'input str,
@@ -20553,12 +20957,12 @@ ___2: (usize, Atom, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action132(
+let ___temp0 = ___action135(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action300(
+___action303(
 text,
 ___0,
 ___temp0,
@@ -20567,7 +20971,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action352<
+fn ___action355<
     'input,
 >(
 text: &'input str,
@@ -20576,13 +20980,13 @@ ___0: (usize, Atom, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action131(
+let ___temp0 = ___action134(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action301(
+___action304(
 text,
 ___temp0,
 ___0,
@@ -20590,7 +20994,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action353<
+fn ___action356<
     'input,
 >(
 text: &'input str,
@@ -20600,12 +21004,12 @@ ___1: (usize, Atom, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action132(
+let ___temp0 = ___action135(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action301(
+___action304(
 text,
 ___temp0,
 ___1,
@@ -20613,7 +21017,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action354<
+fn ___action357<
     'input,
 >(
 text: &'input str,
@@ -20623,20 +21027,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action210(
+let ___temp0 = ___action213(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action294(
+___action297(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action355<
+fn ___action358<
     'input,
 >(
 text: &'input str,
@@ -20647,13 +21051,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action210(
+let ___temp0 = ___action213(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action295(
+___action298(
 text,
 ___0,
 ___temp0,
@@ -20661,7 +21065,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action356<
+fn ___action359<
     'input,
 >(
 text: &'input str,
@@ -20670,13 +21074,13 @@ ___0: (usize, ::std::option::Option<Lifetime>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action208(
+let ___temp0 = ___action211(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action171(
+___action174(
 text,
 ___temp0,
 ___0,
@@ -20684,7 +21088,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action357<
+fn ___action360<
     'input,
 >(
 text: &'input str,
@@ -20694,12 +21098,12 @@ ___1: (usize, ::std::option::Option<Lifetime>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action209(
+let ___temp0 = ___action212(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action171(
+___action174(
 text,
 ___temp0,
 ___1,
@@ -20707,7 +21111,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action358<
+fn ___action361<
     'input,
 >(
 text: &'input str,
@@ -20717,20 +21121,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action259(
+let ___temp0 = ___action262(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action276(
+___action279(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action359<
+fn ___action362<
     'input,
 >(
 text: &'input str,
@@ -20741,13 +21145,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action259(
+let ___temp0 = ___action262(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action277(
+___action280(
 text,
 ___0,
 ___temp0,
@@ -20755,7 +21159,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action360<
+fn ___action363<
     'input,
 >(
 text: &'input str,
@@ -20764,13 +21168,13 @@ ___0: (usize, ::std::option::Option<MatchItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action257(
+let ___temp0 = ___action260(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action128(
+___action131(
 text,
 ___temp0,
 ___0,
@@ -20778,7 +21182,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action361<
+fn ___action364<
     'input,
 >(
 text: &'input str,
@@ -20788,12 +21192,12 @@ ___1: (usize, ::std::option::Option<MatchItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action258(
+let ___temp0 = ___action261(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action128(
+___action131(
 text,
 ___temp0,
 ___1,
@@ -20801,7 +21205,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action362<
+fn ___action365<
     'input,
 >(
 text: &'input str,
@@ -20811,20 +21215,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action235(
+let ___temp0 = ___action238(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action284(
+___action287(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action363<
+fn ___action366<
     'input,
 >(
 text: &'input str,
@@ -20835,13 +21239,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action235(
+let ___temp0 = ___action238(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action285(
+___action288(
 text,
 ___0,
 ___temp0,
@@ -20849,7 +21253,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action364<
+fn ___action367<
     'input,
 >(
 text: &'input str,
@@ -20858,13 +21262,13 @@ ___0: (usize, ::std::option::Option<NonterminalString>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action233(
+let ___temp0 = ___action236(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action154(
+___action157(
 text,
 ___temp0,
 ___0,
@@ -20872,7 +21276,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action365<
+fn ___action368<
     'input,
 >(
 text: &'input str,
@@ -20882,12 +21286,12 @@ ___1: (usize, ::std::option::Option<NonterminalString>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action234(
+let ___temp0 = ___action237(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action154(
+___action157(
 text,
 ___temp0,
 ___1,
@@ -20895,7 +21299,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action366<
+fn ___action369<
     'input,
 >(
 text: &'input str,
@@ -20905,20 +21309,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action269(
+let ___temp0 = ___action272(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action272(
+___action275(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action367<
+fn ___action370<
     'input,
 >(
 text: &'input str,
@@ -20929,13 +21333,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action269(
+let ___temp0 = ___action272(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action273(
+___action276(
 text,
 ___0,
 ___temp0,
@@ -20943,7 +21347,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action368<
+fn ___action371<
     'input,
 >(
 text: &'input str,
@@ -20952,13 +21356,13 @@ ___0: (usize, ::std::option::Option<Pattern<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action267(
+let ___temp0 = ___action270(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action126(
+___action129(
 text,
 ___temp0,
 ___0,
@@ -20966,7 +21370,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action369<
+fn ___action372<
     'input,
 >(
 text: &'input str,
@@ -20976,12 +21380,12 @@ ___1: (usize, ::std::option::Option<Pattern<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action268(
+let ___temp0 = ___action271(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action126(
+___action129(
 text,
 ___temp0,
 ___1,
@@ -20989,7 +21393,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action370<
+fn ___action373<
     'input,
 >(
 text: &'input str,
@@ -20999,20 +21403,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action245(
+let ___temp0 = ___action248(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action280(
+___action283(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action371<
+fn ___action374<
     'input,
 >(
 text: &'input str,
@@ -21023,13 +21427,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action245(
+let ___temp0 = ___action248(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action281(
+___action284(
 text,
 ___0,
 ___temp0,
@@ -21037,7 +21441,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action372<
+fn ___action375<
     'input,
 >(
 text: &'input str,
@@ -21046,13 +21450,13 @@ ___0: (usize, ::std::option::Option<Symbol>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action243(
+let ___temp0 = ___action246(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action139(
+___action142(
 text,
 ___temp0,
 ___0,
@@ -21060,7 +21464,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action373<
+fn ___action376<
     'input,
 >(
 text: &'input str,
@@ -21070,12 +21474,12 @@ ___1: (usize, ::std::option::Option<Symbol>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action244(
+let ___temp0 = ___action247(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action139(
+___action142(
 text,
 ___temp0,
 ___1,
@@ -21083,7 +21487,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action374<
+fn ___action377<
     'input,
 >(
 text: &'input str,
@@ -21093,20 +21497,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action215(
+let ___temp0 = ___action218(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action292(
+___action295(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action375<
+fn ___action378<
     'input,
 >(
 text: &'input str,
@@ -21117,13 +21521,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action215(
+let ___temp0 = ___action218(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action293(
+___action296(
 text,
 ___0,
 ___temp0,
@@ -21131,7 +21535,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action376<
+fn ___action379<
     'input,
 >(
 text: &'input str,
@@ -21140,13 +21544,13 @@ ___0: (usize, ::std::option::Option<TypeBound<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action213(
+let ___temp0 = ___action216(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action169(
+___action172(
 text,
 ___temp0,
 ___0,
@@ -21154,7 +21558,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action377<
+fn ___action380<
     'input,
 >(
 text: &'input str,
@@ -21164,12 +21568,12 @@ ___1: (usize, ::std::option::Option<TypeBound<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action214(
+let ___temp0 = ___action217(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action169(
+___action172(
 text,
 ___temp0,
 ___1,
@@ -21177,7 +21581,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action378<
+fn ___action381<
     'input,
 >(
 text: &'input str,
@@ -21187,20 +21591,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action225(
+let ___temp0 = ___action228(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action288(
+___action291(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action379<
+fn ___action382<
     'input,
 >(
 text: &'input str,
@@ -21211,13 +21615,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action225(
+let ___temp0 = ___action228(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action289(
+___action292(
 text,
 ___0,
 ___temp0,
@@ -21225,7 +21629,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action380<
+fn ___action383<
     'input,
 >(
 text: &'input str,
@@ -21234,13 +21638,13 @@ ___0: (usize, ::std::option::Option<TypeBoundParameter<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action223(
+let ___temp0 = ___action226(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action164(
+___action167(
 text,
 ___temp0,
 ___0,
@@ -21248,7 +21652,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action381<
+fn ___action384<
     'input,
 >(
 text: &'input str,
@@ -21258,12 +21662,12 @@ ___1: (usize, ::std::option::Option<TypeBoundParameter<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action224(
+let ___temp0 = ___action227(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action164(
+___action167(
 text,
 ___temp0,
 ___1,
@@ -21271,7 +21675,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action382<
+fn ___action385<
     'input,
 >(
 text: &'input str,
@@ -21281,20 +21685,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action202(
+let ___temp0 = ___action205(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action298(
+___action301(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action383<
+fn ___action386<
     'input,
 >(
 text: &'input str,
@@ -21305,13 +21709,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action202(
+let ___temp0 = ___action205(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action299(
+___action302(
 text,
 ___0,
 ___temp0,
@@ -21319,7 +21723,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action384<
+fn ___action387<
     'input,
 >(
 text: &'input str,
@@ -21328,13 +21732,13 @@ ___0: (usize, ::std::option::Option<TypeParameter>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action200(
+let ___temp0 = ___action203(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action173(
+___action176(
 text,
 ___temp0,
 ___0,
@@ -21342,7 +21746,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action385<
+fn ___action388<
     'input,
 >(
 text: &'input str,
@@ -21352,12 +21756,12 @@ ___1: (usize, ::std::option::Option<TypeParameter>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action201(
+let ___temp0 = ___action204(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action173(
+___action176(
 text,
 ___temp0,
 ___1,
@@ -21365,7 +21769,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action386<
+fn ___action389<
     'input,
 >(
 text: &'input str,
@@ -21375,20 +21779,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action220(
+let ___temp0 = ___action223(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action290(
+___action293(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action387<
+fn ___action390<
     'input,
 >(
 text: &'input str,
@@ -21399,13 +21803,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action220(
+let ___temp0 = ___action223(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action291(
+___action294(
 text,
 ___0,
 ___temp0,
@@ -21413,7 +21817,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action388<
+fn ___action391<
     'input,
 >(
 text: &'input str,
@@ -21422,13 +21826,13 @@ ___0: (usize, ::std::option::Option<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action218(
+let ___temp0 = ___action221(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action168(
+___action171(
 text,
 ___temp0,
 ___0,
@@ -21436,7 +21840,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action389<
+fn ___action392<
     'input,
 >(
 text: &'input str,
@@ -21446,12 +21850,12 @@ ___1: (usize, ::std::option::Option<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action219(
+let ___temp0 = ___action222(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action168(
+___action171(
 text,
 ___temp0,
 ___1,
@@ -21459,7 +21863,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action390<
+fn ___action393<
     'input,
 >(
 text: &'input str,
@@ -21469,20 +21873,20 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action250(
+let ___temp0 = ___action253(
 text,
 ___0,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action278(
+___action281(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action391<
+fn ___action394<
     'input,
 >(
 text: &'input str,
@@ -21493,13 +21897,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action250(
+let ___temp0 = ___action253(
 text,
 ___1,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action279(
+___action282(
 text,
 ___0,
 ___temp0,
@@ -21507,7 +21911,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action392<
+fn ___action395<
     'input,
 >(
 text: &'input str,
@@ -21516,13 +21920,13 @@ ___0: (usize, ::std::option::Option<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action248(
+let ___temp0 = ___action251(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action136(
+___action139(
 text,
 ___temp0,
 ___0,
@@ -21530,7 +21934,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action393<
+fn ___action396<
     'input,
 >(
 text: &'input str,
@@ -21540,12 +21944,12 @@ ___1: (usize, ::std::option::Option<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action249(
+let ___temp0 = ___action252(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action136(
+___action139(
 text,
 ___temp0,
 ___1,
@@ -21553,119 +21957,127 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action394<
+fn ___action397<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
-___1: (usize, Tok<'input>, usize),
-___2: (usize, Condition, usize),
-___3: (usize, ::std::option::Option<ActionKind>, usize),
-___4: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, Condition, usize),
+___4: (usize, ::std::option::Option<ActionKind>, usize),
+___5: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___0.0.clone();
-let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___start0 = ___0.2.clone();
+let ___end0 = ___1.0.clone();
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action320(
+___action323(
 text,
-___temp0,
 ___0,
+___temp0,
 ___1,
 ___2,
 ___3,
 ___4,
+___5,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action395<
+fn ___action398<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
-___1: (usize, ::std::option::Option<ActionKind>, usize),
-___2: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, ::std::option::Option<ActionKind>, usize),
+___3: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___0.0.clone();
-let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___start0 = ___0.2.clone();
+let ___end0 = ___1.0.clone();
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action321(
+___action324(
 text,
-___temp0,
 ___0,
+___temp0,
 ___1,
 ___2,
+___3,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action396<
+fn ___action399<
     'input,
 >(
 text: &'input str,
-___0: (usize, Tok<'input>, usize),
-___1: (usize, Condition, usize),
-___2: (usize, ActionKind, usize),
-___3: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, Tok<'input>, usize),
+___2: (usize, Condition, usize),
+___3: (usize, ActionKind, usize),
+___4: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___0.0.clone();
-let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___start0 = ___0.2.clone();
+let ___end0 = ___1.0.clone();
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action322(
+___action325(
 text,
-___temp0,
 ___0,
+___temp0,
 ___1,
 ___2,
 ___3,
+___4,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action397<
+fn ___action400<
     'input,
 >(
 text: &'input str,
-___0: (usize, ActionKind, usize),
-___1: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ActionKind, usize),
+___2: (usize, usize, usize),
 ) -> Alternative
 {
-let ___start0 = ___0.0.clone();
-let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___start0 = ___0.2.clone();
+let ___end0 = ___1.0.clone();
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action323(
+___action326(
 text,
-___temp0,
 ___0,
+___temp0,
 ___1,
+___2,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action398<
+fn ___action401<
     'input,
 >(
 text: &'input str,
@@ -21679,7 +22091,7 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -21698,7 +22110,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action399<
+fn ___action402<
     'input,
 >(
 text: &'input str,
@@ -21712,13 +22124,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action94(
+___action95(
 text,
 ___0,
 ___temp0,
@@ -21731,7 +22143,30 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action400<
+fn ___action403<
+    'input,
+>(
+text: &'input str,
+___0: (usize, &'input str, usize),
+) -> Result<Atom,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action186(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action122(
+text,
+___temp0,
+___0,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action404<
     'input,
 >(
 text: &'input str,
@@ -21743,7 +22178,7 @@ ___3: (usize, usize, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -21760,43 +22195,45 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action401<
+fn ___action405<
     'input,
 >(
 text: &'input str,
-___0: (usize, TerminalString, usize),
-___1: (usize, &'input str, usize),
-___2: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalString, usize),
+___2: (usize, &'input str, usize),
+___3: (usize, usize, usize),
 ) -> Result<Conversion,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
 {
-let ___start0 = ___0.0.clone();
-let ___end0 = ___0.0.clone();
-let ___start1 = ___0.2.clone();
-let ___end1 = ___1.0.clone();
-let ___temp0 = ___action183(
+let ___start0 = ___0.2.clone();
+let ___end0 = ___1.0.clone();
+let ___start1 = ___1.2.clone();
+let ___end1 = ___2.0.clone();
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action183(
+let ___temp1 = ___action186(
 text,
 &___start1,
 &___end1,
 );
 let ___temp1 = (___start1, ___temp1, ___end1);
-___action95(
+___action96(
 text,
-___temp0,
 ___0,
-___temp1,
+___temp0,
 ___1,
+___temp1,
 ___2,
+___3,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action402<
+fn ___action406<
     'input,
 >(
 text: &'input str,
@@ -21810,13 +22247,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action93(
+___action94(
 text,
 ___0,
 ___temp0,
@@ -21829,7 +22266,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action403<
+fn ___action407<
     'input,
 >(
 text: &'input str,
@@ -21844,7 +22281,7 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -21864,7 +22301,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action404<
+fn ___action408<
     'input,
 >(
 text: &'input str,
@@ -21877,7 +22314,7 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -21895,7 +22332,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action405<
+fn ___action409<
     'input,
 >(
 text: &'input str,
@@ -21907,13 +22344,13 @@ ___3: (usize, Pattern<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action106(
+___action107(
 text,
 ___temp0,
 ___0,
@@ -21924,7 +22361,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action406<
+fn ___action410<
     'input,
 >(
 text: &'input str,
@@ -21942,7 +22379,7 @@ ___9: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -21965,7 +22402,7 @@ ___9,
 }
 
 #[allow(unused_variables)]
-fn ___action407<
+fn ___action411<
     'input,
 >(
 text: &'input str,
@@ -21975,7 +22412,7 @@ ___1: (usize, usize, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -21990,17 +22427,18 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action408<
+fn ___action412<
     'input,
 >(
 text: &'input str,
-___0: (usize, TerminalLiteral, usize),
-___1: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalLiteral, usize),
+___2: (usize, usize, usize),
 ) -> MatchItem
 {
-let ___start0 = ___0.0.clone();
-let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___start0 = ___0.2.clone();
+let ___end0 = ___1.0.clone();
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -22008,33 +22446,35 @@ text,
 let ___temp0 = (___start0, ___temp0, ___end0);
 ___action88(
 text,
-___temp0,
 ___0,
+___temp0,
 ___1,
+___2,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action409<
+fn ___action413<
     'input,
 >(
 text: &'input str,
-___0: (usize, TerminalLiteral, usize),
-___1: (usize, &'input str, usize),
-___2: (usize, usize, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalLiteral, usize),
+___2: (usize, &'input str, usize),
+___3: (usize, usize, usize),
 ) -> Result<MatchItem,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
 {
-let ___start0 = ___0.0.clone();
-let ___end0 = ___0.0.clone();
-let ___start1 = ___0.2.clone();
-let ___end1 = ___1.0.clone();
-let ___temp0 = ___action183(
+let ___start0 = ___0.2.clone();
+let ___end0 = ___1.0.clone();
+let ___start1 = ___1.2.clone();
+let ___end1 = ___2.0.clone();
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action183(
+let ___temp1 = ___action186(
 text,
 &___start1,
 &___end1,
@@ -22042,16 +22482,17 @@ text,
 let ___temp1 = (___start1, ___temp1, ___end1);
 ___action89(
 text,
-___temp0,
 ___0,
-___temp1,
+___temp0,
 ___1,
+___temp1,
 ___2,
+___3,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action410<
+fn ___action414<
     'input,
 >(
 text: &'input str,
@@ -22064,7 +22505,7 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -22082,7 +22523,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action411<
+fn ___action415<
     'input,
 >(
 text: &'input str,
@@ -22098,13 +22539,13 @@ ___7: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action314(
+___action317(
 text,
 ___0,
 ___1,
@@ -22119,7 +22560,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action412<
+fn ___action416<
     'input,
 >(
 text: &'input str,
@@ -22133,13 +22574,13 @@ ___5: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action315(
+___action318(
 text,
 ___0,
 ___1,
@@ -22152,7 +22593,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action413<
+fn ___action417<
     'input,
 >(
 text: &'input str,
@@ -22162,13 +22603,13 @@ ___1: (usize, usize, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action96(
+___action97(
 text,
 ___temp0,
 ___0,
@@ -22177,7 +22618,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action414<
+fn ___action418<
     'input,
 >(
 text: &'input str,
@@ -22186,13 +22627,13 @@ ___0: (usize, &'input str, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action118(
+___action120(
 text,
 ___temp0,
 ___0,
@@ -22200,7 +22641,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action415<
+fn ___action419<
     'input,
 >(
 text: &'input str,
@@ -22217,19 +22658,19 @@ let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
 let ___start1 = ___1.2.clone();
 let ___end1 = ___2.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action183(
+let ___temp1 = ___action186(
 text,
 &___start1,
 &___end1,
 );
 let ___temp1 = (___start1, ___temp1, ___end1);
-___action304(
+___action307(
 text,
 ___temp0,
 ___0,
@@ -22244,7 +22685,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action416<
+fn ___action420<
     'input,
 >(
 text: &'input str,
@@ -22260,19 +22701,19 @@ let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
 let ___start1 = ___0.2.clone();
 let ___end1 = ___1.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action183(
+let ___temp1 = ___action186(
 text,
 &___start1,
 &___end1,
 );
 let ___temp1 = (___start1, ___temp1, ___end1);
-___action305(
+___action308(
 text,
 ___temp0,
 ___0,
@@ -22286,7 +22727,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action417<
+fn ___action421<
     'input,
 >(
 text: &'input str,
@@ -22298,7 +22739,7 @@ ___3: (usize, usize, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -22315,7 +22756,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action418<
+fn ___action422<
     'input,
 >(
 text: &'input str,
@@ -22325,7 +22766,7 @@ ___1: (usize, usize, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action183(
+let ___temp0 = ___action186(
 text,
 &___start0,
 &___end0,
@@ -22340,111 +22781,119 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action419<
+fn ___action423<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
-___1: (usize, Tok<'input>, usize),
-___2: (usize, Condition, usize),
-___3: (usize, ::std::option::Option<ActionKind>, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, Condition, usize),
+___4: (usize, ::std::option::Option<ActionKind>, usize),
 ) -> Alternative
 {
-let ___start0 = ___3.2.clone();
-let ___end0 = ___3.2.clone();
-let ___temp0 = ___action182(
+let ___start0 = ___4.2.clone();
+let ___end0 = ___4.2.clone();
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action394(
+___action397(
 text,
 ___0,
 ___1,
 ___2,
 ___3,
+___4,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action420<
+fn ___action424<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
-___1: (usize, ::std::option::Option<ActionKind>, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, ::std::option::Option<ActionKind>, usize),
 ) -> Alternative
 {
-let ___start0 = ___1.2.clone();
-let ___end0 = ___1.2.clone();
-let ___temp0 = ___action182(
+let ___start0 = ___2.2.clone();
+let ___end0 = ___2.2.clone();
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action395(
+___action398(
 text,
 ___0,
 ___1,
+___2,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action421<
+fn ___action425<
     'input,
 >(
 text: &'input str,
-___0: (usize, Tok<'input>, usize),
-___1: (usize, Condition, usize),
-___2: (usize, ActionKind, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, Tok<'input>, usize),
+___2: (usize, Condition, usize),
+___3: (usize, ActionKind, usize),
 ) -> Alternative
 {
-let ___start0 = ___2.2.clone();
-let ___end0 = ___2.2.clone();
-let ___temp0 = ___action182(
+let ___start0 = ___3.2.clone();
+let ___end0 = ___3.2.clone();
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action396(
+___action399(
 text,
 ___0,
 ___1,
 ___2,
+___3,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action422<
+fn ___action426<
     'input,
 >(
 text: &'input str,
-___0: (usize, ActionKind, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ActionKind, usize),
 ) -> Alternative
 {
-let ___start0 = ___0.2.clone();
-let ___end0 = ___0.2.clone();
-let ___temp0 = ___action182(
+let ___start0 = ___1.2.clone();
+let ___end0 = ___1.2.clone();
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action397(
+___action400(
 text,
 ___0,
+___1,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action423<
+fn ___action427<
     'input,
 >(
 text: &'input str,
@@ -22457,13 +22906,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action398(
+___action401(
 text,
 ___0,
 ___1,
@@ -22475,7 +22924,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action424<
+fn ___action428<
     'input,
 >(
 text: &'input str,
@@ -22488,13 +22937,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action399(
+___action402(
 text,
 ___0,
 ___1,
@@ -22506,7 +22955,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action425<
+fn ___action429<
     'input,
 >(
 text: &'input str,
@@ -22517,13 +22966,13 @@ ___2: (usize, Atom, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action400(
+___action404(
 text,
 ___0,
 ___1,
@@ -22533,32 +22982,34 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action426<
+fn ___action430<
     'input,
 >(
 text: &'input str,
-___0: (usize, TerminalString, usize),
-___1: (usize, &'input str, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalString, usize),
+___2: (usize, &'input str, usize),
 ) -> Result<Conversion,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
 {
-let ___start0 = ___1.2.clone();
-let ___end0 = ___1.2.clone();
-let ___temp0 = ___action182(
+let ___start0 = ___2.2.clone();
+let ___end0 = ___2.2.clone();
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action401(
+___action405(
 text,
 ___0,
 ___1,
+___2,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action427<
+fn ___action431<
     'input,
 >(
 text: &'input str,
@@ -22571,13 +23022,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action402(
+___action406(
 text,
 ___0,
 ___1,
@@ -22589,7 +23040,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action428<
+fn ___action432<
     'input,
 >(
 text: &'input str,
@@ -22603,13 +23054,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action403(
+___action407(
 text,
 ___0,
 ___temp0,
@@ -22622,7 +23073,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action429<
+fn ___action433<
     'input,
 >(
 text: &'input str,
@@ -22634,13 +23085,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action404(
+___action408(
 text,
 ___0,
 ___temp0,
@@ -22651,7 +23102,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action430<
+fn ___action434<
     'input,
 >(
 text: &'input str,
@@ -22662,13 +23113,13 @@ ___2: (usize, Pattern<TypeRef>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action405(
+___action409(
 text,
 ___0,
 ___temp0,
@@ -22678,7 +23129,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action431<
+fn ___action435<
     'input,
 >(
 text: &'input str,
@@ -22695,13 +23146,13 @@ ___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action406(
+___action410(
 text,
 ___0,
 ___1,
@@ -22717,7 +23168,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action432<
+fn ___action436<
     'input,
 >(
 text: &'input str,
@@ -22726,13 +23177,13 @@ ___0: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action407(
+___action411(
 text,
 ___0,
 ___temp0,
@@ -22740,55 +23191,59 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action433<
+fn ___action437<
     'input,
 >(
 text: &'input str,
-___0: (usize, TerminalLiteral, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalLiteral, usize),
 ) -> MatchItem
 {
-let ___start0 = ___0.2.clone();
-let ___end0 = ___0.2.clone();
-let ___temp0 = ___action182(
+let ___start0 = ___1.2.clone();
+let ___end0 = ___1.2.clone();
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action408(
+___action412(
 text,
 ___0,
+___1,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action434<
+fn ___action438<
     'input,
 >(
 text: &'input str,
-___0: (usize, TerminalLiteral, usize),
-___1: (usize, &'input str, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalLiteral, usize),
+___2: (usize, &'input str, usize),
 ) -> Result<MatchItem,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
 {
-let ___start0 = ___1.2.clone();
-let ___end0 = ___1.2.clone();
-let ___temp0 = ___action182(
+let ___start0 = ___2.2.clone();
+let ___end0 = ___2.2.clone();
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action409(
+___action413(
 text,
 ___0,
 ___1,
+___2,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action435<
+fn ___action439<
     'input,
 >(
 text: &'input str,
@@ -22800,13 +23255,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action410(
+___action414(
 text,
 ___0,
 ___temp0,
@@ -22817,7 +23272,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action436<
+fn ___action440<
     'input,
 >(
 text: &'input str,
@@ -22832,13 +23287,13 @@ ___6: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action411(
+___action415(
 text,
 ___0,
 ___1,
@@ -22852,7 +23307,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action437<
+fn ___action441<
     'input,
 >(
 text: &'input str,
@@ -22865,13 +23320,13 @@ ___4: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action412(
+___action416(
 text,
 ___0,
 ___1,
@@ -22883,7 +23338,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action438<
+fn ___action442<
     'input,
 >(
 text: &'input str,
@@ -22892,13 +23347,13 @@ ___0: (usize, PatternKind<TypeRef>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action413(
+___action417(
 text,
 ___0,
 ___temp0,
@@ -22906,7 +23361,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action439<
+fn ___action443<
     'input,
 >(
 text: &'input str,
@@ -22920,13 +23375,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___5.2.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action415(
+___action419(
 text,
 ___0,
 ___1,
@@ -22939,7 +23394,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action440<
+fn ___action444<
     'input,
 >(
 text: &'input str,
@@ -22952,13 +23407,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action416(
+___action420(
 text,
 ___0,
 ___1,
@@ -22970,7 +23425,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action441<
+fn ___action445<
     'input,
 >(
 text: &'input str,
@@ -22981,13 +23436,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action417(
+___action421(
 text,
 ___0,
 ___1,
@@ -22997,7 +23452,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action442<
+fn ___action446<
     'input,
 >(
 text: &'input str,
@@ -23007,7 +23462,7 @@ ___1: (usize, RepeatOp, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
@@ -23022,7 +23477,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action443<
+fn ___action447<
     'input,
 >(
 text: &'input str,
@@ -23031,13 +23486,13 @@ ___0: (usize, SymbolKind, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action182(
+let ___temp0 = ___action185(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action418(
+___action422(
 text,
 ___0,
 ___temp0,
@@ -23045,107 +23500,115 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action444<
+fn ___action448<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
-___1: (usize, Tok<'input>, usize),
-___2: (usize, Condition, usize),
-___3: (usize, ActionKind, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, Condition, usize),
+___4: (usize, ActionKind, usize),
 ) -> Alternative
 {
-let ___start0 = ___3.0.clone();
-let ___end0 = ___3.2.clone();
-let ___temp0 = ___action144(
+let ___start0 = ___4.0.clone();
+let ___end0 = ___4.2.clone();
+let ___temp0 = ___action147(
 text,
-___3,
+___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action419(
+___action423(
 text,
 ___0,
 ___1,
 ___2,
+___3,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action445<
+fn ___action449<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
-___1: (usize, Tok<'input>, usize),
-___2: (usize, Condition, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, Condition, usize),
 ) -> Alternative
 {
-let ___start0 = ___2.2.clone();
-let ___end0 = ___2.2.clone();
-let ___temp0 = ___action145(
+let ___start0 = ___3.2.clone();
+let ___end0 = ___3.2.clone();
+let ___temp0 = ___action148(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action419(
+___action423(
 text,
 ___0,
 ___1,
 ___2,
+___3,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action446<
+fn ___action450<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
-___1: (usize, ActionKind, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, ActionKind, usize),
 ) -> Alternative
 {
-let ___start0 = ___1.0.clone();
-let ___end0 = ___1.2.clone();
-let ___temp0 = ___action144(
+let ___start0 = ___2.0.clone();
+let ___end0 = ___2.2.clone();
+let ___temp0 = ___action147(
 text,
-___1,
+___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action420(
+___action424(
 text,
 ___0,
+___1,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action447<
+fn ___action451<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<Symbol>, usize),
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
 ) -> Alternative
 {
-let ___start0 = ___0.2.clone();
-let ___end0 = ___0.2.clone();
-let ___temp0 = ___action145(
+let ___start0 = ___1.2.clone();
+let ___end0 = ___1.2.clone();
+let ___temp0 = ___action148(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action420(
+___action424(
 text,
 ___0,
+___1,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action448<
+fn ___action452<
     'input,
 >(
 text: &'input str,
@@ -23154,19 +23617,19 @@ ___0: (usize, Alternative, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action236(
+let ___temp0 = ___action239(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action328(
+___action331(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action449<
+fn ___action453<
     'input,
 >(
 text: &'input str,
@@ -23176,20 +23639,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action237(
+let ___temp0 = ___action240(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action328(
+___action331(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action450<
+fn ___action454<
     'input,
 >(
 text: &'input str,
@@ -23199,12 +23662,12 @@ ___1: (usize, Alternative, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action236(
+let ___temp0 = ___action239(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action329(
+___action332(
 text,
 ___0,
 ___temp0,
@@ -23212,7 +23675,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action451<
+fn ___action455<
     'input,
 >(
 text: &'input str,
@@ -23221,13 +23684,13 @@ ___0: (usize, ::std::vec::Vec<Alternative>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action237(
+let ___temp0 = ___action240(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action329(
+___action332(
 text,
 ___0,
 ___temp0,
@@ -23235,66 +23698,424 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action452<
+fn ___action456<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<String>, usize),
-___1: (usize, ::std::vec::Vec<GrammarItem>, usize),
-___2: (usize, Tok<'input>, usize),
-___3: (usize, ::std::option::Option<Vec<TypeParameter>>, usize),
-___4: (usize, ::std::option::Option<Vec<Parameter>>, usize),
-___5: (usize, ::std::option::Option<Vec<WhereClause<TypeRef>>>, usize),
-___6: (usize, Tok<'input>, usize),
-___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
-) -> Grammar
+___0: (usize, ::std::vec::Vec<Symbol>, usize),
+___1: (usize, Tok<'input>, usize),
+___2: (usize, Condition, usize),
+___3: (usize, ActionKind, usize),
+) -> Alternative
 {
-let ___start0 = ___1.2.clone();
-let ___end0 = ___2.0.clone();
-let ___temp0 = ___action184(
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action431(
+___action448(
 text,
+___temp0,
 ___0,
 ___1,
-___temp0,
 ___2,
 ___3,
-___4,
-___5,
-___6,
-___7,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action453<
+fn ___action457<
     'input,
 >(
 text: &'input str,
-___0: (usize, ::std::vec::Vec<String>, usize),
-___1: (usize, ::std::vec::Vec<GrammarItem>, usize),
-___2: (usize, ::std::vec::Vec<Annotation>, usize),
-___3: (usize, Tok<'input>, usize),
-___4: (usize, ::std::option::Option<Vec<TypeParameter>>, usize),
-___5: (usize, ::std::option::Option<Vec<Parameter>>, usize),
-___6: (usize, ::std::option::Option<Vec<WhereClause<TypeRef>>>, usize),
-___7: (usize, Tok<'input>, usize),
-___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
-) -> Grammar
-{
-let ___start0 = ___2.0.clone();
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, Condition, usize),
+___4: (usize, ActionKind, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action448(
+text,
+___temp0,
+___1,
+___2,
+___3,
+___4,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action458<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Symbol>, usize),
+___1: (usize, Tok<'input>, usize),
+___2: (usize, Condition, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action449(
+text,
+___temp0,
+___0,
+___1,
+___2,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action459<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, Condition, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action449(
+text,
+___temp0,
+___1,
+___2,
+___3,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action460<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Symbol>, usize),
+___1: (usize, ActionKind, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action450(
+text,
+___temp0,
+___0,
+___1,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action461<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+___2: (usize, ActionKind, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action450(
+text,
+___temp0,
+___1,
+___2,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action462<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Symbol>, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action451(
+text,
+___temp0,
+___0,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action463<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ::std::vec::Vec<Symbol>, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action451(
+text,
+___temp0,
+___1,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action464<
+    'input,
+>(
+text: &'input str,
+___0: (usize, Tok<'input>, usize),
+___1: (usize, Condition, usize),
+___2: (usize, ActionKind, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action425(
+text,
+___temp0,
+___0,
+___1,
+___2,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action465<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, Tok<'input>, usize),
+___2: (usize, Condition, usize),
+___3: (usize, ActionKind, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action425(
+text,
+___temp0,
+___1,
+___2,
+___3,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action466<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ActionKind, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action426(
+text,
+___temp0,
+___0,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action467<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, ActionKind, usize),
+) -> Alternative
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action426(
+text,
+___temp0,
+___1,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action468<
+    'input,
+>(
+text: &'input str,
+___0: (usize, TerminalString, usize),
+___1: (usize, &'input str, usize),
+) -> Result<Conversion,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action430(
+text,
+___temp0,
+___0,
+___1,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action469<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalString, usize),
+___2: (usize, &'input str, usize),
+) -> Result<Conversion,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action430(
+text,
+___temp0,
+___1,
+___2,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action470<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<String>, usize),
+___1: (usize, ::std::vec::Vec<GrammarItem>, usize),
+___2: (usize, Tok<'input>, usize),
+___3: (usize, ::std::option::Option<Vec<TypeParameter>>, usize),
+___4: (usize, ::std::option::Option<Vec<Parameter>>, usize),
+___5: (usize, ::std::option::Option<Vec<WhereClause<TypeRef>>>, usize),
+___6: (usize, Tok<'input>, usize),
+___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
+) -> Grammar
+{
+let ___start0 = ___1.2.clone();
+let ___end0 = ___2.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action435(
+text,
+___0,
+___1,
+___temp0,
+___2,
+___3,
+___4,
+___5,
+___6,
+___7,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action471<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<String>, usize),
+___1: (usize, ::std::vec::Vec<GrammarItem>, usize),
+___2: (usize, ::std::vec::Vec<Annotation>, usize),
+___3: (usize, Tok<'input>, usize),
+___4: (usize, ::std::option::Option<Vec<TypeParameter>>, usize),
+___5: (usize, ::std::option::Option<Vec<Parameter>>, usize),
+___6: (usize, ::std::option::Option<Vec<WhereClause<TypeRef>>>, usize),
+___7: (usize, Tok<'input>, usize),
+___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
+) -> Grammar
+{
+let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action185(
+let ___temp0 = ___action188(
 text,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action431(
+___action435(
 text,
 ___0,
 ___1,
@@ -23309,7 +24130,103 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action454<
+fn ___action472<
+    'input,
+>(
+text: &'input str,
+___0: (usize, TerminalLiteral, usize),
+) -> MatchItem
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action437(
+text,
+___temp0,
+___0,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action473<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalLiteral, usize),
+) -> MatchItem
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action437(
+text,
+___temp0,
+___1,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action474<
+    'input,
+>(
+text: &'input str,
+___0: (usize, TerminalLiteral, usize),
+___1: (usize, &'input str, usize),
+) -> Result<MatchItem,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.0.clone();
+let ___temp0 = ___action187(
+text,
+&___start0,
+&___end0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action438(
+text,
+___temp0,
+___0,
+___1,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action475<
+    'input,
+>(
+text: &'input str,
+___0: (usize, ::std::vec::Vec<Annotation>, usize),
+___1: (usize, TerminalLiteral, usize),
+___2: (usize, &'input str, usize),
+) -> Result<MatchItem,___lalrpop_util::ParseError<usize,Tok<'input>,tok::Error>>
+{
+let ___start0 = ___0.0.clone();
+let ___end0 = ___0.2.clone();
+let ___temp0 = ___action188(
+text,
+___0,
+);
+let ___temp0 = (___start0, ___temp0, ___end0);
+___action438(
+text,
+___temp0,
+___1,
+___2,
+)
+}
+
+#[allow(unused_variables)]
+fn ___action476<
     'input,
 >(
 text: &'input str,
@@ -23323,13 +24240,13 @@ ___5: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action184(
+let ___temp0 = ___action187(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action436(
+___action440(
 text,
 ___temp0,
 ___0,
@@ -23342,7 +24259,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action455<
+fn ___action477<
     'input,
 >(
 text: &'input str,
@@ -23357,12 +24274,12 @@ ___6: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action185(
+let ___temp0 = ___action188(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action436(
+___action440(
 text,
 ___temp0,
 ___1,
@@ -23375,7 +24292,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action456<
+fn ___action478<
     'input,
 >(
 text: &'input str,
@@ -23387,13 +24304,13 @@ ___3: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action184(
+let ___temp0 = ___action187(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action437(
+___action441(
 text,
 ___temp0,
 ___0,
@@ -23404,7 +24321,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action457<
+fn ___action479<
     'input,
 >(
 text: &'input str,
@@ -23417,12 +24334,12 @@ ___4: (usize, Vec<Alternative>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action185(
+let ___temp0 = ___action188(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action437(
+___action441(
 text,
 ___temp0,
 ___1,
@@ -23433,7 +24350,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action458<
+fn ___action480<
     'input,
 >(
 text: &'input str,
@@ -23446,12 +24363,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action155(
+let ___temp0 = ___action158(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action423(
+___action427(
 text,
 ___0,
 ___1,
@@ -23462,7 +24379,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action459<
+fn ___action481<
     'input,
 >(
 text: &'input str,
@@ -23474,13 +24391,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action156(
+let ___temp0 = ___action159(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action423(
+___action427(
 text,
 ___0,
 ___1,
@@ -23491,7 +24408,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action460<
+fn ___action482<
     'input,
 >(
 text: &'input str,
@@ -23505,19 +24422,19 @@ let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
 let ___start1 = ___2.2.clone();
 let ___end1 = ___3.0.clone();
-let ___temp0 = ___action129(
+let ___temp0 = ___action132(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action129(
+let ___temp1 = ___action132(
 text,
 &___start1,
 &___end1,
 );
 let ___temp1 = (___start1, ___temp1, ___end1);
-___action428(
+___action432(
 text,
 ___0,
 ___1,
@@ -23529,7 +24446,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action461<
+fn ___action483<
     'input,
 >(
 text: &'input str,
@@ -23544,18 +24461,18 @@ let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
 let ___start1 = ___3.0.clone();
 let ___end1 = ___3.2.clone();
-let ___temp0 = ___action129(
+let ___temp0 = ___action132(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action130(
+let ___temp1 = ___action133(
 text,
 ___3,
 );
 let ___temp1 = (___start1, ___temp1, ___end1);
-___action428(
+___action432(
 text,
 ___0,
 ___1,
@@ -23567,7 +24484,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action462<
+fn ___action484<
     'input,
 >(
 text: &'input str,
@@ -23582,18 +24499,18 @@ let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
 let ___start1 = ___3.2.clone();
 let ___end1 = ___4.0.clone();
-let ___temp0 = ___action130(
+let ___temp0 = ___action133(
 text,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action129(
+let ___temp1 = ___action132(
 text,
 &___start1,
 &___end1,
 );
 let ___temp1 = (___start1, ___temp1, ___end1);
-___action428(
+___action432(
 text,
 ___0,
 ___1,
@@ -23605,7 +24522,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action463<
+fn ___action485<
     'input,
 >(
 text: &'input str,
@@ -23621,17 +24538,17 @@ let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
 let ___start1 = ___4.0.clone();
 let ___end1 = ___4.2.clone();
-let ___temp0 = ___action130(
+let ___temp0 = ___action133(
 text,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-let ___temp1 = ___action130(
+let ___temp1 = ___action133(
 text,
 ___4,
 );
 let ___temp1 = (___start1, ___temp1, ___end1);
-___action428(
+___action432(
 text,
 ___0,
 ___1,
@@ -23643,7 +24560,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action464<
+fn ___action486<
     'input,
 >(
 text: &'input str,
@@ -23654,13 +24571,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action129(
+let ___temp0 = ___action132(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action429(
+___action433(
 text,
 ___0,
 ___1,
@@ -23670,7 +24587,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action465<
+fn ___action487<
     'input,
 >(
 text: &'input str,
@@ -23682,12 +24599,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action130(
+let ___temp0 = ___action133(
 text,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action429(
+___action433(
 text,
 ___0,
 ___1,
@@ -23697,7 +24614,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action466<
+fn ___action488<
     'input,
 >(
 text: &'input str,
@@ -23706,19 +24623,19 @@ ___0: (usize, Conversion, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action260(
+let ___temp0 = ___action263(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action332(
+___action335(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action467<
+fn ___action489<
     'input,
 >(
 text: &'input str,
@@ -23728,20 +24645,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action261(
+let ___temp0 = ___action264(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action332(
+___action335(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action468<
+fn ___action490<
     'input,
 >(
 text: &'input str,
@@ -23751,12 +24668,12 @@ ___1: (usize, Conversion, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action260(
+let ___temp0 = ___action263(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action333(
+___action336(
 text,
 ___0,
 ___temp0,
@@ -23764,7 +24681,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action469<
+fn ___action491<
     'input,
 >(
 text: &'input str,
@@ -23773,13 +24690,13 @@ ___0: (usize, ::std::vec::Vec<Conversion>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action261(
+let ___temp0 = ___action264(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action333(
+___action336(
 text,
 ___0,
 ___temp0,
@@ -23787,7 +24704,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action470<
+fn ___action492<
     'input,
 >(
 text: &'input str,
@@ -23799,12 +24716,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.0.clone();
 let ___end0 = ___2.2.clone();
-let ___temp0 = ___action121(
+let ___temp0 = ___action124(
 text,
 ___2,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action336(
+___action339(
 text,
 ___0,
 ___1,
@@ -23814,7 +24731,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action471<
+fn ___action493<
     'input,
 >(
 text: &'input str,
@@ -23825,13 +24742,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.2.clone();
 let ___end0 = ___2.0.clone();
-let ___temp0 = ___action122(
+let ___temp0 = ___action125(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action336(
+___action339(
 text,
 ___0,
 ___1,
@@ -23841,7 +24758,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action472<
+fn ___action494<
     'input,
 >(
 text: &'input str,
@@ -23854,12 +24771,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action121(
+let ___temp0 = ___action124(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action337(
+___action340(
 text,
 ___0,
 ___1,
@@ -23870,7 +24787,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action473<
+fn ___action495<
     'input,
 >(
 text: &'input str,
@@ -23882,13 +24799,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action122(
+let ___temp0 = ___action125(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action337(
+___action340(
 text,
 ___0,
 ___1,
@@ -23899,7 +24816,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action474<
+fn ___action496<
     'input,
 >(
 text: &'input str,
@@ -23932,7 +24849,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action475<
+fn ___action497<
     'input,
 >(
 text: &'input str,
@@ -23943,7 +24860,7 @@ ___2: (usize, Vec<TypeBound<TypeRef>>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action324(
+let ___temp0 = ___action327(
 text,
 &___start0,
 &___end0,
@@ -23959,7 +24876,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action476<
+fn ___action498<
     'input,
 >(
 text: &'input str,
@@ -23985,7 +24902,7 @@ ___2,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action309(
+___action312(
 text,
 ___temp0,
 ___4,
@@ -23998,7 +24915,7 @@ ___9,
 }
 
 #[allow(unused_variables)]
-fn ___action477<
+fn ___action499<
     'input,
 >(
 text: &'input str,
@@ -24012,13 +24929,13 @@ ___5: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action324(
+let ___temp0 = ___action327(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action309(
+___action312(
 text,
 ___temp0,
 ___0,
@@ -24031,7 +24948,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action478<
+fn ___action500<
     'input,
 >(
 text: &'input str,
@@ -24055,7 +24972,7 @@ ___2,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action310(
+___action313(
 text,
 ___temp0,
 ___4,
@@ -24066,7 +24983,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action479<
+fn ___action501<
     'input,
 >(
 text: &'input str,
@@ -24078,13 +24995,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action324(
+let ___temp0 = ___action327(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action310(
+___action313(
 text,
 ___temp0,
 ___0,
@@ -24095,7 +25012,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action480<
+fn ___action502<
     'input,
 >(
 text: &'input str,
@@ -24119,7 +25036,7 @@ ___2,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action317(
+___action320(
 text,
 ___temp0,
 ___4,
@@ -24130,7 +25047,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action481<
+fn ___action503<
     'input,
 >(
 text: &'input str,
@@ -24142,13 +25059,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action324(
+let ___temp0 = ___action327(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action317(
+___action320(
 text,
 ___temp0,
 ___0,
@@ -24159,7 +25076,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action482<
+fn ___action504<
     'input,
 >(
 text: &'input str,
@@ -24180,7 +25097,7 @@ ___2,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action318(
+___action321(
 text,
 ___temp0,
 ___4,
@@ -24188,7 +25105,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action483<
+fn ___action505<
     'input,
 >(
 text: &'input str,
@@ -24197,13 +25114,13 @@ ___0: (usize, Path, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action324(
+let ___temp0 = ___action327(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action318(
+___action321(
 text,
 ___temp0,
 ___0,
@@ -24211,7 +25128,7 @@ ___0,
 }
 
 #[allow(unused_variables)]
-fn ___action484<
+fn ___action506<
     'input,
 >(
 text: &'input str,
@@ -24238,7 +25155,7 @@ ___3,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action311(
+___action314(
 text,
 ___0,
 ___temp0,
@@ -24252,7 +25169,7 @@ ___10,
 }
 
 #[allow(unused_variables)]
-fn ___action485<
+fn ___action507<
     'input,
 >(
 text: &'input str,
@@ -24267,13 +25184,13 @@ ___6: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action324(
+let ___temp0 = ___action327(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action311(
+___action314(
 text,
 ___0,
 ___temp0,
@@ -24287,7 +25204,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action486<
+fn ___action508<
     'input,
 >(
 text: &'input str,
@@ -24312,7 +25229,7 @@ ___3,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action312(
+___action315(
 text,
 ___0,
 ___temp0,
@@ -24324,7 +25241,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action487<
+fn ___action509<
     'input,
 >(
 text: &'input str,
@@ -24337,13 +25254,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action324(
+let ___temp0 = ___action327(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action312(
+___action315(
 text,
 ___0,
 ___temp0,
@@ -24355,7 +25272,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action488<
+fn ___action510<
     'input,
 >(
 text: &'input str,
@@ -24370,13 +25287,13 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___6.2.clone();
 let ___end0 = ___6.2.clone();
-let ___temp0 = ___action174(
+let ___temp0 = ___action177(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action452(
+___action470(
 text,
 ___0,
 ___1,
@@ -24390,7 +25307,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action489<
+fn ___action511<
     'input,
 >(
 text: &'input str,
@@ -24406,12 +25323,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___7.0.clone();
 let ___end0 = ___7.2.clone();
-let ___temp0 = ___action175(
+let ___temp0 = ___action178(
 text,
 ___7,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action452(
+___action470(
 text,
 ___0,
 ___1,
@@ -24425,7 +25342,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action490<
+fn ___action512<
     'input,
 >(
 text: &'input str,
@@ -24441,13 +25358,13 @@ ___7: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___7.2.clone();
 let ___end0 = ___7.2.clone();
-let ___temp0 = ___action174(
+let ___temp0 = ___action177(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action453(
+___action471(
 text,
 ___0,
 ___1,
@@ -24462,7 +25379,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action491<
+fn ___action513<
     'input,
 >(
 text: &'input str,
@@ -24479,12 +25396,12 @@ ___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___8.0.clone();
 let ___end0 = ___8.2.clone();
-let ___temp0 = ___action175(
+let ___temp0 = ___action178(
 text,
 ___8,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action453(
+___action471(
 text,
 ___0,
 ___1,
@@ -24499,7 +25416,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action492<
+fn ___action514<
     'input,
 >(
 text: &'input str,
@@ -24508,19 +25425,19 @@ ___0: (usize, Parameter, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action226(
+let ___temp0 = ___action229(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action342(
+___action345(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action493<
+fn ___action515<
     'input,
 >(
 text: &'input str,
@@ -24530,20 +25447,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action227(
+let ___temp0 = ___action230(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action342(
+___action345(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action494<
+fn ___action516<
     'input,
 >(
 text: &'input str,
@@ -24553,12 +25470,12 @@ ___1: (usize, Parameter, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action226(
+let ___temp0 = ___action229(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action343(
+___action346(
 text,
 ___0,
 ___temp0,
@@ -24566,7 +25483,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action495<
+fn ___action517<
     'input,
 >(
 text: &'input str,
@@ -24575,13 +25492,13 @@ ___0: (usize, ::std::vec::Vec<Parameter>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action227(
+let ___temp0 = ___action230(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action343(
+___action346(
 text,
 ___0,
 ___temp0,
@@ -24589,7 +25506,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action496<
+fn ___action518<
     'input,
 >(
 text: &'input str,
@@ -24604,12 +25521,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action178(
+let ___temp0 = ___action181(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action488(
+___action510(
 text,
 ___0,
 ___1,
@@ -24622,7 +25539,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action497<
+fn ___action519<
     'input,
 >(
 text: &'input str,
@@ -24636,13 +25553,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action179(
+let ___temp0 = ___action182(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action488(
+___action510(
 text,
 ___0,
 ___1,
@@ -24655,7 +25572,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action498<
+fn ___action520<
     'input,
 >(
 text: &'input str,
@@ -24671,12 +25588,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action178(
+let ___temp0 = ___action181(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action489(
+___action511(
 text,
 ___0,
 ___1,
@@ -24690,7 +25607,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action499<
+fn ___action521<
     'input,
 >(
 text: &'input str,
@@ -24705,13 +25622,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action179(
+let ___temp0 = ___action182(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action489(
+___action511(
 text,
 ___0,
 ___1,
@@ -24725,7 +25642,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action500<
+fn ___action522<
     'input,
 >(
 text: &'input str,
@@ -24741,12 +25658,12 @@ ___7: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action178(
+let ___temp0 = ___action181(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action490(
+___action512(
 text,
 ___0,
 ___1,
@@ -24760,7 +25677,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action501<
+fn ___action523<
     'input,
 >(
 text: &'input str,
@@ -24775,13 +25692,13 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action179(
+let ___temp0 = ___action182(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action490(
+___action512(
 text,
 ___0,
 ___1,
@@ -24795,7 +25712,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action502<
+fn ___action524<
     'input,
 >(
 text: &'input str,
@@ -24812,12 +25729,12 @@ ___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action178(
+let ___temp0 = ___action181(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action491(
+___action513(
 text,
 ___0,
 ___1,
@@ -24832,7 +25749,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action503<
+fn ___action525<
     'input,
 >(
 text: &'input str,
@@ -24848,13 +25765,13 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action179(
+let ___temp0 = ___action182(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action491(
+___action513(
 text,
 ___0,
 ___1,
@@ -24869,7 +25786,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action504<
+fn ___action526<
     'input,
 >(
 text: &'input str,
@@ -24884,12 +25801,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action496(
+___action518(
 text,
 ___0,
 ___1,
@@ -24902,7 +25819,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action505<
+fn ___action527<
     'input,
 >(
 text: &'input str,
@@ -24916,13 +25833,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action496(
+___action518(
 text,
 ___0,
 ___1,
@@ -24935,7 +25852,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action506<
+fn ___action528<
     'input,
 >(
 text: &'input str,
@@ -24949,12 +25866,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action497(
+___action519(
 text,
 ___0,
 ___1,
@@ -24966,7 +25883,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action507<
+fn ___action529<
     'input,
 >(
 text: &'input str,
@@ -24979,13 +25896,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action497(
+___action519(
 text,
 ___0,
 ___1,
@@ -24997,7 +25914,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action508<
+fn ___action530<
     'input,
 >(
 text: &'input str,
@@ -25013,12 +25930,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action498(
+___action520(
 text,
 ___0,
 ___1,
@@ -25032,7 +25949,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action509<
+fn ___action531<
     'input,
 >(
 text: &'input str,
@@ -25047,13 +25964,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action498(
+___action520(
 text,
 ___0,
 ___1,
@@ -25067,7 +25984,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action510<
+fn ___action532<
     'input,
 >(
 text: &'input str,
@@ -25082,12 +25999,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action499(
+___action521(
 text,
 ___0,
 ___1,
@@ -25100,7 +26017,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action511<
+fn ___action533<
     'input,
 >(
 text: &'input str,
@@ -25114,13 +26031,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action499(
+___action521(
 text,
 ___0,
 ___1,
@@ -25133,7 +26050,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action512<
+fn ___action534<
     'input,
 >(
 text: &'input str,
@@ -25149,12 +26066,12 @@ ___7: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action500(
+___action522(
 text,
 ___0,
 ___1,
@@ -25168,7 +26085,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action513<
+fn ___action535<
     'input,
 >(
 text: &'input str,
@@ -25183,13 +26100,13 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action500(
+___action522(
 text,
 ___0,
 ___1,
@@ -25203,7 +26120,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action514<
+fn ___action536<
     'input,
 >(
 text: &'input str,
@@ -25218,12 +26135,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action501(
+___action523(
 text,
 ___0,
 ___1,
@@ -25236,7 +26153,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action515<
+fn ___action537<
     'input,
 >(
 text: &'input str,
@@ -25250,13 +26167,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action501(
+___action523(
 text,
 ___0,
 ___1,
@@ -25269,7 +26186,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action516<
+fn ___action538<
     'input,
 >(
 text: &'input str,
@@ -25286,12 +26203,12 @@ ___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action502(
+___action524(
 text,
 ___0,
 ___1,
@@ -25306,7 +26223,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action517<
+fn ___action539<
     'input,
 >(
 text: &'input str,
@@ -25322,13 +26239,13 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action502(
+___action524(
 text,
 ___0,
 ___1,
@@ -25343,7 +26260,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action518<
+fn ___action540<
     'input,
 >(
 text: &'input str,
@@ -25359,12 +26276,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action180(
+let ___temp0 = ___action183(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action503(
+___action525(
 text,
 ___0,
 ___1,
@@ -25378,7 +26295,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action519<
+fn ___action541<
     'input,
 >(
 text: &'input str,
@@ -25393,13 +26310,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action181(
+let ___temp0 = ___action184(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action503(
+___action525(
 text,
 ___0,
 ___1,
@@ -25413,7 +26330,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action520<
+fn ___action542<
     'input,
 >(
 text: &'input str,
@@ -25422,19 +26339,19 @@ ___0: (usize, WhereClause<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action203(
+let ___temp0 = ___action206(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action346(
+___action349(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action521<
+fn ___action543<
     'input,
 >(
 text: &'input str,
@@ -25444,20 +26361,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action204(
+let ___temp0 = ___action207(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action346(
+___action349(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action522<
+fn ___action544<
     'input,
 >(
 text: &'input str,
@@ -25467,12 +26384,12 @@ ___1: (usize, WhereClause<TypeRef>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action203(
+let ___temp0 = ___action206(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action347(
+___action350(
 text,
 ___0,
 ___temp0,
@@ -25480,7 +26397,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action523<
+fn ___action545<
     'input,
 >(
 text: &'input str,
@@ -25489,13 +26406,13 @@ ___0: (usize, ::std::vec::Vec<WhereClause<TypeRef>>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action204(
+let ___temp0 = ___action207(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action347(
+___action350(
 text,
 ___0,
 ___temp0,
@@ -25503,7 +26420,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action524<
+fn ___action546<
     'input,
 >(
 text: &'input str,
@@ -25518,12 +26435,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action504(
+___action526(
 text,
 ___0,
 ___1,
@@ -25536,7 +26453,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action525<
+fn ___action547<
     'input,
 >(
 text: &'input str,
@@ -25550,13 +26467,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action504(
+___action526(
 text,
 ___0,
 ___1,
@@ -25569,7 +26486,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action526<
+fn ___action548<
     'input,
 >(
 text: &'input str,
@@ -25583,12 +26500,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action505(
+___action527(
 text,
 ___0,
 ___1,
@@ -25600,7 +26517,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action527<
+fn ___action549<
     'input,
 >(
 text: &'input str,
@@ -25613,13 +26530,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action505(
+___action527(
 text,
 ___0,
 ___1,
@@ -25631,7 +26548,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action528<
+fn ___action550<
     'input,
 >(
 text: &'input str,
@@ -25645,12 +26562,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action506(
+___action528(
 text,
 ___0,
 ___1,
@@ -25662,7 +26579,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action529<
+fn ___action551<
     'input,
 >(
 text: &'input str,
@@ -25675,13 +26592,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action506(
+___action528(
 text,
 ___0,
 ___1,
@@ -25693,7 +26610,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action530<
+fn ___action552<
     'input,
 >(
 text: &'input str,
@@ -25706,12 +26623,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action507(
+___action529(
 text,
 ___0,
 ___1,
@@ -25722,7 +26639,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action531<
+fn ___action553<
     'input,
 >(
 text: &'input str,
@@ -25734,13 +26651,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action507(
+___action529(
 text,
 ___0,
 ___1,
@@ -25751,7 +26668,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action532<
+fn ___action554<
     'input,
 >(
 text: &'input str,
@@ -25767,12 +26684,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action508(
+___action530(
 text,
 ___0,
 ___1,
@@ -25786,7 +26703,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action533<
+fn ___action555<
     'input,
 >(
 text: &'input str,
@@ -25801,13 +26718,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action508(
+___action530(
 text,
 ___0,
 ___1,
@@ -25821,7 +26738,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action534<
+fn ___action556<
     'input,
 >(
 text: &'input str,
@@ -25836,12 +26753,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action509(
+___action531(
 text,
 ___0,
 ___1,
@@ -25854,7 +26771,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action535<
+fn ___action557<
     'input,
 >(
 text: &'input str,
@@ -25868,13 +26785,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action509(
+___action531(
 text,
 ___0,
 ___1,
@@ -25887,7 +26804,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action536<
+fn ___action558<
     'input,
 >(
 text: &'input str,
@@ -25902,12 +26819,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action510(
+___action532(
 text,
 ___0,
 ___1,
@@ -25920,7 +26837,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action537<
+fn ___action559<
     'input,
 >(
 text: &'input str,
@@ -25934,13 +26851,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action510(
+___action532(
 text,
 ___0,
 ___1,
@@ -25953,7 +26870,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action538<
+fn ___action560<
     'input,
 >(
 text: &'input str,
@@ -25967,12 +26884,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.0.clone();
 let ___end0 = ___3.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___3,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action511(
+___action533(
 text,
 ___0,
 ___1,
@@ -25984,7 +26901,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action539<
+fn ___action561<
     'input,
 >(
 text: &'input str,
@@ -25997,13 +26914,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___2.2.clone();
 let ___end0 = ___3.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action511(
+___action533(
 text,
 ___0,
 ___1,
@@ -26015,7 +26932,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action540<
+fn ___action562<
     'input,
 >(
 text: &'input str,
@@ -26031,12 +26948,12 @@ ___7: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___6.0.clone();
 let ___end0 = ___6.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___6,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action512(
+___action534(
 text,
 ___0,
 ___1,
@@ -26050,7 +26967,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action541<
+fn ___action563<
     'input,
 >(
 text: &'input str,
@@ -26065,13 +26982,13 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___5.2.clone();
 let ___end0 = ___6.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action512(
+___action534(
 text,
 ___0,
 ___1,
@@ -26085,7 +27002,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action542<
+fn ___action564<
     'input,
 >(
 text: &'input str,
@@ -26100,12 +27017,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action513(
+___action535(
 text,
 ___0,
 ___1,
@@ -26118,7 +27035,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action543<
+fn ___action565<
     'input,
 >(
 text: &'input str,
@@ -26132,13 +27049,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action513(
+___action535(
 text,
 ___0,
 ___1,
@@ -26151,7 +27068,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action544<
+fn ___action566<
     'input,
 >(
 text: &'input str,
@@ -26166,12 +27083,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action514(
+___action536(
 text,
 ___0,
 ___1,
@@ -26184,7 +27101,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action545<
+fn ___action567<
     'input,
 >(
 text: &'input str,
@@ -26198,13 +27115,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action514(
+___action536(
 text,
 ___0,
 ___1,
@@ -26217,7 +27134,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action546<
+fn ___action568<
     'input,
 >(
 text: &'input str,
@@ -26231,12 +27148,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action515(
+___action537(
 text,
 ___0,
 ___1,
@@ -26248,7 +27165,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action547<
+fn ___action569<
     'input,
 >(
 text: &'input str,
@@ -26261,13 +27178,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action515(
+___action537(
 text,
 ___0,
 ___1,
@@ -26279,7 +27196,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action548<
+fn ___action570<
     'input,
 >(
 text: &'input str,
@@ -26296,12 +27213,12 @@ ___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___6.0.clone();
 let ___end0 = ___6.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___6,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action516(
+___action538(
 text,
 ___0,
 ___1,
@@ -26316,7 +27233,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action549<
+fn ___action571<
     'input,
 >(
 text: &'input str,
@@ -26332,13 +27249,13 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___5.2.clone();
 let ___end0 = ___6.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action516(
+___action538(
 text,
 ___0,
 ___1,
@@ -26353,7 +27270,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action550<
+fn ___action572<
     'input,
 >(
 text: &'input str,
@@ -26369,12 +27286,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action517(
+___action539(
 text,
 ___0,
 ___1,
@@ -26388,7 +27305,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action551<
+fn ___action573<
     'input,
 >(
 text: &'input str,
@@ -26403,13 +27320,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action517(
+___action539(
 text,
 ___0,
 ___1,
@@ -26423,7 +27340,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action552<
+fn ___action574<
     'input,
 >(
 text: &'input str,
@@ -26439,12 +27356,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___5.0.clone();
 let ___end0 = ___5.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___5,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action518(
+___action540(
 text,
 ___0,
 ___1,
@@ -26458,7 +27375,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action553<
+fn ___action575<
     'input,
 >(
 text: &'input str,
@@ -26473,13 +27390,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.2.clone();
 let ___end0 = ___5.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action518(
+___action540(
 text,
 ___0,
 ___1,
@@ -26493,7 +27410,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action554<
+fn ___action576<
     'input,
 >(
 text: &'input str,
@@ -26508,12 +27425,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___4.0.clone();
 let ___end0 = ___4.2.clone();
-let ___temp0 = ___action176(
+let ___temp0 = ___action179(
 text,
 ___4,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action519(
+___action541(
 text,
 ___0,
 ___1,
@@ -26526,7 +27443,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action555<
+fn ___action577<
     'input,
 >(
 text: &'input str,
@@ -26540,13 +27457,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___3.2.clone();
 let ___end0 = ___4.0.clone();
-let ___temp0 = ___action177(
+let ___temp0 = ___action180(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action519(
+___action541(
 text,
 ___0,
 ___1,
@@ -26559,7 +27476,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action556<
+fn ___action578<
     'input,
 >(
 text: &'input str,
@@ -26568,19 +27485,19 @@ ___0: (usize, Lifetime, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action137(
+let ___temp0 = ___action140(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action356(
+___action359(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action557<
+fn ___action579<
     'input,
 >(
 text: &'input str,
@@ -26590,20 +27507,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action138(
+let ___temp0 = ___action141(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action356(
+___action359(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action558<
+fn ___action580<
     'input,
 >(
 text: &'input str,
@@ -26613,12 +27530,12 @@ ___1: (usize, Lifetime, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action137(
+let ___temp0 = ___action140(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action357(
+___action360(
 text,
 ___0,
 ___temp0,
@@ -26626,7 +27543,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action559<
+fn ___action581<
     'input,
 >(
 text: &'input str,
@@ -26635,13 +27552,13 @@ ___0: (usize, ::std::vec::Vec<Lifetime>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action138(
+let ___temp0 = ___action141(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action357(
+___action360(
 text,
 ___0,
 ___temp0,
@@ -26649,7 +27566,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action560<
+fn ___action582<
     'input,
 >(
 text: &'input str,
@@ -26661,12 +27578,12 @@ ___3: (usize, TypeRef, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action137(
+let ___temp0 = ___action140(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action306(
+___action309(
 text,
 ___0,
 ___temp0,
@@ -26676,7 +27593,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action561<
+fn ___action583<
     'input,
 >(
 text: &'input str,
@@ -26687,13 +27604,13 @@ ___2: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action138(
+let ___temp0 = ___action141(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action306(
+___action309(
 text,
 ___0,
 ___temp0,
@@ -26703,7 +27620,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action562<
+fn ___action584<
     'input,
 >(
 text: &'input str,
@@ -26714,12 +27631,12 @@ ___2: (usize, TypeRef, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action137(
+let ___temp0 = ___action140(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action307(
+___action310(
 text,
 ___0,
 ___temp0,
@@ -26728,7 +27645,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action563<
+fn ___action585<
     'input,
 >(
 text: &'input str,
@@ -26738,13 +27655,13 @@ ___1: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action138(
+let ___temp0 = ___action141(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action307(
+___action310(
 text,
 ___0,
 ___temp0,
@@ -26753,7 +27670,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action564<
+fn ___action586<
     'input,
 >(
 text: &'input str,
@@ -26762,19 +27679,19 @@ ___0: (usize, MatchItem, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action255(
+let ___temp0 = ___action258(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action360(
+___action363(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action565<
+fn ___action587<
     'input,
 >(
 text: &'input str,
@@ -26784,20 +27701,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action256(
+let ___temp0 = ___action259(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action360(
+___action363(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action566<
+fn ___action588<
     'input,
 >(
 text: &'input str,
@@ -26807,12 +27724,12 @@ ___1: (usize, MatchItem, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action255(
+let ___temp0 = ___action258(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action361(
+___action364(
 text,
 ___0,
 ___temp0,
@@ -26820,7 +27737,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action567<
+fn ___action589<
     'input,
 >(
 text: &'input str,
@@ -26829,13 +27746,13 @@ ___0: (usize, ::std::vec::Vec<MatchItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action256(
+let ___temp0 = ___action259(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action361(
+___action364(
 text,
 ___0,
 ___temp0,
@@ -26843,7 +27760,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action568<
+fn ___action590<
     'input,
 >(
 text: &'input str,
@@ -26852,19 +27769,19 @@ ___0: (usize, NonterminalString, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action231(
+let ___temp0 = ___action234(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action364(
+___action367(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action569<
+fn ___action591<
     'input,
 >(
 text: &'input str,
@@ -26874,20 +27791,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action232(
+let ___temp0 = ___action235(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action364(
+___action367(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action570<
+fn ___action592<
     'input,
 >(
 text: &'input str,
@@ -26897,12 +27814,12 @@ ___1: (usize, NonterminalString, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action231(
+let ___temp0 = ___action234(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action365(
+___action368(
 text,
 ___0,
 ___temp0,
@@ -26910,7 +27827,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action571<
+fn ___action593<
     'input,
 >(
 text: &'input str,
@@ -26919,13 +27836,13 @@ ___0: (usize, ::std::vec::Vec<NonterminalString>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action232(
+let ___temp0 = ___action235(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action365(
+___action368(
 text,
 ___0,
 ___temp0,
@@ -26933,7 +27850,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action572<
+fn ___action594<
     'input,
 >(
 text: &'input str,
@@ -26942,19 +27859,19 @@ ___0: (usize, Pattern<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action265(
+let ___temp0 = ___action268(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action368(
+___action371(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action573<
+fn ___action595<
     'input,
 >(
 text: &'input str,
@@ -26964,20 +27881,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action266(
+let ___temp0 = ___action269(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action368(
+___action371(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action574<
+fn ___action596<
     'input,
 >(
 text: &'input str,
@@ -26987,12 +27904,12 @@ ___1: (usize, Pattern<TypeRef>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action265(
+let ___temp0 = ___action268(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action369(
+___action372(
 text,
 ___0,
 ___temp0,
@@ -27000,7 +27917,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action575<
+fn ___action597<
     'input,
 >(
 text: &'input str,
@@ -27009,13 +27926,13 @@ ___0: (usize, ::std::vec::Vec<Pattern<TypeRef>>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action266(
+let ___temp0 = ___action269(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action369(
+___action372(
 text,
 ___0,
 ___temp0,
@@ -27023,7 +27940,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action576<
+fn ___action598<
     'input,
 >(
 text: &'input str,
@@ -27037,13 +27954,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action524(
+___action546(
 text,
 ___temp0,
 ___0,
@@ -27056,7 +27973,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action577<
+fn ___action599<
     'input,
 >(
 text: &'input str,
@@ -27071,12 +27988,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action524(
+___action546(
 text,
 ___temp0,
 ___1,
@@ -27089,7 +28006,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action578<
+fn ___action600<
     'input,
 >(
 text: &'input str,
@@ -27102,13 +28019,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action525(
+___action547(
 text,
 ___temp0,
 ___0,
@@ -27120,7 +28037,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action579<
+fn ___action601<
     'input,
 >(
 text: &'input str,
@@ -27134,12 +28051,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action525(
+___action547(
 text,
 ___temp0,
 ___1,
@@ -27151,7 +28068,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action580<
+fn ___action602<
     'input,
 >(
 text: &'input str,
@@ -27164,13 +28081,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action526(
+___action548(
 text,
 ___temp0,
 ___0,
@@ -27182,7 +28099,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action581<
+fn ___action603<
     'input,
 >(
 text: &'input str,
@@ -27196,12 +28113,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action526(
+___action548(
 text,
 ___temp0,
 ___1,
@@ -27213,7 +28130,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action582<
+fn ___action604<
     'input,
 >(
 text: &'input str,
@@ -27225,13 +28142,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action527(
+___action549(
 text,
 ___temp0,
 ___0,
@@ -27242,7 +28159,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action583<
+fn ___action605<
     'input,
 >(
 text: &'input str,
@@ -27255,12 +28172,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action527(
+___action549(
 text,
 ___temp0,
 ___1,
@@ -27271,7 +28188,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action584<
+fn ___action606<
     'input,
 >(
 text: &'input str,
@@ -27284,13 +28201,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action528(
+___action550(
 text,
 ___temp0,
 ___0,
@@ -27302,7 +28219,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action585<
+fn ___action607<
     'input,
 >(
 text: &'input str,
@@ -27316,12 +28233,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action528(
+___action550(
 text,
 ___temp0,
 ___1,
@@ -27333,7 +28250,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action586<
+fn ___action608<
     'input,
 >(
 text: &'input str,
@@ -27345,13 +28262,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action529(
+___action551(
 text,
 ___temp0,
 ___0,
@@ -27362,7 +28279,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action587<
+fn ___action609<
     'input,
 >(
 text: &'input str,
@@ -27375,12 +28292,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action529(
+___action551(
 text,
 ___temp0,
 ___1,
@@ -27391,7 +28308,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action588<
+fn ___action610<
     'input,
 >(
 text: &'input str,
@@ -27403,13 +28320,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action530(
+___action552(
 text,
 ___temp0,
 ___0,
@@ -27420,7 +28337,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action589<
+fn ___action611<
     'input,
 >(
 text: &'input str,
@@ -27433,12 +28350,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action530(
+___action552(
 text,
 ___temp0,
 ___1,
@@ -27449,7 +28366,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action590<
+fn ___action612<
     'input,
 >(
 text: &'input str,
@@ -27460,13 +28377,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action531(
+___action553(
 text,
 ___temp0,
 ___0,
@@ -27476,7 +28393,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action591<
+fn ___action613<
     'input,
 >(
 text: &'input str,
@@ -27488,12 +28405,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action531(
+___action553(
 text,
 ___temp0,
 ___1,
@@ -27503,7 +28420,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action592<
+fn ___action614<
     'input,
 >(
 text: &'input str,
@@ -27518,13 +28435,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action532(
+___action554(
 text,
 ___temp0,
 ___0,
@@ -27538,7 +28455,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action593<
+fn ___action615<
     'input,
 >(
 text: &'input str,
@@ -27554,12 +28471,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action532(
+___action554(
 text,
 ___temp0,
 ___1,
@@ -27573,7 +28490,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action594<
+fn ___action616<
     'input,
 >(
 text: &'input str,
@@ -27587,13 +28504,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action533(
+___action555(
 text,
 ___temp0,
 ___0,
@@ -27606,7 +28523,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action595<
+fn ___action617<
     'input,
 >(
 text: &'input str,
@@ -27621,12 +28538,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action533(
+___action555(
 text,
 ___temp0,
 ___1,
@@ -27639,7 +28556,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action596<
+fn ___action618<
     'input,
 >(
 text: &'input str,
@@ -27653,13 +28570,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action534(
+___action556(
 text,
 ___temp0,
 ___0,
@@ -27672,7 +28589,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action597<
+fn ___action619<
     'input,
 >(
 text: &'input str,
@@ -27687,12 +28604,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action534(
+___action556(
 text,
 ___temp0,
 ___1,
@@ -27705,7 +28622,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action598<
+fn ___action620<
     'input,
 >(
 text: &'input str,
@@ -27718,13 +28635,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action535(
+___action557(
 text,
 ___temp0,
 ___0,
@@ -27736,7 +28653,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action599<
+fn ___action621<
     'input,
 >(
 text: &'input str,
@@ -27750,12 +28667,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action535(
+___action557(
 text,
 ___temp0,
 ___1,
@@ -27767,7 +28684,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action600<
+fn ___action622<
     'input,
 >(
 text: &'input str,
@@ -27781,13 +28698,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action536(
+___action558(
 text,
 ___temp0,
 ___0,
@@ -27800,7 +28717,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action601<
+fn ___action623<
     'input,
 >(
 text: &'input str,
@@ -27815,12 +28732,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action536(
+___action558(
 text,
 ___temp0,
 ___1,
@@ -27833,7 +28750,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action602<
+fn ___action624<
     'input,
 >(
 text: &'input str,
@@ -27846,13 +28763,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action537(
+___action559(
 text,
 ___temp0,
 ___0,
@@ -27864,7 +28781,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action603<
+fn ___action625<
     'input,
 >(
 text: &'input str,
@@ -27878,12 +28795,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action537(
+___action559(
 text,
 ___temp0,
 ___1,
@@ -27895,7 +28812,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action604<
+fn ___action626<
     'input,
 >(
 text: &'input str,
@@ -27908,13 +28825,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action538(
+___action560(
 text,
 ___temp0,
 ___0,
@@ -27926,7 +28843,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action605<
+fn ___action627<
     'input,
 >(
 text: &'input str,
@@ -27940,12 +28857,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action538(
+___action560(
 text,
 ___temp0,
 ___1,
@@ -27957,7 +28874,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action606<
+fn ___action628<
     'input,
 >(
 text: &'input str,
@@ -27969,13 +28886,13 @@ ___3: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action539(
+___action561(
 text,
 ___temp0,
 ___0,
@@ -27986,7 +28903,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action607<
+fn ___action629<
     'input,
 >(
 text: &'input str,
@@ -27999,12 +28916,12 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action539(
+___action561(
 text,
 ___temp0,
 ___1,
@@ -28015,7 +28932,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action608<
+fn ___action630<
     'input,
 >(
 text: &'input str,
@@ -28030,13 +28947,13 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action540(
+___action562(
 text,
 ___temp0,
 ___0,
@@ -28050,7 +28967,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action609<
+fn ___action631<
     'input,
 >(
 text: &'input str,
@@ -28066,12 +28983,12 @@ ___7: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action540(
+___action562(
 text,
 ___temp0,
 ___1,
@@ -28085,7 +29002,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action610<
+fn ___action632<
     'input,
 >(
 text: &'input str,
@@ -28099,13 +29016,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action541(
+___action563(
 text,
 ___temp0,
 ___0,
@@ -28118,7 +29035,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action611<
+fn ___action633<
     'input,
 >(
 text: &'input str,
@@ -28133,12 +29050,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action541(
+___action563(
 text,
 ___temp0,
 ___1,
@@ -28151,7 +29068,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action612<
+fn ___action634<
     'input,
 >(
 text: &'input str,
@@ -28165,13 +29082,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action542(
+___action564(
 text,
 ___temp0,
 ___0,
@@ -28184,7 +29101,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action613<
+fn ___action635<
     'input,
 >(
 text: &'input str,
@@ -28199,12 +29116,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action542(
+___action564(
 text,
 ___temp0,
 ___1,
@@ -28217,7 +29134,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action614<
+fn ___action636<
     'input,
 >(
 text: &'input str,
@@ -28230,13 +29147,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action543(
+___action565(
 text,
 ___temp0,
 ___0,
@@ -28248,7 +29165,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action615<
+fn ___action637<
     'input,
 >(
 text: &'input str,
@@ -28262,12 +29179,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action543(
+___action565(
 text,
 ___temp0,
 ___1,
@@ -28279,7 +29196,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action616<
+fn ___action638<
     'input,
 >(
 text: &'input str,
@@ -28293,13 +29210,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action544(
+___action566(
 text,
 ___temp0,
 ___0,
@@ -28312,7 +29229,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action617<
+fn ___action639<
     'input,
 >(
 text: &'input str,
@@ -28327,12 +29244,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action544(
+___action566(
 text,
 ___temp0,
 ___1,
@@ -28345,7 +29262,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action618<
+fn ___action640<
     'input,
 >(
 text: &'input str,
@@ -28358,13 +29275,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action545(
+___action567(
 text,
 ___temp0,
 ___0,
@@ -28376,7 +29293,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action619<
+fn ___action641<
     'input,
 >(
 text: &'input str,
@@ -28390,12 +29307,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action545(
+___action567(
 text,
 ___temp0,
 ___1,
@@ -28407,7 +29324,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action620<
+fn ___action642<
     'input,
 >(
 text: &'input str,
@@ -28420,13 +29337,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action546(
+___action568(
 text,
 ___temp0,
 ___0,
@@ -28438,7 +29355,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action621<
+fn ___action643<
     'input,
 >(
 text: &'input str,
@@ -28452,12 +29369,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action546(
+___action568(
 text,
 ___temp0,
 ___1,
@@ -28469,7 +29386,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action622<
+fn ___action644<
     'input,
 >(
 text: &'input str,
@@ -28481,13 +29398,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action547(
+___action569(
 text,
 ___temp0,
 ___0,
@@ -28498,7 +29415,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action623<
+fn ___action645<
     'input,
 >(
 text: &'input str,
@@ -28511,12 +29428,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action547(
+___action569(
 text,
 ___temp0,
 ___1,
@@ -28527,7 +29444,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action624<
+fn ___action646<
     'input,
 >(
 text: &'input str,
@@ -28543,13 +29460,13 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action548(
+___action570(
 text,
 ___temp0,
 ___0,
@@ -28564,7 +29481,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action625<
+fn ___action647<
     'input,
 >(
 text: &'input str,
@@ -28581,12 +29498,12 @@ ___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action548(
+___action570(
 text,
 ___temp0,
 ___1,
@@ -28601,7 +29518,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action626<
+fn ___action648<
     'input,
 >(
 text: &'input str,
@@ -28616,13 +29533,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action549(
+___action571(
 text,
 ___temp0,
 ___0,
@@ -28636,7 +29553,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action627<
+fn ___action649<
     'input,
 >(
 text: &'input str,
@@ -28652,12 +29569,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action549(
+___action571(
 text,
 ___temp0,
 ___1,
@@ -28671,7 +29588,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action628<
+fn ___action650<
     'input,
 >(
 text: &'input str,
@@ -28686,13 +29603,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action550(
+___action572(
 text,
 ___temp0,
 ___0,
@@ -28706,7 +29623,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action629<
+fn ___action651<
     'input,
 >(
 text: &'input str,
@@ -28722,12 +29639,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action550(
+___action572(
 text,
 ___temp0,
 ___1,
@@ -28741,7 +29658,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action630<
+fn ___action652<
     'input,
 >(
 text: &'input str,
@@ -28755,13 +29672,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action551(
+___action573(
 text,
 ___temp0,
 ___0,
@@ -28774,7 +29691,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action631<
+fn ___action653<
     'input,
 >(
 text: &'input str,
@@ -28789,12 +29706,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action551(
+___action573(
 text,
 ___temp0,
 ___1,
@@ -28807,7 +29724,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action632<
+fn ___action654<
     'input,
 >(
 text: &'input str,
@@ -28822,13 +29739,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action552(
+___action574(
 text,
 ___temp0,
 ___0,
@@ -28842,7 +29759,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action633<
+fn ___action655<
     'input,
 >(
 text: &'input str,
@@ -28858,12 +29775,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action552(
+___action574(
 text,
 ___temp0,
 ___1,
@@ -28877,7 +29794,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action634<
+fn ___action656<
     'input,
 >(
 text: &'input str,
@@ -28891,13 +29808,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action553(
+___action575(
 text,
 ___temp0,
 ___0,
@@ -28910,7 +29827,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action635<
+fn ___action657<
     'input,
 >(
 text: &'input str,
@@ -28925,12 +29842,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action553(
+___action575(
 text,
 ___temp0,
 ___1,
@@ -28943,7 +29860,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action636<
+fn ___action658<
     'input,
 >(
 text: &'input str,
@@ -28957,13 +29874,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action554(
+___action576(
 text,
 ___temp0,
 ___0,
@@ -28976,7 +29893,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action637<
+fn ___action659<
     'input,
 >(
 text: &'input str,
@@ -28991,12 +29908,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action554(
+___action576(
 text,
 ___temp0,
 ___1,
@@ -29009,7 +29926,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action638<
+fn ___action660<
     'input,
 >(
 text: &'input str,
@@ -29022,13 +29939,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action188(
+let ___temp0 = ___action191(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action555(
+___action577(
 text,
 ___temp0,
 ___0,
@@ -29040,7 +29957,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action639<
+fn ___action661<
     'input,
 >(
 text: &'input str,
@@ -29054,12 +29971,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action189(
+let ___temp0 = ___action192(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action555(
+___action577(
 text,
 ___temp0,
 ___1,
@@ -29071,7 +29988,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action640<
+fn ___action662<
     'input,
 >(
 text: &'input str,
@@ -29081,7 +29998,7 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action142(
+let ___temp0 = ___action145(
 text,
 &___start0,
 &___end0,
@@ -29094,7 +30011,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action641<
+fn ___action663<
     'input,
 >(
 text: &'input str,
@@ -29103,7 +30020,7 @@ ___0: (usize, ::std::vec::Vec<Symbol>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action143(
+let ___temp0 = ___action146(
 text,
 ___0,
 );
@@ -29115,7 +30032,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action642<
+fn ___action664<
     'input,
 >(
 text: &'input str,
@@ -29124,19 +30041,19 @@ ___0: (usize, Symbol, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action241(
+let ___temp0 = ___action244(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action372(
+___action375(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action643<
+fn ___action665<
     'input,
 >(
 text: &'input str,
@@ -29146,20 +30063,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action242(
+let ___temp0 = ___action245(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action372(
+___action375(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action644<
+fn ___action666<
     'input,
 >(
 text: &'input str,
@@ -29169,12 +30086,12 @@ ___1: (usize, Symbol, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action241(
+let ___temp0 = ___action244(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action373(
+___action376(
 text,
 ___0,
 ___temp0,
@@ -29182,7 +30099,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action645<
+fn ___action667<
     'input,
 >(
 text: &'input str,
@@ -29191,13 +30108,13 @@ ___0: (usize, ::std::vec::Vec<Symbol>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action242(
+let ___temp0 = ___action245(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action373(
+___action376(
 text,
 ___0,
 ___temp0,
@@ -29205,7 +30122,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action646<
+fn ___action668<
     'input,
 >(
 text: &'input str,
@@ -29214,19 +30131,19 @@ ___0: (usize, TypeBound<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action211(
+let ___temp0 = ___action214(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action376(
+___action379(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action647<
+fn ___action669<
     'input,
 >(
 text: &'input str,
@@ -29236,20 +30153,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action212(
+let ___temp0 = ___action215(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action376(
+___action379(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action648<
+fn ___action670<
     'input,
 >(
 text: &'input str,
@@ -29259,12 +30176,12 @@ ___1: (usize, TypeBound<TypeRef>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action211(
+let ___temp0 = ___action214(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action377(
+___action380(
 text,
 ___0,
 ___temp0,
@@ -29272,7 +30189,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action649<
+fn ___action671<
     'input,
 >(
 text: &'input str,
@@ -29281,13 +30198,13 @@ ___0: (usize, ::std::vec::Vec<TypeBound<TypeRef>>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action212(
+let ___temp0 = ___action215(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action377(
+___action380(
 text,
 ___0,
 ___temp0,
@@ -29295,7 +30212,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action650<
+fn ___action672<
     'input,
 >(
 text: &'input str,
@@ -29304,19 +30221,19 @@ ___0: (usize, TypeBoundParameter<TypeRef>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action221(
+let ___temp0 = ___action224(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action380(
+___action383(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action651<
+fn ___action673<
     'input,
 >(
 text: &'input str,
@@ -29326,20 +30243,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action222(
+let ___temp0 = ___action225(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action380(
+___action383(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action652<
+fn ___action674<
     'input,
 >(
 text: &'input str,
@@ -29349,12 +30266,12 @@ ___1: (usize, TypeBoundParameter<TypeRef>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action221(
+let ___temp0 = ___action224(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action381(
+___action384(
 text,
 ___0,
 ___temp0,
@@ -29362,7 +30279,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action653<
+fn ___action675<
     'input,
 >(
 text: &'input str,
@@ -29371,13 +30288,13 @@ ___0: (usize, ::std::vec::Vec<TypeBoundParameter<TypeRef>>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action222(
+let ___temp0 = ___action225(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action381(
+___action384(
 text,
 ___0,
 ___temp0,
@@ -29385,7 +30302,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action654<
+fn ___action676<
     'input,
 >(
 text: &'input str,
@@ -29394,19 +30311,19 @@ ___0: (usize, TypeParameter, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action198(
+let ___temp0 = ___action201(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action384(
+___action387(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action655<
+fn ___action677<
     'input,
 >(
 text: &'input str,
@@ -29416,20 +30333,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action199(
+let ___temp0 = ___action202(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action384(
+___action387(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action656<
+fn ___action678<
     'input,
 >(
 text: &'input str,
@@ -29439,12 +30356,12 @@ ___1: (usize, TypeParameter, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action198(
+let ___temp0 = ___action201(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action385(
+___action388(
 text,
 ___0,
 ___temp0,
@@ -29452,7 +30369,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action657<
+fn ___action679<
     'input,
 >(
 text: &'input str,
@@ -29461,13 +30378,13 @@ ___0: (usize, ::std::vec::Vec<TypeParameter>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action199(
+let ___temp0 = ___action202(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action385(
+___action388(
 text,
 ___0,
 ___temp0,
@@ -29475,7 +30392,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action658<
+fn ___action680<
     'input,
 >(
 text: &'input str,
@@ -29484,19 +30401,19 @@ ___0: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action216(
+let ___temp0 = ___action219(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action388(
+___action391(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action659<
+fn ___action681<
     'input,
 >(
 text: &'input str,
@@ -29506,20 +30423,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action217(
+let ___temp0 = ___action220(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action388(
+___action391(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action660<
+fn ___action682<
     'input,
 >(
 text: &'input str,
@@ -29529,12 +30446,12 @@ ___1: (usize, TypeRef, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action216(
+let ___temp0 = ___action219(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action389(
+___action392(
 text,
 ___0,
 ___temp0,
@@ -29542,7 +30459,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action661<
+fn ___action683<
     'input,
 >(
 text: &'input str,
@@ -29551,13 +30468,13 @@ ___0: (usize, ::std::vec::Vec<TypeRef>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action217(
+let ___temp0 = ___action220(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action389(
+___action392(
 text,
 ___0,
 ___temp0,
@@ -29565,7 +30482,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action662<
+fn ___action684<
     'input,
 >(
 text: &'input str,
@@ -29574,19 +30491,19 @@ ___0: (usize, TypeRef, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action246(
+let ___temp0 = ___action249(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action392(
+___action395(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action663<
+fn ___action685<
     'input,
 >(
 text: &'input str,
@@ -29596,20 +30513,20 @@ ___lookahead: &usize,
 {
 let ___start0 = ___lookbehind.clone();
 let ___end0 = ___lookahead.clone();
-let ___temp0 = ___action247(
+let ___temp0 = ___action250(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action392(
+___action395(
 text,
 ___temp0,
 )
 }
 
 #[allow(unused_variables)]
-fn ___action664<
+fn ___action686<
     'input,
 >(
 text: &'input str,
@@ -29619,12 +30536,12 @@ ___1: (usize, TypeRef, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action246(
+let ___temp0 = ___action249(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action393(
+___action396(
 text,
 ___0,
 ___temp0,
@@ -29632,7 +30549,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action665<
+fn ___action687<
     'input,
 >(
 text: &'input str,
@@ -29641,13 +30558,13 @@ ___0: (usize, ::std::vec::Vec<TypeRef>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action247(
+let ___temp0 = ___action250(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action393(
+___action396(
 text,
 ___0,
 ___temp0,
@@ -29655,7 +30572,7 @@ ___temp0,
 }
 
 #[allow(unused_variables)]
-fn ___action666<
+fn ___action688<
     'input,
 >(
 text: &'input str,
@@ -29668,13 +30585,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action576(
+___action598(
 text,
 ___temp0,
 ___0,
@@ -29686,7 +30603,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action667<
+fn ___action689<
     'input,
 >(
 text: &'input str,
@@ -29700,12 +30617,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action576(
+___action598(
 text,
 ___temp0,
 ___1,
@@ -29717,7 +30634,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action668<
+fn ___action690<
     'input,
 >(
 text: &'input str,
@@ -29731,13 +30648,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action577(
+___action599(
 text,
 ___0,
 ___temp0,
@@ -29750,7 +30667,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action669<
+fn ___action691<
     'input,
 >(
 text: &'input str,
@@ -29765,12 +30682,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action577(
+___action599(
 text,
 ___0,
 ___temp0,
@@ -29783,7 +30700,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action670<
+fn ___action692<
     'input,
 >(
 text: &'input str,
@@ -29795,13 +30712,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action578(
+___action600(
 text,
 ___temp0,
 ___0,
@@ -29812,7 +30729,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action671<
+fn ___action693<
     'input,
 >(
 text: &'input str,
@@ -29825,12 +30742,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action578(
+___action600(
 text,
 ___temp0,
 ___1,
@@ -29841,7 +30758,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action672<
+fn ___action694<
     'input,
 >(
 text: &'input str,
@@ -29854,13 +30771,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action579(
+___action601(
 text,
 ___0,
 ___temp0,
@@ -29872,7 +30789,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action673<
+fn ___action695<
     'input,
 >(
 text: &'input str,
@@ -29886,12 +30803,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action579(
+___action601(
 text,
 ___0,
 ___temp0,
@@ -29903,7 +30820,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action674<
+fn ___action696<
     'input,
 >(
 text: &'input str,
@@ -29915,13 +30832,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action580(
+___action602(
 text,
 ___temp0,
 ___0,
@@ -29932,7 +30849,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action675<
+fn ___action697<
     'input,
 >(
 text: &'input str,
@@ -29945,12 +30862,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action580(
+___action602(
 text,
 ___temp0,
 ___1,
@@ -29961,7 +30878,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action676<
+fn ___action698<
     'input,
 >(
 text: &'input str,
@@ -29974,13 +30891,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action581(
+___action603(
 text,
 ___0,
 ___temp0,
@@ -29992,7 +30909,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action677<
+fn ___action699<
     'input,
 >(
 text: &'input str,
@@ -30006,12 +30923,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action581(
+___action603(
 text,
 ___0,
 ___temp0,
@@ -30023,7 +30940,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action678<
+fn ___action700<
     'input,
 >(
 text: &'input str,
@@ -30034,13 +30951,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action582(
+___action604(
 text,
 ___temp0,
 ___0,
@@ -30050,7 +30967,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action679<
+fn ___action701<
     'input,
 >(
 text: &'input str,
@@ -30062,12 +30979,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action582(
+___action604(
 text,
 ___temp0,
 ___1,
@@ -30077,7 +30994,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action680<
+fn ___action702<
     'input,
 >(
 text: &'input str,
@@ -30089,13 +31006,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action583(
+___action605(
 text,
 ___0,
 ___temp0,
@@ -30106,7 +31023,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action681<
+fn ___action703<
     'input,
 >(
 text: &'input str,
@@ -30119,12 +31036,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action583(
+___action605(
 text,
 ___0,
 ___temp0,
@@ -30135,7 +31052,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action682<
+fn ___action704<
     'input,
 >(
 text: &'input str,
@@ -30147,13 +31064,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action584(
+___action606(
 text,
 ___temp0,
 ___0,
@@ -30164,7 +31081,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action683<
+fn ___action705<
     'input,
 >(
 text: &'input str,
@@ -30177,12 +31094,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action584(
+___action606(
 text,
 ___temp0,
 ___1,
@@ -30193,7 +31110,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action684<
+fn ___action706<
     'input,
 >(
 text: &'input str,
@@ -30206,13 +31123,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action585(
+___action607(
 text,
 ___0,
 ___temp0,
@@ -30224,7 +31141,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action685<
+fn ___action707<
     'input,
 >(
 text: &'input str,
@@ -30238,12 +31155,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action585(
+___action607(
 text,
 ___0,
 ___temp0,
@@ -30255,7 +31172,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action686<
+fn ___action708<
     'input,
 >(
 text: &'input str,
@@ -30266,13 +31183,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action586(
+___action608(
 text,
 ___temp0,
 ___0,
@@ -30282,7 +31199,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action687<
+fn ___action709<
     'input,
 >(
 text: &'input str,
@@ -30294,12 +31211,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action586(
+___action608(
 text,
 ___temp0,
 ___1,
@@ -30309,7 +31226,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action688<
+fn ___action710<
     'input,
 >(
 text: &'input str,
@@ -30321,13 +31238,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action587(
+___action609(
 text,
 ___0,
 ___temp0,
@@ -30338,7 +31255,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action689<
+fn ___action711<
     'input,
 >(
 text: &'input str,
@@ -30351,12 +31268,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action587(
+___action609(
 text,
 ___0,
 ___temp0,
@@ -30367,7 +31284,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action690<
+fn ___action712<
     'input,
 >(
 text: &'input str,
@@ -30378,13 +31295,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action588(
+___action610(
 text,
 ___temp0,
 ___0,
@@ -30394,7 +31311,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action691<
+fn ___action713<
     'input,
 >(
 text: &'input str,
@@ -30406,12 +31323,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action588(
+___action610(
 text,
 ___temp0,
 ___1,
@@ -30421,7 +31338,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action692<
+fn ___action714<
     'input,
 >(
 text: &'input str,
@@ -30433,13 +31350,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action589(
+___action611(
 text,
 ___0,
 ___temp0,
@@ -30450,7 +31367,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action693<
+fn ___action715<
     'input,
 >(
 text: &'input str,
@@ -30463,12 +31380,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action589(
+___action611(
 text,
 ___0,
 ___temp0,
@@ -30479,7 +31396,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action694<
+fn ___action716<
     'input,
 >(
 text: &'input str,
@@ -30489,13 +31406,13 @@ ___1: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action590(
+___action612(
 text,
 ___temp0,
 ___0,
@@ -30504,7 +31421,7 @@ ___1,
 }
 
 #[allow(unused_variables)]
-fn ___action695<
+fn ___action717<
     'input,
 >(
 text: &'input str,
@@ -30515,12 +31432,12 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action590(
+___action612(
 text,
 ___temp0,
 ___1,
@@ -30529,7 +31446,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action696<
+fn ___action718<
     'input,
 >(
 text: &'input str,
@@ -30540,13 +31457,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action591(
+___action613(
 text,
 ___0,
 ___temp0,
@@ -30556,7 +31473,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action697<
+fn ___action719<
     'input,
 >(
 text: &'input str,
@@ -30568,12 +31485,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action591(
+___action613(
 text,
 ___0,
 ___temp0,
@@ -30583,7 +31500,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action698<
+fn ___action720<
     'input,
 >(
 text: &'input str,
@@ -30597,13 +31514,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action592(
+___action614(
 text,
 ___temp0,
 ___0,
@@ -30616,7 +31533,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action699<
+fn ___action721<
     'input,
 >(
 text: &'input str,
@@ -30631,12 +31548,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action592(
+___action614(
 text,
 ___temp0,
 ___1,
@@ -30649,7 +31566,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action700<
+fn ___action722<
     'input,
 >(
 text: &'input str,
@@ -30664,13 +31581,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action593(
+___action615(
 text,
 ___0,
 ___temp0,
@@ -30684,7 +31601,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action701<
+fn ___action723<
     'input,
 >(
 text: &'input str,
@@ -30700,12 +31617,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action593(
+___action615(
 text,
 ___0,
 ___temp0,
@@ -30719,7 +31636,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action702<
+fn ___action724<
     'input,
 >(
 text: &'input str,
@@ -30732,13 +31649,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action594(
+___action616(
 text,
 ___temp0,
 ___0,
@@ -30750,7 +31667,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action703<
+fn ___action725<
     'input,
 >(
 text: &'input str,
@@ -30764,12 +31681,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action594(
+___action616(
 text,
 ___temp0,
 ___1,
@@ -30781,7 +31698,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action704<
+fn ___action726<
     'input,
 >(
 text: &'input str,
@@ -30795,13 +31712,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action595(
+___action617(
 text,
 ___0,
 ___temp0,
@@ -30814,7 +31731,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action705<
+fn ___action727<
     'input,
 >(
 text: &'input str,
@@ -30829,12 +31746,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action595(
+___action617(
 text,
 ___0,
 ___temp0,
@@ -30847,7 +31764,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action706<
+fn ___action728<
     'input,
 >(
 text: &'input str,
@@ -30860,13 +31777,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action596(
+___action618(
 text,
 ___temp0,
 ___0,
@@ -30878,7 +31795,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action707<
+fn ___action729<
     'input,
 >(
 text: &'input str,
@@ -30892,12 +31809,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action596(
+___action618(
 text,
 ___temp0,
 ___1,
@@ -30909,7 +31826,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action708<
+fn ___action730<
     'input,
 >(
 text: &'input str,
@@ -30923,13 +31840,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action597(
+___action619(
 text,
 ___0,
 ___temp0,
@@ -30942,7 +31859,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action709<
+fn ___action731<
     'input,
 >(
 text: &'input str,
@@ -30957,12 +31874,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action597(
+___action619(
 text,
 ___0,
 ___temp0,
@@ -30975,7 +31892,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action710<
+fn ___action732<
     'input,
 >(
 text: &'input str,
@@ -30987,13 +31904,13 @@ ___3: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action598(
+___action620(
 text,
 ___temp0,
 ___0,
@@ -31004,7 +31921,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action711<
+fn ___action733<
     'input,
 >(
 text: &'input str,
@@ -31017,12 +31934,12 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action598(
+___action620(
 text,
 ___temp0,
 ___1,
@@ -31033,7 +31950,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action712<
+fn ___action734<
     'input,
 >(
 text: &'input str,
@@ -31046,13 +31963,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action599(
+___action621(
 text,
 ___0,
 ___temp0,
@@ -31064,7 +31981,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action713<
+fn ___action735<
     'input,
 >(
 text: &'input str,
@@ -31078,12 +31995,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action599(
+___action621(
 text,
 ___0,
 ___temp0,
@@ -31095,7 +32012,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action714<
+fn ___action736<
     'input,
 >(
 text: &'input str,
@@ -31108,13 +32025,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action600(
+___action622(
 text,
 ___temp0,
 ___0,
@@ -31126,7 +32043,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action715<
+fn ___action737<
     'input,
 >(
 text: &'input str,
@@ -31140,12 +32057,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action600(
+___action622(
 text,
 ___temp0,
 ___1,
@@ -31157,7 +32074,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action716<
+fn ___action738<
     'input,
 >(
 text: &'input str,
@@ -31171,13 +32088,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action601(
+___action623(
 text,
 ___0,
 ___temp0,
@@ -31190,7 +32107,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action717<
+fn ___action739<
     'input,
 >(
 text: &'input str,
@@ -31205,12 +32122,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action601(
+___action623(
 text,
 ___0,
 ___temp0,
@@ -31223,7 +32140,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action718<
+fn ___action740<
     'input,
 >(
 text: &'input str,
@@ -31235,13 +32152,13 @@ ___3: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action602(
+___action624(
 text,
 ___temp0,
 ___0,
@@ -31252,7 +32169,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action719<
+fn ___action741<
     'input,
 >(
 text: &'input str,
@@ -31265,12 +32182,12 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action602(
+___action624(
 text,
 ___temp0,
 ___1,
@@ -31281,7 +32198,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action720<
+fn ___action742<
     'input,
 >(
 text: &'input str,
@@ -31294,13 +32211,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action603(
+___action625(
 text,
 ___0,
 ___temp0,
@@ -31312,7 +32229,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action721<
+fn ___action743<
     'input,
 >(
 text: &'input str,
@@ -31326,12 +32243,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action603(
+___action625(
 text,
 ___0,
 ___temp0,
@@ -31343,7 +32260,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action722<
+fn ___action744<
     'input,
 >(
 text: &'input str,
@@ -31355,13 +32272,13 @@ ___3: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action604(
+___action626(
 text,
 ___temp0,
 ___0,
@@ -31372,7 +32289,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action723<
+fn ___action745<
     'input,
 >(
 text: &'input str,
@@ -31385,12 +32302,12 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action604(
+___action626(
 text,
 ___temp0,
 ___1,
@@ -31401,7 +32318,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action724<
+fn ___action746<
     'input,
 >(
 text: &'input str,
@@ -31414,13 +32331,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action605(
+___action627(
 text,
 ___0,
 ___temp0,
@@ -31432,7 +32349,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action725<
+fn ___action747<
     'input,
 >(
 text: &'input str,
@@ -31446,12 +32363,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action605(
+___action627(
 text,
 ___0,
 ___temp0,
@@ -31463,7 +32380,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action726<
+fn ___action748<
     'input,
 >(
 text: &'input str,
@@ -31474,13 +32391,13 @@ ___2: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action606(
+___action628(
 text,
 ___temp0,
 ___0,
@@ -31490,7 +32407,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action727<
+fn ___action749<
     'input,
 >(
 text: &'input str,
@@ -31502,12 +32419,12 @@ ___3: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action606(
+___action628(
 text,
 ___temp0,
 ___1,
@@ -31517,7 +32434,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action728<
+fn ___action750<
     'input,
 >(
 text: &'input str,
@@ -31529,13 +32446,13 @@ ___3: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action607(
+___action629(
 text,
 ___0,
 ___temp0,
@@ -31546,7 +32463,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action729<
+fn ___action751<
     'input,
 >(
 text: &'input str,
@@ -31559,12 +32476,12 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action607(
+___action629(
 text,
 ___0,
 ___temp0,
@@ -31575,7 +32492,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action730<
+fn ___action752<
     'input,
 >(
 text: &'input str,
@@ -31589,13 +32506,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action608(
+___action630(
 text,
 ___temp0,
 ___0,
@@ -31608,7 +32525,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action731<
+fn ___action753<
     'input,
 >(
 text: &'input str,
@@ -31623,12 +32540,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action608(
+___action630(
 text,
 ___temp0,
 ___1,
@@ -31641,7 +32558,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action732<
+fn ___action754<
     'input,
 >(
 text: &'input str,
@@ -31656,13 +32573,13 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action609(
+___action631(
 text,
 ___0,
 ___temp0,
@@ -31676,7 +32593,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action733<
+fn ___action755<
     'input,
 >(
 text: &'input str,
@@ -31692,12 +32609,12 @@ ___7: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action609(
+___action631(
 text,
 ___0,
 ___temp0,
@@ -31711,7 +32628,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action734<
+fn ___action756<
     'input,
 >(
 text: &'input str,
@@ -31724,13 +32641,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action610(
+___action632(
 text,
 ___temp0,
 ___0,
@@ -31742,7 +32659,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action735<
+fn ___action757<
     'input,
 >(
 text: &'input str,
@@ -31756,12 +32673,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action610(
+___action632(
 text,
 ___temp0,
 ___1,
@@ -31773,7 +32690,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action736<
+fn ___action758<
     'input,
 >(
 text: &'input str,
@@ -31787,13 +32704,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action611(
+___action633(
 text,
 ___0,
 ___temp0,
@@ -31806,7 +32723,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action737<
+fn ___action759<
     'input,
 >(
 text: &'input str,
@@ -31821,12 +32738,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action611(
+___action633(
 text,
 ___0,
 ___temp0,
@@ -31839,7 +32756,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action738<
+fn ___action760<
     'input,
 >(
 text: &'input str,
@@ -31852,13 +32769,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action612(
+___action634(
 text,
 ___temp0,
 ___0,
@@ -31870,7 +32787,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action739<
+fn ___action761<
     'input,
 >(
 text: &'input str,
@@ -31884,12 +32801,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action612(
+___action634(
 text,
 ___temp0,
 ___1,
@@ -31901,7 +32818,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action740<
+fn ___action762<
     'input,
 >(
 text: &'input str,
@@ -31915,13 +32832,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action613(
+___action635(
 text,
 ___0,
 ___temp0,
@@ -31934,7 +32851,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action741<
+fn ___action763<
     'input,
 >(
 text: &'input str,
@@ -31949,12 +32866,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action613(
+___action635(
 text,
 ___0,
 ___temp0,
@@ -31967,7 +32884,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action742<
+fn ___action764<
     'input,
 >(
 text: &'input str,
@@ -31979,13 +32896,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action614(
+___action636(
 text,
 ___temp0,
 ___0,
@@ -31996,7 +32913,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action743<
+fn ___action765<
     'input,
 >(
 text: &'input str,
@@ -32009,12 +32926,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action614(
+___action636(
 text,
 ___temp0,
 ___1,
@@ -32025,7 +32942,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action744<
+fn ___action766<
     'input,
 >(
 text: &'input str,
@@ -32038,13 +32955,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action615(
+___action637(
 text,
 ___0,
 ___temp0,
@@ -32056,7 +32973,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action745<
+fn ___action767<
     'input,
 >(
 text: &'input str,
@@ -32070,12 +32987,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action615(
+___action637(
 text,
 ___0,
 ___temp0,
@@ -32087,7 +33004,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action746<
+fn ___action768<
     'input,
 >(
 text: &'input str,
@@ -32100,13 +33017,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action616(
+___action638(
 text,
 ___temp0,
 ___0,
@@ -32118,7 +33035,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action747<
+fn ___action769<
     'input,
 >(
 text: &'input str,
@@ -32132,12 +33049,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action616(
+___action638(
 text,
 ___temp0,
 ___1,
@@ -32149,7 +33066,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action748<
+fn ___action770<
     'input,
 >(
 text: &'input str,
@@ -32163,13 +33080,13 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action617(
+___action639(
 text,
 ___0,
 ___temp0,
@@ -32182,7 +33099,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action749<
+fn ___action771<
     'input,
 >(
 text: &'input str,
@@ -32197,12 +33114,12 @@ ___6: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action617(
+___action639(
 text,
 ___0,
 ___temp0,
@@ -32215,7 +33132,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action750<
+fn ___action772<
     'input,
 >(
 text: &'input str,
@@ -32227,13 +33144,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action618(
+___action640(
 text,
 ___temp0,
 ___0,
@@ -32244,7 +33161,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action751<
+fn ___action773<
     'input,
 >(
 text: &'input str,
@@ -32257,12 +33174,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action618(
+___action640(
 text,
 ___temp0,
 ___1,
@@ -32273,7 +33190,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action752<
+fn ___action774<
     'input,
 >(
 text: &'input str,
@@ -32286,13 +33203,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action619(
+___action641(
 text,
 ___0,
 ___temp0,
@@ -32304,7 +33221,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action753<
+fn ___action775<
     'input,
 >(
 text: &'input str,
@@ -32318,12 +33235,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action619(
+___action641(
 text,
 ___0,
 ___temp0,
@@ -32335,7 +33252,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action754<
+fn ___action776<
     'input,
 >(
 text: &'input str,
@@ -32347,13 +33264,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action620(
+___action642(
 text,
 ___temp0,
 ___0,
@@ -32364,7 +33281,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action755<
+fn ___action777<
     'input,
 >(
 text: &'input str,
@@ -32377,12 +33294,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action620(
+___action642(
 text,
 ___temp0,
 ___1,
@@ -32393,7 +33310,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action756<
+fn ___action778<
     'input,
 >(
 text: &'input str,
@@ -32406,13 +33323,13 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action621(
+___action643(
 text,
 ___0,
 ___temp0,
@@ -32424,7 +33341,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action757<
+fn ___action779<
     'input,
 >(
 text: &'input str,
@@ -32438,12 +33355,12 @@ ___5: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action621(
+___action643(
 text,
 ___0,
 ___temp0,
@@ -32455,7 +33372,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action758<
+fn ___action780<
     'input,
 >(
 text: &'input str,
@@ -32466,13 +33383,13 @@ ___2: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action622(
+___action644(
 text,
 ___temp0,
 ___0,
@@ -32482,7 +33399,7 @@ ___2,
 }
 
 #[allow(unused_variables)]
-fn ___action759<
+fn ___action781<
     'input,
 >(
 text: &'input str,
@@ -32494,12 +33411,12 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action622(
+___action644(
 text,
 ___temp0,
 ___1,
@@ -32509,7 +33426,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action760<
+fn ___action782<
     'input,
 >(
 text: &'input str,
@@ -32521,13 +33438,13 @@ ___3: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action623(
+___action645(
 text,
 ___0,
 ___temp0,
@@ -32538,7 +33455,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action761<
+fn ___action783<
     'input,
 >(
 text: &'input str,
@@ -32551,12 +33468,12 @@ ___4: (usize, Tok<'input>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action623(
+___action645(
 text,
 ___0,
 ___temp0,
@@ -32567,7 +33484,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action762<
+fn ___action784<
     'input,
 >(
 text: &'input str,
@@ -32582,13 +33499,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action624(
+___action646(
 text,
 ___temp0,
 ___0,
@@ -32602,7 +33519,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action763<
+fn ___action785<
     'input,
 >(
 text: &'input str,
@@ -32618,12 +33535,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action624(
+___action646(
 text,
 ___temp0,
 ___1,
@@ -32637,7 +33554,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action764<
+fn ___action786<
     'input,
 >(
 text: &'input str,
@@ -32653,13 +33570,13 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action625(
+___action647(
 text,
 ___0,
 ___temp0,
@@ -32674,7 +33591,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action765<
+fn ___action787<
     'input,
 >(
 text: &'input str,
@@ -32691,12 +33608,12 @@ ___8: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action625(
+___action647(
 text,
 ___0,
 ___temp0,
@@ -32711,7 +33628,7 @@ ___8,
 }
 
 #[allow(unused_variables)]
-fn ___action766<
+fn ___action788<
     'input,
 >(
 text: &'input str,
@@ -32725,13 +33642,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action626(
+___action648(
 text,
 ___temp0,
 ___0,
@@ -32744,7 +33661,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action767<
+fn ___action789<
     'input,
 >(
 text: &'input str,
@@ -32759,12 +33676,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action626(
+___action648(
 text,
 ___temp0,
 ___1,
@@ -32777,7 +33694,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action768<
+fn ___action790<
     'input,
 >(
 text: &'input str,
@@ -32792,13 +33709,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action627(
+___action649(
 text,
 ___0,
 ___temp0,
@@ -32812,7 +33729,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action769<
+fn ___action791<
     'input,
 >(
 text: &'input str,
@@ -32828,12 +33745,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action627(
+___action649(
 text,
 ___0,
 ___temp0,
@@ -32847,7 +33764,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action770<
+fn ___action792<
     'input,
 >(
 text: &'input str,
@@ -32861,13 +33778,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action628(
+___action650(
 text,
 ___temp0,
 ___0,
@@ -32880,7 +33797,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action771<
+fn ___action793<
     'input,
 >(
 text: &'input str,
@@ -32895,12 +33812,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action628(
+___action650(
 text,
 ___temp0,
 ___1,
@@ -32913,7 +33830,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action772<
+fn ___action794<
     'input,
 >(
 text: &'input str,
@@ -32928,13 +33845,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action629(
+___action651(
 text,
 ___0,
 ___temp0,
@@ -32948,7 +33865,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action773<
+fn ___action795<
     'input,
 >(
 text: &'input str,
@@ -32964,12 +33881,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action629(
+___action651(
 text,
 ___0,
 ___temp0,
@@ -32983,7 +33900,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action774<
+fn ___action796<
     'input,
 >(
 text: &'input str,
@@ -32996,13 +33913,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action630(
+___action652(
 text,
 ___temp0,
 ___0,
@@ -33014,7 +33931,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action775<
+fn ___action797<
     'input,
 >(
 text: &'input str,
@@ -33028,12 +33945,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action630(
+___action652(
 text,
 ___temp0,
 ___1,
@@ -33045,7 +33962,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action776<
+fn ___action798<
     'input,
 >(
 text: &'input str,
@@ -33059,13 +33976,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action631(
+___action653(
 text,
 ___0,
 ___temp0,
@@ -33078,7 +33995,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action777<
+fn ___action799<
     'input,
 >(
 text: &'input str,
@@ -33093,12 +34010,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action631(
+___action653(
 text,
 ___0,
 ___temp0,
@@ -33111,7 +34028,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action778<
+fn ___action800<
     'input,
 >(
 text: &'input str,
@@ -33125,13 +34042,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action632(
+___action654(
 text,
 ___temp0,
 ___0,
@@ -33144,7 +34061,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action779<
+fn ___action801<
     'input,
 >(
 text: &'input str,
@@ -33159,12 +34076,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action632(
+___action654(
 text,
 ___temp0,
 ___1,
@@ -33177,7 +34094,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action780<
+fn ___action802<
     'input,
 >(
 text: &'input str,
@@ -33192,13 +34109,13 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action633(
+___action655(
 text,
 ___0,
 ___temp0,
@@ -33212,7 +34129,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action781<
+fn ___action803<
     'input,
 >(
 text: &'input str,
@@ -33228,12 +34145,12 @@ ___7: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action633(
+___action655(
 text,
 ___0,
 ___temp0,
@@ -33247,7 +34164,7 @@ ___7,
 }
 
 #[allow(unused_variables)]
-fn ___action782<
+fn ___action804<
     'input,
 >(
 text: &'input str,
@@ -33260,13 +34177,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action634(
+___action656(
 text,
 ___temp0,
 ___0,
@@ -33278,7 +34195,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action783<
+fn ___action805<
     'input,
 >(
 text: &'input str,
@@ -33292,12 +34209,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action634(
+___action656(
 text,
 ___temp0,
 ___1,
@@ -33309,7 +34226,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action784<
+fn ___action806<
     'input,
 >(
 text: &'input str,
@@ -33323,13 +34240,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action635(
+___action657(
 text,
 ___0,
 ___temp0,
@@ -33342,7 +34259,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action785<
+fn ___action807<
     'input,
 >(
 text: &'input str,
@@ -33357,12 +34274,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action635(
+___action657(
 text,
 ___0,
 ___temp0,
@@ -33375,7 +34292,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action786<
+fn ___action808<
     'input,
 >(
 text: &'input str,
@@ -33388,13 +34305,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action636(
+___action658(
 text,
 ___temp0,
 ___0,
@@ -33406,7 +34323,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action787<
+fn ___action809<
     'input,
 >(
 text: &'input str,
@@ -33420,12 +34337,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action636(
+___action658(
 text,
 ___temp0,
 ___1,
@@ -33437,7 +34354,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action788<
+fn ___action810<
     'input,
 >(
 text: &'input str,
@@ -33451,13 +34368,13 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action637(
+___action659(
 text,
 ___0,
 ___temp0,
@@ -33470,7 +34387,7 @@ ___5,
 }
 
 #[allow(unused_variables)]
-fn ___action789<
+fn ___action811<
     'input,
 >(
 text: &'input str,
@@ -33485,12 +34402,12 @@ ___6: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action637(
+___action659(
 text,
 ___0,
 ___temp0,
@@ -33503,7 +34420,7 @@ ___6,
 }
 
 #[allow(unused_variables)]
-fn ___action790<
+fn ___action812<
     'input,
 >(
 text: &'input str,
@@ -33515,13 +34432,13 @@ ___3: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action638(
+___action660(
 text,
 ___temp0,
 ___0,
@@ -33532,7 +34449,7 @@ ___3,
 }
 
 #[allow(unused_variables)]
-fn ___action791<
+fn ___action813<
     'input,
 >(
 text: &'input str,
@@ -33545,12 +34462,12 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.0.clone();
 let ___end0 = ___0.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action638(
+___action660(
 text,
 ___temp0,
 ___1,
@@ -33561,7 +34478,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action792<
+fn ___action814<
     'input,
 >(
 text: &'input str,
@@ -33574,13 +34491,13 @@ ___4: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___0.2.clone();
 let ___end0 = ___1.0.clone();
-let ___temp0 = ___action186(
+let ___temp0 = ___action189(
 text,
 &___start0,
 &___end0,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action639(
+___action661(
 text,
 ___0,
 ___temp0,
@@ -33592,7 +34509,7 @@ ___4,
 }
 
 #[allow(unused_variables)]
-fn ___action793<
+fn ___action815<
     'input,
 >(
 text: &'input str,
@@ -33606,12 +34523,12 @@ ___5: (usize, ::std::vec::Vec<GrammarItem>, usize),
 {
 let ___start0 = ___1.0.clone();
 let ___end0 = ___1.2.clone();
-let ___temp0 = ___action187(
+let ___temp0 = ___action190(
 text,
 ___1,
 );
 let ___temp0 = (___start0, ___temp0, ___end0);
-___action639(
+___action661(
 text,
 ___0,
 ___temp0,
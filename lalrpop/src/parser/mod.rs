@@ -45,13 +45,18 @@ macro_rules! parser {
 
 pub fn parse_grammar<'input>(input: &'input str) -> Result<Grammar, ParseError<'input>> {
     let mut grammar = parser!(input, 0, Grammar, StartGrammar)?;
+    grammar.prefix = unique_prefix(input, &grammar.prefix);
+    Ok(grammar)
+}
 
-    // find a unique prefix that does not appear anywhere in the input
-    while input.contains(&grammar.prefix) {
-        grammar.prefix.push('_');
+/// Extends `prefix` with underscores until it does not appear anywhere
+/// in `input`.
+pub fn unique_prefix(input: &str, prefix: &str) -> String {
+    let mut prefix = prefix.to_owned();
+    while input.contains(&prefix) {
+        prefix.push('_');
     }
-
-    Ok(grammar)
+    prefix
 }
 
 fn parse_pattern<'input>(
@@ -12,6 +12,7 @@ fn match_block() {
         r#"grammar; match { r"(?i)begin" => BEGIN }"#, // Regex
         r#"grammar; match { "abc", "def" => "DEF", _ } else { "foo" => BAR, r"(?i)begin" => BEGIN, _ }"#, // Complex
         r#"grammar; match { "abc" } else { "def" } else { _ }"#, // Multi-chain
+        r#"grammar; match { "/*" => { "*/" }, _ }"#,             // Nested skip block
     ];
 
     for block in blocks {
@@ -52,7 +53,7 @@ fn match_complex() {
             // r"(?i)begin" => "BEGIN"
             let item00 = contents0.items.get(0).unwrap();
             match *item00 {
-                MatchItem::Mapped(ref sym, ref mapping, _) => {
+                MatchItem::Mapped(ref sym, ref mapping, _, _) => {
                     assert_eq!(format!("{:?}", sym), "r#\"(?i)begin\"#");
                     assert_eq!(format!("{}", mapping), "\"BEGIN\"");
                 }
@@ -61,7 +62,7 @@ fn match_complex() {
             // r"(?i)end" => "END",
             let item01 = contents0.items.get(1).unwrap();
             match *item01 {
-                MatchItem::Mapped(ref sym, ref mapping, _) => {
+                MatchItem::Mapped(ref sym, ref mapping, _, _) => {
                     assert_eq!(format!("{:?}", sym), "r#\"(?i)end\"#");
                     assert_eq!(format!("{}", mapping), "\"END\"");
                 }
@@ -72,7 +73,7 @@ fn match_complex() {
             // r"[a-zA-Z_][a-zA-Z0-9_]*" => IDENTIFIER,
             let item10 = contents1.items.get(0).unwrap();
             match *item10 {
-                MatchItem::Mapped(ref sym, ref mapping, _) => {
+                MatchItem::Mapped(ref sym, ref mapping, _, _) => {
                     assert_eq!(format!("{:?}", sym), "r#\"[a-zA-Z_][a-zA-Z0-9_]*\"#");
                     assert_eq!(format!("{}", mapping), "IDENTIFIER");
                 }
@@ -83,7 +84,7 @@ fn match_complex() {
             // "other",
             let item20 = contents2.items.get(0).unwrap();
             match *item20 {
-                MatchItem::Unmapped(ref sym, _) => {
+                MatchItem::Unmapped(ref sym, _, _) => {
                     assert_eq!(format!("{:?}", sym), "\"other\"");
                 }
                 _ => panic!("expected MatchItem::Unmapped, but was: {:?}", item20),
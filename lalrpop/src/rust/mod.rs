@@ -143,12 +143,17 @@ impl<'me, W: Write> RustWrite<W> {
     pub fn write_standard_uses(&mut self, prefix: &str) -> io::Result<()> {
         // Stuff that we plan to use.
         // Occasionally we happen to not use it after all, hence the allow.
-        rust!(self, "#[allow(unused_extern_crates)]");
-        rust!(
-            self,
-            "extern crate lalrpop_util as {p}lalrpop_util;",
-            p = prefix,
-        );
+        if Tls::session().emit_extern_crate {
+            rust!(self, "#[allow(unused_extern_crates)]");
+            rust!(
+                self,
+                "extern crate lalrpop_util as {p}lalrpop_util;",
+                p = prefix,
+            );
+        } else {
+            rust!(self, "#[allow(unused_imports)]");
+            rust!(self, "use ::lalrpop_util as {p}lalrpop_util;", p = prefix);
+        }
         rust!(self, "#[allow(unused_imports)]");
         rust!(
             self,
@@ -50,6 +50,67 @@ pub struct Session {
     /// Emit report file about generated code
     pub emit_report: bool,
 
+    /// Emit a GraphViz file of the LR states for each public symbol.
+    pub emit_dot: bool,
+
+    /// Report conflicts as JSON rather than as text.
+    pub emit_json_conflicts: bool,
+
+    /// Report each conflict on a single line rather than with example
+    /// pictures.
+    pub compact_conflicts: bool,
+
+    /// Show terminals that a `match` block renames by the literal or
+    /// regular expression they match when printing conflict examples.
+    pub literal_conflict_examples: bool,
+
+    /// Show the LR item the parser is in under each example in
+    /// conflict reports.
+    pub conflict_items: bool,
+
+    /// Declare `extern crate lalrpop_util` in the generated modules,
+    /// rather than referring to it as `::lalrpop_util`.
+    pub emit_extern_crate: bool,
+
+    /// Mark the generated `parse` methods with `#[must_use]`.
+    pub emit_must_use: bool,
+
+    /// Emit a `dump` method next to each `parse` method that parses
+    /// the input and pretty-prints the result using `Debug`.
+    pub emit_dump_helpers: bool,
+
+    /// Emit a `parse_boxed` method next to each `parse` method that
+    /// returns its error as a `Box<dyn Error>`.
+    pub emit_boxed_errors: bool,
+
+    /// Emit a `parse_items` method next to each `parse` method that
+    /// parses one instance of the start symbol at a time.
+    pub emit_item_parsers: bool,
+
+    /// Emit a `parse_prefix` method next to each `parse` method that
+    /// parses a prefix of its input and reports where it stopped.
+    pub emit_prefix_parsers: bool,
+
+    /// Emit `tokenize` and `terminal_name` methods on each parser that
+    /// uses the built-in lexer, to run the lexer on its own, and a
+    /// `parse_tokens` method to run the parser on its own.
+    pub emit_tokenizers: bool,
+
+    /// Emit a `STATE_COUNT` constant on each parser and have it call a
+    /// `trace_state` function in scope each time it enters a state.
+    pub emit_state_trace: bool,
+
+    /// Have the generated tokenizer read `&[u8]` rather than `&str`.
+    pub byte_input: bool,
+
+    /// Attach the locations of the reduced symbols to `User` errors
+    /// returned by fallible actions.
+    pub user_error_spans: bool,
+
+    /// Emit regex terminals as written, leaving Unicode classes for
+    /// the `regex` crate to resolve, rather than expanded to ranges.
+    pub unicode_classes: bool,
+
     pub color_config: ColorConfig,
 
     /// Stop after you find `max_errors` errors. If this value is 0,
@@ -57,6 +118,19 @@ pub struct Session {
     /// this value if we so choose.
     pub max_errors: usize,
 
+    /// Examples in conflict reports with more symbols than this are
+    /// left out, unless there is no shorter one.
+    pub max_example_symbols: usize,
+
+    /// Warn about `#[inline]` nonterminals whose inlining multiplies
+    /// the number of LR states by more than this factor. If this value
+    /// is 0, do not check.
+    pub max_inline_growth: usize,
+
+    /// Warn about terminals that no production uses and nonterminals
+    /// that no public nonterminal derives.
+    pub warn_unused: bool,
+
     // Styles to use when formatting error reports
     /// Applied to the heading in a message.
     pub heading: Style,
@@ -88,6 +162,11 @@ pub struct Session {
 
     /// Features used for conditional compilation
     pub features: Option<BTreeSet<String>>,
+
+    /// Prefix for the names of generated items, like `__action0`. If
+    /// `None`, `__` is used. Underscores are added to it until it does
+    /// not occur in the grammar.
+    pub prefix: Option<String>,
 }
 
 impl Session {
@@ -101,8 +180,27 @@ impl Session {
             emit_comments: false,
             emit_whitespace: true,
             emit_report: false,
+            emit_dot: false,
+            emit_json_conflicts: false,
+            compact_conflicts: false,
+            literal_conflict_examples: false,
+            conflict_items: false,
+            emit_extern_crate: true,
+            emit_must_use: true,
+            emit_dump_helpers: false,
+            emit_boxed_errors: false,
+            emit_item_parsers: false,
+            emit_prefix_parsers: false,
+            emit_tokenizers: false,
+            emit_state_trace: false,
+            byte_input: false,
+            user_error_spans: false,
+            unicode_classes: false,
             color_config: ColorConfig::default(),
             max_errors: 1,
+            max_example_symbols: 20,
+            max_inline_growth: 0,
+            warn_unused: false,
             heading: style::FG_WHITE.with(style::BOLD),
             ambig_symbols: style::FG_WHITE,
             observed_symbols: style::FG_BRIGHT_GREEN,
@@ -113,6 +211,7 @@ impl Session {
             hint_text: style::FG_BRIGHT_MAGENTA.with(style::BOLD),
             unit_test: false,
             features: Default::default(),
+            prefix: None,
         }
     }
 
@@ -128,8 +227,27 @@ impl Session {
             emit_comments: false,
             emit_whitespace: true,
             emit_report: false,
+            emit_dot: false,
+            emit_json_conflicts: false,
+            compact_conflicts: false,
+            literal_conflict_examples: false,
+            conflict_items: false,
+            emit_extern_crate: true,
+            emit_must_use: true,
+            emit_dump_helpers: false,
+            emit_boxed_errors: false,
+            emit_item_parsers: false,
+            emit_prefix_parsers: false,
+            emit_tokenizers: false,
+            emit_state_trace: false,
+            byte_input: false,
+            user_error_spans: false,
+            unicode_classes: false,
             color_config: ColorConfig::IfTty,
             max_errors: 1,
+            max_example_symbols: 20,
+            max_inline_growth: 0,
+            warn_unused: false,
             heading: Style::new(),
             ambig_symbols: Style::new(),
             observed_symbols: Style::new(),
@@ -140,6 +258,7 @@ impl Session {
             hint_text: Style::new(),
             unit_test: true,
             features: Default::default(),
+            prefix: None,
         }
     }
 
@@ -156,6 +275,16 @@ impl Session {
         self.log.log(level, message)
     }
 
+    /// Indicates whether error reports should be styled with ANSI
+    /// colors, resolving `ColorConfig::IfTty` against stdout.
+    pub fn use_colors(&self) -> bool {
+        match self.color_config {
+            ColorConfig::Yes => true,
+            ColorConfig::No => false,
+            ColorConfig::IfTty => atty::is(atty::Stream::Stdout),
+        }
+    }
+
     pub fn emit_rerun_directive(&self, path: &path::Path) {
         if self.emit_rerun_directives {
             if let Some(display) = path.to_str() {
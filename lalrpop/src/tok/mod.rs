@@ -63,6 +63,7 @@ pub enum Tok<'input> {
     StringLiteral(&'input str), // excludes the `"`
     CharLiteral(&'input str),   // excludes the `'`
     RegexLiteral(&'input str),  // excludes the `r"` and `"`
+    CaseInsensitiveLiteral(&'input str), // excludes the `i"` and `"`
 
     // Symbols:
     Ampersand,
@@ -373,6 +374,13 @@ impl<'input> Tokenizer<'input> {
                                 Some(self.identifierish(idx0))
                             }
                         }
+                    } else if c == 'i' {
+                        // watch out for i"..." strings
+                        self.bump();
+                        match self.lookahead {
+                            Some((_, '"')) => Some(self.case_insensitive_literal(idx0)),
+                            _ => Some(self.identifierish(idx0)),
+                        }
                     } else {
                         Some(self.identifierish(idx0))
                     }
@@ -562,6 +570,17 @@ impl<'input> Tokenizer<'input> {
         }
     }
 
+    // parses `i"..."`, starts after the `i` has been consumed; idx0
+    // points at the `i`
+    fn case_insensitive_literal(&mut self, idx0: usize) -> Result<Spanned<Tok<'input>>, Error> {
+        let idx1 = idx0 + 1; // the opening quote
+        self.bump();
+        match self.string_or_char_literal(idx1, '"', CaseInsensitiveLiteral) {
+            Some((_, tok, end)) => Ok((idx0, tok, end)),
+            None => error(UnterminatedStringLiteral, idx0),
+        }
+    }
+
     // parses `r#"..."#` (for some number of #), starts after the `r`
     // has been consumed; idx0 points at the `r`
     fn regex_literal(&mut self, idx0: usize) -> Result<Spanned<Tok<'input>>, Error> {
@@ -680,6 +680,18 @@ fn regex2() {
     test(r#"r"(123""#, vec![(r#"~~~~~~~"#, RegexLiteral(r"(123"))]);
 }
 
+#[test]
+fn case_insensitive_literal() {
+    test(
+        r#"i"select" id i "#,
+        vec![
+            (r#"~~~~~~~~~      "#, CaseInsensitiveLiteral("select")),
+            (r#"          ~~   "#, Id("id")),
+            (r#"             ~ "#, Id("i")),
+        ],
+    );
+}
+
 #[test]
 fn char_literals() {
     test(